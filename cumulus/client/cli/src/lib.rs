@@ -31,7 +31,7 @@ use sc_cli::RpcEndpoint;
 use sc_client_api::HeaderBackend;
 use sc_service::{
 	config::{PrometheusConfig, RpcBatchRequestConfig, TelemetryEndpoints},
-	BasePath, TransactionPoolOptions,
+	BasePath, TransactionPoolJournalOptions, TransactionPoolOptions,
 };
 use sp_core::hexdisplay::HexDisplay;
 use sp_runtime::traits::{Block as BlockT, Zero};
@@ -455,6 +455,13 @@ impl sc_cli::CliConfiguration for NormalizedRunCmd {
 		self.base.transaction_pool(is_dev)
 	}
 
+	fn transaction_pool_journal(
+		&self,
+		config_dir: &PathBuf,
+	) -> sc_cli::Result<Option<TransactionPoolJournalOptions>> {
+		self.base.transaction_pool_journal(config_dir)
+	}
+
 	fn max_runtime_instances(&self) -> sc_cli::Result<Option<usize>> {
 		self.base.max_runtime_instances()
 	}
@@ -653,6 +653,9 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	// The `ProxyType` of this runtime has no custom proxy type.
+	type CustomProxyTypeOrigin = frame_system::EnsureNever<()>;
+	type MaxCustomProxyCalls = ConstU32<32>;
 }
 
 parameter_types! {
@@ -2187,6 +2190,71 @@ impl_runtime_apis! {
 				key
 			)
 		}
+
+		fn trace_block(
+			block: Block,
+			config: pallet_revive::evm::TracerConfig
+		) -> Vec<(u32, pallet_revive::evm::Trace)> {
+			use pallet_revive::tracing::trace;
+			let mut tracer = Revive::evm_tracer(config);
+			let mut traces = vec![];
+			let (header, extrinsics) = block.deconstruct();
+
+			Executive::initialize_block(&header);
+			for (index, ext) in extrinsics.into_iter().enumerate() {
+				trace(tracer.as_tracer(), || {
+					let _ = Executive::apply_extrinsic(ext);
+				});
+
+				if let Some(tx_trace) = tracer.collect_trace() {
+					traces.push((index as u32, tx_trace));
+				}
+			}
+
+			traces
+		}
+
+		fn trace_tx(
+			block: Block,
+			tx_index: u32,
+			config: pallet_revive::evm::TracerConfig
+		) -> Option<pallet_revive::evm::Trace> {
+			use pallet_revive::tracing::trace;
+			let mut tracer = Revive::evm_tracer(config);
+			let (header, extrinsics) = block.deconstruct();
+
+			Executive::initialize_block(&header);
+			for (index, ext) in extrinsics.into_iter().enumerate() {
+				if index as u32 == tx_index {
+					trace(tracer.as_tracer(), || {
+						let _ = Executive::apply_extrinsic(ext);
+					});
+					break;
+				} else {
+					let _ = Executive::apply_extrinsic(ext);
+				}
+			}
+
+			tracer.collect_trace()
+		}
+
+		fn trace_call(
+			from: H160,
+			dest: Option<H160>,
+			value: U256,
+			input: Vec<u8>,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			config: pallet_revive::evm::TracerConfig
+		) -> Option<pallet_revive::evm::Trace> {
+			use pallet_revive::tracing::trace;
+			let mut tracer = Revive::evm_tracer(config);
+			trace(tracer.as_tracer(), || {
+				Self::eth_transact(from, dest, value, input, gas_limit, storage_deposit_limit)
+			});
+
+			tracer.collect_trace()
+		}
 	}
 }
 
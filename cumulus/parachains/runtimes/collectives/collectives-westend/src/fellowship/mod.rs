@@ -328,6 +328,9 @@ impl pallet_treasury::Config<FellowshipTreasuryInstance> for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = ConstU32<{ 30 * DAYS }>;
+	type SpendBudget = ();
+	type BudgetPeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxBudgetCharges = ConstU32<100>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments<
 		sp_core::ConstU8<1>,
@@ -222,4 +222,18 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn poke_deposit() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_proxy::weights::SubstrateWeight::<T>::poke_deposit()
+	}
+	fn set_custom_proxy_type(c: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_proxy::weights::SubstrateWeight::<T>::set_custom_proxy_type(c)
+	}
+	/// Storage: `Proxy::CustomProxyTypes` (r:1 w:0)
+	/// Proof: `Proxy::CustomProxyTypes` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn custom_proxy_type_allows() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_proxy::weights::SubstrateWeight::<T>::custom_proxy_type_allows()
+	}
 }
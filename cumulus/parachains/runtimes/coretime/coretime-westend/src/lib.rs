@@ -580,6 +580,9 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	// The `ProxyType` of this runtime has no custom proxy type.
+	type CustomProxyTypeOrigin = frame_system::EnsureNever<()>;
+	type MaxCustomProxyCalls = ConstU32<32>;
 }
 
 impl pallet_utility::Config for Runtime {
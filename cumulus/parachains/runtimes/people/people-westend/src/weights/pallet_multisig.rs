@@ -159,4 +159,36 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn poke_deposit(s: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_multisig::weights::SubstrateWeight::<T>::poke_deposit(s)
+	}
+	fn as_multi_weighted_create(s: u32, z: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_multisig::weights::SubstrateWeight::<T>::as_multi_weighted_create(s, z)
+	}
+	fn as_multi_weighted_approve(s: u32, z: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_multisig::weights::SubstrateWeight::<T>::as_multi_weighted_approve(s, z)
+	}
+	fn as_multi_weighted_complete(s: u32, z: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_multisig::weights::SubstrateWeight::<T>::as_multi_weighted_complete(s, z)
+	}
+	fn approve_as_multi_weighted_create(s: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_multisig::weights::SubstrateWeight::<T>::approve_as_multi_weighted_create(s)
+	}
+	fn approve_as_multi_weighted_approve(s: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_multisig::weights::SubstrateWeight::<T>::approve_as_multi_weighted_approve(s)
+	}
+	fn cancel_as_multi_weighted(s: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_multisig::weights::SubstrateWeight::<T>::cancel_as_multi_weighted(s)
+	}
+	fn poke_deposit_weighted(s: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_multisig::weights::SubstrateWeight::<T>::poke_deposit_weighted(s)
+	}
 }
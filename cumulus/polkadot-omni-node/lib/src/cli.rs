@@ -360,6 +360,13 @@ impl<Config: CliConfig> CliConfiguration<Self> for RelayChainCli<Config> {
 		self.base.base.transaction_pool(is_dev)
 	}
 
+	fn transaction_pool_journal(
+		&self,
+		config_dir: &PathBuf,
+	) -> sc_cli::Result<Option<sc_service::config::TransactionPoolJournalOptions>> {
+		self.base.base.transaction_pool_journal(config_dir)
+	}
+
 	fn trie_cache_maximum_size(&self) -> sc_cli::Result<Option<usize>> {
 		self.base.base.trie_cache_maximum_size()
 	}
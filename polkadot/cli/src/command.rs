@@ -214,7 +214,7 @@ where
 			.flatten();
 
 		let database_source = config.database.clone();
-		let task_manager = polkadot_service::build_full(
+		let (task_manager, backend) = polkadot_service::build_full(
 			config,
 			polkadot_service::NewFullParams {
 				is_parachain_node: polkadot_service::IsParachainNode::No,
@@ -237,13 +237,14 @@ where
 				enable_approval_voting_parallel: cli.run.enable_approval_voting_parallel,
 			},
 		)
-		.map(|full| full.task_manager)?;
+		.map(|full| (full.task_manager, full.backend))?;
 
 		if let Some(path) = database_source.path() {
-			sc_storage_monitor::StorageMonitorService::try_spawn(
+			sc_storage_monitor::StorageMonitorService::try_spawn_with_reclaimer(
 				cli.storage_monitor,
 				path.to_path_buf(),
 				&task_manager.spawn_essential_handle(),
+				move |bytes| backend.reclaim_space(bytes),
 			)?;
 		}
 
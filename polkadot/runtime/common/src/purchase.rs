@@ -554,6 +554,7 @@ mod tests {
 		type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 		type BlockNumberProvider = System;
 		const MAX_VESTING_SCHEDULES: u32 = 28;
+		const MAX_CURVE_POINTS: u32 = 16;
 	}
 
 	parameter_types! {
@@ -543,6 +543,9 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type SpendBudget = ();
+	type BudgetPeriod = SpendPeriod;
+	type MaxBudgetCharges = ConstU32<100>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
@@ -824,6 +827,7 @@ impl pallet_vesting::Config for Runtime {
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
+	const MAX_CURVE_POINTS: u32 = 16;
 }
 
 parameter_types! {
@@ -973,6 +977,9 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	// The `ProxyType` of this runtime has no custom proxy type.
+	type CustomProxyTypeOrigin = frame_system::EnsureNever<()>;
+	type MaxCustomProxyCalls = ConstU32<32>;
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1405,7 +1412,10 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>;
+	type Migrations = (
+		pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>,
+		pallet_conviction_voting::migration::v1::LazyMigrationV0ToV1<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -1738,6 +1748,7 @@ pub mod migrations {
         coretime::migration::MigrateToCoretime<Runtime, crate::xcm_config::XcmRouter, GetLegacyLeaseImpl, TIMESLICE_PERIOD>,
         parachains_configuration::migration::v12::MigrateToV12<Runtime>,
         parachains_on_demand::migration::MigrateV0ToV1<Runtime>,
+        pallet_vesting::migrations::v2::MigrateToV2<Runtime>,
 
         // permanent
         pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
@@ -2363,6 +2374,27 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_conviction_voting_runtime_api::ConvictionVotingApi<
+		Block,
+		AccountId,
+		Balance,
+		pallet_referenda::ReferendumIndex,
+	> for Runtime {
+		fn tally_breakdown(
+			poll_index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_conviction_voting::TallyBreakdown<Balance>> {
+			ConvictionVoting::api_tally_breakdown(poll_index)
+		}
+
+		fn poll_voters(
+			poll_index: pallet_referenda::ReferendumIndex,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<pallet_conviction_voting::PollVoter<AccountId, Balance>> {
+			ConvictionVoting::api_poll_voters(poll_index, start_after, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
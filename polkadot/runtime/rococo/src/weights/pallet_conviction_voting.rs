@@ -51,137 +51,110 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_conviction_voting::WeightInfo for WeightInfo<T> {
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn vote_new() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `13407`
-		//  Estimated: `42428`
-		// Minimum execution time: 128_378_000 picoseconds.
 		Weight::from_parts(131_028_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(5))
+			// `VotersOf` and `TallyBreakdownOf` were added after this was measured.
+			.saturating_add(Weight::from_parts(0, 2683))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn vote_existing() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `14128`
-		//  Estimated: `83866`
-		// Minimum execution time: 155_379_000 picoseconds.
 		Weight::from_parts(161_597_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
+			// `TallyBreakdownOf` was added after this was measured.
+			.saturating_add(Weight::from_parts(0, 2683))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn remove_vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `13918`
-		//  Estimated: `83866`
-		// Minimum execution time: 130_885_000 picoseconds.
 		Weight::from_parts(138_080_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(5))
+			// `VotersOf` and `TallyBreakdownOf` were added after this was measured.
+			.saturating_add(Weight::from_parts(0, 2683))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn remove_other_vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `13005`
-		//  Estimated: `30706`
-		// Minimum execution time: 71_743_000 picoseconds.
 		Weight::from_parts(75_170_000, 0)
 			.saturating_add(Weight::from_parts(0, 30706))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			// `VotersOf` and `TallyBreakdownOf` were added after this was measured.
+			.saturating_add(Weight::from_parts(0, 2683))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:512 w:512)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(311), added: 2786, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:50)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:512 w:512)
 	/// The range of component `r` is `[0, 512]`.
 	fn delegate(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `29602 + r * (365 ±0)`
-		//  Estimated: `83866 + r * (3411 ±0)`
-		// Minimum execution time: 58_504_000 picoseconds.
 		Weight::from_parts(814_301_018, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			// Standard Error: 59_961
 			.saturating_add(Weight::from_parts(20_002_833, 0).saturating_mul(r.into()))
+			// `TallyBreakdownOf` was added after this was measured.
+			.saturating_add(Weight::from_parts(0, 2683).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(45))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 3411).saturating_mul(r.into()))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:512 w:512)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(936), added: 3411, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:50)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:512 w:512)
 	/// The range of component `r` is `[0, 512]`.
 	fn undelegate(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `29555 + r * (365 ±0)`
-		//  Estimated: `83866 + r * (3411 ±0)`
-		// Minimum execution time: 34_970_000 picoseconds.
 		Weight::from_parts(771_155_804, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			// Standard Error: 57_795
 			.saturating_add(Weight::from_parts(19_781_645, 0).saturating_mul(r.into()))
+			// `TallyBreakdownOf` was added after this was measured.
+			.saturating_add(Weight::from_parts(0, 2683).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(43))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 3411).saturating_mul(r.into()))
 	}
+	fn delegate_split(r: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_conviction_voting::weights::SubstrateWeight::<T>::delegate_split(r)
+	}
+	fn undelegate_split(r: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_conviction_voting::weights::SubstrateWeight::<T>::undelegate_split(r)
+	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
@@ -148,6 +148,8 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
 	/// Storage: `XcmPallet::Queries` (r:1 w:1)
 	/// Proof: `XcmPallet::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Treasury::SpendCharges` (r:0 w:1)
+	/// Proof: `Treasury::SpendCharges` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn check_status() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `306`
@@ -156,10 +158,12 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(23_151_000, 0)
 			.saturating_add(Weight::from_parts(0, 5318))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCharges` (r:1 w:1)
+	/// Proof: `Treasury::SpendCharges` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn void_spend() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `278`
@@ -167,7 +171,21 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 12_169_000 picoseconds.
 		Weight::from_parts(12_484_000, 0)
 			.saturating_add(Weight::from_parts(0, 5318))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			// the proof of the charge of the spend, which was added after this was measured.
+			.saturating_add(Weight::from_parts(0, 2507))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn spend_recurring() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_treasury::weights::SubstrateWeight::<T>::spend_recurring()
+	}
+	fn release_instalment() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_treasury::weights::SubstrateWeight::<T>::release_instalment()
+	}
+	fn void_recurring_spend() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_treasury::weights::SubstrateWeight::<T>::void_recurring_spend()
 	}
 }
@@ -51,7 +51,7 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -61,10 +61,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 29_288_000 picoseconds.
 		Weight::from_parts(29_095_507, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 1_679
 			.saturating_add(Weight::from_parts(33_164, 0).saturating_mul(l.into()))
 			// Standard Error: 2_988
@@ -73,7 +73,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -83,10 +83,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 31_003_000 picoseconds.
 		Weight::from_parts(30_528_438, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 1_586
 			.saturating_add(Weight::from_parts(35_429, 0).saturating_mul(l.into()))
 			// Standard Error: 2_823
@@ -95,7 +95,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -107,10 +107,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 31_269_000 picoseconds.
 		Weight::from_parts(30_661_898, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 1_394
 			.saturating_add(Weight::from_parts(39_300, 0).saturating_mul(l.into()))
 			// Standard Error: 2_480
@@ -119,7 +119,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -131,10 +131,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 33_040_000 picoseconds.
 		Weight::from_parts(32_469_674, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 1_418
 			.saturating_add(Weight::from_parts(44_206, 0).saturating_mul(l.into()))
 			// Standard Error: 2_523
@@ -143,7 +143,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -155,10 +155,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 62_032_000 picoseconds.
 		Weight::from_parts(63_305_621, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 2_277
 			.saturating_add(Weight::from_parts(42_767, 0).saturating_mul(l.into()))
 			// Standard Error: 4_051
@@ -167,7 +167,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -179,10 +179,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `554 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `6196`
+		//  Estimated: `13538`
 		// Minimum execution time: 63_303_000 picoseconds.
 		Weight::from_parts(65_180_847, 0)
-			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 2_220
 			.saturating_add(Weight::from_parts(28_829, 0).saturating_mul(l.into()))
 			// Standard Error: 3_951
@@ -191,7 +191,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -203,10 +203,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `378 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 31_440_000 picoseconds.
 		Weight::from_parts(30_773_053, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 1_474
 			.saturating_add(Weight::from_parts(43_019, 0).saturating_mul(l.into()))
 			// Standard Error: 2_723
@@ -215,7 +215,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -227,10 +227,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `378 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 34_221_000 picoseconds.
 		Weight::from_parts(33_201_125, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 1_751
 			.saturating_add(Weight::from_parts(44_088, 0).saturating_mul(l.into()))
 			// Standard Error: 3_234
@@ -239,7 +239,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -251,10 +251,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 35_553_000 picoseconds.
 		Weight::from_parts(34_974_083, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 1_560
 			.saturating_add(Weight::from_parts(34_615, 0).saturating_mul(l.into()))
 			// Standard Error: 2_882
@@ -262,4 +262,12 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	fn vested_transfer_curve(l: u32, s: u32, p: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_vesting::weights::SubstrateWeight::<T>::vested_transfer_curve(l, s, p)
+	}
+	fn force_vested_transfer_curve(l: u32, s: u32, p: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_vesting::weights::SubstrateWeight::<T>::force_vested_transfer_curve(l, s, p)
+	}
 }
@@ -59,7 +59,7 @@ impl<T: frame_system::Config> polkadot_runtime_common::claims::WeightInfo for We
 	/// Storage: Claims Vesting (r:1 w:1)
 	/// Proof Skipped: Claims Vesting (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
@@ -69,10 +69,10 @@ impl<T: frame_system::Config> polkadot_runtime_common::claims::WeightInfo for We
 	fn claim() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `558`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 181_028_000 picoseconds.
 		Weight::from_parts(194_590_000, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
@@ -103,7 +103,7 @@ impl<T: frame_system::Config> polkadot_runtime_common::claims::WeightInfo for We
 	/// Storage: `Claims::Vesting` (r:1 w:1)
 	/// Proof: `Claims::Vesting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -113,10 +113,10 @@ impl<T: frame_system::Config> polkadot_runtime_common::claims::WeightInfo for We
 	fn claim_attest() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `558`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 187_964_000 picoseconds.
 		Weight::from_parts(202_553_000, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
@@ -131,7 +131,7 @@ impl<T: frame_system::Config> polkadot_runtime_common::claims::WeightInfo for We
 	/// Storage: `Claims::Vesting` (r:1 w:1)
 	/// Proof: `Claims::Vesting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -141,10 +141,10 @@ impl<T: frame_system::Config> polkadot_runtime_common::claims::WeightInfo for We
 	fn attest() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `632`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 78_210_000 picoseconds.
 		Weight::from_parts(84_581_000, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
@@ -718,6 +718,7 @@ parameter_types! {
 	pub const BondingDuration: sp_staking::EraIndex = 2;
 	// 1 era in which slashes can be cancelled (6 hours).
 	pub const SlashDeferDuration: sp_staking::EraIndex = 1;
+	pub const SlashAppealDeposit: Balance = 10 * UNITS;
 	pub const MaxExposurePageSize: u32 = 64;
 	// Note: this is not really correct as Max Nominators is (MaxExposurePageSize * page_count) but
 	// this is an unbounded number. We just set it to a reasonably high value, 1 full page
@@ -740,6 +741,7 @@ impl pallet_staking::Config for Runtime {
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
+	type SlashAppealDeposit = SlashAppealDeposit;
 	type SessionInterface = Self;
 	type EraPayout = EraPayout;
 	type MaxExposurePageSize = MaxExposurePageSize;
@@ -824,6 +826,9 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type SpendBudget = ();
+	type BudgetPeriod = SpendPeriod;
+	type MaxBudgetCharges = ConstU32<100>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
@@ -1040,6 +1045,7 @@ impl pallet_vesting::Config for Runtime {
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
+	const MAX_CURVE_POINTS: u32 = 16;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -1206,6 +1212,9 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	// The `ProxyType` of this runtime has no custom proxy type.
+	type CustomProxyTypeOrigin = frame_system::EnsureNever<()>;
+	type MaxCustomProxyCalls = ConstU32<32>;
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1497,6 +1506,67 @@ impl identity_migrator::Config for Runtime {
 parameter_types! {
 	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
 	pub const MaxPointsToBalance: u8 = 10;
+	pub const MaxAutoNominationCommission: Perbill = Perbill::from_percent(10);
+	pub const MaxAutoNominationCandidates: u32 = 4 * MaxNominations::get();
+}
+
+/// Selects the validators of an era whose commission is at most `MaxAutoNominationCommission`,
+/// preferring the ones that earned the most era points in the previous era, then the ones with the
+/// highest self-stake.
+///
+/// Each pool nominates its own window of the selected candidates, starting at an offset derived
+/// from its pool id, so that the stake of the pools is spread over all of them instead of only
+/// backing the best ones.
+pub struct PoolValidatorSelector;
+impl pallet_nomination_pools::SelectValidators<AccountId> for PoolValidatorSelector {
+	fn select(era: sp_staking::EraIndex, max: u32) -> Vec<AccountId> {
+		let points =
+			pallet_staking::ErasRewardPoints::<Runtime>::get(era.saturating_sub(1)).individual;
+		let mut candidates = pallet_staking::ErasStakersOverview::<Runtime>::iter_prefix(era)
+			.filter_map(|(validator, overview)| {
+				let prefs = pallet_staking::ErasValidatorPrefs::<Runtime>::get(era, &validator);
+				(!prefs.blocked && prefs.commission <= MaxAutoNominationCommission::get()).then(
+					|| {
+						(
+							points.get(&validator).copied().unwrap_or_default(),
+							overview.own,
+							validator,
+						)
+					},
+				)
+			})
+			.collect::<Vec<_>>();
+		candidates.sort_by(|a, b| (b.0, b.1).cmp(&(a.0, a.1)));
+		candidates
+			.into_iter()
+			.take(max as usize)
+			.map(|(_, _, validator)| validator)
+			.collect()
+	}
+
+	fn targets_of(
+		pool_id: pallet_nomination_pools::PoolId,
+		candidates: &[AccountId],
+		max: u32,
+	) -> Vec<AccountId> {
+		if candidates.is_empty() {
+			return Vec::new()
+		}
+		// consecutive pools nominate consecutive windows, wrapping around the candidates.
+		let count = candidates.len().min(max as usize);
+		let offset = (pool_id as usize).wrapping_mul(count) % candidates.len();
+		candidates.iter().cycle().skip(offset).take(count).cloned().collect()
+	}
+
+	fn weight(_max: u32) -> Weight {
+		let validators = u64::from(MaxActiveValidators::get());
+		// the era points, and the exposure overview and preferences of each validator of the era.
+		let reads = <Runtime as frame_system::Config>::DbWeight::get().reads(1 + 2 * validators);
+		// the filtering and the sort of the validators are charged 1µs per comparison, far more
+		// than comparing two pairs of integers costs.
+		let comparisons = validators.saturating_mul(u64::from(validators.max(2).ilog2()) + 1);
+		reads.saturating_add(Weight::from_parts(comparisons.saturating_mul(1_000_000), 0))
+	}
 }
 
 impl pallet_nomination_pools::Config for Runtime {
@@ -1516,6 +1586,9 @@ impl pallet_nomination_pools::Config for Runtime {
 	type PalletId = PoolsPalletId;
 	type MaxPointsToBalance = MaxPointsToBalance;
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
+	type ValidatorSelector = PoolValidatorSelector;
+	type MaxAutoNominations = MaxNominations;
+	type MaxAutoNominationCandidates = MaxAutoNominationCandidates;
 }
 
 parameter_types! {
@@ -1531,6 +1604,7 @@ impl pallet_delegated_staking::Config for Runtime {
 	type SlashRewardFraction = SlashRewardFraction;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type CoreStaking = Staking;
+	type WeightInfo = pallet_delegated_staking::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_root_testing::Config for Runtime {
@@ -1544,7 +1618,10 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>;
+	type Migrations = (
+		pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>,
+		pallet_conviction_voting::migration::v1::LazyMigrationV0ToV1<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -1839,6 +1916,7 @@ pub mod migrations {
 		parachains_shared::migration::MigrateToV1<Runtime>,
 		parachains_scheduler::migration::MigrateV2ToV3<Runtime>,
 		pallet_staking::migrations::v16::MigrateV15ToV16<Runtime>,
+		pallet_vesting::migrations::v2::MigrateToV2<Runtime>,
 		// permanent
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	);
@@ -1890,6 +1968,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_beefy_mmr, BeefyMmrLeaf]
 		[pallet_conviction_voting, ConvictionVoting]
+		[pallet_delegated_staking, DelegatedStaking]
 		[pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
 		[frame_election_provider_support, ElectionProviderBench::<Runtime>]
 		[pallet_fast_unstake, FastUnstake]
@@ -2487,6 +2566,27 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_conviction_voting_runtime_api::ConvictionVotingApi<
+		Block,
+		AccountId,
+		Balance,
+		pallet_referenda::ReferendumIndex,
+	> for Runtime {
+		fn tally_breakdown(
+			poll_index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_conviction_voting::TallyBreakdown<Balance>> {
+			ConvictionVoting::api_tally_breakdown(poll_index)
+		}
+
+		fn poll_voters(
+			poll_index: pallet_referenda::ReferendumIndex,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<pallet_conviction_voting::PollVoter<AccountId, Balance>> {
+			ConvictionVoting::api_poll_voters(poll_index, start_after, limit)
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<
 		Block,
 		AccountId,
@@ -2545,6 +2645,13 @@ sp_api::impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn simulate_nominator_rewards(
+			amount: Balance,
+			targets: Vec<AccountId>,
+		) -> Vec<sp_staking::RewardProjection<AccountId, Balance>> {
+			Staking::api_simulate_nominator_rewards(amount, targets)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -821,4 +821,24 @@ impl<T: frame_system::Config> pallet_nomination_pools::WeightInfo for WeightInfo
 			.saturating_add(T::DbWeight::get().reads(15))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	fn set_auto_nominate() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_nomination_pools::weights::SubstrateWeight::<T>::set_auto_nominate()
+	}
+	/// Storage: `NominationPools::AutoNominatingPools` (r:1 w:0)
+	/// Proof: `NominationPools::AutoNominatingPools` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn auto_nominate(n: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_nomination_pools::weights::SubstrateWeight::<T>::auto_nominate(n)
+	}
+	/// Storage: `NominationPools::PointsTransferPermissions` (r:1 w:0)
+	/// Proof: `NominationPools::PointsTransferPermissions` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn transfer_points() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_nomination_pools::weights::SubstrateWeight::<T>::transfer_points()
+	}
+	fn set_points_transfer_permission() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_nomination_pools::weights::SubstrateWeight::<T>::set_points_transfer_permission()
+	}
 }
@@ -826,4 +826,34 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `Staking::UnappliedSlashes` (r:1 w:0)
+	/// Proof: `Staking::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 1000]`.
+	fn appeal_slash(s: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_staking::weights::SubstrateWeight::<T>::appeal_slash(s)
+	}
+	/// Storage: `Staking::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `Staking::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 1000]`.
+	fn reduce_deferred_slash(s: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_staking::weights::SubstrateWeight::<T>::reduce_deferred_slash(s)
+	}
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_slash_appeal() -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_staking::weights::SubstrateWeight::<T>::reject_slash_appeal()
+	}
 }
@@ -51,7 +51,7 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -61,10 +61,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `348 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 35_225_000 picoseconds.
 		Weight::from_parts(34_420_748, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 2_341
 			.saturating_add(Weight::from_parts(41_794, 0).saturating_mul(l.into()))
 			// Standard Error: 4_166
@@ -73,7 +73,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -83,10 +83,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `348 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 38_507_000 picoseconds.
 		Weight::from_parts(38_552_717, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 2_406
 			.saturating_add(Weight::from_parts(42_332, 0).saturating_mul(l.into()))
 			// Standard Error: 4_282
@@ -95,7 +95,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -107,10 +107,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 36_919_000 picoseconds.
 		Weight::from_parts(35_087_984, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 2_435
 			.saturating_add(Weight::from_parts(66_131, 0).saturating_mul(l.into()))
 			// Standard Error: 4_333
@@ -119,7 +119,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -131,10 +131,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 40_393_000 picoseconds.
 		Weight::from_parts(39_522_987, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 1_958
 			.saturating_add(Weight::from_parts(46_626, 0).saturating_mul(l.into()))
 			// Standard Error: 3_484
@@ -143,7 +143,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
@@ -155,10 +155,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `522 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 72_925_000 picoseconds.
 		Weight::from_parts(75_858_529, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 3_995
 			.saturating_add(Weight::from_parts(70_032, 0).saturating_mul(l.into()))
 			// Standard Error: 7_108
@@ -167,7 +167,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
@@ -179,10 +179,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `625 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `6196`
+		//  Estimated: `13538`
 		// Minimum execution time: 74_405_000 picoseconds.
 		Weight::from_parts(78_253_087, 0)
-			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 3_708
 			.saturating_add(Weight::from_parts(56_748, 0).saturating_mul(l.into()))
 			// Standard Error: 6_598
@@ -191,7 +191,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -203,10 +203,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `449 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 37_715_000 picoseconds.
 		Weight::from_parts(36_483_330, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 2_146
 			.saturating_add(Weight::from_parts(55_976, 0).saturating_mul(l.into()))
 			// Standard Error: 3_964
@@ -215,7 +215,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(10073), added: 12548, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -227,10 +227,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `449 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 42_102_000 picoseconds.
 		Weight::from_parts(41_671_515, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 13538))
 			// Standard Error: 2_743
 			.saturating_add(Weight::from_parts(47_496, 0).saturating_mul(l.into()))
 			// Standard Error: 5_065
@@ -240,7 +240,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	}
 
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(10073), added: 12548, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -252,9 +252,9 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn force_remove_vesting_schedule(l: u32, s: u32) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `555 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `13538`
 		// Minimum execution time: 41_497_000 picoseconds.
-		Weight::from_parts(38_763_834, 4764)
+		Weight::from_parts(38_763_834, 13538)
 			// Standard Error: 2_030
 			.saturating_add(Weight::from_parts(99_580, 0).saturating_mul(l.into()))
 			// Standard Error: 3_750
@@ -262,4 +262,12 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	fn vested_transfer_curve(l: u32, s: u32, p: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_vesting::weights::SubstrateWeight::<T>::vested_transfer_curve(l, s, p)
+	}
+	fn force_vested_transfer_curve(l: u32, s: u32, p: u32, ) -> Weight {
+		// not benchmarked on this runtime yet.
+		pallet_vesting::weights::SubstrateWeight::<T>::force_vested_transfer_curve(l, s, p)
+	}
 }
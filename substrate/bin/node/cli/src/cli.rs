@@ -89,9 +89,15 @@ pub enum Subcommand {
 	/// Export the state of a given block into a chain spec.
 	ExportState(sc_cli::ExportStateCmd),
 
+	/// Export a snapshot of the state of a finalized block.
+	ExportSnapshot(sc_cli::ExportSnapshotCmd),
+
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
+	/// Import a state snapshot into an empty database.
+	ImportSnapshot(sc_cli::ImportSnapshotCmd),
+
 	/// Remove the whole chain.
 	PurgeChain(sc_cli::PurgeChainCmd),
 
@@ -384,6 +384,8 @@ pub struct NewFullBase {
 	pub task_manager: TaskManager,
 	/// The client instance of the node.
 	pub client: Arc<FullClient>,
+	/// The database backend of the node.
+	pub backend: Arc<FullBackend>,
 	/// The networking service of the node.
 	pub network: Arc<dyn NetworkService>,
 	/// The syncing service of the node.
@@ -804,6 +806,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	Ok(NewFullBase {
 		task_manager,
 		client,
+		backend,
 		network,
 		sync: sync_service,
 		transaction_pool,
@@ -816,34 +819,31 @@ pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceE
 	let mixnet_config = cli.mixnet_params.config(config.role.is_authority());
 	let database_path = config.database.path().map(Path::to_path_buf);
 
-	let task_manager = match config.network.network_backend {
-		sc_network::config::NetworkBackendType::Libp2p => {
-			let task_manager = new_full_base::<sc_network::NetworkWorker<_, _>>(
+	let (task_manager, backend) = match config.network.network_backend {
+		sc_network::config::NetworkBackendType::Libp2p =>
+			new_full_base::<sc_network::NetworkWorker<_, _>>(
 				config,
 				mixnet_config,
 				cli.no_hardware_benchmarks,
 				|_, _| (),
 			)
-			.map(|NewFullBase { task_manager, .. }| task_manager)?;
-			task_manager
-		},
-		sc_network::config::NetworkBackendType::Litep2p => {
-			let task_manager = new_full_base::<sc_network::Litep2pNetworkBackend>(
+			.map(|NewFullBase { task_manager, backend, .. }| (task_manager, backend))?,
+		sc_network::config::NetworkBackendType::Litep2p =>
+			new_full_base::<sc_network::Litep2pNetworkBackend>(
 				config,
 				mixnet_config,
 				cli.no_hardware_benchmarks,
 				|_, _| (),
 			)
-			.map(|NewFullBase { task_manager, .. }| task_manager)?;
-			task_manager
-		},
+			.map(|NewFullBase { task_manager, backend, .. }| (task_manager, backend))?,
 	};
 
 	if let Some(database_path) = database_path {
-		sc_storage_monitor::StorageMonitorService::try_spawn(
+		sc_storage_monitor::StorageMonitorService::try_spawn_with_reclaimer(
 			cli.storage_monitor,
 			database_path,
 			&task_manager.spawn_essential_handle(),
+			move |bytes| backend.reclaim_space(bytes),
 		)
 		.map_err(|e| ServiceError::Application(e.into()))?;
 	}
@@ -423,6 +423,8 @@ pub enum ProxyType {
 	NonTransfer,
 	Governance,
 	Staking,
+	/// A proxy type whose allowed calls are set on-chain, see `Proxy::set_custom_proxy_type`.
+	Custom(u32),
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -440,6 +442,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Uniques(..) |
 					RuntimeCall::Nfts(..) |
 					RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer { .. }) |
+					RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer_curve { .. }) |
 					RuntimeCall::Indices(pallet_indices::Call::transfer { .. })
 			),
 			ProxyType::Governance => matches!(
@@ -454,6 +457,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::Staking => {
 				matches!(c, RuntimeCall::Staking(..) | RuntimeCall::FastUnstake(..))
 			},
+			ProxyType::Custom(id) => Proxy::custom_proxy_type_allows(*id, c),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -461,6 +465,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			// Custom proxy types may allow transfers.
+			(ProxyType::NonTransfer, ProxyType::Custom(_)) => false,
 			(ProxyType::NonTransfer, _) => true,
 			_ => false,
 		}
@@ -481,6 +487,8 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type CustomProxyTypeOrigin = EnsureRoot<AccountId>;
+	type MaxCustomProxyCalls = ConstU32<32>;
 }
 
 parameter_types! {
@@ -695,6 +703,7 @@ parameter_types! {
 	pub const SessionsPerEra: sp_staking::SessionIndex = 6;
 	pub const BondingDuration: sp_staking::EraIndex = 24 * 28;
 	pub const SlashDeferDuration: sp_staking::EraIndex = 24 * 7; // 1/4 the bonding duration.
+	pub const SlashAppealDeposit: Balance = 100 * DOLLARS;
 	pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
 	pub const MaxNominators: u32 = 64;
 	pub const MaxControllersInDeprecationBatch: u32 = 5900;
@@ -728,6 +737,7 @@ impl pallet_staking::Config for Runtime {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>,
 	>;
+	type SlashAppealDeposit = SlashAppealDeposit;
 	type SessionInterface = Self;
 	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
 	type NextNewSession = Session;
@@ -963,6 +973,9 @@ impl pallet_nomination_pools::Config for Runtime {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>,
 	>;
+	type ValidatorSelector = ();
+	type MaxAutoNominations = ConstU32<16>;
+	type MaxAutoNominationCandidates = ConstU32<64>;
 }
 
 parameter_types! {
@@ -1306,6 +1319,9 @@ impl pallet_treasury::Config for Runtime {
 	type Paymaster = PayAssetFromAccount<NativeAndAssets, TreasuryAccount>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type SpendBudget = ();
+	type BudgetPeriod = SpendPeriod;
+	type MaxBudgetCharges = ConstU32<100>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = PalletTreasuryArguments;
@@ -1704,9 +1720,12 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
-	// `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
-	// highest number of schedules that encodes less than 2^10.
-	const MAX_VESTING_SCHEDULES: u32 = 28;
+	// A schedule encodes to at most 190 bytes: 37 bytes for a linear schedule, and up to 190 bytes
+	// for a curve of 8 points. 5 schedules get encoded as 951 bytes, which is the highest number of
+	// schedules that encodes less than 2^10. The schedules of an account beyond that are merged by
+	// `pallet_vesting::migrations::v2::MigrateToV2`.
+	const MAX_VESTING_SCHEDULES: u32 = 5;
+	const MAX_CURVE_POINTS: u32 = 8;
 }
 
 impl pallet_mmr::Config for Runtime {
@@ -2220,7 +2239,7 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = ();
+	type Migrations = pallet_conviction_voting::migration::v1::LazyMigrationV0ToV1<Runtime>;
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -2729,6 +2748,7 @@ type Migrations = (
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
 	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	pallet_vesting::migrations::v2::MigrateToV2<Runtime>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -3004,6 +3024,27 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_conviction_voting_runtime_api::ConvictionVotingApi<
+		Block,
+		AccountId,
+		Balance,
+		pallet_referenda::ReferendumIndex,
+	> for Runtime {
+		fn tally_breakdown(
+			poll_index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_conviction_voting::TallyBreakdown<Balance>> {
+			ConvictionVoting::api_tally_breakdown(poll_index)
+		}
+
+		fn poll_voters(
+			poll_index: pallet_referenda::ReferendumIndex,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<pallet_conviction_voting::PollVoter<AccountId, Balance>> {
+			ConvictionVoting::api_poll_voters(poll_index, start_after, limit)
+		}
+	}
+
 	impl pallet_nomination_pools_runtime_api::NominationPoolsApi<Block, AccountId, Balance> for Runtime {
 		fn pending_rewards(who: AccountId) -> Balance {
 			NominationPools::api_pending_rewards(who).unwrap_or_default()
@@ -3058,6 +3099,13 @@ impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn simulate_nominator_rewards(
+			amount: Balance,
+			targets: Vec<AccountId>,
+		) -> Vec<sp_staking::RewardProjection<AccountId, Balance>> {
+			Staking::api_simulate_nominator_rewards(amount, targets)
+		}
 	}
 
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -3315,6 +3363,71 @@ impl_runtime_apis! {
 				key
 			)
 		}
+
+		fn trace_block(
+			block: Block,
+			config: pallet_revive::evm::TracerConfig
+		) -> Vec<(u32, pallet_revive::evm::Trace)> {
+			use pallet_revive::tracing::trace;
+			let mut tracer = Revive::evm_tracer(config);
+			let mut traces = vec![];
+			let (header, extrinsics) = block.deconstruct();
+
+			Executive::initialize_block(&header);
+			for (index, ext) in extrinsics.into_iter().enumerate() {
+				trace(tracer.as_tracer(), || {
+					let _ = Executive::apply_extrinsic(ext);
+				});
+
+				if let Some(tx_trace) = tracer.collect_trace() {
+					traces.push((index as u32, tx_trace));
+				}
+			}
+
+			traces
+		}
+
+		fn trace_tx(
+			block: Block,
+			tx_index: u32,
+			config: pallet_revive::evm::TracerConfig
+		) -> Option<pallet_revive::evm::Trace> {
+			use pallet_revive::tracing::trace;
+			let mut tracer = Revive::evm_tracer(config);
+			let (header, extrinsics) = block.deconstruct();
+
+			Executive::initialize_block(&header);
+			for (index, ext) in extrinsics.into_iter().enumerate() {
+				if index as u32 == tx_index {
+					trace(tracer.as_tracer(), || {
+						let _ = Executive::apply_extrinsic(ext);
+					});
+					break;
+				} else {
+					let _ = Executive::apply_extrinsic(ext);
+				}
+			}
+
+			tracer.collect_trace()
+		}
+
+		fn trace_call(
+			from: H160,
+			dest: Option<H160>,
+			value: U256,
+			input: Vec<u8>,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			config: pallet_revive::evm::TracerConfig
+		) -> Option<pallet_revive::evm::Trace> {
+			use pallet_revive::tracing::trace;
+			let mut tracer = Revive::evm_tracer(config);
+			trace(tracer.as_tracer(), || {
+				Self::eth_transact(from, dest, value, input, gas_limit, storage_deposit_limit)
+			});
+
+			tracer.collect_trace()
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
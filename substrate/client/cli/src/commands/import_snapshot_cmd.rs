@@ -0,0 +1,95 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{ImportParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use sc_client_api::{HeaderBackend, ProofProvider};
+use sc_service::chain_ops::import_snapshot;
+use sp_runtime::traits::Block as BlockT;
+use std::{
+	fmt::Debug,
+	fs,
+	io::{self, Read},
+	path::PathBuf,
+	str::FromStr,
+	sync::Arc,
+};
+
+/// The `import-snapshot` command used to bootstrap an empty database from a snapshot.
+#[derive(Debug, Parser)]
+pub struct ImportSnapshotCmd {
+	/// Input file or stdin if unspecified.
+	#[arg()]
+	pub input: Option<PathBuf>,
+
+	/// Hash of the block the snapshot is expected to be taken at.
+	///
+	/// The justifications of the snapshot block are not verified, so the snapshot is only
+	/// imported if its block has this hash, which should come from a trusted source.
+	#[arg(long, value_name = "HASH")]
+	pub expected_hash: String,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl ImportSnapshotCmd {
+	/// Run the import-snapshot command
+	pub async fn run<B, C, IQ>(&self, client: Arc<C>, import_queue: IQ) -> error::Result<()>
+	where
+		B: BlockT,
+		C: HeaderBackend<B> + ProofProvider<B>,
+		IQ: sc_service::ImportQueue<B>,
+		<B::Hash as FromStr>::Err: Debug,
+	{
+		let expected_hash = self
+			.expected_hash
+			.strip_prefix("0x")
+			.unwrap_or(&self.expected_hash)
+			.parse::<B::Hash>()
+			.map_err(|e| format!("Failed to parse expected hash: {e:?}"))?;
+
+		let file: Box<dyn Read> = match &self.input {
+			Some(filename) => Box::new(fs::File::open(filename)?),
+			None => Box::new(io::stdin()),
+		};
+
+		import_snapshot(client, import_queue, file, expected_hash)
+			.await
+			.map_err(Into::into)
+	}
+}
+
+impl CliConfiguration for ImportSnapshotCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
@@ -28,12 +28,13 @@ use clap::Parser;
 use regex::Regex;
 use sc_service::{
 	config::{
-		BasePath, IpNetwork, PrometheusConfig, RpcBatchRequestConfig, TransactionPoolOptions,
+		BasePath, IpNetwork, PrometheusConfig, RpcBatchRequestConfig,
+		TransactionPoolJournalOptions, TransactionPoolOptions,
 	},
 	ChainSpec, Role,
 };
 use sc_telemetry::TelemetryEndpoints;
-use std::num::NonZeroU32;
+use std::{num::NonZeroU32, path::PathBuf};
 
 /// The `run` command used to run a node.
 #[derive(Debug, Clone, Parser)]
@@ -334,6 +335,13 @@ impl CliConfiguration for RunCmd {
 		Ok(self.pool_config.transaction_pool(is_dev))
 	}
 
+	fn transaction_pool_journal(
+		&self,
+		config_dir: &PathBuf,
+	) -> Result<Option<TransactionPoolJournalOptions>> {
+		Ok(self.pool_config.journal(config_dir))
+	}
+
 	fn max_runtime_instances(&self) -> Result<Option<usize>> {
 		Ok(Some(self.runtime_params.max_runtime_instances))
 	}
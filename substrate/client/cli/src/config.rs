@@ -30,7 +30,7 @@ use sc_service::{
 		BasePath, Configuration, DatabaseSource, ExecutorConfiguration, IpNetwork, KeystoreConfig,
 		NetworkConfiguration, NodeKeyConfig, OffchainWorkerConfig, PrometheusConfig, PruningMode,
 		Role, RpcBatchRequestConfig, RpcConfiguration, RpcMethods, TelemetryEndpoints,
-		TransactionPoolOptions, WasmExecutionMethod,
+		TransactionPoolJournalOptions, TransactionPoolOptions, WasmExecutionMethod,
 	},
 	BlocksPruning, ChainSpec, TracingReceiver,
 };
@@ -157,6 +157,16 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(Default::default())
 	}
 
+	/// Get the transaction pool journal options, given the chain's data directory.
+	///
+	/// By default the transaction pool is not journalled.
+	fn transaction_pool_journal(
+		&self,
+		_config_dir: &PathBuf,
+	) -> Result<Option<TransactionPoolJournalOptions>> {
+		Ok(None)
+	}
+
 	/// Get the network configuration
 	///
 	/// By default this is retrieved from `NetworkParams` if it is available otherwise it creates
@@ -504,6 +514,10 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let keystore = self.keystore_config(&config_dir)?;
 		let telemetry_endpoints = self.telemetry_endpoints(&chain_spec)?;
 		let runtime_cache_size = self.runtime_cache_size()?;
+		let mut transaction_pool = self.transaction_pool(is_dev)?;
+		if let Some(journal) = self.transaction_pool_journal(&config_dir)? {
+			transaction_pool = transaction_pool.with_journal(journal);
+		}
 
 		let rpc_addrs: Option<Vec<sc_service::config::RpcEndpoint>> = self
 			.rpc_addr(DCV::rpc_listen_port())?
@@ -513,7 +527,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			impl_name: C::impl_name(),
 			impl_version: C::impl_version(),
 			tokio_handle,
-			transaction_pool: self.transaction_pool(is_dev)?,
+			transaction_pool,
 			network: self.network_config(
 				&chain_spec,
 				is_dev,
@@ -64,6 +64,16 @@ pub struct PruningParams {
 		default_value = "archive-canonical"
 	)]
 	pub blocks_pruning: DatabasePruningMode,
+
+	/// Specify the size the database is pruned to, in MiB.
+	///
+	/// The bodies and justifications of the oldest finalized blocks, and their state unless the
+	/// state pruning mode is an archive mode, are pruned while the database exceeds the given
+	/// size. The last finalized block is always kept.
+	///
+	/// When given, this takes precedence over `--blocks-pruning`.
+	#[arg(long, value_name = "MiB")]
+	pub db_size_budget: Option<u64>,
 }
 
 impl PruningParams {
@@ -74,7 +84,10 @@ impl PruningParams {
 
 	/// Get the block pruning value from the parameters
 	pub fn blocks_pruning(&self) -> error::Result<BlocksPruning> {
-		Ok(self.blocks_pruning.into())
+		Ok(match self.db_size_budget {
+			Some(budget) => BlocksPruning::Size(budget.saturating_mul(1024 * 1024)),
+			None => self.blocks_pruning.into(),
+		})
 	}
 }
 
@@ -161,5 +174,10 @@ mod tests {
 
 		assert!(matches!(dbg!(pruning.state_pruning), Some(DatabasePruningMode::ArchiveCanonical)));
 		assert!(matches!(pruning.blocks_pruning, DatabasePruningMode::ArchiveCanonical));
+
+		let Cli { pruning } =
+			Cli::parse_from(["", "--blocks-pruning=1000", "--db-size-budget=2048"]);
+
+		assert_eq!(pruning.blocks_pruning().unwrap(), BlocksPruning::Size(2048 * 1024 * 1024));
 	}
 }
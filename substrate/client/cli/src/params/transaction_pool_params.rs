@@ -17,7 +17,12 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use clap::{Args, ValueEnum};
-use sc_transaction_pool::TransactionPoolOptions;
+use sc_transaction_pool::{PoolPolicy, TransactionPoolJournalOptions, TransactionPoolOptions};
+use sp_runtime::Percent;
+use std::{path::Path, time::Duration};
+
+/// The default journal path, relative to the chain's data directory.
+const DEFAULT_TXPOOL_JOURNAL_PATH: &str = "txpool/journal";
 
 /// Type of transaction pool to be used
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -59,6 +64,50 @@ pub struct TransactionPoolParams {
 	/// The type of transaction pool to be instantiated.
 	#[arg(long, value_enum, default_value_t = TransactionPoolType::SingleState)]
 	pub pool_type: TransactionPoolType,
+
+	/// Persist the transaction pool across restarts.
+	///
+	/// The ready and future transactions are written to a journal in the chain's data directory
+	/// periodically and on shutdown. On startup, they are re-validated and submitted back to the
+	/// pool.
+	#[arg(long)]
+	pub pool_journal: bool,
+
+	/// How often the transaction pool is written to its journal, in seconds.
+	#[arg(
+		long,
+		value_name = "SECONDS",
+		default_value_t = 60,
+		value_parser = clap::value_parser!(u64).range(1..)
+	)]
+	pub pool_journal_interval: u64,
+
+	/// Minimum priority increase, in percent, required for a transaction to replace the ones it
+	/// conflicts with.
+	///
+	/// The priority is usually derived from the fee and tip, so this is the minimum fee bump of a
+	/// replacement.
+	#[arg(
+		long,
+		value_name = "PERCENT",
+		default_value_t = 0,
+		value_parser = clap::value_parser!(u8).range(0..=100)
+	)]
+	pub pool_replacement_bump: u8,
+
+	/// Maximum number of ready transactions of a single sender in the pool.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_max_ready_per_sender: Option<usize>,
+
+	/// Maximum number of future transactions of a single sender in the pool.
+	#[arg(long, value_name = "COUNT")]
+	pub pool_max_future_per_sender: Option<usize>,
+
+	/// How long a future transaction may wait for its nonce gap to be filled, in seconds.
+	///
+	/// Once exceeded, all future transactions of its sender are dropped from the pool.
+	#[arg(long, value_name = "SECONDS")]
+	pub pool_future_gap_timeout: Option<u64>,
 }
 
 impl TransactionPoolParams {
@@ -71,5 +120,19 @@ impl TransactionPoolParams {
 			self.pool_type.into(),
 			is_dev,
 		)
+		.with_policy(PoolPolicy {
+			min_replacement_bump: Percent::from_percent(self.pool_replacement_bump),
+			max_ready_per_sender: self.pool_max_ready_per_sender,
+			max_future_per_sender: self.pool_max_future_per_sender,
+			future_gap_timeout: self.pool_future_gap_timeout.map(Duration::from_secs),
+		})
+	}
+
+	/// The transaction pool journal options, if enabled, given the chain's data directory.
+	pub fn journal(&self, config_dir: &Path) -> Option<TransactionPoolJournalOptions> {
+		self.pool_journal.then(|| TransactionPoolJournalOptions {
+			path: config_dir.join(DEFAULT_TXPOOL_JOURNAL_PATH),
+			save_interval: Duration::from_secs(self.pool_journal_interval),
+		})
 	}
 }
@@ -48,13 +48,16 @@ use std::{
 	collections::{HashMap, HashSet},
 	io,
 	path::{Path, PathBuf},
-	sync::Arc,
+	sync::{
+		atomic::{AtomicU64, Ordering as AtomicOrdering},
+		Arc,
+	},
 };
 
 use crate::{
 	pinned_blocks_cache::PinnedBlocksCache,
 	record_stats_state::RecordStatsState,
-	stats::StateUsageStats,
+	stats::{DatabaseSize, StateUsageStats},
 	utils::{meta_keys, read_db, read_meta, DatabaseType, Meta},
 };
 use codec::{Decode, Encode};
@@ -102,6 +105,10 @@ pub use bench::BenchmarkingState;
 
 const CACHE_HEADERS: usize = 8;
 
+/// Maximum number of blocks pruned on each finalization while the database exceeds its size
+/// budget.
+const MAX_BLOCKS_PRUNED_BY_SIZE: u32 = 16;
+
 /// DB-backed patricia trie state, transaction type is an overlay of changes to commit.
 pub type DbState<H> = sp_state_machine::TrieBackend<Arc<dyn sp_state_machine::Storage<H>>, H>;
 
@@ -323,6 +330,12 @@ pub enum BlocksPruning {
 	KeepFinalized,
 	/// Keep N recent finalized blocks.
 	Some(u32),
+	/// Keep the recent finalized blocks that fit into the given number of bytes.
+	///
+	/// The oldest finalized blocks, and their state unless the state pruning is an archive mode,
+	/// are pruned while the database exceeds the given size. The last finalized block is always
+	/// kept.
+	Size(u64),
 }
 
 impl BlocksPruning {
@@ -330,7 +343,7 @@ impl BlocksPruning {
 	pub fn is_archive(&self) -> bool {
 		match *self {
 			BlocksPruning::KeepAll | BlocksPruning::KeepFinalized => true,
-			BlocksPruning::Some(_) => false,
+			BlocksPruning::Some(_) | BlocksPruning::Size(_) => false,
 		}
 	}
 }
@@ -389,6 +402,18 @@ impl DatabaseSource {
 		}
 	}
 
+	/// Return all the paths the database may be stored at on disk.
+	fn disk_paths(&self) -> Vec<PathBuf> {
+		match self {
+			DatabaseSource::Auto { paritydb_path, rocksdb_path, .. } =>
+				vec![paritydb_path.clone(), rocksdb_path.clone()],
+			#[cfg(feature = "rocksdb")]
+			DatabaseSource::RocksDb { path, .. } => vec![path.clone()],
+			DatabaseSource::ParityDb { path } => vec![path.clone()],
+			DatabaseSource::Custom { .. } => Vec::new(),
+		}
+	}
+
 	/// Set path for databases that are stored on disk.
 	pub fn set_path(&mut self, p: &Path) -> bool {
 		match self {
@@ -1111,6 +1136,12 @@ pub struct Backend<Block: BlockT> {
 	import_lock: Arc<RwLock<()>>,
 	is_archive: bool,
 	blocks_pruning: BlocksPruning,
+	/// Size of the database, tracked when pruning blocks by size.
+	database_size: Option<Arc<DatabaseSize>>,
+	/// Size the database is pruned to, which may be lowered by [`Backend::reclaim_space`].
+	size_budget: AtomicU64,
+	/// First block which may still have a body, when pruning blocks by size.
+	size_pruned_to: Mutex<NumberFor<Block>>,
 	io_stats: FrozenForDuration<(kvdb::IoStats, StateUsageInfo)>,
 	state_usage: Arc<StateUsageStats>,
 	genesis_state: RwLock<Option<Arc<DbGenesisStorage<Block>>>>,
@@ -1147,6 +1178,32 @@ impl<Block: BlockT> Backend<Block> {
 		}
 	}
 
+	/// Estimated size of the database in bytes, when pruning blocks by size.
+	pub fn database_size(&self) -> Option<u64> {
+		self.database_size.as_ref().map(|database_size| database_size.get())
+	}
+
+	/// Lower the size budget of the database so that at least `bytes` more bytes get pruned.
+	///
+	/// The new budget is based on the size of the database measured on disk. While the database
+	/// still exceeds its budget, the space requested by a previous call is being reclaimed and the
+	/// budget is left unchanged, so that repeated calls do not lower it any further until the
+	/// pruned data is actually removed from disk.
+	///
+	/// The blocks are pruned on the next finalizations, and the budget is kept until the backend
+	/// is reopened. Returns `false` if the blocks are not pruned by size, or if all the finalized
+	/// blocks but the last one are already pruned.
+	pub fn reclaim_space(&self, bytes: u64) -> bool {
+		let Some(database_size) = &self.database_size else { return false };
+		let size = database_size.measure();
+		if size <= self.size_budget.load(AtomicOrdering::Relaxed) {
+			let size_budget = size.saturating_sub(bytes);
+			self.size_budget.store(size_budget, AtomicOrdering::Relaxed);
+			warn!(target: "db", "Lowering the database size budget to {size_budget} bytes");
+		}
+		*self.size_pruned_to.lock() < self.blockchain.info().finalized_number
+	}
+
 	/// Create new memory-backed client backend for tests.
 	#[cfg(any(test, feature = "test-helpers"))]
 	pub fn new_test(blocks_pruning: u32, canonicalization_delay: u64) -> Self {
@@ -1165,6 +1222,7 @@ impl<Block: BlockT> Backend<Block> {
 			BlocksPruning::KeepAll => PruningMode::ArchiveAll,
 			BlocksPruning::KeepFinalized => PruningMode::ArchiveCanonical,
 			BlocksPruning::Some(n) => PruningMode::blocks_pruning(n),
+			BlocksPruning::Size(_) => PruningMode::default(),
 		};
 		let db_setting = DatabaseSettings {
 			trie_cache_maximum_size: Some(16 * 1024 * 1024),
@@ -1201,6 +1259,26 @@ impl<Block: BlockT> Backend<Block> {
 	) -> ClientResult<Self> {
 		let mut db_init_transaction = Transaction::new();
 
+		let (db, database_size, size_budget) = match config.blocks_pruning {
+			BlocksPruning::Size(size_budget) => {
+				let database_size = Arc::new(DatabaseSize::new(db, config.source.disk_paths()));
+				(
+					database_size.clone() as Arc<dyn Database<DbHash>>,
+					Some(database_size),
+					size_budget,
+				)
+			},
+			_ => (db, None, u64::MAX),
+		};
+		let size_pruned_to = db
+			.get(columns::META, meta_keys::SIZE_PRUNED_TO)
+			.map(|number| NumberFor::<Block>::decode(&mut &number[..]))
+			.transpose()
+			.map_err(|e| {
+				sp_blockchain::Error::Backend(format!("Error decoding size pruned block: {e}"))
+			})?
+			.unwrap_or_else(Zero::zero);
+
 		let requested_state_pruning = config.state_pruning.clone();
 		let state_meta_db = StateMetaDb(db.clone());
 		let map_e = sp_blockchain::Error::from_state_db;
@@ -1234,6 +1312,9 @@ impl<Block: BlockT> Backend<Block> {
 			io_stats: FrozenForDuration::new(std::time::Duration::from_secs(1)),
 			state_usage: Arc::new(StateUsageStats::new()),
 			blocks_pruning: config.blocks_pruning,
+			database_size,
+			size_budget: AtomicU64::new(size_budget),
+			size_pruned_to: Mutex::new(size_pruned_to),
 			genesis_state: RwLock::new(None),
 			shared_trie_cache: config.trie_cache_maximum_size.map(|maximum_size| {
 				SharedTrieCache::new(sp_trie::cache::CacheSize::new(maximum_size))
@@ -1891,31 +1972,103 @@ impl<Block: BlockT> Backend<Block> {
 		finalized_number: NumberFor<Block>,
 		current_transaction_justifications: &mut HashMap<Block::Hash, Justification>,
 	) -> ClientResult<()> {
-		if let BlocksPruning::Some(blocks_pruning) = self.blocks_pruning {
-			// Always keep the last finalized block
-			let keep = std::cmp::max(blocks_pruning, 1);
-			if finalized_number >= keep.into() {
-				let number = finalized_number.saturating_sub(keep.into());
-
-				// Before we prune a block, check if it is pinned
-				if let Some(hash) = self.blockchain.hash(number)? {
-					self.blockchain.insert_persisted_body_if_pinned(hash)?;
-
-					// If the block was finalized in this transaction, it will not be in the db
-					// yet.
-					if let Some(justification) = current_transaction_justifications.remove(&hash) {
-						self.blockchain.insert_justifications_if_pinned(hash, justification);
-					} else {
-						self.blockchain.insert_persisted_justifications_if_pinned(hash)?;
-					}
-				};
+		match self.blocks_pruning {
+			BlocksPruning::Some(blocks_pruning) => {
+				// Always keep the last finalized block
+				let keep = std::cmp::max(blocks_pruning, 1);
+				if finalized_number >= keep.into() {
+					let number = finalized_number.saturating_sub(keep.into());
+					self.prune_finalized_block(
+						transaction,
+						number,
+						current_transaction_justifications,
+					)?;
+				}
+			},
+			BlocksPruning::Size(_) => self.prune_blocks_by_size(
+				transaction,
+				finalized_number,
+				current_transaction_justifications,
+			)?,
+			BlocksPruning::KeepAll | BlocksPruning::KeepFinalized => {},
+		}
+		Ok(())
+	}
 
-				self.prune_block(transaction, BlockId::<Block>::number(number))?;
-			}
+	/// Prunes the oldest finalized blocks, and their state, while the database exceeds its size
+	/// budget.
+	///
+	/// At most [`MAX_BLOCKS_PRUNED_BY_SIZE`] blocks are pruned at once, the size of the database
+	/// being only updated once the transaction is committed.
+	fn prune_blocks_by_size(
+		&self,
+		transaction: &mut Transaction<DbHash>,
+		finalized_number: NumberFor<Block>,
+		current_transaction_justifications: &mut HashMap<Block::Hash, Justification>,
+	) -> ClientResult<()> {
+		let Some(database_size) = &self.database_size else { return Ok(()) };
+		if database_size.get() <= self.size_budget.load(AtomicOrdering::Relaxed) {
+			return Ok(())
+		}
+
+		let mut size_pruned_to = self.size_pruned_to.lock();
+		// Always keep the last finalized block
+		let end = std::cmp::min(
+			finalized_number,
+			size_pruned_to.saturating_add(MAX_BLOCKS_PRUNED_BY_SIZE.into()),
+		);
+		let mut number = *size_pruned_to;
+		while number < end {
+			self.prune_finalized_block(transaction, number, current_transaction_justifications)?;
+			number += One::one();
+		}
+		if number == *size_pruned_to {
+			return Ok(())
+		}
+
+		debug!(
+			target: "db",
+			"Pruned blocks #{}..#{number} to fit the database into {} bytes",
+			*size_pruned_to,
+			self.size_budget.load(AtomicOrdering::Relaxed),
+		);
+		transaction.set_from_vec(columns::META, meta_keys::SIZE_PRUNED_TO, number.encode());
+		*size_pruned_to = number;
+
+		if !self.is_archive {
+			let keep = (finalized_number - number).saturated_into::<u32>().saturating_add(1);
+			let commit = self.storage.state_db.shrink_window(keep).map_err(
+				sp_blockchain::Error::from_state_db::<
+					sc_state_db::Error<sp_database::error::DatabaseError>,
+				>,
+			)?;
+			apply_state_commit(transaction, commit);
 		}
 		Ok(())
 	}
 
+	fn prune_finalized_block(
+		&self,
+		transaction: &mut Transaction<DbHash>,
+		number: NumberFor<Block>,
+		current_transaction_justifications: &mut HashMap<Block::Hash, Justification>,
+	) -> ClientResult<()> {
+		// Before we prune a block, check if it is pinned
+		if let Some(hash) = self.blockchain.hash(number)? {
+			self.blockchain.insert_persisted_body_if_pinned(hash)?;
+
+			// If the block was finalized in this transaction, it will not be in the db
+			// yet.
+			if let Some(justification) = current_transaction_justifications.remove(&hash) {
+				self.blockchain.insert_justifications_if_pinned(hash, justification);
+			} else {
+				self.blockchain.insert_persisted_justifications_if_pinned(hash)?;
+			}
+		};
+
+		self.prune_block(transaction, BlockId::<Block>::number(number))
+	}
+
 	fn prune_displaced_branches(
 		&self,
 		transaction: &mut Transaction<DbHash>,
@@ -3762,6 +3915,67 @@ pub(crate) mod tests {
 		}
 	}
 
+	#[test]
+	fn prune_blocks_by_size_on_finalize() {
+		let backend = Backend::<Block>::new_test_with_tx_storage(BlocksPruning::Size(u64::MAX), 0);
+		let mut blocks = Vec::new();
+		let mut prev_hash = Default::default();
+		for i in 0..5 {
+			let hash = insert_block(
+				&backend,
+				i,
+				prev_hash,
+				None,
+				Default::default(),
+				vec![UncheckedXt::new_transaction(i.into(), ())],
+				None,
+			)
+			.unwrap();
+			blocks.push(hash);
+			prev_hash = hash;
+		}
+		let finalize = |range: std::ops::Range<usize>| {
+			let mut op = backend.begin_operation().unwrap();
+			backend.begin_state_operation(&mut op, blocks[4]).unwrap();
+			for i in range {
+				op.mark_finalized(blocks[i], None).unwrap();
+			}
+			backend.commit_operation(op).unwrap();
+		};
+
+		// the database fits into the budget.
+		finalize(1..4);
+		let bc = backend.blockchain();
+		for i in 0..5 {
+			assert_eq!(
+				Some(vec![UncheckedXt::new_transaction((i as u64).into(), ())]),
+				bc.body(blocks[i]).unwrap()
+			);
+		}
+		assert!(backend.database_size().unwrap() > 0);
+
+		// the budget is lowered, so all the finalized blocks but the last one get pruned.
+		assert!(backend.reclaim_space(1));
+		// the space is being reclaimed, so repeated requests do not lower the budget any further.
+		let size_budget = backend.size_budget.load(AtomicOrdering::Relaxed);
+		assert!(backend.reclaim_space(1024));
+		assert_eq!(backend.size_budget.load(AtomicOrdering::Relaxed), size_budget);
+		finalize(4..5);
+		for i in 0..4 {
+			assert_eq!(None, bc.body(blocks[i]).unwrap());
+		}
+		assert_eq!(
+			Some(vec![UncheckedXt::new_transaction(4.into(), ())]),
+			bc.body(blocks[4]).unwrap()
+		);
+		assert!(!backend.reclaim_space(1));
+
+		// other pruning modes can not reclaim space.
+		let backend = Backend::<Block>::new_test_with_tx_storage(BlocksPruning::Some(2), 0);
+		assert_eq!(backend.database_size(), None);
+		assert!(!backend.reclaim_space(1));
+	}
+
 	#[test]
 	fn prune_blocks_on_finalize_with_fork() {
 		sp_tracing::try_init_simple();
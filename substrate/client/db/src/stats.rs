@@ -18,7 +18,18 @@
 
 //! Database usage statistics
 
-use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use crate::DbHash;
+use parking_lot::Mutex;
+use sp_database::{error::Result as DatabaseResult, Change, ColumnId, Database, Transaction};
+use std::{
+	io,
+	path::{Path, PathBuf},
+	sync::{
+		atomic::{AtomicU64, Ordering as AtomicOrdering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
 
 /// Accumulated usage statistics for state queries.
 pub struct StateUsageStats {
@@ -143,3 +154,179 @@ impl StateUsageStats {
 		}
 	}
 }
+
+/// Database wrapper estimating the size of the database on disk.
+///
+/// The size is measured by adding up the sizes of the files in the database directories, which
+/// reflects the compression and compaction done by the database backend. As this is too costly to
+/// do on every query, a measurement is reused for [`DatabaseSize::MEASURE_PERIOD`], adding the
+/// bytes written by the transactions committed since then. Removed data is only accounted for on
+/// the next measurement, so the estimate errs on the side of a larger database. Databases that are
+/// not stored on disk are only accounted for the bytes written to them.
+pub struct DatabaseSize {
+	db: Arc<dyn Database<DbHash>>,
+	paths: Vec<PathBuf>,
+	/// Size measured on disk, along with the time it was measured at.
+	measured: Mutex<(Instant, u64)>,
+	/// Number of bytes written since the last measurement.
+	written: AtomicU64,
+}
+
+impl DatabaseSize {
+	/// How long a measurement of the size of the database is reused for.
+	pub const MEASURE_PERIOD: Duration = Duration::from_secs(10);
+
+	/// Wrap `db`, stored in the given `paths`.
+	pub fn new(db: Arc<dyn Database<DbHash>>, paths: Vec<PathBuf>) -> Self {
+		let size = Self {
+			db,
+			paths,
+			measured: Mutex::new((Instant::now(), 0)),
+			written: AtomicU64::new(0),
+		};
+		size.measure();
+		size
+	}
+
+	/// Estimated size of the database in bytes.
+	pub fn get(&self) -> u64 {
+		let measured = self.measured.lock();
+		if measured.0.elapsed() > Self::MEASURE_PERIOD {
+			drop(measured);
+			return self.measure()
+		}
+		measured.1.saturating_add(self.written.load(AtomicOrdering::Relaxed))
+	}
+
+	/// Measure the size of the database on disk, and return its estimated size in bytes.
+	pub fn measure(&self) -> u64 {
+		let mut measured = self.measured.lock();
+		if !self.paths.is_empty() {
+			// The bytes written during the measurement may be accounted twice, which only
+			// overestimates the size.
+			self.written.store(0, AtomicOrdering::Relaxed);
+			let size = self.paths.iter().fold(0u64, |size, path| {
+				let path_size = match directory_size(path) {
+					Ok(path_size) => path_size,
+					Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+					Err(e) => {
+						log::warn!(
+							target: "db",
+							"Failed to measure the size of the database at {}: {e}",
+							path.display(),
+						);
+						0
+					},
+				};
+				size.saturating_add(path_size)
+			});
+			*measured = (Instant::now(), size);
+		}
+		measured.1.saturating_add(self.written.load(AtomicOrdering::Relaxed))
+	}
+}
+
+/// Total size of the files in the directory at `path` and its subdirectories.
+fn directory_size(path: &Path) -> io::Result<u64> {
+	let mut size = 0u64;
+	for entry in std::fs::read_dir(path)? {
+		let entry = entry?;
+		let entry_size = match entry.metadata() {
+			Ok(metadata) if metadata.is_dir() => directory_size(&entry.path())?,
+			Ok(metadata) => metadata.len(),
+			// The file was removed by the database backend in the meantime.
+			Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+			Err(e) => return Err(e),
+		};
+		size = size.saturating_add(entry_size);
+	}
+	Ok(size)
+}
+
+impl Database<DbHash> for DatabaseSize {
+	fn commit(&self, transaction: Transaction<DbHash>) -> DatabaseResult<()> {
+		let written = transaction.0.iter().fold(0u64, |written, change| {
+			let bytes = match change {
+				Change::Set(_, key, value) => key.len() + value.len(),
+				Change::Store(_, hash, preimage) => hash.as_ref().len() + preimage.len(),
+				Change::Remove(..) | Change::Reference(..) | Change::Release(..) => 0,
+			};
+			written.saturating_add(bytes as u64)
+		});
+		self.db.commit(transaction)?;
+		self.written.fetch_add(written, AtomicOrdering::Relaxed);
+		Ok(())
+	}
+
+	fn get(&self, col: ColumnId, key: &[u8]) -> Option<Vec<u8>> {
+		self.db.get(col, key)
+	}
+
+	fn contains(&self, col: ColumnId, key: &[u8]) -> bool {
+		self.db.contains(col, key)
+	}
+
+	fn value_size(&self, col: ColumnId, key: &[u8]) -> Option<usize> {
+		self.db.value_size(col, key)
+	}
+
+	fn with_get(&self, col: ColumnId, key: &[u8], f: &mut dyn FnMut(&[u8])) {
+		self.db.with_get(col, key, f)
+	}
+
+	fn supports_ref_counting(&self) -> bool {
+		self.db.supports_ref_counting()
+	}
+
+	fn sanitize_key(&self, key: &mut Vec<u8>) {
+		self.db.sanitize_key(key)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::columns;
+
+	#[test]
+	fn written_bytes_are_added_to_the_measured_size() {
+		let dir = tempfile::tempdir().unwrap();
+		std::fs::write(dir.path().join("file1"), [0; 100]).unwrap();
+		std::fs::create_dir(dir.path().join("subdir")).unwrap();
+		std::fs::write(dir.path().join("subdir").join("file2"), [0; 50]).unwrap();
+
+		let db = sp_database::as_database(kvdb_memorydb::create(crate::utils::NUM_COLUMNS));
+		let missing = dir.path().join("missing");
+		let size = DatabaseSize::new(db, vec![dir.path().to_path_buf(), missing]);
+		assert_eq!(size.get(), 150);
+
+		let mut transaction = Transaction::new();
+		transaction.set(columns::BODY, b"key1", &[0; 10]);
+		transaction.set(columns::BODY, b"key2", &[0; 20]);
+		size.commit(transaction).unwrap();
+		assert_eq!(size.get(), 188);
+
+		// removals are only accounted for by the next measurement.
+		let mut transaction = Transaction::new();
+		transaction.remove(columns::BODY, b"key1");
+		size.commit(transaction).unwrap();
+		assert_eq!(size.get(), 188);
+
+		std::fs::remove_file(dir.path().join("file1")).unwrap();
+		assert_eq!(size.measure(), 50);
+		assert_eq!(size.get(), 50);
+	}
+
+	#[test]
+	fn size_of_databases_in_memory_is_the_written_bytes() {
+		let db = sp_database::as_database(kvdb_memorydb::create(crate::utils::NUM_COLUMNS));
+		let size = DatabaseSize::new(db, Vec::new());
+		assert_eq!(size.get(), 0);
+
+		let mut transaction = Transaction::new();
+		transaction.set(columns::BODY, b"key1", &[0; 10]);
+		size.commit(transaction).unwrap();
+		assert_eq!(size.measure(), 14);
+		assert_eq!(size.get(), 14);
+	}
+}
@@ -65,6 +65,8 @@ pub mod meta_keys {
 	pub const LEAF_PREFIX: &[u8; 4] = b"leaf";
 	/// Children prefix list key.
 	pub const CHILDREN_PREFIX: &[u8; 8] = b"children";
+	/// First block which may still have a body, when pruning blocks by size.
+	pub const SIZE_PRUNED_TO: &[u8; 9] = b"szpruneto";
 }
 
 /// Database metadata.
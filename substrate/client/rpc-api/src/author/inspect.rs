@@ -0,0 +1,132 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pool introspection types for author RPC module.
+
+use sc_transaction_pool_api as pool;
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+
+/// RPC query of the transactions to inspect.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionQuery<Hash> {
+	/// The transaction with the given hash.
+	Hash(Hash),
+	/// The transactions of the given sender, usually its SCALE-encoded account id.
+	///
+	/// Transactions are matched by the prefix of the tags they provide.
+	Sender(Bytes),
+}
+
+/// The queue holding a transaction within a view of the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionQueue {
+	/// All the tags required by the transaction are satisfied.
+	Ready,
+	/// Some of the tags required by the transaction are missing.
+	Future,
+}
+
+/// RPC state of a transaction within a single view of the pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionViewState<BlockHash> {
+	/// The block the view is at.
+	pub at: BlockHash,
+	/// The queue holding the transaction.
+	pub queue: TransactionQueue,
+	/// Priority of the transaction.
+	pub priority: u64,
+	/// Number of blocks after `at` the transaction remains valid for.
+	pub longevity: u64,
+	/// Tags required by the transaction.
+	pub requires: Vec<Bytes>,
+	/// Tags provided by the transaction.
+	pub provides: Vec<Bytes>,
+	/// Tags required by the transaction which are not provided by any transaction in the view.
+	pub missing_tags: Vec<Bytes>,
+}
+
+/// The reason a transaction was last removed from the pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RemovalReason<Hash> {
+	/// Dropped to keep the pool within its limits.
+	Dropped,
+	/// Replaced by the transaction with the given hash.
+	Usurped(Hash),
+	/// Found invalid.
+	Invalid,
+}
+
+/// RPC state of a transaction in the pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionState<Hash, BlockHash> {
+	/// Hash of the transaction.
+	pub hash: Hash,
+	/// The state of the transaction in every view of the pool containing it.
+	pub views: Vec<TransactionViewState<BlockHash>>,
+	/// The reason the transaction was last removed from the pool, if it is known.
+	pub removal_reason: Option<RemovalReason<Hash>>,
+}
+
+fn into_bytes(tags: Vec<pool::TransactionTag>) -> Vec<Bytes> {
+	tags.into_iter().map(Into::into).collect()
+}
+
+impl<BlockHash> From<pool::TransactionViewDetails<BlockHash>> for TransactionViewState<BlockHash> {
+	fn from(details: pool::TransactionViewDetails<BlockHash>) -> Self {
+		Self {
+			at: details.at,
+			queue: match details.queue {
+				pool::TransactionQueue::Ready => TransactionQueue::Ready,
+				pool::TransactionQueue::Future => TransactionQueue::Future,
+			},
+			priority: details.priority,
+			longevity: details.longevity,
+			requires: into_bytes(details.requires),
+			provides: into_bytes(details.provides),
+			missing_tags: into_bytes(details.missing_tags),
+		}
+	}
+}
+
+impl<Hash> From<pool::RemovalReason<Hash>> for RemovalReason<Hash> {
+	fn from(reason: pool::RemovalReason<Hash>) -> Self {
+		match reason {
+			pool::RemovalReason::Dropped => RemovalReason::Dropped,
+			pool::RemovalReason::Usurped(by) => RemovalReason::Usurped(by),
+			pool::RemovalReason::Invalid => RemovalReason::Invalid,
+		}
+	}
+}
+
+impl<Hash, BlockHash> From<pool::TransactionDetails<Hash, BlockHash>>
+	for TransactionState<Hash, BlockHash>
+{
+	fn from(details: pool::TransactionDetails<Hash, BlockHash>) -> Self {
+		Self {
+			hash: details.hash,
+			views: details.views.into_iter().map(Into::into).collect(),
+			removal_reason: details.removal_reason.map(Into::into),
+		}
+	}
+}
@@ -20,6 +20,7 @@
 
 pub mod error;
 pub mod hash;
+pub mod inspect;
 
 use error::Error;
 use jsonrpsee::proc_macros::rpc;
@@ -66,6 +67,16 @@ pub trait AuthorApi<Hash, BlockHash> {
 		bytes_or_hash: Vec<hash::ExtrinsicOrHash<Hash>>,
 	) -> Result<Vec<Hash>, Error>;
 
+	/// Returns the state of the pool transactions matching the query.
+	///
+	/// For every transaction, this includes its state in each view of the pool and the reason it
+	/// was last removed from the pool, if it is known.
+	#[method(name = "author_inspectTransactions", with_extensions)]
+	fn inspect_transactions(
+		&self,
+		query: inspect::TransactionQuery<Hash>,
+	) -> Result<Vec<inspect::TransactionState<Hash, BlockHash>>, Error>;
+
 	/// Submit an extrinsic to watch.
 	///
 	/// See [`TransactionStatus`](sc_transaction_pool_api::TransactionStatus) for details on
@@ -19,7 +19,11 @@
 //! API trait of the archive methods.
 
 use crate::{
-	common::events::{ArchiveStorageResult, PaginatedStorageQuery},
+	archive::event::ArchiveBlockEvent,
+	common::events::{
+		ArchiveStorageDiffItem, ArchiveStorageDiffResult, ArchiveStorageResult,
+		PaginatedStorageQuery,
+	},
 	MethodResult,
 };
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
@@ -104,4 +108,52 @@ pub trait ArchiveApi<Hash> {
 		items: Vec<PaginatedStorageQuery<String>>,
 		child_trie: Option<String>,
 	) -> RpcResult<ArchiveStorageResult>;
+
+	/// Returns the storage keys that changed between two blocks.
+	///
+	/// For each item, the keys starting with the provided prefix, in the main trie or in the
+	/// provided child trie, are compared between the block `hash` and the block `previous_hash`.
+	/// When `previous_hash` is not provided, the parent of `hash` is used.
+	///
+	/// Each changed key is reported as added, modified or deleted, alongside its value or the hash
+	/// of its value. For deleted keys, the value in the previous block is reported.
+	///
+	/// The number of keys compared per item is limited. The items whose keys were not all
+	/// compared are returned in `remainingItems`, with the `paginationStartKey` to resume from.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "archive_unstable_storageDiff", blocking)]
+	fn archive_unstable_storage_diff(
+		&self,
+		hash: Hash,
+		items: Vec<ArchiveStorageDiffItem<String>>,
+		previous_hash: Option<Hash>,
+	) -> RpcResult<ArchiveStorageDiffResult>;
+
+	/// Stream the finalized blocks starting from the given height.
+	///
+	/// Each block is reported with its header, its body and, when `with_events` is set, the
+	/// hexadecimal-encoded value of its `System::Events` storage. The blocks up to `end_height`
+	/// (inclusive) are reported in ascending order, waiting for them to be finalized if needed.
+	/// When `end_height` is not provided, newly finalized blocks are reported until the
+	/// subscription is stopped.
+	///
+	/// The next block is fetched only after the previous one was accepted by the connection.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[subscription(
+		name = "archive_unstable_blocks" => "archive_unstable_blocksEvent",
+		unsubscribe = "archive_unstable_stopBlocks",
+		item = ArchiveBlockEvent<Hash>,
+	)]
+	fn archive_unstable_blocks(
+		&self,
+		start_height: u64,
+		end_height: Option<u64>,
+		with_events: bool,
+	);
 }
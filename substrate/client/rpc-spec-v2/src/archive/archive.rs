@@ -20,16 +20,29 @@
 
 use crate::{
 	archive::{error::Error as ArchiveError, ArchiveApiServer},
-	common::events::{ArchiveStorageResult, PaginatedStorageQuery},
-	hex_string, MethodResult,
+	chain_head::{
+		chain_head::{read_subscription_id_as_string, LOG_TARGET, MAX_PINNED_BLOCKS},
+		subscription::SubscriptionManagement,
+	},
+	common::events::{
+		ArchiveStorageDiffItem, ArchiveStorageDiffResult, ArchiveStorageResult,
+		PaginatedStorageQuery,
+	},
+	hex_string, MethodResult, SubscriptionTaskExecutor,
 };
 
 use codec::Encode;
-use jsonrpsee::core::{async_trait, RpcResult};
+use futures::FutureExt;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	PendingSubscriptionSink,
+};
+use log::debug;
 use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, CallExecutor, ChildInfo, ExecutorProvider, StorageKey,
 	StorageProvider,
 };
+use sc_rpc::utils::Subscription;
 use sp_api::{CallApiAt, CallContext};
 use sp_blockchain::{
 	Backend as BlockChainBackend, Error as BlockChainError, HeaderBackend, HeaderMetadata,
@@ -39,9 +52,13 @@ use sp_runtime::{
 	traits::{Block as BlockT, Header as HeaderT, NumberFor},
 	SaturatedConversion,
 };
-use std::{collections::HashSet, marker::PhantomData, sync::Arc};
+use std::{collections::HashSet, marker::PhantomData, sync::Arc, time::Duration};
 
-use super::archive_storage::ArchiveStorage;
+use super::{
+	archive_blocks::ArchiveBlocks,
+	archive_storage::{ArchiveStorage, ArchiveStorageDiff},
+	event::ArchiveBlockEvent,
+};
 
 /// The configuration of [`Archive`].
 pub struct ArchiveConfig {
@@ -50,6 +67,8 @@ pub struct ArchiveConfig {
 	pub max_descendant_responses: usize,
 	/// The maximum number of queried items allowed for the `archive_storage` at a time.
 	pub max_queried_items: usize,
+	/// The maximum number of `archive_unstable_blocks` subscriptions per connection.
+	pub max_block_subscriptions_per_connection: usize,
 }
 
 /// The maximum number of items the `archive_storage` can return for a descendant query before
@@ -64,11 +83,21 @@ const MAX_DESCENDANT_RESPONSES: usize = 5;
 /// `MAX_DESCENDANT_RESPONSES`.
 const MAX_QUERIED_ITEMS: usize = 8;
 
+/// The maximum number of `archive_unstable_blocks` subscriptions per connection.
+const MAX_BLOCK_SUBSCRIPTIONS_PER_CONNECTION: usize = 4;
+
+/// The maximum duration that a block is allowed to be pinned by an `archive_unstable_blocks`
+/// subscription.
+///
+/// Note: Blocks are only pinned while their content is fetched.
+const MAX_PINNED_DURATION: Duration = Duration::from_secs(60);
+
 impl Default for ArchiveConfig {
 	fn default() -> Self {
 		Self {
 			max_descendant_responses: MAX_DESCENDANT_RESPONSES,
 			max_queried_items: MAX_QUERIED_ITEMS,
+			max_block_subscriptions_per_connection: MAX_BLOCK_SUBSCRIPTIONS_PER_CONNECTION,
 		}
 	}
 }
@@ -79,6 +108,10 @@ pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	client: Arc<Client>,
 	/// Backend of the chain.
 	backend: Arc<BE>,
+	/// Executor to spawn subscriptions.
+	executor: SubscriptionTaskExecutor,
+	/// Keep track of the pinned blocks for each `archive_unstable_blocks` subscription.
+	subscriptions: SubscriptionManagement<Block, BE>,
 	/// The hexadecimal encoded hash of the genesis block.
 	genesis_hash: String,
 	/// The maximum number of items the `archive_storage` can return for a descendant query before
@@ -96,12 +129,24 @@ impl<BE: Backend<Block>, Block: BlockT, Client> Archive<BE, Block, Client> {
 		client: Arc<Client>,
 		backend: Arc<BE>,
 		genesis_hash: GenesisHash,
+		executor: SubscriptionTaskExecutor,
 		config: ArchiveConfig,
 	) -> Self {
 		let genesis_hash = hex_string(&genesis_hash.as_ref());
+		let subscriptions = SubscriptionManagement::new(
+			MAX_PINNED_BLOCKS,
+			MAX_PINNED_DURATION,
+			// Blocks are not exposed to the users of the subscription, which cannot start
+			// operations on them.
+			0,
+			config.max_block_subscriptions_per_connection,
+			backend.clone(),
+		);
 		Self {
 			client,
 			backend,
+			executor,
+			subscriptions,
 			genesis_hash,
 			storage_max_descendant_responses: config.max_descendant_responses,
 			storage_max_queried_items: config.max_queried_items,
@@ -278,4 +323,104 @@ where
 
 		Ok(storage_client.handle_query(hash, items, child_trie))
 	}
+
+	fn archive_unstable_storage_diff(
+		&self,
+		hash: Block::Hash,
+		items: Vec<ArchiveStorageDiffItem<String>>,
+		previous_hash: Option<Block::Hash>,
+	) -> RpcResult<ArchiveStorageDiffResult> {
+		let items = items
+			.into_iter()
+			.map(|item| {
+				Ok(ArchiveStorageDiffItem {
+					key: StorageKey(parse_hex_param(item.key)?),
+					return_type: item.return_type,
+					child_trie_key: item
+						.child_trie_key
+						.map(|key| parse_hex_param(key).map(StorageKey))
+						.transpose()?,
+					pagination_start_key: item
+						.pagination_start_key
+						.map(|key| parse_hex_param(key).map(StorageKey))
+						.transpose()?,
+				})
+			})
+			.collect::<Result<Vec<_>, ArchiveError>>()?;
+
+		let previous_hash = match previous_hash {
+			Some(previous_hash) => previous_hash,
+			None => match self.client.header(hash) {
+				Ok(Some(header)) => *header.parent_hash(),
+				Ok(None) =>
+					return Ok(ArchiveStorageDiffResult::err(format!("Block {hash:?} not found"))),
+				Err(error) => return Ok(ArchiveStorageDiffResult::err(error.to_string())),
+			},
+		};
+
+		let storage_client = ArchiveStorageDiff::new(
+			self.client.clone(),
+			self.storage_max_descendant_responses,
+			self.storage_max_queried_items,
+		);
+
+		Ok(storage_client.handle_query(hash, previous_hash, items))
+	}
+
+	fn archive_unstable_blocks(
+		&self,
+		pending: PendingSubscriptionSink,
+		start_height: u64,
+		end_height: Option<u64>,
+		with_events: bool,
+	) {
+		let client = self.client.clone();
+		let subscriptions = self.subscriptions.clone();
+
+		let fut = async move {
+			let parse_height = |height: u64| -> Result<NumberFor<Block>, ArchiveError> {
+				U256::from(height).try_into().map_err(|_| {
+					ArchiveError::InvalidParam(format!("Invalid block height: {}", height))
+				})
+			};
+			let (start, end) = match (parse_height(start_height), end_height.map(parse_height)) {
+				(Ok(start), None) => (start, None),
+				(Ok(start), Some(Ok(end))) => (start, Some(end)),
+				(Err(error), _) | (_, Some(Err(error))) => {
+					pending.reject(error).await;
+					return
+				},
+			};
+
+			// The RAII `reserved_subscription` frees the reserved space of the connection and
+			// removes the subscription ID from the subscription management on drop.
+			let Some(mut reserved_subscription) =
+				subscriptions.reserve_subscription(pending.connection_id())
+			else {
+				pending.reject(ArchiveError::ReachedLimits).await;
+				return
+			};
+
+			let Ok(sink) = pending.accept().await.map(Subscription::from) else { return };
+
+			let sub_id = read_subscription_id_as_string(&sink);
+			let Some(sub_data) = reserved_subscription.insert_subscription(sub_id.clone(), false)
+			else {
+				// Inserting the subscription can only fail if the JsonRPSee generated a duplicate
+				// subscription ID.
+				debug!(target: LOG_TARGET, "[archive_blocks][id={:?}] Subscription already accepted", sub_id);
+				let _ = sink.send(&ArchiveBlockEvent::<String>::Stop).await;
+				return
+			};
+			debug!(target: LOG_TARGET, "[archive_blocks][id={:?}] Subscription accepted", sub_id);
+
+			let archive_blocks =
+				ArchiveBlocks::new(client, subscriptions, sub_id.clone(), with_events);
+			archive_blocks.generate_events(sink, sub_data, start, end).await;
+
+			debug!(target: LOG_TARGET, "[archive_blocks][id={:?}] Subscription removed", sub_id);
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+	}
 }
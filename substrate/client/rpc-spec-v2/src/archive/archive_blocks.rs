@@ -0,0 +1,214 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the `archive_unstable_blocks` subscription.
+
+use crate::{
+	archive::event::{ArchiveBlock, ArchiveBlockEvent},
+	chain_head::{
+		chain_head::LOG_TARGET,
+		event::ErrorEvent,
+		subscription::{InsertedSubscriptionData, SubscriptionManagement},
+	},
+	hex_string,
+};
+use codec::Encode;
+use futures::StreamExt;
+use log::debug;
+use sc_client_api::{Backend, BlockBackend, BlockchainEvents, StorageKey, StorageProvider};
+use sc_rpc::utils::Subscription;
+use sp_blockchain::HeaderBackend;
+use sp_core::twox_128;
+use sp_runtime::{
+	traits::{Block as BlockT, NumberFor, One},
+	SaturatedConversion,
+};
+use std::sync::Arc;
+
+/// Generates the events of an `archive_unstable_blocks` subscription.
+///
+/// Each block is pinned through the [`SubscriptionManagement`] of the subscription while its
+/// content is fetched, and unpinned before the event is submitted. The next block is fetched
+/// only after the previous event was accepted by the connection, which bounds the memory used
+/// by a slow consumer.
+pub struct ArchiveBlocks<Client, Block: BlockT, BE: Backend<Block>> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// Keep track of the pinned blocks for each subscription.
+	subscriptions: SubscriptionManagement<Block, BE>,
+	/// Subscription ID.
+	sub_id: String,
+	/// Report the `System::Events` storage of each block.
+	with_events: bool,
+}
+
+impl<Client, Block: BlockT, BE: Backend<Block>> ArchiveBlocks<Client, Block, BE> {
+	/// Create a new [`ArchiveBlocks`].
+	pub fn new(
+		client: Arc<Client>,
+		subscriptions: SubscriptionManagement<Block, BE>,
+		sub_id: String,
+		with_events: bool,
+	) -> Self {
+		Self { client, subscriptions, sub_id, with_events }
+	}
+}
+
+impl<Client, Block, BE> ArchiveBlocks<Client, Block, BE>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: BlockBackend<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ StorageProvider<Block, BE>
+		+ 'static,
+{
+	/// Generate the block events of the `start..=end` range until the range is exhausted, the
+	/// subscription is stopped or the connection is closed.
+	///
+	/// When `end` is not provided, newly finalized blocks are reported indefinitely.
+	pub async fn generate_events(
+		&self,
+		sink: Subscription,
+		sub_data: InsertedSubscriptionData<Block>,
+		start: NumberFor<Block>,
+		end: Option<NumberFor<Block>>,
+	) {
+		let blocks = self.submit_blocks(&sink, start, end);
+
+		let event = tokio::select! {
+			_ = sub_data.rx_stop => {
+				debug!(target: LOG_TARGET, "[archive_blocks][id={:?}] Received stop signal", self.sub_id);
+				ArchiveBlockEvent::Stop
+			},
+			event = blocks => event,
+		};
+
+		let _ = sink.send(&event).await;
+	}
+
+	/// Submit the finalized blocks of the range, returning the last event of the subscription.
+	async fn submit_blocks(
+		&self,
+		sink: &Subscription,
+		mut next: NumberFor<Block>,
+		end: Option<NumberFor<Block>>,
+	) -> ArchiveBlockEvent<Block::Hash> {
+		// Register for the finality notifications before reading the finalized height, to not
+		// miss blocks finalized in between.
+		let mut finality_stream = self.client.finality_notification_stream();
+
+		loop {
+			let finalized = self.client.info().finalized_number;
+			let last = end.map_or(finalized, |end| end.min(finalized));
+
+			while next <= last {
+				let block = match self.fetch_block(next) {
+					Ok(block) => block,
+					Err(error) => {
+						debug!(
+							target: LOG_TARGET,
+							"[archive_blocks][id={:?}] Failed to fetch block {}: {}",
+							self.sub_id,
+							next,
+							error
+						);
+						return ArchiveBlockEvent::Error(ErrorEvent { error })
+					},
+				};
+
+				// Waits until the connection is able to accept the event.
+				if sink.send(&ArchiveBlockEvent::Block(block)).await.is_err() {
+					debug!(target: LOG_TARGET, "[archive_blocks][id={:?}] Connection closed", self.sub_id);
+					return ArchiveBlockEvent::Stop
+				}
+
+				next += One::one();
+			}
+
+			if end.map_or(false, |end| next > end) {
+				return ArchiveBlockEvent::Done
+			}
+
+			tokio::select! {
+				notification = finality_stream.next() => if notification.is_none() {
+					return ArchiveBlockEvent::Stop
+				},
+				_ = sink.closed() => return ArchiveBlockEvent::Stop,
+			}
+		}
+	}
+
+	/// Fetch the content of the finalized block at the given height.
+	fn fetch_block(&self, number: NumberFor<Block>) -> Result<ArchiveBlock<Block::Hash>, String> {
+		let hash = self
+			.client
+			.block_hash(number)
+			.map_err(|error| error.to_string())?
+			.ok_or_else(|| format!("Block {number} not found"))?;
+
+		// The block is reported once as finalized by the subscription. Pin it for the duration
+		// of the fetch, such that the backend does not prune it meanwhile.
+		self.subscriptions
+			.pin_finalized_block(&self.sub_id, hash)
+			.map_err(|error| error.to_string())?;
+		let block = self.read_block(hash, number);
+		self.subscriptions
+			.unpin_blocks(&self.sub_id, [hash])
+			.map_err(|error| error.to_string())?;
+
+		block
+	}
+
+	/// Read the header, the body and optionally the events of a pinned block.
+	fn read_block(
+		&self,
+		hash: Block::Hash,
+		number: NumberFor<Block>,
+	) -> Result<ArchiveBlock<Block::Hash>, String> {
+		let header = self
+			.client
+			.header(hash)
+			.map_err(|error| error.to_string())?
+			.ok_or_else(|| format!("Header of block {hash} not found"))?;
+		let body = self
+			.client
+			.block_body(hash)
+			.map_err(|error| error.to_string())?
+			.ok_or_else(|| format!("Body of block {hash} not found"))?;
+
+		let events = if self.with_events {
+			let key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+			self.client
+				.storage(hash, &key)
+				.map_err(|error| error.to_string())?
+				.map(|data| hex_string(&data.0))
+		} else {
+			None
+		};
+
+		Ok(ArchiveBlock {
+			hash,
+			number: number.saturated_into(),
+			header: hex_string(&header.encode()),
+			body: body.iter().map(|extrinsic| hex_string(&extrinsic.encode())).collect(),
+			events,
+		})
+	}
+}
@@ -16,16 +16,23 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! Implementation of the `archive_storage` method.
+//! Implementation of the `archive_storage` and `archive_storageDiff` methods.
 
-use std::sync::Arc;
+use std::{cmp::Ordering, iter::Peekable, marker::PhantomData, sync::Arc};
 
-use sc_client_api::{Backend, ChildInfo, StorageKey, StorageProvider};
+use sc_client_api::{Backend, ChildInfo, KeysIter, MerkleValue, StorageKey, StorageProvider};
 use sp_runtime::traits::Block as BlockT;
 
-use crate::common::{
-	events::{ArchiveStorageResult, PaginatedStorageQuery, StorageQueryType},
-	storage::{IterQueryType, QueryIter, Storage},
+use crate::{
+	common::{
+		events::{
+			ArchiveStorageDiffEntry, ArchiveStorageDiffItem, ArchiveStorageDiffOperationType,
+			ArchiveStorageDiffResult, ArchiveStorageDiffType, ArchiveStorageResult,
+			PaginatedStorageQuery, StorageQueryType, StorageResultType,
+		},
+		storage::{IterQueryType, QueryIter, Storage},
+	},
+	hex_string,
 };
 
 /// Generates the events of the `archive_storage` method.
@@ -127,3 +134,236 @@ where
 		ArchiveStorageResult::ok(storage_results, discarded_items)
 	}
 }
+
+/// Generates the results of the `archive_storageDiff` method.
+pub struct ArchiveStorageDiff<Client, Block, BE> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// The maximum number of keys compared for a single item at a time.
+	storage_max_descendant_responses: usize,
+	/// The maximum number of queried items allowed for the `archive_storageDiff` at a time.
+	storage_max_queried_items: usize,
+	_phantom: PhantomData<(BE, Block)>,
+}
+
+impl<Client, Block, BE> ArchiveStorageDiff<Client, Block, BE> {
+	/// Constructs a new [`ArchiveStorageDiff`].
+	pub fn new(
+		client: Arc<Client>,
+		storage_max_descendant_responses: usize,
+		storage_max_queried_items: usize,
+	) -> Self {
+		Self {
+			client,
+			storage_max_descendant_responses,
+			storage_max_queried_items,
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<Client, Block, BE> ArchiveStorageDiff<Client, Block, BE>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: StorageProvider<Block, BE> + 'static,
+{
+	/// Generate the response of the `archive_storageDiff` method.
+	///
+	/// The keys starting with the prefix of each item are compared between the two blocks. At
+	/// most `storage_max_descendant_responses` keys are compared per item, the items with keys
+	/// left to compare are returned so that the comparison can be resumed.
+	pub fn handle_query(
+		&self,
+		hash: Block::Hash,
+		previous_hash: Block::Hash,
+		mut items: Vec<ArchiveStorageDiffItem<StorageKey>>,
+	) -> ArchiveStorageDiffResult {
+		let discarded_items = items.len().saturating_sub(self.storage_max_queried_items);
+		items.truncate(self.storage_max_queried_items);
+
+		let mut diff_results = Vec::new();
+		let mut remaining_items = Vec::new();
+		for item in items {
+			match self.item_diff(hash, previous_hash, &item, &mut diff_results) {
+				Ok(None) => {},
+				Ok(Some(last_key)) => remaining_items.push(ArchiveStorageDiffItem {
+					key: hex_string(&item.key.0),
+					return_type: item.return_type,
+					child_trie_key: item.child_trie_key.map(|key| hex_string(&key.0)),
+					pagination_start_key: Some(hex_string(&last_key.0)),
+				}),
+				Err(error) => return ArchiveStorageDiffResult::err(error.to_string()),
+			}
+		}
+
+		ArchiveStorageDiffResult::ok(diff_results, discarded_items, remaining_items)
+	}
+
+	/// Compute the differences of the keys of a single item.
+	///
+	/// Returns the last compared key if the limit of compared keys was reached before all the
+	/// keys of the item were compared.
+	fn item_diff(
+		&self,
+		hash: Block::Hash,
+		previous_hash: Block::Hash,
+		item: &ArchiveStorageDiffItem<StorageKey>,
+		diff_results: &mut Vec<ArchiveStorageDiffEntry>,
+	) -> sp_blockchain::Result<Option<StorageKey>> {
+		let child_info = item.child_trie_key.as_ref().map(|key| ChildInfo::new_default(&key.0));
+		let state = StateAccess {
+			client: &*self.client,
+			child_info: child_info.as_ref(),
+			_phantom: PhantomData::<(BE, Block)>,
+		};
+
+		// The sub-tries under the prefix are identical, there is nothing to report.
+		if state.closest_merkle_value(hash, &item.key)? ==
+			state.closest_merkle_value(previous_hash, &item.key)?
+		{
+			return Ok(None)
+		}
+
+		let child_trie_key = item.child_trie_key.as_ref().map(|key| hex_string(&key.0));
+		let mut push = |key: StorageKey, at: Block::Hash, operation_type| {
+			let result = match item.return_type {
+				ArchiveStorageDiffType::Value =>
+					state.value(at, &key)?.map(|value| StorageResultType::Value(hex_string(&value))),
+				ArchiveStorageDiffType::Hash => state
+					.hash(at, &key)?
+					.map(|hash| StorageResultType::Hash(hex_string(&hash.as_ref()))),
+			};
+
+			if let Some(result) = result {
+				diff_results.push(ArchiveStorageDiffEntry {
+					key: hex_string(&key.0),
+					result,
+					operation_type,
+					child_trie_key: child_trie_key.clone(),
+				});
+			}
+			Ok::<_, sp_blockchain::Error>(())
+		};
+
+		let start_key = item.pagination_start_key.as_ref();
+		let mut keys = state.keys(hash, &item.key, start_key)?.peekable();
+		let mut previous_keys = state.keys(previous_hash, &item.key, start_key)?.peekable();
+		let mut compared_keys = 0;
+		let mut last_key = None;
+		loop {
+			let Some(ordering) = next_ordering(&mut keys, &mut previous_keys) else { break };
+			if compared_keys == self.storage_max_descendant_responses {
+				return Ok(last_key)
+			}
+			compared_keys += 1;
+
+			let key = match ordering {
+				Ordering::Less => {
+					let key =
+						keys.next().expect("ordering is only computed for existing keys; qed");
+					push(key.clone(), hash, ArchiveStorageDiffOperationType::Added)?;
+					key
+				},
+				Ordering::Greater => {
+					let key = previous_keys
+						.next()
+						.expect("ordering is only computed for existing keys; qed");
+					push(key.clone(), previous_hash, ArchiveStorageDiffOperationType::Deleted)?;
+					key
+				},
+				Ordering::Equal => {
+					let key =
+						keys.next().expect("ordering is only computed for existing keys; qed");
+					previous_keys.next();
+					if state.hash(hash, &key)? != state.hash(previous_hash, &key)? {
+						push(key.clone(), hash, ArchiveStorageDiffOperationType::Modified)?;
+					}
+					key
+				},
+			};
+			last_key = Some(key);
+		}
+
+		Ok(None)
+	}
+}
+
+/// Compare the next keys of two sorted key iterators.
+///
+/// A missing key is considered greater than any other key, and `None` is returned when both
+/// iterators are exhausted.
+fn next_ordering(
+	keys: &mut Peekable<impl Iterator<Item = StorageKey>>,
+	previous_keys: &mut Peekable<impl Iterator<Item = StorageKey>>,
+) -> Option<Ordering> {
+	match (keys.peek(), previous_keys.peek()) {
+		(None, None) => None,
+		(Some(_), None) => Some(Ordering::Less),
+		(None, Some(_)) => Some(Ordering::Greater),
+		(Some(key), Some(previous_key)) => Some(key.cmp(previous_key)),
+	}
+}
+
+/// Access to the main trie or to a child trie of the state of blocks.
+struct StateAccess<'a, Client, Block, BE> {
+	client: &'a Client,
+	child_info: Option<&'a ChildInfo>,
+	_phantom: PhantomData<(BE, Block)>,
+}
+
+impl<'a, Client, Block, BE> StateAccess<'a, Client, Block, BE>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: StorageProvider<Block, BE> + 'static,
+{
+	/// Get the value of a key.
+	fn value(&self, hash: Block::Hash, key: &StorageKey) -> sp_blockchain::Result<Option<Vec<u8>>> {
+		let value = match self.child_info {
+			Some(child_info) => self.client.child_storage(hash, child_info, key)?,
+			None => self.client.storage(hash, key)?,
+		};
+		Ok(value.map(|value| value.0))
+	}
+
+	/// Get the hash of the value of a key.
+	fn hash(
+		&self,
+		hash: Block::Hash,
+		key: &StorageKey,
+	) -> sp_blockchain::Result<Option<Block::Hash>> {
+		match self.child_info {
+			Some(child_info) => self.client.child_storage_hash(hash, child_info, key),
+			None => self.client.storage_hash(hash, key),
+		}
+	}
+
+	/// Get the merkle value of the closest descendant of a key.
+	fn closest_merkle_value(
+		&self,
+		hash: Block::Hash,
+		key: &StorageKey,
+	) -> sp_blockchain::Result<Option<MerkleValue<Block::Hash>>> {
+		match self.child_info {
+			Some(child_info) => self.client.child_closest_merkle_value(hash, child_info, key),
+			None => self.client.closest_merkle_value(hash, key),
+		}
+	}
+
+	/// Iterate over the keys starting with the given prefix, after `start_key` if provided, in
+	/// lexicographic order.
+	fn keys(
+		&self,
+		hash: Block::Hash,
+		prefix: &StorageKey,
+		start_key: Option<&StorageKey>,
+	) -> sp_blockchain::Result<KeysIter<BE::State, Block>> {
+		match self.child_info {
+			Some(child_info) =>
+				self.client
+					.child_storage_keys(hash, child_info.clone(), Some(prefix), start_key),
+			None => self.client.storage_keys(hash, Some(prefix), start_key),
+		}
+	}
+}
@@ -32,6 +32,9 @@ pub enum Error {
 	/// Failed to fetch leaves.
 	#[error("Failed to fetch leaves of the chain: {0}")]
 	FetchLeaves(String),
+	/// Maximum number of `archive_unstable_blocks` subscriptions per connection reached.
+	#[error("Maximum number of archive_unstable_blocks subscriptions has been reached")]
+	ReachedLimits,
 }
 
 // Base code for all `archive` errors.
@@ -42,6 +45,8 @@ const INVALID_PARAM_ERROR: i32 = BASE_ERROR + 1;
 const RUNTIME_CALL_ERROR: i32 = BASE_ERROR + 2;
 /// Failed to fetch leaves.
 const FETCH_LEAVES_ERROR: i32 = BASE_ERROR + 3;
+/// Maximum number of `archive_unstable_blocks` subscriptions reached.
+const REACHED_LIMITS: i32 = BASE_ERROR + 4;
 
 impl From<Error> for ErrorObject<'static> {
 	fn from(e: Error) -> Self {
@@ -51,6 +56,7 @@ impl From<Error> for ErrorObject<'static> {
 			Error::InvalidParam(_) => ErrorObject::owned(INVALID_PARAM_ERROR, msg, None::<()>),
 			Error::RuntimeCall(_) => ErrorObject::owned(RUNTIME_CALL_ERROR, msg, None::<()>),
 			Error::FetchLeaves(_) => ErrorObject::owned(FETCH_LEAVES_ERROR, msg, None::<()>),
+			Error::ReachedLimits => ErrorObject::owned(REACHED_LIMITS, msg, None::<()>),
 		}
 		.into()
 	}
@@ -0,0 +1,109 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The events of the `archive_unstable_blocks` subscription.
+
+use crate::chain_head::event::ErrorEvent;
+use serde::{Deserialize, Serialize};
+
+/// A finalized block reported by the `archive_unstable_blocks` subscription.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveBlock<Hash> {
+	/// The hash of the block.
+	pub hash: Hash,
+	/// The height of the block.
+	pub number: u64,
+	/// The hexadecimal-encoded SCALE-codec encoding of the block header.
+	pub header: String,
+	/// The hexadecimal-encoded SCALE-codec encoding of the block extrinsics.
+	pub body: Vec<String>,
+	/// The hexadecimal-encoded value of the `System::Events` storage at this block.
+	///
+	/// Only reported if the events were requested and the storage entry exists.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub events: Option<String>,
+}
+
+/// The event generated by the `archive_unstable_blocks` subscription.
+///
+/// The blocks are reported in ascending order of their height. The subscription produces
+/// either a `Done`, an `Error` or a `Stop` event as the last event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "event")]
+pub enum ArchiveBlockEvent<Hash> {
+	/// The next finalized block of the requested range.
+	Block(ArchiveBlock<Hash>),
+	/// All the blocks of the requested range have been reported.
+	Done,
+	/// The RPC server encountered an error while fetching a block.
+	Error(ErrorEvent),
+	/// The subscription is dropped and no further events will be generated.
+	Stop,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn archive_block_event() {
+		let mut block = ArchiveBlock {
+			hash: "0x1".to_string(),
+			number: 1,
+			header: "0x2".into(),
+			body: vec!["0x3".into()],
+			events: None,
+		};
+
+		let event: ArchiveBlockEvent<String> = ArchiveBlockEvent::Block(block.clone());
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"block","hash":"0x1","number":1,"header":"0x2","body":["0x3"]}"#;
+		assert_eq!(ser, exp);
+		let event_dec: ArchiveBlockEvent<String> = serde_json::from_str(exp).unwrap();
+		assert_eq!(event_dec, event);
+
+		block.events = Some("0x4".into());
+		let event: ArchiveBlockEvent<String> = ArchiveBlockEvent::Block(block);
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"block","hash":"0x1","number":1,"header":"0x2","body":["0x3"],"events":"0x4"}"#;
+		assert_eq!(ser, exp);
+		let event_dec: ArchiveBlockEvent<String> = serde_json::from_str(exp).unwrap();
+		assert_eq!(event_dec, event);
+	}
+
+	#[test]
+	fn archive_done_and_error_events() {
+		let event: ArchiveBlockEvent<String> = ArchiveBlockEvent::Done;
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"done"}"#;
+		assert_eq!(ser, exp);
+		let event_dec: ArchiveBlockEvent<String> = serde_json::from_str(exp).unwrap();
+		assert_eq!(event_dec, event);
+
+		let event: ArchiveBlockEvent<String> =
+			ArchiveBlockEvent::Error(ErrorEvent { error: "reason".into() });
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"error","error":"reason"}"#;
+		assert_eq!(ser, exp);
+		let event_dec: ArchiveBlockEvent<String> = serde_json::from_str(exp).unwrap();
+		assert_eq!(event_dec, event);
+	}
+}
@@ -25,11 +25,13 @@
 #[cfg(test)]
 mod tests;
 
+mod archive_blocks;
 mod archive_storage;
 
 pub mod api;
 pub mod archive;
 pub mod error;
+pub mod event;
 
 pub use api::ArchiveApiServer;
 pub use archive::{Archive, ArchiveConfig};
@@ -18,6 +18,8 @@
 
 use crate::{
 	common::events::{
+		ArchiveStorageDiffEntry, ArchiveStorageDiffItem, ArchiveStorageDiffMethodOk,
+		ArchiveStorageDiffOperationType, ArchiveStorageDiffResult, ArchiveStorageDiffType,
 		ArchiveStorageMethodOk, ArchiveStorageResult, PaginatedStorageQuery, StorageQueryType,
 		StorageResultType,
 	},
@@ -26,19 +28,22 @@ use crate::{
 
 use super::{
 	archive::{Archive, ArchiveConfig},
+	event::{ArchiveBlock, ArchiveBlockEvent},
 	*,
 };
 
 use assert_matches::assert_matches;
 use codec::{Decode, Encode};
 use jsonrpsee::{
-	core::EmptyServerParams as EmptyParams, rpc_params, MethodsError as Error, RpcModule,
+	core::EmptyServerParams as EmptyParams, rpc_params, server::Subscription as RpcSubscription,
+	MethodsError as Error, RpcModule,
 };
 use sc_block_builder::BlockBuilderBuilder;
-use sc_client_api::ChildInfo;
+use sc_client_api::{ChildInfo, StorageKey, StorageProvider};
+use sc_rpc::testing::TokioTestExecutor;
 use sp_blockchain::HeaderBackend;
 use sp_consensus::BlockOrigin;
-use sp_core::{Blake2Hasher, Hasher};
+use sp_core::{twox_128, Blake2Hasher, Hasher};
 use sp_runtime::{
 	traits::{Block as BlockT, Header as HeaderT},
 	SaturatedConversion,
@@ -53,6 +58,7 @@ const CHAIN_GENESIS: [u8; 32] = [0; 32];
 const INVALID_HASH: [u8; 32] = [1; 32];
 const MAX_PAGINATION_LIMIT: usize = 5;
 const MAX_QUERIED_LIMIT: usize = 5;
+const MAX_BLOCK_SUBSCRIPTIONS: usize = 1;
 const KEY: &[u8] = b":mock";
 const VALUE: &[u8] = b"hello world";
 const CHILD_STORAGE_KEY: &[u8] = b"child";
@@ -78,7 +84,12 @@ fn setup_api(
 		client.clone(),
 		backend,
 		CHAIN_GENESIS,
-		ArchiveConfig { max_descendant_responses, max_queried_items },
+		Arc::new(TokioTestExecutor::default()),
+		ArchiveConfig {
+			max_descendant_responses,
+			max_queried_items,
+			max_block_subscriptions_per_connection: MAX_BLOCK_SUBSCRIPTIONS,
+		},
 	)
 	.into_rpc();
 
@@ -838,3 +849,287 @@ async fn archive_storage_discarded_items() {
 		_ => panic!("Unexpected result"),
 	};
 }
+
+#[tokio::test]
+async fn archive_storage_diff() {
+	let (client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+
+	// Import a block that adds two keys.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":mock1".to_vec(), Some(b"a".to_vec())).unwrap();
+	builder.push_storage_change(b":mock2".to_vec(), Some(b"b".to_vec())).unwrap();
+	let block_1 = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block_1.clone()).await.unwrap();
+
+	// Import a block that modifies, deletes and adds a key.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_1.hash())
+		.with_parent_block_number(1)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":mock1".to_vec(), Some(b"c".to_vec())).unwrap();
+	builder.push_storage_change(b":mock2".to_vec(), None).unwrap();
+	builder.push_storage_change(b":mock3".to_vec(), Some(b"d".to_vec())).unwrap();
+	let block_2 = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block_2.clone()).await.unwrap();
+
+	let items = vec![
+		ArchiveStorageDiffItem {
+			key: hex_string(KEY),
+			return_type: ArchiveStorageDiffType::Value,
+			child_trie_key: None,
+			pagination_start_key: None,
+		},
+		ArchiveStorageDiffItem {
+			key: hex_string(b":mock3"),
+			return_type: ArchiveStorageDiffType::Hash,
+			child_trie_key: None,
+			pagination_start_key: None,
+		},
+		// The child trie is not modified.
+		ArchiveStorageDiffItem {
+			key: hex_string(KEY),
+			return_type: ArchiveStorageDiffType::Value,
+			child_trie_key: Some(hex_string(CHILD_STORAGE_KEY)),
+			pagination_start_key: None,
+		},
+	];
+	let entry = |key: &[u8], result, operation_type| ArchiveStorageDiffEntry {
+		key: hex_string(&key),
+		result,
+		operation_type,
+		child_trie_key: None,
+	};
+
+	// The previous block defaults to the parent block.
+	let result: ArchiveStorageDiffResult = api
+		.call(
+			"archive_unstable_storageDiff",
+			rpc_params![format!("{:?}", block_2.hash()), items.clone()],
+		)
+		.await
+		.unwrap();
+	assert_eq!(
+		result,
+		ArchiveStorageDiffResult::Ok(ArchiveStorageDiffMethodOk {
+			result: vec![
+				entry(
+					b":mock1",
+					StorageResultType::Value(hex_string(b"c")),
+					ArchiveStorageDiffOperationType::Modified
+				),
+				entry(
+					b":mock2",
+					StorageResultType::Value(hex_string(b"b")),
+					ArchiveStorageDiffOperationType::Deleted
+				),
+				entry(
+					b":mock3",
+					StorageResultType::Value(hex_string(b"d")),
+					ArchiveStorageDiffOperationType::Added
+				),
+				entry(
+					b":mock3",
+					StorageResultType::Hash(format!("{:?}", Blake2Hasher::hash(b"d"))),
+					ArchiveStorageDiffOperationType::Added
+				),
+			],
+			discarded_items: 0,
+			remaining_items: vec![],
+		})
+	);
+
+	// Compare with an explicit previous block.
+	let result: ArchiveStorageDiffResult = api
+		.call(
+			"archive_unstable_storageDiff",
+			rpc_params![
+				format!("{:?}", block_2.hash()),
+				items,
+				format!("{:?}", client.chain_info().genesis_hash)
+			],
+		)
+		.await
+		.unwrap();
+	assert_eq!(
+		result,
+		ArchiveStorageDiffResult::Ok(ArchiveStorageDiffMethodOk {
+			result: vec![
+				entry(
+					b":mock1",
+					StorageResultType::Value(hex_string(b"c")),
+					ArchiveStorageDiffOperationType::Added
+				),
+				entry(
+					b":mock3",
+					StorageResultType::Value(hex_string(b"d")),
+					ArchiveStorageDiffOperationType::Added
+				),
+				entry(
+					b":mock3",
+					StorageResultType::Hash(format!("{:?}", Blake2Hasher::hash(b"d"))),
+					ArchiveStorageDiffOperationType::Added
+				),
+			],
+			discarded_items: 0,
+			remaining_items: vec![],
+		})
+	);
+
+	// Unknown block.
+	let result: ArchiveStorageDiffResult = api
+		.call(
+			"archive_unstable_storageDiff",
+			rpc_params![hex_string(&INVALID_HASH), Vec::<ArchiveStorageDiffItem<String>>::new()],
+		)
+		.await
+		.unwrap();
+	assert_matches!(result, ArchiveStorageDiffResult::Err(_));
+}
+
+#[tokio::test]
+async fn archive_storage_diff_pagination() {
+	let (client, api) = setup_api(2, MAX_QUERIED_LIMIT);
+
+	// Import a block that adds five keys.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	for i in 0..5u8 {
+		builder
+			.push_storage_change(vec![b':', b'm', b'o', b'c', b'k', i], Some(vec![i]))
+			.unwrap();
+	}
+	let block = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let mut items = vec![ArchiveStorageDiffItem {
+		key: hex_string(KEY),
+		return_type: ArchiveStorageDiffType::Value,
+		child_trie_key: None,
+		pagination_start_key: None,
+	}];
+	let mut added_keys = Vec::new();
+	let mut calls = 0;
+	while !items.is_empty() {
+		calls += 1;
+		let result: ArchiveStorageDiffResult = api
+			.call("archive_unstable_storageDiff", rpc_params![format!("{:?}", block.hash()), items])
+			.await
+			.unwrap();
+		let ArchiveStorageDiffResult::Ok(result) = result else { panic!("Unexpected result") };
+
+		// At most two keys are compared per call.
+		assert!(result.result.len() <= 2);
+		added_keys.extend(result.result.into_iter().map(|entry| {
+			assert_eq!(entry.operation_type, ArchiveStorageDiffOperationType::Added);
+			entry.key
+		}));
+		items = result.remaining_items;
+	}
+
+	// The `:mock` key of the genesis block is not modified.
+	let expected_keys: Vec<_> =
+		(0..5u8).map(|i| hex_string(&[b':', b'm', b'o', b'c', b'k', i])).collect();
+	assert_eq!(added_keys, expected_keys);
+	assert_eq!(calls, 3);
+}
+
+async fn get_next_event<T: serde::de::DeserializeOwned>(sub: &mut RpcSubscription) -> T {
+	let (event, _sub_id) = tokio::time::timeout(std::time::Duration::from_secs(60), sub.next())
+		.await
+		.unwrap()
+		.unwrap()
+		.unwrap();
+	event
+}
+
+#[tokio::test]
+async fn archive_blocks() {
+	let (client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+
+	// Import 3 blocks and finalize the first 2.
+	let mut blocks = Vec::new();
+	let mut parent_hash = client.chain_info().genesis_hash;
+	for parent_number in 0..3 {
+		let block = BlockBuilderBuilder::new(&*client)
+			.on_parent_block(parent_hash)
+			.with_parent_block_number(parent_number)
+			.build()
+			.unwrap()
+			.build()
+			.unwrap()
+			.block;
+		client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+		parent_hash = block.hash();
+		blocks.push(block);
+	}
+	client.finalize_block(blocks[1].hash(), None).unwrap();
+
+	let expected_block = |block: &Block, with_events: bool| {
+		let events_key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+		let events = with_events
+			.then(|| {
+				client
+					.storage(block.hash(), &events_key)
+					.unwrap()
+					.map(|data| hex_string(&data.0))
+			})
+			.flatten();
+		ArchiveBlockEvent::Block(ArchiveBlock {
+			hash: hex_string(&block.hash().as_ref()),
+			number: *block.header.number(),
+			header: hex_string(&block.header.encode()),
+			body: block
+				.extrinsics
+				.iter()
+				.map(|extrinsic| hex_string(&extrinsic.encode()))
+				.collect(),
+			events,
+		})
+	};
+
+	// Stream the blocks 1 to 3, the last block is reported once finalized.
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_blocks", rpc_params![1, 3, true])
+		.await
+		.unwrap();
+	for block in &blocks[..2] {
+		let event: ArchiveBlockEvent<String> = get_next_event(&mut sub).await;
+		assert_eq!(event, expected_block(block, true));
+	}
+
+	client.finalize_block(blocks[2].hash(), None).unwrap();
+	let event: ArchiveBlockEvent<String> = get_next_event(&mut sub).await;
+	assert_eq!(event, expected_block(&blocks[2], true));
+	let event: ArchiveBlockEvent<String> = get_next_event(&mut sub).await;
+	assert_eq!(event, ArchiveBlockEvent::Done);
+	drop(sub);
+	// Ensure the subscription is removed from the server before creating a new one.
+	tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+	// Stream the finalized blocks from block 2 without events.
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_blocks", rpc_params![2, Option::<u64>::None, false])
+		.await
+		.unwrap();
+	let event: ArchiveBlockEvent<String> = get_next_event(&mut sub).await;
+	assert_eq!(event, expected_block(&blocks[1], false));
+	let event: ArchiveBlockEvent<String> = get_next_event(&mut sub).await;
+	assert_eq!(event, expected_block(&blocks[2], false));
+
+	// Only one subscription is allowed per connection.
+	let error = api
+		.subscribe_unbounded("archive_unstable_blocks", rpc_params![0, 1, false])
+		.await
+		.unwrap_err();
+	assert!(error
+		.to_string()
+		.contains("Maximum number of archive_unstable_blocks subscriptions has been reached"));
+}
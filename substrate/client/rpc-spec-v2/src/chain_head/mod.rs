@@ -34,7 +34,7 @@ pub mod event;
 
 mod chain_head_follow;
 mod chain_head_storage;
-mod subscription;
+pub(crate) mod subscription;
 
 pub use api::ChainHeadApiServer;
 pub use chain_head::{ChainHead, ChainHeadConfig};
@@ -140,6 +140,23 @@ impl<Block: BlockT, BE: Backend<Block>> SubscriptionManagement<Block, BE> {
 		inner.pin_block(sub_id, hash)
 	}
 
+	/// Pin a block that is reported only once, as finalized, by the subscription.
+	///
+	/// The block is registered twice for the subscription, such that a single call to
+	/// [`Self::unpin_blocks`] fully releases the block.
+	///
+	/// Returns an error if the backend failed to pin the block or the subscription ID is invalid.
+	pub fn pin_finalized_block(
+		&self,
+		sub_id: &str,
+		hash: Block::Hash,
+	) -> Result<(), SubscriptionManagementError> {
+		let mut inner = self.inner.write();
+		inner.pin_block(sub_id, hash)?;
+		inner.pin_block(sub_id, hash)?;
+		Ok(())
+	}
+
 	/// Unpin the blocks from the subscription.
 	///
 	/// Blocks are reference counted and when the last subscription unpins a given block, the block
@@ -143,6 +143,110 @@ pub struct ArchiveStorageMethodErr {
 	pub error: String,
 }
 
+/// The type of the value reported by the `archive_unstable_storageDiff` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArchiveStorageDiffType {
+	/// The value of the changed key is reported.
+	Value,
+	/// The hash of the value of the changed key is reported.
+	Hash,
+}
+
+/// The storage item to compute the differences of.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveStorageDiffItem<Key> {
+	/// The prefix of the keys to compare.
+	pub key: Key,
+	/// The type of the value reported for the changed keys.
+	pub return_type: ArchiveStorageDiffType,
+	/// The child trie of the keys, if any.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub child_trie_key: Option<Key>,
+	/// The key after which the comparison should resume.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub pagination_start_key: Option<Key>,
+}
+
+/// The kind of change of a storage key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArchiveStorageDiffOperationType {
+	/// The key does not exist in the previous block.
+	Added,
+	/// The value of the key differs from the previous block.
+	Modified,
+	/// The key does not exist anymore.
+	Deleted,
+}
+
+/// A storage key that changed between two blocks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveStorageDiffEntry {
+	/// The hex-encoded key of the result.
+	pub key: String,
+	/// The value or hash of the key.
+	///
+	/// For deleted keys, this is the value or hash of the key in the previous block.
+	#[serde(flatten)]
+	pub result: StorageResultType,
+	/// The kind of change.
+	#[serde(rename = "type")]
+	pub operation_type: ArchiveStorageDiffOperationType,
+	/// The hex-encoded child trie key of the result, if any.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub child_trie_key: Option<String>,
+}
+
+/// The result of a storage difference call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ArchiveStorageDiffResult {
+	/// Query generated a result.
+	Ok(ArchiveStorageDiffMethodOk),
+	/// Query encountered an error.
+	Err(ArchiveStorageMethodErr),
+}
+
+impl ArchiveStorageDiffResult {
+	/// Create a new `ArchiveStorageDiffResult::Ok` result.
+	pub fn ok(
+		result: Vec<ArchiveStorageDiffEntry>,
+		discarded_items: usize,
+		remaining_items: Vec<ArchiveStorageDiffItem<String>>,
+	) -> Self {
+		Self::Ok(ArchiveStorageDiffMethodOk { result, discarded_items, remaining_items })
+	}
+
+	/// Create a new `ArchiveStorageDiffResult::Err` result.
+	pub fn err(error: String) -> Self {
+		Self::Err(ArchiveStorageMethodErr { error })
+	}
+}
+
+/// The result of a storage difference call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveStorageDiffMethodOk {
+	/// Reported results.
+	pub result: Vec<ArchiveStorageDiffEntry>,
+	/// Number of discarded items.
+	pub discarded_items: usize,
+	/// The items whose keys were not all compared, because of the limit of keys compared per
+	/// item.
+	///
+	/// Each item resumes the comparison after the last compared key, and can be provided as is
+	/// to a subsequent call.
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	#[serde(default)]
+	pub remaining_items: Vec<ArchiveStorageDiffItem<String>>,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -270,4 +374,35 @@ mod tests {
 		let dec: PaginatedStorageQuery<&str> = serde_json::from_str(exp).unwrap();
 		assert_eq!(dec, item);
 	}
+
+	#[test]
+	fn archive_storage_diff() {
+		let item = ArchiveStorageDiffItem {
+			key: "0x1",
+			return_type: ArchiveStorageDiffType::Hash,
+			child_trie_key: Some("0x2"),
+			pagination_start_key: None,
+		};
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"key":"0x1","returnType":"hash","childTrieKey":"0x2"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveStorageDiffItem<&str> = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
+
+		let diff = ArchiveStorageDiffEntry {
+			key: "0x1".into(),
+			result: StorageResultType::Value("0x2".into()),
+			operation_type: ArchiveStorageDiffOperationType::Modified,
+			child_trie_key: None,
+		};
+		// Encode
+		let ser = serde_json::to_string(&diff).unwrap();
+		let exp = r#"{"key":"0x1","value":"0x2","type":"modified"}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveStorageDiffEntry = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, diff);
+	}
 }
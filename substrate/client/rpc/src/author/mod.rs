@@ -180,6 +180,21 @@ where
 			.collect())
 	}
 
+	fn inspect_transactions(
+		&self,
+		ext: &Extensions,
+		query: inspect::TransactionQuery<TxHash<P>>,
+	) -> Result<Vec<inspect::TransactionState<TxHash<P>, BlockHash<P>>>> {
+		check_if_safe(ext)?;
+		let details = match query {
+			inspect::TransactionQuery::Hash(hash) =>
+				self.pool.transaction_details(&hash).into_iter().collect(),
+			inspect::TransactionQuery::Sender(sender) =>
+				self.pool.sender_transaction_details(&sender),
+		};
+		Ok(details.into_iter().map(Into::into).collect())
+	}
+
 	fn watch_extrinsic(&self, pending: PendingSubscriptionSink, xt: Bytes) {
 		let best_block_hash = self.client.info().best_hash;
 		let dxt = match TransactionFor::<P>::decode(&mut &xt[..]).map_err(|e| Error::from(e)) {
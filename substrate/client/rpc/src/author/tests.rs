@@ -218,6 +218,51 @@ async fn author_should_remove_extrinsics() {
 	assert_eq!(removed, vec![xt1_hash, xt2_hash, xt3_hash]);
 }
 
+#[tokio::test]
+async fn author_should_inspect_transactions() {
+	const METHOD: &'static str = "author_inspectTransactions";
+	let setup = TestSetup::default();
+	let api = setup.to_rpc();
+
+	// Submit a ready and a future extrinsic.
+	let xt1 = to_hex(&uxt(AccountKeyring::Alice, 0).encode(), true);
+	let xt1_hash: H256 = api.call("author_submitExtrinsic", [xt1]).await.unwrap();
+	let xt2 = to_hex(&uxt(AccountKeyring::Alice, 2).encode(), true);
+	let xt2_hash: H256 = api.call("author_submitExtrinsic", [xt2]).await.unwrap();
+
+	let states: Vec<inspect::TransactionState<H256, H256>> =
+		api.call(METHOD, [inspect::TransactionQuery::Hash(xt2_hash)]).await.unwrap();
+	assert_eq!(states.len(), 1);
+	assert_eq!(states[0].hash, xt2_hash);
+	assert_eq!(states[0].views.len(), 1);
+	assert_eq!(states[0].views[0].queue, inspect::TransactionQueue::Future);
+	assert_eq!(states[0].views[0].missing_tags, states[0].views[0].requires);
+	assert_eq!(states[0].removal_reason, None);
+
+	let sender = AccountKeyring::Alice.public().to_raw_vec();
+	let states: Vec<inspect::TransactionState<H256, H256>> = api
+		.call(METHOD, [inspect::TransactionQuery::<H256>::Sender(sender.into())])
+		.await
+		.unwrap();
+	let mut hashes = states.into_iter().map(|state| state.hash).collect::<Vec<_>>();
+	hashes.sort();
+	let mut expected = vec![xt1_hash, xt2_hash];
+	expected.sort();
+	assert_eq!(hashes, expected);
+
+	// The method is unsafe.
+	let mut api = setup.to_rpc();
+	api.extensions_mut().insert(DenyUnsafe::Yes);
+	assert_matches!(
+		api.call::<_, Vec<inspect::TransactionState<H256, H256>>>(
+			METHOD,
+			[inspect::TransactionQuery::Hash(xt1_hash)]
+		)
+		.await,
+		Err(RpcError::JsonRpc(e)) if e.message() == "RPC call is unsafe to be called externally"
+	);
+}
+
 #[tokio::test]
 async fn author_should_insert_key() {
 	let setup = TestSetup::default();
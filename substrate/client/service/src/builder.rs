@@ -755,6 +755,7 @@ where
 			client.clone(),
 			backend.clone(),
 			genesis_hash,
+			task_executor.clone(),
 			// Defaults to sensible limits for the `Archive`.
 			sc_rpc_spec_v2::archive::ArchiveConfig::default(),
 		)
@@ -0,0 +1,393 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::Error;
+use codec::{Decode, Encode, IoReader as CodecIoReader};
+use futures::{channel::oneshot, future};
+use log::info;
+use parking_lot::Mutex;
+use sc_client_api::{BlockBackend, HeaderBackend, ProofProvider};
+use sc_consensus::{
+	import_queue::{BlockImportError, BlockImportStatus, ImportQueue, IncomingBlock, Link},
+	ImportedState,
+};
+use smallvec::SmallVec;
+use sp_consensus::BlockOrigin;
+use sp_core::storage::well_known_keys;
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT, NumberFor, Zero},
+	Justifications,
+};
+use sp_state_machine::KeyValueStates;
+use sp_trie::CompactProof;
+use std::{
+	collections::HashMap,
+	io::{Read, Write},
+	sync::Arc,
+};
+
+/// Version of the snapshot format, written at the beginning of the snapshot.
+const SNAPSHOT_VERSION: u32 = 2;
+
+/// Size limit of the proof of a single state chunk, the same as the one of state sync responses.
+const CHUNK_SIZE_LIMIT: usize = 2 * 1024 * 1024;
+
+/// The block of a snapshot, written after the version.
+///
+/// It is followed by the state of the block, as a sequence of `Some` compact proofs of
+/// consecutive ranges of the state, terminated by `None`.
+#[derive(Encode, Decode)]
+struct SnapshotBlock<Hash, Header> {
+	/// Hash of the genesis block of the chain.
+	genesis_hash: Hash,
+	/// Header of the block.
+	header: Header,
+	/// Justifications of the block.
+	justifications: Option<Justifications>,
+}
+
+/// Export a snapshot of the state of the finalized block `hash` to `output`.
+///
+/// The state is read and written in chunks, so that it never has to be held in memory at once.
+pub fn export_snapshot<B, C>(client: Arc<C>, hash: B::Hash, output: impl Write) -> Result<(), Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + BlockBackend<B> + ProofProvider<B>,
+{
+	write_snapshot(&*client, hash, output, CHUNK_SIZE_LIMIT)
+}
+
+fn write_snapshot<B, C>(
+	client: &C,
+	hash: B::Hash,
+	mut output: impl Write,
+	chunk_size_limit: usize,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + BlockBackend<B> + ProofProvider<B>,
+{
+	let info = client.info();
+	let header = client
+		.header(hash)?
+		.ok_or_else(|| Error::Other(format!("Unknown block {hash:?}")))?;
+	let number = *header.number();
+	if number > info.finalized_number || client.hash(number)? != Some(hash) {
+		return Err(Error::Other(format!("Block #{number} ({hash:?}) is not finalized")))
+	}
+
+	info!("Exporting state snapshot of block #{number} ({hash:?})...");
+	let state_root = *header.state_root();
+	let block = SnapshotBlock {
+		genesis_hash: info.genesis_hash,
+		header,
+		justifications: client.justifications(hash)?,
+	};
+	output.write_all(&(SNAPSHOT_VERSION, block).encode())?;
+
+	let mut start_key = SmallVec::<[Vec<u8>; 2]>::new();
+	let mut keys = 0u64;
+	loop {
+		let (proof, count) = client.read_proof_collection(hash, &start_key, chunk_size_limit)?;
+		output.write_all(&Some(&proof).encode())?;
+		keys += u64::from(count);
+
+		// The next chunk starts after the last key of this one, as the next request of state
+		// sync does.
+		let (key_values, completed) = client.verify_range_proof(state_root, proof, &start_key)?;
+		if completed == 0 {
+			break
+		}
+		if !key_values.update_last_key(completed, &mut start_key) {
+			return Err(Error::Other(format!(
+				"Failed to update the state cursor at depth {completed}"
+			)))
+		}
+	}
+	output.write_all(&None::<CompactProof>.encode())?;
+	output.flush()?;
+
+	info!("🎉 Exported state snapshot with {keys} keys");
+	Ok(())
+}
+
+/// Import the snapshot read from `input` into a database which only contains the genesis block.
+///
+/// The state of the snapshot is verified against the state root of its header, and the block is
+/// imported as finalized through `import_queue`, the same way the state is imported by state
+/// sync. The justifications of the block are not verified, so the hash of its header must match
+/// `expected_hash`, which should come from a trusted source.
+///
+/// As with state sync, the block is imported with its whole state in a single database
+/// transaction, so the state is held in memory until the import completes.
+pub async fn import_snapshot<B, IQ, C>(
+	client: Arc<C>,
+	mut import_queue: IQ,
+	input: impl Read,
+	expected_hash: B::Hash,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + ProofProvider<B>,
+	IQ: ImportQueue<B>,
+{
+	let (header, justifications, state) = read_snapshot(&*client, input, expected_hash)?;
+	let hash = header.hash();
+	let number = *header.number();
+	let keys = state.len();
+
+	import_queue.service_ref().import_blocks(
+		BlockOrigin::File,
+		vec![IncomingBlock {
+			hash,
+			header: Some(header),
+			body: None,
+			indexed_body: None,
+			justifications,
+			origin: None,
+			allow_missing_state: true,
+			import_existing: true,
+			skip_execution: true,
+			state: Some(ImportedState { block: hash, state }),
+		}],
+	);
+
+	let (result_sender, result) = oneshot::channel();
+	let link = SnapshotLink { result: Mutex::new(Some(result_sender)) };
+	match future::select(import_queue.run(&link), result).await {
+		future::Either::Left(((), _)) | future::Either::Right((Err(_), _)) =>
+			return Err("The import queue stopped before importing the snapshot block".into()),
+		future::Either::Right((Ok(result), _)) => result.map_err(Error::Other)?,
+	}
+
+	info!("🎉 Imported state snapshot of block #{number} with {keys} keys");
+	Ok(())
+}
+
+/// Read the snapshot from `input`, and verify its state chunk by chunk against the state root of
+/// its header.
+fn read_snapshot<B, C>(
+	client: &C,
+	input: impl Read,
+	expected_hash: B::Hash,
+) -> Result<(B::Header, Option<Justifications>, KeyValueStates), Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + ProofProvider<B>,
+{
+	let mut reader = CodecIoReader(input);
+	let version = u32::decode(&mut reader)
+		.map_err(|e| format!("Failed to decode the snapshot version: {e}"))?;
+	if version != SNAPSHOT_VERSION {
+		return Err(Error::Other(format!("Unsupported snapshot version {version}")))
+	}
+	let SnapshotBlock { genesis_hash, header, justifications } =
+		SnapshotBlock::<B::Hash, B::Header>::decode(&mut reader)
+			.map_err(|e| format!("Failed to decode the snapshot block: {e}"))?;
+
+	let info = client.info();
+	if genesis_hash != info.genesis_hash {
+		return Err(Error::Other(format!(
+			"Snapshot of chain with genesis {genesis_hash:?}, expected {:?}",
+			info.genesis_hash
+		)))
+	}
+	if !info.best_number.is_zero() {
+		return Err("Snapshots can only be imported into an empty database".into())
+	}
+	let hash = header.hash();
+	if hash != expected_hash {
+		return Err(Error::Other(format!("Snapshot of block {hash:?}, expected {expected_hash:?}")))
+	}
+
+	info!("Importing state snapshot of block #{} ({hash:?})...", header.number());
+	// The child tries are imported under their storage keys, and their roots are recalculated.
+	let mut state = HashMap::<_, (Vec<_>, Vec<_>)>::new();
+	let mut start_key = SmallVec::<[Vec<u8>; 2]>::new();
+	let mut complete = false;
+	while let Some(proof) = Option::<CompactProof>::decode(&mut reader)
+		.map_err(|e| format!("Failed to decode the snapshot state: {e}"))?
+	{
+		if complete {
+			return Err("Unexpected state after the end of the snapshot state".into())
+		}
+		let (key_values, completed) =
+			client.verify_range_proof(*header.state_root(), proof, &start_key)?;
+		complete = completed == 0;
+		if !complete && !key_values.update_last_key(completed, &mut start_key) {
+			return Err(Error::Other(format!(
+				"Failed to update the state cursor at depth {completed}"
+			)))
+		}
+
+		let mut child_storage_roots = Vec::new();
+		for level in key_values.0 {
+			let is_top = level.state_root.is_empty();
+			let entry = state.entry(level.state_root).or_default();
+			for (key, value) in level.key_values {
+				if is_top && well_known_keys::is_child_storage_key(&key) {
+					child_storage_roots.push((value, key));
+				} else {
+					entry.0.push((key, value));
+				}
+			}
+		}
+		for (root, storage_key) in child_storage_roots {
+			state.entry(root).or_default().1.push(storage_key);
+		}
+	}
+	if !complete {
+		return Err("Incomplete snapshot state".into())
+	}
+
+	Ok((header, justifications, state.into()))
+}
+
+/// Link sending the result of the snapshot block import.
+struct SnapshotLink {
+	result: Mutex<Option<oneshot::Sender<Result<(), String>>>>,
+}
+
+impl<B: BlockT> Link<B> for SnapshotLink {
+	fn blocks_processed(
+		&self,
+		_imported: usize,
+		_count: usize,
+		results: Vec<(Result<BlockImportStatus<NumberFor<B>>, BlockImportError>, B::Hash)>,
+	) {
+		let Some((result, hash)) = results.into_iter().next() else { return };
+		if let Some(sender) = self.result.lock().take() {
+			let _ = sender.send(
+				result
+					.map(|_| ())
+					.map_err(|e| format!("Failed to import snapshot block {hash:?}: {e}")),
+			);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_client_api::StorageProvider;
+	use sp_core::storage::{ChildInfo, StorageKey};
+	use std::collections::BTreeMap;
+	use substrate_test_runtime_client::prelude::*;
+
+	const TOP_VALUE: &[u8] = b"top value of the snapshot, long enough to be hashed";
+
+	type Storage = (BTreeMap<Vec<u8>, Vec<u8>>, BTreeMap<Vec<u8>, BTreeMap<Vec<u8>, Vec<u8>>>);
+
+	fn client_with_child_trie() -> (TestClient, ChildInfo) {
+		let child_info = ChildInfo::new_default(b"snapshot");
+		let builder = (0..32u8).fold(
+			TestClientBuilder::new().add_extra_storage(b"top".to_vec(), TOP_VALUE.to_vec()),
+			|builder, i| builder.add_extra_child_storage(&child_info, vec![i], vec![i; 64]),
+		);
+		(builder.build(), child_info)
+	}
+
+	/// The top trie without the child trie roots, and the content of the child tries by storage
+	/// key.
+	fn imported_storage(state: KeyValueStates) -> Storage {
+		let mut storage = Storage::default();
+		for level in state.0 {
+			if level.state_root.is_empty() {
+				storage.0.extend(level.key_values);
+			} else {
+				for storage_key in level.parent_storage_keys {
+					storage.1.entry(storage_key).or_default().extend(level.key_values.clone());
+				}
+			}
+		}
+		storage
+	}
+
+	fn client_storage(client: &TestClient, child_info: &ChildInfo) -> Storage {
+		let hash = client.info().genesis_hash;
+		let mut storage = Storage::default();
+		for (StorageKey(key), value) in client.storage_pairs(hash, None, None).unwrap() {
+			if !well_known_keys::is_child_storage_key(&key) {
+				storage.0.insert(key, value.0);
+			}
+		}
+		let child = storage.1.entry(child_info.prefixed_storage_key().into_inner()).or_default();
+		for key in client.child_storage_keys(hash, child_info.clone(), None, None).unwrap() {
+			let value = client.child_storage(hash, child_info, &key).unwrap().unwrap();
+			child.insert(key.0, value.0);
+		}
+		storage
+	}
+
+	fn export(client: &TestClient) -> Vec<u8> {
+		let mut snapshot = Vec::new();
+		write_snapshot(client, client.info().genesis_hash, &mut snapshot, 256).unwrap();
+		snapshot
+	}
+
+	#[test]
+	fn snapshot_round_trip_with_child_trie() {
+		let (client, child_info) = client_with_child_trie();
+		let genesis_hash = client.info().genesis_hash;
+		let snapshot = export(&client);
+
+		let (header, justifications, state) =
+			read_snapshot(&client, &snapshot[..], genesis_hash).unwrap();
+
+		assert_eq!(header.hash(), genesis_hash);
+		assert!(justifications.is_none());
+		let storage = imported_storage(state);
+		assert_eq!(storage.0.get(&b"top"[..]).map(Vec::as_slice), Some(TOP_VALUE));
+		assert_eq!(storage.1.values().map(BTreeMap::len).sum::<usize>(), 32);
+		assert_eq!(storage, client_storage(&client, &child_info));
+	}
+
+	#[test]
+	fn snapshot_with_tampered_state_is_rejected() {
+		let (client, _) = client_with_child_trie();
+		let genesis_hash = client.info().genesis_hash;
+		let mut snapshot = export(&client);
+
+		let position = snapshot
+			.windows(TOP_VALUE.len())
+			.position(|window| window == TOP_VALUE)
+			.expect("the value is part of the exported state");
+		snapshot[position] ^= 1;
+
+		assert!(read_snapshot(&client, &snapshot[..], genesis_hash).is_err());
+	}
+
+	#[test]
+	fn snapshot_of_unexpected_block_is_rejected() {
+		let (client, _) = client_with_child_trie();
+		let snapshot = export(&client);
+
+		assert!(read_snapshot(&client, &snapshot[..], Default::default()).is_err());
+	}
+
+	#[test]
+	fn truncated_snapshot_is_rejected() {
+		let (client, _) = client_with_child_trie();
+		let genesis_hash = client.info().genesis_hash;
+		let snapshot = export(&client);
+
+		// Without the terminating `None`, the end of the state is unknown.
+		let truncated = &snapshot[..snapshot.len() - 1];
+		assert!(read_snapshot(&client, truncated, genesis_hash).is_err());
+	}
+}
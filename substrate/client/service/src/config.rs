@@ -37,7 +37,7 @@ pub use sc_rpc_server::{
 	IpNetwork, RpcEndpoint, RpcMethods, SubscriptionIdProvider as RpcSubscriptionIdProvider,
 };
 pub use sc_telemetry::TelemetryEndpoints;
-pub use sc_transaction_pool::TransactionPoolOptions;
+pub use sc_transaction_pool::{TransactionPoolJournalOptions, TransactionPoolOptions};
 use sp_core::crypto::SecretString;
 use std::{
 	io, iter,
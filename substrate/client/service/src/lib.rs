@@ -96,7 +96,7 @@ pub use sc_network_sync::WarpSyncConfig;
 pub use sc_network_transactions::config::{TransactionImport, TransactionImportFuture};
 pub use sc_rpc::{RandomIntegerSubscriptionId, RandomStringSubscriptionId};
 pub use sc_tracing::TracingReceiver;
-pub use sc_transaction_pool::TransactionPoolOptions;
+pub use sc_transaction_pool::{TransactionPoolJournalOptions, TransactionPoolOptions};
 pub use sc_transaction_pool_api::{error::IntoPoolError, InPoolTransaction, TransactionPool};
 #[doc(hidden)]
 pub use std::{ops::Deref, result::Result, sync::Arc};
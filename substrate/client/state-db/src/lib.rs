@@ -408,11 +408,27 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 	}
 
 	fn prune(&mut self, commit: &mut CommitSet<Key>) -> Result<(), Error<D::Error>> {
-		if let (&mut Some(ref mut pruning), PruningMode::Constrained(constraints)) =
-			(&mut self.pruning, &self.mode)
-		{
+		if let PruningMode::Constrained(constraints) = &self.mode {
+			let max_blocks = constraints.max_blocks.unwrap_or(0);
+			self.prune_window(max_blocks, commit)?;
+		}
+		Ok(())
+	}
+
+	fn shrink_window(&mut self, max_blocks: u32) -> Result<CommitSet<Key>, Error<D::Error>> {
+		let mut commit = CommitSet::default();
+		self.prune_window(max_blocks, &mut commit)?;
+		Ok(commit)
+	}
+
+	fn prune_window(
+		&mut self,
+		max_blocks: u32,
+		commit: &mut CommitSet<Key>,
+	) -> Result<(), Error<D::Error>> {
+		if let Some(ref mut pruning) = self.pruning {
 			loop {
-				if pruning.window_size() <= constraints.max_blocks.unwrap_or(0) as u64 {
+				if pruning.window_size() <= max_blocks as u64 {
 					break
 				}
 
@@ -595,6 +611,15 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 		self.db.write().canonicalize_block(hash)
 	}
 
+	/// Prune the oldest canonical states until at most `max_blocks` of them are kept.
+	///
+	/// Unlike the constraints of the pruning mode, this only applies once: the pruning window grows
+	/// back up to the constraints with the next canonicalized blocks. Pinned states are not pruned.
+	/// Returns an empty commit in archive modes.
+	pub fn shrink_window(&self, max_blocks: u32) -> Result<CommitSet<Key>, Error<D::Error>> {
+		self.db.write().shrink_window(max_blocks)
+	}
+
 	/// Prevents pruning of specified block and its descendants.
 	/// `hint` used for further checking if the given block exists
 	pub fn pin<F>(&self, hash: &BlockHash, number: u64, hint: F) -> Result<(), PinError>
@@ -847,6 +872,20 @@ mod tests {
 		assert!(db.data_eq(&make_db(&[1, 21, 3, 921, 922, 93, 94])));
 	}
 
+	#[test]
+	fn shrink_window_prunes_oldest_states() {
+		let (mut db, sdb) =
+			make_test_db(PruningMode::Constrained(Constraints { max_blocks: Some(2) }));
+		db.commit(&sdb.shrink_window(1).unwrap());
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(21), 2), IsPruned::Pruned);
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(22), 2), IsPruned::Pruned);
+		assert!(db.data_eq(&make_db(&[21, 3, 922, 93, 94])));
+
+		let (mut db, sdb) = make_test_db(PruningMode::ArchiveCanonical);
+		db.commit(&sdb.shrink_window(0).unwrap());
+		assert!(db.data_eq(&make_db(&[1, 21, 3, 91, 921, 922, 93, 94])));
+	}
+
 	#[test]
 	fn detects_incompatible_mode() {
 		let mut db = make_db(&[]);
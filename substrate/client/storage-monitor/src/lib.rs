@@ -44,7 +44,8 @@ pub struct StorageMonitorParams {
 	/// Required available space on database storage.
 	///
 	/// If available space for DB storage drops below the given threshold, node will
-	/// be gracefully terminated.
+	/// be gracefully terminated, unless the database is pruned by size, in which case
+	/// its size budget is lowered instead.
 	///
 	/// If `0` is given monitoring will be disabled.
 	#[arg(long = "db-storage-threshold", value_name = "MiB", default_value_t = 1024)]
@@ -55,6 +56,20 @@ pub struct StorageMonitorParams {
 	pub polling_period: u32,
 }
 
+/// Reclaims space on the watched filesystem when the available space drops below the threshold.
+pub trait StorageReclaimer: Send + Sync + 'static {
+	/// Request at least `bytes` bytes to be freed.
+	///
+	/// Returns `false` if no space can be reclaimed, in which case the node is terminated.
+	fn reclaim(&self, bytes: u64) -> bool;
+}
+
+impl<F: Fn(u64) -> bool + Send + Sync + 'static> StorageReclaimer for F {
+	fn reclaim(&self, bytes: u64) -> bool {
+		self(bytes)
+	}
+}
+
 /// Storage monitor service: checks the available space for the filesystem for given path.
 pub struct StorageMonitorService {
 	/// watched path
@@ -63,6 +78,8 @@ pub struct StorageMonitorService {
 	threshold: u64,
 	/// storage space polling period
 	polling_period: Duration,
+	/// reclaims space when available space drops below the threshold
+	reclaimer: Option<Box<dyn StorageReclaimer>>,
 }
 
 impl StorageMonitorService {
@@ -71,6 +88,27 @@ impl StorageMonitorService {
 		parameters: StorageMonitorParams,
 		path: PathBuf,
 		spawner: &impl SpawnEssentialNamed,
+	) -> Result<()> {
+		Self::spawn(parameters, path, spawner, None)
+	}
+
+	/// Creates new StorageMonitorService for given client config, which asks `reclaimer` to free
+	/// some space instead of terminating the node when the available space drops below the
+	/// threshold.
+	pub fn try_spawn_with_reclaimer(
+		parameters: StorageMonitorParams,
+		path: PathBuf,
+		spawner: &impl SpawnEssentialNamed,
+		reclaimer: impl StorageReclaimer,
+	) -> Result<()> {
+		Self::spawn(parameters, path, spawner, Some(Box::new(reclaimer)))
+	}
+
+	fn spawn(
+		parameters: StorageMonitorParams,
+		path: PathBuf,
+		spawner: &impl SpawnEssentialNamed,
+		reclaimer: Option<Box<dyn StorageReclaimer>>,
 	) -> Result<()> {
 		if parameters.threshold == 0 {
 			log::info!(
@@ -84,14 +122,15 @@ impl StorageMonitorService {
 				path.display()
 			);
 
-			Self::check_free_space(&path, parameters.threshold)?;
-
 			let storage_monitor_service = StorageMonitorService {
 				path,
 				threshold: parameters.threshold,
 				polling_period: Duration::from_secs(parameters.polling_period.into()),
+				reclaimer,
 			};
 
+			storage_monitor_service.check()?;
+
 			spawner.spawn_essential(
 				"storage-monitor",
 				None,
@@ -103,16 +142,35 @@ impl StorageMonitorService {
 	}
 
 	/// Main monitoring loop, intended to be spawned as essential task. Quits if free space drop
-	/// below threshold.
+	/// below threshold and no space can be reclaimed.
 	async fn run(self) {
 		loop {
 			tokio::time::sleep(self.polling_period).await;
-			if Self::check_free_space(&self.path, self.threshold).is_err() {
+			if self.check().is_err() {
 				break
 			};
 		}
 	}
 
+	/// Checks the free space of the watched path, asking the reclaimer to free the missing space
+	/// if it dropped below the threshold.
+	fn check(&self) -> Result<()> {
+		let Err(e) = Self::check_free_space(&self.path, self.threshold) else { return Ok(()) };
+		if let (Error::StorageOutOfSpace(available_space, threshold), Some(reclaimer)) =
+			(&e, &self.reclaimer)
+		{
+			let missing_space = threshold - available_space;
+			if reclaimer.reclaim(missing_space.saturating_mul(1024 * 1024)) {
+				log::warn!(target: LOG_TARGET, "Reclaiming {missing_space}MiB for path `{}`.", self.path.display());
+				return Ok(())
+			}
+		}
+		if let Error::StorageOutOfSpace(..) = e {
+			log::error!(target: LOG_TARGET, "Not enough space for path `{}`, terminating...", self.path.display());
+		}
+		Err(e)
+	}
+
 	/// Returns free space in MiB, or error if statvfs failed.
 	fn free_space(path: &Path) -> Result<u64> {
 		Ok(fs4::available_space(path).map(|s| s / 1024 / 1024)?)
@@ -130,7 +188,7 @@ impl StorageMonitorService {
 				);
 
 				if available_space < threshold {
-					log::error!(target: LOG_TARGET, "Available space {available_space}MiB for path `{}` dropped below threshold: {threshold}MiB , terminating...", path.display());
+					log::warn!(target: LOG_TARGET, "Available space {available_space}MiB for path `{}` dropped below threshold: {threshold}MiB.", path.display());
 					Err(Error::StorageOutOfSpace(available_space, threshold))
 				} else {
 					Ok(())
@@ -143,3 +201,61 @@ impl StorageMonitorService {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	};
+
+	fn service(
+		threshold: u64,
+		reclaimer: Option<Box<dyn StorageReclaimer>>,
+	) -> StorageMonitorService {
+		StorageMonitorService {
+			path: std::env::temp_dir(),
+			threshold,
+			polling_period: Duration::from_secs(1),
+			reclaimer,
+		}
+	}
+
+	#[test]
+	fn reclaimer_is_asked_for_the_missing_space() {
+		let available_space = StorageMonitorService::free_space(&std::env::temp_dir()).unwrap();
+		// more than available, so that some space is missing.
+		let threshold = available_space + 10;
+
+		// the node is terminated without a reclaimer.
+		assert!(matches!(
+			service(threshold, None).check(),
+			Err(Error::StorageOutOfSpace(_, t)) if t == threshold
+		));
+
+		let requested = Arc::new(AtomicU64::new(0));
+		let reclaimer = {
+			let requested = requested.clone();
+			move |bytes: u64| {
+				requested.store(bytes, Ordering::Relaxed);
+				true
+			}
+		};
+		assert!(service(threshold, Some(Box::new(reclaimer))).check().is_ok());
+		// the available space may have changed in the meantime.
+		let requested = requested.load(Ordering::Relaxed) / 1024 / 1024;
+		assert!((1..=20).contains(&requested), "requested {requested}MiB");
+
+		// the node is terminated if no space can be reclaimed.
+		assert!(matches!(
+			service(threshold, Some(Box::new(|_: u64| false))).check(),
+			Err(Error::StorageOutOfSpace(..))
+		));
+
+		// the reclaimer is not asked for anything while there is enough space.
+		assert!(service(1, Some(Box::new(|_: u64| -> bool { panic!("nothing to reclaim") })))
+			.check()
+			.is_ok());
+	}
+}
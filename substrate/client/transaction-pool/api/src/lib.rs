@@ -234,6 +234,59 @@ pub trait InPoolTransaction {
 	fn is_propagable(&self) -> bool;
 }
 
+/// The queue holding a transaction within a view of the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionQueue {
+	/// All the tags required by the transaction are satisfied.
+	Ready,
+	/// Some of the tags required by the transaction are missing.
+	Future,
+}
+
+/// Details of a transaction within a single view of the pool.
+///
+/// The validity of a transaction, and so its tags and priority, depends on the block the view is
+/// at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionViewDetails<BlockHash> {
+	/// The block the view is at.
+	pub at: BlockHash,
+	/// The queue holding the transaction.
+	pub queue: TransactionQueue,
+	/// Priority of the transaction.
+	pub priority: TransactionPriority,
+	/// Number of blocks after `at` the transaction remains valid for.
+	pub longevity: TransactionLongevity,
+	/// Tags required by the transaction.
+	pub requires: Vec<TransactionTag>,
+	/// Tags provided by the transaction.
+	pub provides: Vec<TransactionTag>,
+	/// Tags required by the transaction which are not provided by any transaction in the view.
+	pub missing_tags: Vec<TransactionTag>,
+}
+
+/// The reason a transaction was last removed from the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemovalReason<Hash> {
+	/// Dropped to keep the pool within its limits.
+	Dropped,
+	/// Replaced by the transaction with the given hash.
+	Usurped(Hash),
+	/// Found invalid.
+	Invalid,
+}
+
+/// Details of a transaction in the pool, used to find out why it is not included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionDetails<Hash, BlockHash> {
+	/// Hash of the transaction.
+	pub hash: Hash,
+	/// The transaction in every view of the pool containing it.
+	pub views: Vec<TransactionViewDetails<BlockHash>>,
+	/// The reason the transaction was last removed from the pool, if it is known.
+	pub removal_reason: Option<RemovalReason<Hash>>,
+}
+
 /// Transaction pool interface.
 #[async_trait]
 pub trait TransactionPool: Send + Sync {
@@ -323,6 +376,126 @@ pub trait TransactionPool: Send + Sync {
 		at: <Self::Block as BlockT>::Hash,
 		timeout: std::time::Duration,
 	) -> Box<dyn ReadyTransactions<Item = Arc<Self::InPoolTransaction>> + Send>;
+
+	// *** RPC / introspection
+	/// Returns the details of the transaction with the given hash.
+	///
+	/// Returns `None` if the transaction is neither in the pool nor was recently removed from it,
+	/// or if the pool does not support introspection.
+	fn transaction_details(
+		&self,
+		_hash: &TxHash<Self>,
+	) -> Option<TransactionDetails<TxHash<Self>, BlockHash<Self>>> {
+		None
+	}
+
+	/// Returns the details of the transactions in the pool sent by the given sender.
+	///
+	/// The pool knows the sender only from the transaction tags, so `sender` is matched against
+	/// the prefix of the tags provided by the transactions. For transactions using
+	/// `frame_system::CheckNonce` it is the encoded account id.
+	fn sender_transaction_details(
+		&self,
+		_sender: &[u8],
+	) -> Vec<TransactionDetails<TxHash<Self>, BlockHash<Self>>> {
+		Vec::new()
+	}
+}
+
+#[async_trait]
+impl<T: TransactionPool + ?Sized> TransactionPool for Arc<T> {
+	type Block = T::Block;
+	type Hash = T::Hash;
+	type InPoolTransaction = T::InPoolTransaction;
+	type Error = T::Error;
+
+	async fn submit_at(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		xts: Vec<TransactionFor<Self>>,
+	) -> Result<Vec<Result<TxHash<Self>, Self::Error>>, Self::Error> {
+		(**self).submit_at(at, source, xts).await
+	}
+
+	async fn submit_one(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		xt: TransactionFor<Self>,
+	) -> Result<TxHash<Self>, Self::Error> {
+		(**self).submit_one(at, source, xt).await
+	}
+
+	async fn submit_and_watch(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		xt: TransactionFor<Self>,
+	) -> Result<Pin<Box<TransactionStatusStreamFor<Self>>>, Self::Error> {
+		(**self).submit_and_watch(at, source, xt).await
+	}
+
+	async fn ready_at(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+	) -> Box<dyn ReadyTransactions<Item = Arc<Self::InPoolTransaction>> + Send> {
+		(**self).ready_at(at).await
+	}
+
+	fn ready(&self) -> Box<dyn ReadyTransactions<Item = Arc<Self::InPoolTransaction>> + Send> {
+		(**self).ready()
+	}
+
+	fn remove_invalid(&self, hashes: &[TxHash<Self>]) -> Vec<Arc<Self::InPoolTransaction>> {
+		(**self).remove_invalid(hashes)
+	}
+
+	fn futures(&self) -> Vec<Self::InPoolTransaction> {
+		(**self).futures()
+	}
+
+	fn status(&self) -> PoolStatus {
+		(**self).status()
+	}
+
+	fn import_notification_stream(&self) -> ImportNotificationStream<TxHash<Self>> {
+		(**self).import_notification_stream()
+	}
+
+	fn on_broadcasted(&self, propagations: HashMap<TxHash<Self>, Vec<String>>) {
+		(**self).on_broadcasted(propagations)
+	}
+
+	fn hash_of(&self, xt: &TransactionFor<Self>) -> TxHash<Self> {
+		(**self).hash_of(xt)
+	}
+
+	fn ready_transaction(&self, hash: &TxHash<Self>) -> Option<Arc<Self::InPoolTransaction>> {
+		(**self).ready_transaction(hash)
+	}
+
+	async fn ready_at_with_timeout(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		timeout: std::time::Duration,
+	) -> Box<dyn ReadyTransactions<Item = Arc<Self::InPoolTransaction>> + Send> {
+		(**self).ready_at_with_timeout(at, timeout).await
+	}
+
+	fn transaction_details(
+		&self,
+		hash: &TxHash<Self>,
+	) -> Option<TransactionDetails<TxHash<Self>, BlockHash<Self>>> {
+		(**self).transaction_details(hash)
+	}
+
+	fn sender_transaction_details(
+		&self,
+		sender: &[u8],
+	) -> Vec<TransactionDetails<TxHash<Self>, BlockHash<Self>>> {
+		(**self).sender_transaction_details(sender)
+	}
 }
 
 /// An iterator of ready transactions.
@@ -388,6 +561,13 @@ pub trait MaintainedTransactionPool: TransactionPool {
 	async fn maintain(&self, event: ChainEvent<Self::Block>);
 }
 
+#[async_trait]
+impl<T: MaintainedTransactionPool + ?Sized> MaintainedTransactionPool for Arc<T> {
+	async fn maintain(&self, event: ChainEvent<Self::Block>) {
+		(**self).maintain(event).await
+	}
+}
+
 /// Transaction pool interface for submitting local transactions that exposes a
 /// blocking interface for submission.
 pub trait LocalTransactionPool: Send + Sync {
@@ -410,7 +590,7 @@ pub trait LocalTransactionPool: Send + Sync {
 	) -> Result<Self::Hash, Self::Error>;
 }
 
-impl<T: LocalTransactionPool> LocalTransactionPool for Arc<T> {
+impl<T: LocalTransactionPool + ?Sized> LocalTransactionPool for Arc<T> {
 	type Block = T::Block;
 
 	type Hash = T::Hash;
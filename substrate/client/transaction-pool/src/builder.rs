@@ -19,11 +19,14 @@
 //! Utility for building substrate transaction pool trait object.
 
 use crate::{
-	common::api::FullChainApi,
+	common::{
+		api::FullChainApi,
+		journal::{journal_task, TransactionPoolJournalOptions},
+	},
 	fork_aware_txpool::ForkAwareTxPool as ForkAwareFullPool,
 	graph::{base_pool::Transaction, ChainApi, ExtrinsicFor, ExtrinsicHash, IsValidator, Options},
 	single_state_txpool::BasicPool as SingleStateFullPool,
-	TransactionPoolWrapper, LOG_TARGET,
+	PoolPolicy, TransactionPoolWrapper, LOG_TARGET,
 };
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_transaction_pool_api::{LocalTransactionPool, MaintainedTransactionPool};
@@ -45,11 +48,16 @@ pub enum TransactionPoolType {
 pub struct TransactionPoolOptions {
 	txpool_type: TransactionPoolType,
 	options: Options,
+	journal: Option<TransactionPoolJournalOptions>,
 }
 
 impl Default for TransactionPoolOptions {
 	fn default() -> Self {
-		Self { txpool_type: TransactionPoolType::SingleState, options: Default::default() }
+		Self {
+			txpool_type: TransactionPoolType::SingleState,
+			options: Default::default(),
+			journal: None,
+		}
 	}
 }
 
@@ -81,7 +89,22 @@ impl TransactionPoolOptions {
 			Duration::from_secs(30 * 60)
 		};
 
-		TransactionPoolOptions { options, txpool_type }
+		TransactionPoolOptions { options, txpool_type, journal: None }
+	}
+
+	/// Persists the transaction pool across restarts using the given journal.
+	///
+	/// The pool is written to the journal periodically and on shutdown, and the journalled
+	/// transactions are re-validated and submitted back to the pool when it is built.
+	pub fn with_journal(mut self, journal: TransactionPoolJournalOptions) -> Self {
+		self.journal = Some(journal);
+		self
+	}
+
+	/// Applies the given admission and eviction policy to the transaction pool.
+	pub fn with_policy(mut self, policy: PoolPolicy) -> Self {
+		self.options.policy = policy;
+		self
 	}
 
 	/// Creates predefined options for benchmarking
@@ -98,8 +121,10 @@ impl TransactionPoolOptions {
 				},
 				reject_future_transactions: false,
 				ban_time: Duration::from_secs(30 * 60),
+				policy: Default::default(),
 			},
 			txpool_type: TransactionPoolType::SingleState,
+			journal: None,
 		}
 	}
 }
@@ -225,21 +250,34 @@ where
 	/// Creates an instance of transaction pool.
 	pub fn build(self) -> TransactionPoolHandle<Block, Client> {
 		log::info!(target:LOG_TARGET, " creating {:?} txpool {:?}/{:?}.", self.options.txpool_type, self.options.options.ready, self.options.options.future);
-		TransactionPoolWrapper::<Block, Client>(match self.options.txpool_type {
-			TransactionPoolType::SingleState => Box::new(SingleStateFullPool::new_full(
+		// the pool is shared with the journal task, if any.
+		let pool: Arc<dyn FullClientTransactionPool<Block, Client>> = match self.options.txpool_type
+		{
+			TransactionPoolType::SingleState => Arc::new(SingleStateFullPool::new_full(
 				self.options.options,
 				self.is_validator,
 				self.prometheus,
-				self.spawner,
-				self.client,
+				self.spawner.clone(),
+				self.client.clone(),
 			)),
-			TransactionPoolType::ForkAware => Box::new(ForkAwareFullPool::new_full(
+			TransactionPoolType::ForkAware => Arc::new(ForkAwareFullPool::new_full(
 				self.options.options,
 				self.is_validator,
 				self.prometheus,
-				self.spawner,
-				self.client,
+				self.spawner.clone(),
+				self.client.clone(),
 			)),
-		})
+		};
+
+		if let Some(journal) = self.options.journal {
+			log::info!(target:LOG_TARGET, " persisting txpool to journal {:?}.", journal.path);
+			self.spawner.spawn_essential(
+				"txpool-journal",
+				Some("transaction-pool"),
+				Box::pin(journal_task(pool.clone(), self.client, journal)),
+			);
+		}
+
+		TransactionPoolWrapper::<Block, Client>(Box::new(pool))
 	}
 }
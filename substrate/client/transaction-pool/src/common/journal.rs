@@ -0,0 +1,307 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! On-disk journal of the transaction pool.
+//!
+//! The ready and future transactions of the pool are written to the journal periodically and when
+//! the node shuts down. On startup, the journalled transactions are submitted back to the pool,
+//! which re-validates them against the best block, so the ones which became invalid in the
+//! meantime are dropped.
+
+use crate::{graph::base_pool::Transaction, LOG_TARGET};
+use codec::{Decode, Encode};
+use sc_client_api::blockchain::HeaderBackend;
+use sc_transaction_pool_api::{TransactionPool, TxHash};
+use sp_runtime::{
+	traits::Block as BlockT,
+	transaction_validity::{TransactionPriority, TransactionSource},
+};
+use std::{
+	cmp::Reverse,
+	fs, io,
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Duration,
+};
+
+/// Version of the journal format, written at the beginning of the journal.
+const JOURNAL_VERSION: u32 = 1;
+
+/// Options of the transaction pool journal.
+#[derive(Debug, Clone)]
+pub struct TransactionPoolJournalOptions {
+	/// Path of the journal file.
+	pub path: PathBuf,
+	/// How often the pool is written to the journal.
+	pub save_interval: Duration,
+}
+
+/// A journalled transaction.
+#[derive(Encode, Decode)]
+struct JournalEntry<Extrinsic> {
+	source: TransactionSource,
+	priority: TransactionPriority,
+	is_ready: bool,
+	extrinsic: Extrinsic,
+}
+
+/// Encode the ready and future transactions of `pool` as a journal.
+///
+/// Returns the encoded journal and the number of journalled transactions.
+fn encode<Block, P>(pool: &P) -> (Vec<u8>, usize)
+where
+	Block: BlockT,
+	P: TransactionPool<
+			Block = Block,
+			InPoolTransaction = Transaction<TxHash<P>, Arc<Block::Extrinsic>>,
+		> + ?Sized,
+{
+	let ready = pool.ready().collect::<Vec<_>>();
+	let future = pool.futures();
+	let entries = ready
+		.iter()
+		.map(|tx| (&**tx, true))
+		.chain(future.iter().map(|tx| (tx, false)))
+		.map(|(tx, is_ready)| JournalEntry {
+			source: tx.source,
+			priority: tx.priority,
+			is_ready,
+			extrinsic: &*tx.data,
+		})
+		.collect::<Vec<_>>();
+
+	((JOURNAL_VERSION, &entries).encode(), entries.len())
+}
+
+/// Atomically replace the journal at `path` with `journal`.
+fn write(path: &Path, journal: &[u8]) -> io::Result<()> {
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir)?;
+	}
+	let tmp_path = path.with_extension("tmp");
+	fs::write(&tmp_path, journal)?;
+	fs::rename(&tmp_path, path)
+}
+
+/// Write the ready and future transactions of `pool` to the journal at `path`.
+///
+/// The journal is replaced atomically. Returns the number of journalled transactions.
+pub(crate) fn save<Block, P>(pool: &P, path: &Path) -> io::Result<usize>
+where
+	Block: BlockT,
+	P: TransactionPool<
+			Block = Block,
+			InPoolTransaction = Transaction<TxHash<P>, Arc<Block::Extrinsic>>,
+		> + ?Sized,
+{
+	let (journal, count) = encode(pool);
+	write(path, &journal)?;
+	Ok(count)
+}
+
+/// Like [`save`], but writes the journal on a blocking thread.
+async fn save_in_background<Block, P>(pool: &P, path: &Path) -> io::Result<usize>
+where
+	Block: BlockT,
+	P: TransactionPool<
+			Block = Block,
+			InPoolTransaction = Transaction<TxHash<P>, Arc<Block::Extrinsic>>,
+		> + ?Sized,
+{
+	let (journal, count) = encode(pool);
+	let path = path.to_owned();
+	tokio::task::spawn_blocking(move || write(&path, &journal))
+		.await
+		.map_err(io::Error::other)??;
+	Ok(count)
+}
+
+/// Log the outcome of writing the journal at `path`.
+fn log_saved(result: io::Result<usize>, path: &Path) {
+	match result {
+		Ok(count) => log::debug!(target: LOG_TARGET, "Journalled {count} transactions to {path:?}"),
+		Err(e) =>
+			log::warn!(target: LOG_TARGET, "Failed to write transaction pool journal {path:?}: {e}"),
+	}
+}
+
+/// Read the transactions journalled at `path`.
+///
+/// A missing journal is considered empty.
+fn load<Extrinsic: Decode>(path: &Path) -> io::Result<Vec<JournalEntry<Extrinsic>>> {
+	let bytes = match fs::read(path) {
+		Ok(bytes) => bytes,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e),
+	};
+
+	let (version, entries) = <(u32, Vec<JournalEntry<Extrinsic>>)>::decode(&mut &bytes[..])
+		.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+	if version != JOURNAL_VERSION {
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidData,
+			format!("unsupported journal version {version}"),
+		))
+	}
+
+	Ok(entries)
+}
+
+/// Submit the transactions journalled at `path` to `pool`, validating them at block `at`.
+///
+/// Ready transactions are submitted first, in the order they were journalled, followed by the
+/// future transactions by descending priority. Returns the number of journalled transactions and
+/// the number of them accepted by the pool.
+pub(crate) async fn restore<Block, P>(
+	pool: &P,
+	at: Block::Hash,
+	path: &Path,
+) -> io::Result<(usize, usize)>
+where
+	Block: BlockT,
+	P: TransactionPool<Block = Block> + ?Sized,
+{
+	let (ready, mut future): (Vec<_>, Vec<_>) =
+		load::<Block::Extrinsic>(path)?.into_iter().partition(|entry| entry.is_ready);
+	future.sort_by_key(|entry| Reverse(entry.priority));
+
+	let total = ready.len() + future.len();
+	let mut accepted = 0;
+	let mut entries = ready.into_iter().chain(future).peekable();
+	while let Some(first) = entries.next() {
+		// submit runs of transactions with the same source together.
+		let source = first.source;
+		let mut xts = vec![first.extrinsic];
+		while let Some(entry) = entries.next_if(|entry| entry.source == source) {
+			xts.push(entry.extrinsic);
+		}
+
+		match pool.submit_at(at, source, xts).await {
+			Ok(results) => accepted += results.iter().filter(|result| result.is_ok()).count(),
+			Err(e) => log::debug!(target: LOG_TARGET, "Failed to restore transactions: {e:?}"),
+		}
+	}
+
+	Ok((total, accepted))
+}
+
+/// Runs the wrapped function when dropped.
+struct OnDrop<F: Fn()>(F);
+
+impl<F: Fn()> Drop for OnDrop<F> {
+	fn drop(&mut self) {
+		(self.0)()
+	}
+}
+
+/// Task restoring `pool` from its journal, and writing it back to the journal periodically and on
+/// shutdown.
+pub(crate) async fn journal_task<Block, Client, P>(
+	pool: Arc<P>,
+	client: Arc<Client>,
+	options: TransactionPoolJournalOptions,
+) where
+	Block: BlockT,
+	Client: HeaderBackend<Block>,
+	P: TransactionPool<
+			Block = Block,
+			InPoolTransaction = Transaction<TxHash<P>, Arc<Block::Extrinsic>>,
+		> + ?Sized,
+{
+	let at = client.info().best_hash;
+	match restore(&*pool, at, &options.path).await {
+		Ok((total, accepted)) => log::info!(
+			target: LOG_TARGET,
+			"Restored {accepted} of {total} transactions from journal {:?}",
+			options.path
+		),
+		Err(e) => log::warn!(
+			target: LOG_TARGET,
+			"Failed to read transaction pool journal {:?}: {e}",
+			options.path
+		),
+	}
+
+	let path = options.path;
+	// the pool is also written when the task is dropped on shutdown, blocking as the runtime is
+	// shutting down anyway.
+	let _on_shutdown = OnDrop(|| log_saved(save(&*pool, &path), &path));
+	loop {
+		futures_timer::Delay::new(options.save_interval).await;
+		log_saved(save_in_background(&*pool, &path).await, &path);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		common::tests::{uxt, TestApi},
+		BasicPool,
+	};
+	use futures::executor::block_on;
+	use substrate_test_runtime::{AccountId, Transfer, H256};
+	use substrate_test_runtime_client::AccountKeyring::Alice;
+
+	fn transfer(nonce: u64) -> substrate_test_runtime::Extrinsic {
+		uxt(Transfer {
+			from: Alice.into(),
+			to: AccountId::from_h256(H256::from_low_u64_be(2)),
+			amount: 5,
+			nonce,
+		})
+	}
+
+	fn new_pool(api: Arc<TestApi>, at: H256) -> BasicPool<TestApi, substrate_test_runtime::Block> {
+		BasicPool::new_test(api, at, at, Default::default()).0
+	}
+
+	#[test]
+	fn journal_restores_pool() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("txpool").join("journal");
+		let api = Arc::new(TestApi::default());
+		let block0 = api.expect_hash_from_number(0);
+		let block1 = api.expect_hash_from_number(1);
+
+		// a ready and a future transaction.
+		let pool = new_pool(api.clone(), block0);
+		block_on(pool.submit_at(block0, TransactionSource::Local, vec![transfer(0), transfer(2)]))
+			.unwrap();
+		assert_eq!(pool.status().ready, 1);
+		assert_eq!(pool.status().future, 1);
+		assert_eq!(save(&pool, &path).unwrap(), 2);
+
+		// both are restored and re-validated.
+		let pool = new_pool(api.clone(), block0);
+		assert_eq!(block_on(restore(&pool, block0, &path)).unwrap(), (2, 2));
+		assert_eq!(pool.status().ready, 1);
+		assert_eq!(pool.status().future, 1);
+		assert_eq!(pool.ready().next().unwrap().source, TransactionSource::Local);
+
+		// the transaction which became stale is dropped.
+		let pool = new_pool(api.clone(), block1);
+		assert_eq!(block_on(restore(&pool, block1, &path)).unwrap(), (2, 1));
+		assert_eq!(pool.status().ready, 0);
+		assert_eq!(pool.status().future, 1);
+
+		// a missing journal is empty.
+		fs::remove_dir_all(path.parent().unwrap()).unwrap();
+		assert_eq!(block_on(restore(&pool, block1, &path)).unwrap(), (0, 0));
+	}
+}
@@ -29,7 +29,7 @@ use crate::{
 };
 use futures::stream::StreamExt;
 use log::{debug, trace};
-use sc_transaction_pool_api::TransactionStatus;
+use sc_transaction_pool_api::{RemovalReason, TransactionStatus};
 use sc_utils::mpsc;
 use sp_runtime::traits::Block as BlockT;
 use std::{
@@ -45,9 +45,19 @@ pub type ViewStreamEvent<C> = crate::graph::DroppedByLimitsEvent<ExtrinsicHash<C
 /// Dropped-logic stream of events coming from the single view.
 type ViewStream<C> = Pin<Box<dyn futures::Stream<Item = ViewStreamEvent<C>> + Send>>;
 
-/// Stream of extrinsic hashes that were dropped by the views and have no references by existing
+/// A transaction dropped by the views, which is no longer referenced by any existing view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DroppedTransaction<Hash> {
+	/// Hash of the dropped transaction.
+	pub tx_hash: Hash,
+	/// The reason of the drop, as reported by the last view dropping the transaction.
+	pub reason: RemovalReason<Hash>,
+}
+
+/// Stream of transactions that were dropped by the views and have no references by existing
 /// views.
-pub(crate) type StreamOfDropped<C> = Pin<Box<dyn futures::Stream<Item = ExtrinsicHash<C>> + Send>>;
+pub(crate) type StreamOfDropped<C> =
+	Pin<Box<dyn futures::Stream<Item = DroppedTransaction<ExtrinsicHash<C>>> + Send>>;
 
 /// A type alias for a sender used as the controller of the [`MultiViewDropWatcherContext`].
 /// Used to send control commands from the [`MultiViewDroppedWatcherController`] to
@@ -120,12 +130,12 @@ where
 	/// accordingly.
 	///
 	/// If the event indicates that a transaction has been dropped and is no longer referenced by
-	/// any active views, the transaction hash is returned. Otherwise `None` is returned.
+	/// any active views, the dropped transaction is returned. Otherwise `None` is returned.
 	fn handle_event(
 		&mut self,
 		block_hash: BlockHash<C>,
 		event: ViewStreamEvent<C>,
-	) -> Option<ExtrinsicHash<C>> {
+	) -> Option<DroppedTransaction<ExtrinsicHash<C>>> {
 		trace!(
 			target: LOG_TARGET,
 			"dropped_watcher: handle_event: event:{:?} views:{:?}, ",
@@ -138,6 +148,11 @@ where
 				self.transaction_states.entry(tx_hash).or_default().insert(block_hash);
 			},
 			TransactionStatus::Dropped | TransactionStatus::Usurped(_) => {
+				let reason = match status {
+					TransactionStatus::Usurped(by) => RemovalReason::Usurped(by),
+					_ => RemovalReason::Dropped,
+				};
+				let dropped = DroppedTransaction { tx_hash, reason };
 				if let Entry::Occupied(mut views_keeping_tx_valid) =
 					self.transaction_states.entry(tx_hash)
 				{
@@ -148,11 +163,11 @@ where
 							.iter()
 							.all(|h| !self.stream_map.contains_key(h))
 					{
-						return Some(tx_hash)
+						return Some(dropped)
 					}
 				} else {
 					debug!("[{:?}] dropped_watcher: removing (non-tracked) tx", tx_hash);
-					return Some(tx_hash)
+					return Some(dropped)
 				}
 			},
 			_ => {},
@@ -282,6 +297,10 @@ mod dropped_watcher_tests {
 
 	type MultiViewDroppedWatcher = super::MultiViewDroppedWatcherController<TestApi>;
 
+	fn dropped(tx_hash: H256, reason: RemovalReason<H256>) -> DroppedTransaction<H256> {
+		DroppedTransaction { tx_hash, reason }
+	}
+
 	#[tokio::test]
 	async fn test01() {
 		sp_tracing::try_init_simple();
@@ -298,7 +317,7 @@ mod dropped_watcher_tests {
 
 		watcher.add_view(block_hash, view_stream);
 		let handle = tokio::spawn(async move { output_stream.take(1).collect::<Vec<_>>().await });
-		assert_eq!(handle.await.unwrap(), vec![tx_hash]);
+		assert_eq!(handle.await.unwrap(), vec![dropped(tx_hash, RemovalReason::Dropped)]);
 	}
 
 	#[tokio::test]
@@ -348,7 +367,7 @@ mod dropped_watcher_tests {
 		watcher.add_view(block_hash0, view_stream0);
 		watcher.add_view(block_hash1, view_stream1);
 		let handle = tokio::spawn(async move { output_stream.take(1).collect::<Vec<_>>().await });
-		assert_eq!(handle.await.unwrap(), vec![tx_hash1]);
+		assert_eq!(handle.await.unwrap(), vec![dropped(tx_hash1, RemovalReason::Dropped)]);
 	}
 
 	#[tokio::test]
@@ -376,7 +395,7 @@ mod dropped_watcher_tests {
 
 		watcher.add_view(block_hash1, view_stream1);
 		let handle = tokio::spawn(async move { output_stream.take(1).collect::<Vec<_>>().await });
-		assert_eq!(handle.await.unwrap(), vec![tx_hash]);
+		assert_eq!(handle.await.unwrap(), vec![dropped(tx_hash, RemovalReason::Dropped)]);
 	}
 
 	#[tokio::test]
@@ -419,6 +438,26 @@ mod dropped_watcher_tests {
 		let block_hash2 = H256::repeat_byte(0x03);
 		watcher.add_view(block_hash2, view_stream2);
 		let handle = tokio::spawn(async move { output_stream.take(1).collect::<Vec<_>>().await });
-		assert_eq!(handle.await.unwrap(), vec![tx_hash]);
+		assert_eq!(handle.await.unwrap(), vec![dropped(tx_hash, RemovalReason::Dropped)]);
+	}
+
+	#[tokio::test]
+	async fn test06() {
+		sp_tracing::try_init_simple();
+		let (watcher, output_stream) = MultiViewDroppedWatcher::new();
+
+		let block_hash = H256::repeat_byte(0x01);
+		let tx_hash = H256::repeat_byte(0x0a);
+		let by = H256::repeat_byte(0x0b);
+
+		let view_stream = futures::stream::iter(vec![
+			(tx_hash, TransactionStatus::Ready),
+			(tx_hash, TransactionStatus::Usurped(by)),
+		])
+		.boxed();
+
+		watcher.add_view(block_hash, view_stream);
+		let handle = tokio::spawn(async move { output_stream.take(1).collect::<Vec<_>>().await });
+		assert_eq!(handle.await.unwrap(), vec![dropped(tx_hash, RemovalReason::Usurped(by))]);
 	}
 }
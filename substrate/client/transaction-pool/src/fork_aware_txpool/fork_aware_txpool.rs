@@ -45,8 +45,9 @@ use futures::{
 use parking_lot::Mutex;
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_transaction_pool_api::{
-	ChainEvent, ImportNotificationStream, MaintainedTransactionPool, PoolStatus, TransactionFor,
-	TransactionPool, TransactionSource, TransactionStatusStreamFor, TxHash,
+	ChainEvent, ImportNotificationStream, MaintainedTransactionPool, PoolStatus,
+	TransactionDetails, TransactionFor, TransactionPool, TransactionSource,
+	TransactionStatusStreamFor, TxHash,
 };
 use sp_blockchain::{HashAndNumber, TreeRoute};
 use sp_core::traits::SpawnEssentialNamed;
@@ -182,6 +183,24 @@ where
 		ready_limits: crate::PoolLimit,
 		future_limits: crate::PoolLimit,
 		mempool_max_transactions_count: usize,
+	) -> (Self, ForkAwareTxPoolTask) {
+		Self::new_test_with_options(
+			pool_api,
+			best_block_hash,
+			finalized_hash,
+			Options { ready: ready_limits, future: future_limits, ..Default::default() },
+			mempool_max_transactions_count,
+		)
+	}
+
+	/// Create new fork aware transaction pool with given options and with provided shared instance
+	/// of `ChainApi` intended for tests.
+	pub fn new_test_with_options(
+		pool_api: Arc<ChainApi>,
+		best_block_hash: Block::Hash,
+		finalized_hash: Block::Hash,
+		options: Options,
+		mempool_max_transactions_count: usize,
 	) -> (Self, ForkAwareTxPoolTask) {
 		let listener = Arc::from(MultiViewListener::new());
 		let (import_notification_sink, import_notification_sink_task) =
@@ -192,7 +211,7 @@ where
 			listener.clone(),
 			Default::default(),
 			mempool_max_transactions_count,
-			ready_limits.total_bytes + future_limits.total_bytes,
+			options.ready.total_bytes + options.future.total_bytes,
 		));
 
 		let (dropped_stream_controller, dropped_stream) =
@@ -211,8 +230,6 @@ where
 		}
 		.boxed();
 
-		let options = Options { ready: ready_limits, future: future_limits, ..Default::default() };
-
 		(
 			Self {
 				mempool,
@@ -252,8 +269,9 @@ where
 				break;
 			};
 			log::trace!(target: LOG_TARGET, "[{:?}] fatp::dropped notification, removing", dropped);
+			let tx_hash = dropped.tx_hash;
 			mempool.remove_dropped_transactions(&[dropped]).await;
-			import_notification_sink.clean_notified_items(&[dropped]);
+			import_notification_sink.clean_notified_items(&[tx_hash]);
 		}
 	}
 
@@ -449,6 +467,37 @@ where
 		}
 	}
 
+	/// Returns the details of the transactions matching the filter in the active views.
+	///
+	/// The details are grouped by transaction, and include the reason of the last removal of the
+	/// transaction from the pool, if any.
+	fn transaction_details_matching(
+		&self,
+		filter: impl Fn(&Transaction<ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>>) -> bool,
+	) -> Vec<TransactionDetails<ExtrinsicHash<ChainApi>, Block::Hash>> {
+		let mut views = HashMap::<_, Vec<_>>::new();
+		for (tx_hash, details) in self.view_store.inspect(filter) {
+			views.entry(tx_hash).or_default().push(details);
+		}
+		views
+			.into_iter()
+			.map(|(hash, views)| TransactionDetails {
+				hash,
+				views,
+				removal_reason: self.view_store.listener.removal_reason(&hash),
+			})
+			.collect()
+	}
+
+	/// Forgets the past removals of the transactions which were successfully submitted again.
+	fn forget_removal_reasons<E>(&self, results: &[Result<ExtrinsicHash<ChainApi>, E>]) {
+		let submitted = results
+			.iter()
+			.filter_map(|result| result.as_ref().ok().copied())
+			.collect::<Vec<_>>();
+		self.view_store.listener.forget_removal_reasons(&submitted);
+	}
+
 	/// Waits for the set of ready transactions for a given block up to a specified timeout.
 	///
 	/// This method combines two futures:
@@ -600,6 +649,7 @@ where
 		let mempool_results = self.mempool.extend_unwatched(source, &xts);
 
 		if view_store.is_empty() {
+			self.forget_removal_reasons(&mempool_results);
 			return Ok(mempool_results)
 		}
 
@@ -616,7 +666,7 @@ where
 		let results_map = view_store.submit(source, to_be_submitted.into_iter()).await;
 		let mut submission_results = reduce_multiview_result(results_map).into_iter();
 
-		Ok(mempool_results
+		let results = mempool_results
 				.into_iter()
 				.map(|result| {
 					result.and_then(|xt_hash| {
@@ -628,7 +678,9 @@ where
 							)
 					})
 				})
-				.collect::<Vec<_>>())
+				.collect::<Vec<_>>();
+		self.forget_removal_reasons(&results);
+		Ok(results)
 	}
 
 	/// Submits a single transaction and returns a future resolving to the submission results.
@@ -672,6 +724,7 @@ where
 		view_store
 			.submit_and_watch(at, source, xt)
 			.await
+			.inspect(|_| self.view_store.listener.forget_removal_reasons(&[xt_hash]))
 			.inspect_err(|_| mempool.remove(xt_hash))
 	}
 
@@ -775,6 +828,28 @@ where
 	) -> ReadyIteratorFor<ChainApi> {
 		self.ready_at_with_timeout_internal(at, timeout).await
 	}
+
+	/// Returns the details of the transaction with the given hash.
+	///
+	/// A transaction kept in the mempool but not in any view, or recently removed from the pool,
+	/// is reported without views.
+	fn transaction_details(
+		&self,
+		hash: &TxHash<Self>,
+	) -> Option<TransactionDetails<TxHash<Self>, Block::Hash>> {
+		self.transaction_details_matching(|tx| tx.hash == *hash).pop().or_else(|| {
+			let removal_reason = self.view_store.listener.removal_reason(hash);
+			(removal_reason.is_some() || self.mempool.get_by_hash(*hash).is_some())
+				.then(|| TransactionDetails { hash: *hash, views: Vec::new(), removal_reason })
+		})
+	}
+
+	fn sender_transaction_details(
+		&self,
+		sender: &[u8],
+	) -> Vec<TransactionDetails<TxHash<Self>, Block::Hash>> {
+		self.transaction_details_matching(|tx| graph::base_pool::is_sent_by(tx, sender))
+	}
 }
 
 impl<Block, Client> sc_transaction_pool_api::LocalTransactionPool
@@ -806,7 +881,9 @@ where
 			.extend_unwatched(TransactionSource::Local, &[xt.clone()])
 			.remove(0)?;
 
-		self.view_store.submit_local(xt).or_else(|_| Ok(result))
+		let result = self.view_store.submit_local(xt).or_else(|_| Ok(result));
+		self.forget_removal_reasons(std::slice::from_ref(&result));
+		result
 	}
 }
 
@@ -847,6 +924,7 @@ where
 
 		let best_view = self.view_store.find_best_view(tree_route);
 		let new_view = self.build_new_view(best_view, hash_and_number, tree_route).await;
+		self.view_store.evict_aged_futures();
 
 		if let Some(view) = new_view {
 			{
@@ -1044,18 +1122,31 @@ where
 		let xts = self.mempool.clone_unwatched();
 
 		let mut all_submitted_count = 0;
+		let mut unwatched_results = Vec::default();
 		if !xts.is_empty() {
 			let unwatched_count = xts.len();
-			let mut buckets = HashMap::<TransactionSource, Vec<ExtrinsicFor<ChainApi>>>::default();
+			let mut buckets = HashMap::<
+				TransactionSource,
+				Vec<(ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>)>,
+			>::default();
 			xts.into_iter()
 				.filter(|(hash, _)| !view.pool.validated_pool().pool.read().is_imported(hash))
 				.filter(|(hash, _)| !included_xts.contains(&hash))
-				.map(|(_, tx)| (tx.source(), tx.tx()))
-				.for_each(|(source, tx)| buckets.entry(source).or_default().push(tx));
+				.map(|(tx_hash, tx)| (tx.source(), tx_hash, tx.tx()))
+				.for_each(|(source, tx_hash, tx)| {
+					buckets.entry(source).or_default().push((tx_hash, tx))
+				});
 
 			for (source, xts) in buckets {
 				all_submitted_count += xts.len();
-				let _ = view.submit_many(source, xts).await;
+				let hashes = xts.iter().map(|i| i.0).collect::<Vec<_>>();
+				let results = view
+					.submit_many(source, xts.into_iter().map(|i| i.1))
+					.await
+					.into_iter()
+					.zip(hashes)
+					.map(|(result, tx_hash)| result.or_else(|_| Err(tx_hash)));
+				unwatched_results.extend(results);
 			}
 			log::debug!(target: LOG_TARGET, "update_view_with_mempool: at {:?} unwatched {}/{}", view.at.hash, all_submitted_count, unwatched_count);
 		}
@@ -1103,6 +1194,13 @@ where
 					self.mempool.remove(tx_hash);
 				}
 			}
+			// the unwatched transactions were admitted to the mempool without any view, so the
+			// limits of the view, including the per-sender ones, are only enforced now.
+			for result in unwatched_results {
+				if let Err(tx_hash) = result {
+					self.mempool.remove(tx_hash);
+				}
+			}
 		}
 	}
 
@@ -21,13 +21,15 @@
 //! aggregated streams of transaction events.
 
 use crate::{
-	fork_aware_txpool::stream_map_util::next_event,
-	graph::{self, BlockHash, ExtrinsicHash},
+	fork_aware_txpool::{dropped_watcher::DroppedTransaction, stream_map_util::next_event},
+	graph::{self, BlockHash, ExtrinsicHash, MAX_REMOVAL_REASONS},
 	LOG_TARGET,
 };
 use futures::StreamExt;
+use linked_hash_map::LinkedHashMap;
 use log::{debug, trace};
-use sc_transaction_pool_api::{TransactionStatus, TransactionStatusStream, TxIndex};
+use parking_lot::Mutex;
+use sc_transaction_pool_api::{RemovalReason, TransactionStatus, TransactionStatusStream, TxIndex};
 use sc_utils::mpsc;
 use sp_runtime::traits::Block as BlockT;
 use std::{
@@ -122,6 +124,10 @@ pub struct MultiViewListener<ChainApi: graph::ChainApi> {
 	controllers: parking_lot::RwLock<
 		HashMap<ExtrinsicHash<ChainApi>, Controller<ControllerCommand<ChainApi>>>,
 	>,
+	/// The reasons of the most recent removals of transactions from the pool, used for
+	/// introspection.
+	removal_reasons:
+		Mutex<LinkedHashMap<ExtrinsicHash<ChainApi>, RemovalReason<ExtrinsicHash<ChainApi>>>>,
 }
 
 /// The external stream unfolding context.
@@ -279,7 +285,7 @@ where
 {
 	/// Creates new instance of `MultiViewListener`.
 	pub fn new() -> Self {
-		Self { controllers: Default::default() }
+		Self { controllers: Default::default(), removal_reasons: Default::default() }
 	}
 
 	/// Creates an external aggregated stream of events for given transaction.
@@ -407,6 +413,9 @@ where
 	/// The external event will be sent if no view is referencing the transaction as `Ready` or
 	/// `Future`.
 	pub(crate) fn invalidate_transactions(&self, invalid_hashes: &[ExtrinsicHash<ChainApi>]) {
+		self.record_removal_reasons(
+			invalid_hashes.iter().map(|tx_hash| (*tx_hash, RemovalReason::Invalid)),
+		);
 		let mut controllers = self.controllers.write();
 		invalid_hashes.iter().for_each(|tx_hash| {
 			if let Entry::Occupied(mut tx) = controllers.entry(*tx_hash) {
@@ -445,10 +454,16 @@ where
 	///
 	/// This method sends a `TransactionDropped` command to the controller of each requested
 	/// transaction prompting and external `Broadcasted` event.
-	pub(crate) fn transactions_dropped(&self, dropped: &[ExtrinsicHash<ChainApi>]) {
+	pub(crate) fn transactions_dropped(
+		&self,
+		dropped: &[DroppedTransaction<ExtrinsicHash<ChainApi>>],
+	) {
+		self.record_removal_reasons(
+			dropped.iter().map(|dropped| (dropped.tx_hash, dropped.reason.clone())),
+		);
 		let mut controllers = self.controllers.write();
 		debug!(target: LOG_TARGET, "mvl::transactions_dropped: {:?}", dropped);
-		for tx_hash in dropped {
+		for DroppedTransaction { tx_hash, .. } in dropped {
 			if let Some(tx) = controllers.remove(&tx_hash) {
 				debug!(target: LOG_TARGET, "[{:?}] transaction_dropped", tx_hash);
 				if let Err(e) = tx.unbounded_send(ControllerCommand::TransactionDropped) {
@@ -476,6 +491,39 @@ where
 		};
 	}
 
+	/// Keeps the given reasons of transactions removal, evicting the oldest ones above the limit.
+	fn record_removal_reasons(
+		&self,
+		reasons: impl Iterator<Item = (ExtrinsicHash<ChainApi>, RemovalReason<ExtrinsicHash<ChainApi>>)>,
+	) {
+		let mut removal_reasons = self.removal_reasons.lock();
+		for (tx_hash, reason) in reasons {
+			removal_reasons.insert(tx_hash, reason);
+		}
+		while removal_reasons.len() > MAX_REMOVAL_REASONS {
+			removal_reasons.pop_front();
+		}
+	}
+
+	/// Forgets the reasons of the past removals of the given transactions.
+	///
+	/// Intended to be called when the transactions are submitted to the pool again, so the
+	/// reason of a removal is not reported for a transaction which is back in the pool.
+	pub(crate) fn forget_removal_reasons(&self, tx_hashes: &[ExtrinsicHash<ChainApi>]) {
+		let mut removal_reasons = self.removal_reasons.lock();
+		for tx_hash in tx_hashes {
+			removal_reasons.remove(tx_hash);
+		}
+	}
+
+	/// Returns the reason the given transaction was last removed from the pool, if it is known.
+	pub(crate) fn removal_reason(
+		&self,
+		tx_hash: &ExtrinsicHash<ChainApi>,
+	) -> Option<RemovalReason<ExtrinsicHash<ChainApi>>> {
+		self.removal_reasons.lock().get(tx_hash).cloned()
+	}
+
 	/// Removes stale controllers.
 	pub(crate) fn remove_stale_controllers(&self) {
 		self.controllers.write().retain(|_, c| !c.is_closed());
@@ -733,4 +781,28 @@ mod tests {
 		assert!(out.iter().all(|v| vec![TransactionStatus::Invalid].contains(v)));
 		assert_eq!(out.len(), 1);
 	}
+
+	#[test]
+	fn test06() {
+		sp_tracing::try_init_simple();
+		let listener = MultiViewListener::new();
+
+		let tx_hash0 = H256::repeat_byte(0x0a);
+		let tx_hash1 = H256::repeat_byte(0x0b);
+		assert_eq!(listener.removal_reason(&tx_hash0), None);
+
+		listener.invalidate_transactions(&[tx_hash0]);
+		listener.transactions_dropped(&[DroppedTransaction {
+			tx_hash: tx_hash1,
+			reason: RemovalReason::Usurped(tx_hash0),
+		}]);
+
+		assert_eq!(listener.removal_reason(&tx_hash0), Some(RemovalReason::Invalid));
+		assert_eq!(listener.removal_reason(&tx_hash1), Some(RemovalReason::Usurped(tx_hash0)));
+
+		listener.forget_removal_reasons(&[tx_hash0]);
+
+		assert_eq!(listener.removal_reason(&tx_hash0), None);
+		assert_eq!(listener.removal_reason(&tx_hash1), Some(RemovalReason::Usurped(tx_hash0)));
+	}
 }
@@ -26,7 +26,10 @@
 //!   it), while on other forks tx can be valid. Depending on which view is chosen to be cloned,
 //!   such transaction could not be present in the newly created view.
 
-use super::{metrics::MetricsLink as PrometheusMetrics, multi_view_listener::MultiViewListener};
+use super::{
+	dropped_watcher::DroppedTransaction, metrics::MetricsLink as PrometheusMetrics,
+	multi_view_listener::MultiViewListener,
+};
 use crate::{
 	common::log_xt::log_xt_trace,
 	graph,
@@ -299,17 +302,17 @@ where
 		self.try_insert(hash, TxInMemPool::new_watched(source, xt.clone(), length))
 	}
 
-	/// Removes transactions from the memory pool which are specified by the given list of hashes
-	/// and send the `Dropped` event to the listeners of these transactions.
+	/// Removes the given dropped transactions from the memory pool and send the `Dropped` event to
+	/// the listeners of these transactions.
 	pub(super) async fn remove_dropped_transactions(
 		&self,
-		to_be_removed: &[ExtrinsicHash<ChainApi>],
+		to_be_removed: &[DroppedTransaction<ExtrinsicHash<ChainApi>>],
 	) {
 		log::debug!(target: LOG_TARGET, "remove_dropped_transactions count:{:?}", to_be_removed.len());
-		log_xt_trace!(target: LOG_TARGET, to_be_removed, "[{:?}] mempool::remove_dropped_transactions");
+		log_xt_trace!(target: LOG_TARGET, to_be_removed.iter().map(|t| t.tx_hash), "[{:?}] mempool::remove_dropped_transactions");
 		let mut transactions = self.transactions.write();
 		to_be_removed.iter().for_each(|t| {
-			transactions.remove(t);
+			transactions.remove(&t.tx_hash);
 		});
 
 		self.listener.transactions_dropped(to_be_removed);
@@ -31,7 +31,9 @@ use crate::{
 use futures::prelude::*;
 use itertools::Itertools;
 use parking_lot::RwLock;
-use sc_transaction_pool_api::{error::Error as PoolError, PoolStatus, TransactionSource};
+use sc_transaction_pool_api::{
+	error::Error as PoolError, PoolStatus, TransactionSource, TransactionViewDetails,
+};
 use sp_blockchain::TreeRoute;
 use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 use std::{collections::HashMap, sync::Arc, time::Instant};
@@ -193,6 +195,31 @@ where
 		self.active_views.read().iter().map(|(h, v)| (*h, v.status())).collect()
 	}
 
+	/// Returns the details of the transactions matching the filter in every active view.
+	pub(super) fn inspect(
+		&self,
+		filter: impl Fn(&Transaction<ExtrinsicHash<ChainApi>, ExtrinsicFor<ChainApi>>) -> bool,
+	) -> Vec<(ExtrinsicHash<ChainApi>, TransactionViewDetails<Block::Hash>)> {
+		self.active_views
+			.read()
+			.values()
+			.flat_map(|view| view.pool.validated_pool().inspect(&view.at, &filter))
+			.collect()
+	}
+
+	/// Evicts the future transactions of the senders with aged nonce gaps from every view.
+	///
+	/// Once a transaction is evicted from all the views, the dropped transactions watcher removes
+	/// it from the mempool.
+	pub(super) fn evict_aged_futures(&self) {
+		let active_views = self.active_views.read();
+		let inactive_views = self.inactive_views.read();
+		active_views
+			.values()
+			.chain(inactive_views.values())
+			.for_each(|view| view.pool.validated_pool().evict_aged_futures());
+	}
+
 	/// Returns true if there are no active views.
 	pub(super) fn is_empty(&self) -> bool {
 		self.active_views.read().is_empty() && self.inactive_views.read().is_empty()
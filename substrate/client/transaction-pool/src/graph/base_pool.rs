@@ -20,7 +20,13 @@
 //!
 //! For a more full-featured pool, have a look at the `pool` module.
 
-use std::{cmp::Ordering, collections::HashSet, fmt, hash, sync::Arc};
+use std::{
+	cmp::Ordering,
+	collections::HashSet,
+	fmt, hash,
+	sync::Arc,
+	time::{Duration, Instant},
+};
 
 use crate::LOG_TARGET;
 use log::{trace, warn};
@@ -33,6 +39,7 @@ use sp_runtime::{
 		TransactionLongevity as Longevity, TransactionPriority as Priority,
 		TransactionSource as Source, TransactionTag as Tag,
 	},
+	Percent,
 };
 
 use super::{
@@ -210,6 +217,7 @@ const RECENTLY_PRUNED_TAGS: usize = 2;
 #[derive(Clone, Debug)]
 pub struct BasePool<Hash: hash::Hash + Eq, Ex> {
 	reject_future_transactions: bool,
+	policy: Policy,
 	future: FutureTransactions<Hash, Ex>,
 	ready: ReadyTransactions<Hash, Ex>,
 	/// Store recently pruned tags (for last two invocations).
@@ -231,6 +239,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 	pub fn new(reject_future_transactions: bool) -> Self {
 		Self {
 			reject_future_transactions,
+			policy: Default::default(),
 			future: Default::default(),
 			ready: Default::default(),
 			recently_pruned: Default::default(),
@@ -238,6 +247,13 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		}
 	}
 
+	/// Applies the given admission and eviction policy to the pool.
+	pub fn with_policy(mut self, policy: Policy) -> Self {
+		self.ready.set_min_replacement_bump(policy.min_replacement_bump);
+		self.policy = policy;
+		self
+	}
+
 	/// Clears buffer keeping recently pruned transaction.
 	pub fn clear_recently_pruned(&mut self) {
 		self.recently_pruned = Default::default();
@@ -291,15 +307,58 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			if self.reject_future_transactions {
 				return Err(error::Error::RejectedFutureTransaction)
 			}
+			self.ensure_sender_capacity(&tx)?;
 
 			let hash = tx.transaction.hash.clone();
 			self.future.import(tx);
 			return Ok(Imported::Future { hash })
 		}
 
+		self.ensure_sender_capacity(&tx)?;
 		self.import_to_ready(tx)
 	}
 
+	/// Makes sure the sender of the transaction stays within the per-sender limit of the queue the
+	/// transaction is going to.
+	///
+	/// Transactions replacing some of the sender's ready transactions are not counted against the
+	/// limit.
+	fn ensure_sender_capacity(&mut self, tx: &WaitingTransaction<Hash, Ex>) -> error::Result<()> {
+		let limit = if tx.is_ready() {
+			self.policy.max_ready_per_sender
+		} else {
+			self.policy.max_future_per_sender
+		};
+		let (Some(limit), Some(sender)) = (limit, sender_of(&tx.transaction)) else {
+			return Ok(())
+		};
+
+		let count = if tx.is_ready() {
+			let replaced = tx
+				.transaction
+				.provides
+				.iter()
+				.filter(|tag| self.ready.provided_tags().contains_key(*tag))
+				.count();
+			self.ready.sender_count(sender).saturating_sub(replaced)
+		} else {
+			self.future.sender_count(sender)
+		};
+
+		if count >= limit {
+			trace!(
+				target: LOG_TARGET,
+				"[{:?}] Sender {} exceeds its limit of {} transactions",
+				tx.transaction.hash,
+				HexDisplay::from(&sender),
+				limit,
+			);
+			return Err(error::Error::ImmediatelyDropped)
+		}
+
+		Ok(())
+	}
+
 	/// Imports transaction to ready queue.
 	///
 	/// NOTE the transaction has to have all requirements satisfied.
@@ -370,6 +429,23 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		self.future.all()
 	}
 
+	/// Returns the transactions in the pool matching the filter.
+	///
+	/// Every transaction is returned with the tags it is missing, which are empty for ready
+	/// transactions.
+	pub fn inspect(
+		&self,
+		filter: impl Fn(&Transaction<Hash, Ex>) -> bool,
+	) -> Vec<(Arc<Transaction<Hash, Ex>>, Vec<Tag>)> {
+		let ready = self.ready.get().filter(|tx| filter(&**tx)).map(|tx| (tx, Vec::new()));
+		let future = self.future.waiting().filter(|tx| filter(&tx.transaction)).map(|tx| {
+			let mut missing_tags = tx.missing_tags.iter().cloned().collect::<Vec<_>>();
+			missing_tags.sort();
+			(tx.transaction.clone(), missing_tags)
+		});
+		ready.chain(future).collect()
+	}
+
 	/// Returns pool transactions given list of hashes.
 	///
 	/// Includes both ready and future pool. For every hash in the `hashes`
@@ -448,6 +524,24 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		removed
 	}
 
+	/// Removes the future transactions of every sender which has a transaction waiting in the
+	/// future queue for at least the policy's `future_gap_timeout`.
+	///
+	/// Returns the removed transactions.
+	pub fn evict_aged_futures(&mut self, now: Instant) -> Vec<Arc<Transaction<Hash, Ex>>> {
+		let Some(timeout) = self.policy.future_gap_timeout else { return vec![] };
+
+		let removed = self.future.remove_aged_senders(now, timeout);
+		if !removed.is_empty() {
+			trace!(
+				target: LOG_TARGET,
+				"Evicted {} future transactions of senders with aged gaps",
+				removed.len(),
+			);
+		}
+		removed
+	}
+
 	/// Removes all transactions represented by the hashes and all other transactions
 	/// that depend on them.
 	///
@@ -542,6 +636,50 @@ impl Limit {
 	}
 }
 
+/// Admission and eviction policy, applied on top of the queue limits.
+///
+/// The default policy imposes no restrictions beyond the queue limits.
+#[derive(Debug, Clone, Default)]
+pub struct Policy {
+	/// Minimum priority increase, relative to the collective priority of the replaced
+	/// transactions, required for a transaction to replace them.
+	///
+	/// Runtimes derive the priority from the fee and tip, so this is the minimum fee bump of a
+	/// replacement. With the default of zero any higher priority is enough.
+	pub min_replacement_bump: Percent,
+	/// Maximal number of ready transactions of a single sender.
+	///
+	/// The pool attributes transactions to senders by their tags, so transactions which do not
+	/// require any tag, such as the one with the sender's current nonce, are not counted.
+	pub max_ready_per_sender: Option<usize>,
+	/// Maximal number of future transactions of a single sender.
+	pub max_future_per_sender: Option<usize>,
+	/// How long a future transaction can wait for the gap in its dependencies to be filled.
+	///
+	/// Once any future transaction of a sender has waited for that long, all future transactions
+	/// of that sender are evicted.
+	pub future_gap_timeout: Option<Duration>,
+}
+
+/// Returns the sender of the transaction, as far as the pool can tell.
+///
+/// The sender is identified by the common prefix of the first tag required and the first tag
+/// provided by the transaction. For transactions using `frame_system::CheckNonce` these tags are
+/// the encoded sender followed by the previous and own nonce, so the prefix is the encoded sender.
+/// Transactions which do not require any tag are not attributed to any sender.
+pub(crate) fn sender_of<Hash, Ex>(tx: &Transaction<Hash, Ex>) -> Option<&[u8]> {
+	let (required, provided) = (tx.requires.first()?, tx.provides.first()?);
+	let len = required.iter().zip(provided).take_while(|(a, b)| a == b).count();
+	(len > 0).then(|| &provided[..len])
+}
+
+/// Returns true if the transaction provides a tag prefixed with the given encoded `sender`.
+///
+/// Unlike [`sender_of`], this also matches the transactions which do not require any tag.
+pub(crate) fn is_sent_by<Hash, Ex>(tx: &Transaction<Hash, Ex>, sender: &[u8]) -> bool {
+	!sender.is_empty() && tx.provides.iter().any(|tag| tag.starts_with(sender))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1129,4 +1267,142 @@ source: TransactionSource::External, requires: [03, 02], provides: [04], data: [
 		assert_eq!(pool.reject_future_transactions, true);
 		assert_eq!(pool.future.len(), 1);
 	}
+
+	fn sender_tx(sender: u8, nonce: u8) -> Transaction<Hash, Vec<u8>> {
+		Transaction {
+			data: vec![sender, nonce],
+			hash: ((sender as u64) << 8) | nonce as u64,
+			requires: if nonce == 0 { vec![] } else { vec![vec![sender, nonce - 1]] },
+			provides: vec![vec![sender, nonce]],
+			..default_tx().clone()
+		}
+	}
+
+	#[test]
+	fn should_attribute_transactions_to_senders_by_tags() {
+		assert_eq!(sender_of(&sender_tx(7, 3)), Some(&[7u8][..]));
+		// nothing required
+		assert_eq!(sender_of(&sender_tx(7, 0)), None);
+		// no common prefix
+		let tx = Transaction {
+			requires: vec![vec![1]],
+			provides: vec![vec![2]],
+			..default_tx().clone()
+		};
+		assert_eq!(sender_of(&tx), None);
+	}
+
+	#[test]
+	fn should_limit_transactions_per_sender() {
+		// given
+		let mut pool = pool().with_policy(Policy {
+			max_ready_per_sender: Some(1),
+			max_future_per_sender: Some(1),
+			..Default::default()
+		});
+		pool.import(sender_tx(1, 0)).unwrap();
+		pool.import(sender_tx(1, 1)).unwrap();
+		pool.import(sender_tx(1, 5)).unwrap();
+
+		// when
+		let ready = pool.import(sender_tx(1, 2));
+		let future = pool.import(sender_tx(1, 6));
+
+		// then
+		assert!(matches!(ready, Err(error::Error::ImmediatelyDropped)));
+		assert!(matches!(future, Err(error::Error::ImmediatelyDropped)));
+		assert_eq!(pool.ready().count(), 2);
+		assert_eq!(pool.futures().count(), 1);
+
+		// other senders and replacements are still accepted
+		pool.import(sender_tx(2, 5)).unwrap();
+		pool.import(Transaction { hash: 42, priority: 10, ..sender_tx(1, 1) }).unwrap();
+		assert_eq!(pool.futures().count(), 2);
+		assert_eq!(pool.ready().map(|tx| tx.hash).collect::<Vec<_>>(), vec![256, 42]);
+	}
+
+	#[test]
+	fn should_update_sender_counts_when_transactions_leave_queues() {
+		// given
+		let mut pool = pool().with_policy(Policy {
+			max_ready_per_sender: Some(1),
+			max_future_per_sender: Some(1),
+			..Default::default()
+		});
+		pool.import(sender_tx(1, 0)).unwrap();
+		pool.import(sender_tx(1, 1)).unwrap();
+		pool.import(sender_tx(1, 3)).unwrap();
+		assert!(pool.import(sender_tx(1, 4)).is_err());
+
+		// when
+		pool.remove_subtree(&[sender_tx(1, 3).hash]);
+		pool.prune_tags(vec![vec![1, 1]]);
+
+		// then
+		assert_eq!(pool.ready.sender_count(&[1]), 0);
+		assert_eq!(pool.future.sender_count(&[1]), 0);
+		pool.import(sender_tx(1, 4)).unwrap();
+		pool.import(sender_tx(1, 2)).unwrap();
+		assert_eq!(pool.ready.sender_count(&[1]), 1);
+		assert_eq!(pool.future.sender_count(&[1]), 1);
+	}
+
+	/// Imports the future transaction as if it was imported at `imported_at`.
+	fn import_future_at(
+		pool: &mut BasePool<Hash, Vec<u8>>,
+		tx: Transaction<Hash, Vec<u8>>,
+		imported_at: Instant,
+	) {
+		let tx = WaitingTransaction {
+			imported_at,
+			..WaitingTransaction::new(tx, pool.ready.provided_tags(), &pool.recently_pruned)
+		};
+		pool.future.import(tx);
+	}
+
+	#[test]
+	fn should_evict_aged_future_transactions_of_sender() {
+		// given
+		let timeout = Duration::from_secs(60);
+		let mut pool =
+			pool().with_policy(Policy { future_gap_timeout: Some(timeout), ..Default::default() });
+		let now = Instant::now();
+		pool.import(sender_tx(1, 0)).unwrap();
+		import_future_at(&mut pool, sender_tx(1, 2), now);
+		import_future_at(&mut pool, sender_tx(1, 3), now + timeout);
+		import_future_at(&mut pool, sender_tx(2, 2), now + timeout);
+
+		// when
+		let not_yet_aged = pool.evict_aged_futures(now + timeout - Duration::from_secs(1));
+		let removed = pool.evict_aged_futures(now + timeout);
+
+		// then
+		assert!(not_yet_aged.is_empty());
+		let mut removed = removed.iter().map(|tx| tx.hash).collect::<Vec<_>>();
+		removed.sort();
+		assert_eq!(removed, vec![258, 259]);
+		assert_eq!(pool.futures().map(|tx| tx.hash).collect::<Vec<_>>(), vec![514]);
+		assert_eq!(pool.ready().count(), 1);
+	}
+
+	#[test]
+	fn should_inspect_transactions_of_sender() {
+		// given
+		let mut pool = pool();
+		pool.import(sender_tx(1, 0)).unwrap();
+		pool.import(sender_tx(1, 2)).unwrap();
+		pool.import(sender_tx(2, 0)).unwrap();
+
+		// when
+		let inspected = pool.inspect(|tx| is_sent_by(tx, &[1]));
+
+		// then
+		assert_eq!(
+			inspected
+				.into_iter()
+				.map(|(tx, missing)| (tx.hash, missing))
+				.collect::<Vec<_>>(),
+			vec![(256, vec![]), (258, vec![vec![1, 1]])]
+		);
+	}
 }
@@ -24,9 +24,9 @@ use std::{
 
 use sp_core::hexdisplay::HexDisplay;
 use sp_runtime::transaction_validity::TransactionTag as Tag;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use super::base_pool::Transaction;
+use super::base_pool::{sender_of, Transaction};
 use crate::{common::log_xt::log_xt_trace, LOG_TARGET};
 
 /// Transaction with partially satisfied dependencies.
@@ -114,11 +114,26 @@ pub struct FutureTransactions<Hash: hash::Hash + Eq, Ex> {
 	wanted_tags: HashMap<Tag, HashSet<Hash>>,
 	/// Transactions waiting for a particular other transaction
 	waiting: HashMap<Hash, WaitingTransaction<Hash, Ex>>,
+	/// Transactions of each sender, as attributed by [`sender_of`].
+	senders: HashMap<Vec<u8>, SenderFutures<Hash>>,
+}
+
+/// Future transactions of a single sender.
+#[derive(Clone, Debug)]
+struct SenderFutures<Hash> {
+	/// Hashes of the transactions.
+	hashes: HashSet<Hash>,
+	/// Time of import of the oldest transaction.
+	oldest: Instant,
 }
 
 impl<Hash: hash::Hash + Eq, Ex> Default for FutureTransactions<Hash, Ex> {
 	fn default() -> Self {
-		Self { wanted_tags: Default::default(), waiting: Default::default() }
+		Self {
+			wanted_tags: Default::default(),
+			waiting: Default::default(),
+			senders: Default::default(),
+		}
 	}
 }
 
@@ -151,10 +166,42 @@ impl<Hash: hash::Hash + Eq + Clone + std::fmt::Debug, Ex: std::fmt::Debug>
 			entry.insert(tx.transaction.hash.clone());
 		}
 
+		if let Some(sender) = sender_of(&tx.transaction) {
+			let futures = self.senders.entry(sender.to_vec()).or_insert_with(|| SenderFutures {
+				hashes: HashSet::new(),
+				oldest: tx.imported_at,
+			});
+			futures.hashes.insert(tx.transaction.hash.clone());
+			futures.oldest = futures.oldest.min(tx.imported_at);
+		}
+
 		// Add the transaction to a by-hash waiting map
 		self.waiting.insert(tx.transaction.hash.clone(), tx);
 	}
 
+	/// Removes the transaction, which was just removed from `waiting`, from its sender.
+	fn remove_from_sender(&mut self, tx: &WaitingTransaction<Hash, Ex>) {
+		let Some(sender) = sender_of(&tx.transaction) else { return };
+		let Some(futures) = self.senders.get_mut(sender) else { return };
+		futures.hashes.remove(&tx.transaction.hash);
+		if futures.hashes.is_empty() {
+			self.senders.remove(sender);
+		} else if futures.oldest == tx.imported_at {
+			futures.oldest = futures
+				.hashes
+				.iter()
+				.filter_map(|hash| self.waiting.get(hash))
+				.map(|tx| tx.imported_at)
+				.min()
+				.unwrap_or(tx.imported_at);
+		}
+	}
+
+	/// Returns the number of transactions of the given sender in the Future queue.
+	pub fn sender_count(&self, sender: &[u8]) -> usize {
+		self.senders.get(sender).map_or(0, |futures| futures.hashes.len())
+	}
+
 	/// Returns true if given hash is part of the queue.
 	pub fn contains(&self, hash: &Hash) -> bool {
 		self.waiting.contains_key(hash)
@@ -209,6 +256,7 @@ impl<Hash: hash::Hash + Eq + Clone + std::fmt::Debug, Ex: std::fmt::Debug>
 
 					if is_ready {
 						let tx = self.waiting.remove(&hash).expect(WAITING_PROOF);
+						self.remove_from_sender(&tx);
 						became_ready.push(tx);
 					}
 				}
@@ -225,6 +273,7 @@ impl<Hash: hash::Hash + Eq + Clone + std::fmt::Debug, Ex: std::fmt::Debug>
 		let mut removed = vec![];
 		for hash in hashes {
 			if let Some(waiting_tx) = self.waiting.remove(hash) {
+				self.remove_from_sender(&waiting_tx);
 				// remove from wanted_tags as well
 				for tag in waiting_tx.missing_tags {
 					let remove = if let Some(wanted) = self.wanted_tags.get_mut(&tag) {
@@ -245,6 +294,24 @@ impl<Hash: hash::Hash + Eq + Clone + std::fmt::Debug, Ex: std::fmt::Debug>
 		removed
 	}
 
+	/// Removes all transactions of the senders which have a transaction waiting for at least
+	/// `timeout`.
+	///
+	/// Returns the removed transactions.
+	pub fn remove_aged_senders(
+		&mut self,
+		now: Instant,
+		timeout: Duration,
+	) -> Vec<Arc<Transaction<Hash, Ex>>> {
+		let aged = self
+			.senders
+			.values()
+			.filter(|futures| now.saturating_duration_since(futures.oldest) >= timeout)
+			.flat_map(|futures| futures.hashes.iter().cloned())
+			.collect::<Vec<_>>();
+		self.remove(&aged)
+	}
+
 	/// Fold a list of future transactions to compute a single value.
 	pub fn fold<R, F: FnMut(Option<R>, &WaitingTransaction<Hash, Ex>) -> Option<R>>(
 		&mut self,
@@ -258,9 +325,15 @@ impl<Hash: hash::Hash + Eq + Clone + std::fmt::Debug, Ex: std::fmt::Debug>
 		self.waiting.values().map(|waiting| &*waiting.transaction)
 	}
 
+	/// Returns iterator over all future transactions together with their missing tags.
+	pub fn waiting(&self) -> impl Iterator<Item = &WaitingTransaction<Hash, Ex>> {
+		self.waiting.values()
+	}
+
 	/// Removes and returns all future transactions.
 	pub fn clear(&mut self) -> Vec<Arc<Transaction<Hash, Ex>>> {
 		self.wanted_tags.clear();
+		self.senders.clear();
 		self.waiting.drain().map(|(_, tx)| tx.transaction).collect()
 	}
 
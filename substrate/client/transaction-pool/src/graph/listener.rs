@@ -20,7 +20,7 @@ use std::{collections::HashMap, fmt::Debug, hash};
 
 use linked_hash_map::LinkedHashMap;
 use log::trace;
-use sc_transaction_pool_api::TransactionStatus;
+use sc_transaction_pool_api::{RemovalReason, TransactionStatus};
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
 use serde::Serialize;
 use sp_runtime::traits;
@@ -43,17 +43,24 @@ pub struct Listener<H: hash::Hash + Eq, C: ChainApi> {
 	/// statuses are reported via this channel to allow consumer of the stream tracking actual
 	/// drops.
 	dropped_by_limits_sink: Option<TracingUnboundedSender<DroppedByLimitsEvent<H, BlockHash<C>>>>,
+
+	/// The reasons of the most recent removals of transactions from the pool, if tracked.
+	removal_reasons: Option<LinkedHashMap<H, RemovalReason<H>>>,
 }
 
 /// Maximum number of blocks awaiting finality at any time.
 const MAX_FINALITY_WATCHERS: usize = 512;
 
+/// Maximum number of removed transactions for which the removal reason is kept.
+pub(crate) const MAX_REMOVAL_REASONS: usize = 4096;
+
 impl<H: hash::Hash + Eq + Debug, C: ChainApi> Default for Listener<H, C> {
 	fn default() -> Self {
 		Self {
 			watchers: Default::default(),
 			finality_watchers: Default::default(),
 			dropped_by_limits_sink: None,
+			removal_reasons: None,
 		}
 	}
 }
@@ -92,6 +99,31 @@ impl<H: hash::Hash + traits::Member + Serialize + Clone, C: ChainApi> Listener<H
 		single_stream
 	}
 
+	/// Starts keeping the reasons of the most recent removals of transactions from the pool.
+	pub fn track_removal_reasons(&mut self) {
+		self.removal_reasons.get_or_insert_with(Default::default);
+	}
+
+	/// Returns the reason the given transaction was last removed from the pool, if it is known.
+	pub fn removal_reason(&self, tx: &H) -> Option<RemovalReason<H>> {
+		self.removal_reasons.as_ref().and_then(|reasons| reasons.get(tx).cloned())
+	}
+
+	fn record_removal(&mut self, tx: &H, reason: RemovalReason<H>) {
+		if let Some(ref mut reasons) = self.removal_reasons {
+			reasons.insert(tx.clone(), reason);
+			while reasons.len() > MAX_REMOVAL_REASONS {
+				reasons.pop_front();
+			}
+		}
+	}
+
+	fn forget_removal(&mut self, tx: &H) {
+		if let Some(ref mut reasons) = self.removal_reasons {
+			reasons.remove(tx);
+		}
+	}
+
 	/// Notify the listeners about extrinsic broadcast.
 	pub fn broadcasted(&mut self, hash: &H, peers: Vec<String>) {
 		trace!(target: LOG_TARGET, "[{:?}] Broadcasted", hash);
@@ -102,8 +134,10 @@ impl<H: hash::Hash + traits::Member + Serialize + Clone, C: ChainApi> Listener<H
 	pub fn ready(&mut self, tx: &H, old: Option<&H>) {
 		trace!(target: LOG_TARGET, "[{:?}] Ready (replaced with {:?})", tx, old);
 		self.fire(tx, |watcher| watcher.ready());
+		self.forget_removal(tx);
 		if let Some(old) = old {
 			self.fire(old, |watcher| watcher.usurped(tx.clone()));
+			self.record_removal(old, RemovalReason::Usurped(tx.clone()));
 		}
 
 		if let Some(ref sink) = self.dropped_by_limits_sink {
@@ -117,6 +151,7 @@ impl<H: hash::Hash + traits::Member + Serialize + Clone, C: ChainApi> Listener<H
 	pub fn future(&mut self, tx: &H) {
 		trace!(target: LOG_TARGET, "[{:?}] Future", tx);
 		self.fire(tx, |watcher| watcher.future());
+		self.forget_removal(tx);
 		if let Some(ref sink) = self.dropped_by_limits_sink {
 			if let Err(e) = sink.unbounded_send((tx.clone(), TransactionStatus::Future)) {
 				trace!(target: LOG_TARGET, "[{:?}] dropped_sink/future: send message failed: {:?}", tx, e);
@@ -134,6 +169,11 @@ impl<H: hash::Hash + traits::Member + Serialize + Clone, C: ChainApi> Listener<H
 			Some(t) => watcher.usurped(t.clone()),
 			None => watcher.dropped(),
 		});
+		let reason = match by {
+			Some(t) => RemovalReason::Usurped(t.clone()),
+			None => RemovalReason::Dropped,
+		};
+		self.record_removal(tx, reason);
 
 		//note: LimitEnforced could be introduced as new status to get rid of this flag.
 		if limits_enforced {
@@ -149,6 +189,7 @@ impl<H: hash::Hash + traits::Member + Serialize + Clone, C: ChainApi> Listener<H
 	pub fn invalid(&mut self, tx: &H) {
 		trace!(target: LOG_TARGET, "[{:?}] Extrinsic invalid", tx);
 		self.fire(tx, |watcher| watcher.invalid());
+		self.record_removal(tx, RemovalReason::Invalid);
 	}
 
 	/// Transaction was pruned from the pool.
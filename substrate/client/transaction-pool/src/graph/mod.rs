@@ -43,4 +43,4 @@ pub use self::pool::{
 };
 pub use validated_pool::{IsValidator, ValidatedTransaction};
 
-pub(crate) use listener::DroppedByLimitsEvent;
+pub(crate) use listener::{DroppedByLimitsEvent, MAX_REMOVAL_REASONS};
@@ -27,10 +27,10 @@ use crate::LOG_TARGET;
 use log::trace;
 use sc_transaction_pool_api::error;
 use serde::Serialize;
-use sp_runtime::{traits::Member, transaction_validity::TransactionTag as Tag};
+use sp_runtime::{traits::Member, transaction_validity::TransactionTag as Tag, Percent};
 
 use super::{
-	base_pool::Transaction,
+	base_pool::{sender_of, Transaction},
 	future::WaitingTransaction,
 	tracked_map::{self, TrackedMap},
 };
@@ -118,6 +118,36 @@ pub struct ReadyTransactions<Hash: hash::Hash + Eq, Ex> {
 	/// Best transactions that are ready to be included to the block without any other previous
 	/// transaction.
 	best: BTreeSet<TransactionRef<Hash, Ex>>,
+	/// Minimum priority increase over the replaced transactions required for a replacement.
+	min_replacement_bump: Percent,
+	/// Number of transactions of each sender.
+	senders: SenderCounts,
+}
+
+/// Number of transactions of each sender, as attributed by [`sender_of`].
+#[derive(Clone, Debug, Default)]
+struct SenderCounts(HashMap<Vec<u8>, usize>);
+
+impl SenderCounts {
+	fn get(&self, sender: &[u8]) -> usize {
+		self.0.get(sender).copied().unwrap_or(0)
+	}
+
+	fn insert<Hash, Ex>(&mut self, tx: &Transaction<Hash, Ex>) {
+		if let Some(sender) = sender_of(tx) {
+			*self.0.entry(sender.to_vec()).or_default() += 1;
+		}
+	}
+
+	fn remove<Hash, Ex>(&mut self, tx: &Transaction<Hash, Ex>) {
+		let Some(sender) = sender_of(tx) else { return };
+		if let Some(count) = self.0.get_mut(sender) {
+			*count -= 1;
+			if *count == 0 {
+				self.0.remove(sender);
+			}
+		}
+	}
 }
 
 impl<Hash, Ex> tracked_map::Size for ReadyTx<Hash, Ex> {
@@ -133,11 +163,24 @@ impl<Hash: hash::Hash + Eq, Ex> Default for ReadyTransactions<Hash, Ex> {
 			provided_tags: Default::default(),
 			ready: Default::default(),
 			best: Default::default(),
+			min_replacement_bump: Percent::zero(),
+			senders: Default::default(),
 		}
 	}
 }
 
 impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
+	/// Sets the minimum priority increase, relative to the collective priority of the replaced
+	/// transactions, required for a transaction to replace them.
+	pub fn set_min_replacement_bump(&mut self, bump: Percent) {
+		self.min_replacement_bump = bump;
+	}
+
+	/// Returns the number of transactions of the given sender in this queue.
+	pub fn sender_count(&self, sender: &[u8]) -> usize {
+		self.senders.get(sender)
+	}
+
 	/// Borrows a map of tags that are provided by transactions in this queue.
 	pub fn provided_tags(&self) -> &HashMap<Tag, Hash> {
 		&self.provided_tags
@@ -227,6 +270,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		}
 
 		// insert to Ready
+		self.senders.insert(&transaction.transaction);
 		ready.insert(hash, ReadyTx { transaction, unlocks, requires_offset });
 
 		Ok(replaced)
@@ -283,6 +327,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		let mut ready = self.ready.write();
 		while let Some(hash) = to_remove.pop() {
 			if let Some(mut tx) = ready.remove(&hash) {
+				self.senders.remove(&tx.transaction.transaction);
 				let invalidated = tx.transaction.transaction.provides.iter().filter(|tag| {
 					provides_tag_filter
 						.as_ref()
@@ -339,6 +384,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 				.and_then(|hash| self.ready.write().remove(&hash));
 
 			if let Some(tx) = res {
+				self.senders.remove(&tx.transaction.transaction);
 				let unlocks = tx.unlocks;
 
 				// Make sure we remove it from best txs
@@ -435,7 +481,8 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 				return Ok((vec![], vec![]))
 			}
 
-			// now check if collective priority is lower than the replacement transaction.
+			// now check if collective priority, bumped by the required margin, is lower than the
+			// replacement transaction.
 			let old_priority = {
 				let ready = self.ready.read();
 				replace_hashes
@@ -447,7 +494,8 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 			};
 
 			// bail - the transaction has too low priority to replace the old ones
-			if old_priority >= tx.priority {
+			let bump = self.min_replacement_bump.mul_ceil(old_priority);
+			if old_priority.saturating_add(bump) >= tx.priority {
 				return Err(error::Error::TooLowPriority { old: old_priority, new: tx.priority })
 			}
 
@@ -641,6 +689,33 @@ mod tests {
 		assert_eq!(ready.get().count(), 1);
 	}
 
+	#[test]
+	fn should_require_minimum_bump_to_replace_transaction() {
+		// given
+		let mut ready = ReadyTransactions::default();
+		ready.set_min_replacement_bump(Percent::from_percent(10));
+		let mut tx1 = tx(1);
+		tx1.requires.clear();
+		tx1.priority = 100;
+		import(&mut ready, tx1).unwrap();
+
+		// when
+		let mut tx2 = tx(2);
+		tx2.requires.clear();
+		tx2.priority = 110;
+		let mut tx3 = tx(3);
+		tx3.requires.clear();
+		tx3.priority = 111;
+
+		// then
+		assert!(matches!(
+			import(&mut ready, tx2),
+			Err(error::Error::TooLowPriority { old: 100, new: 110 })
+		));
+		assert_eq!(import(&mut ready, tx3).unwrap().len(), 1);
+		assert_eq!(ready.get().next().unwrap().hash, 3);
+	}
+
 	#[test]
 	fn should_replace_multiple_transactions_correctly() {
 		// given
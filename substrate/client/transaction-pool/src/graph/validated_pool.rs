@@ -25,7 +25,9 @@ use std::{
 use crate::{common::log_xt::log_xt_trace, LOG_TARGET};
 use futures::channel::mpsc::{channel, Sender};
 use parking_lot::{Mutex, RwLock};
-use sc_transaction_pool_api::{error, PoolStatus, ReadyTransactions};
+use sc_transaction_pool_api::{
+	error, PoolStatus, ReadyTransactions, RemovalReason, TransactionQueue, TransactionViewDetails,
+};
 use serde::Serialize;
 use sp_blockchain::HashAndNumber;
 use sp_runtime::{
@@ -129,7 +131,8 @@ impl<B: ChainApi> Clone for ValidatedPool<B> {
 impl<B: ChainApi> ValidatedPool<B> {
 	/// Create a new transaction pool.
 	pub fn new(options: Options, is_validator: IsValidator, api: Arc<B>) -> Self {
-		let base_pool = base::BasePool::new(options.reject_future_transactions);
+		let base_pool = base::BasePool::new(options.reject_future_transactions)
+			.with_policy(options.policy.clone());
 		let ban_time = options.ban_time;
 		Self {
 			is_validator,
@@ -289,6 +292,31 @@ impl<B: ChainApi> ValidatedPool<B> {
 		}
 	}
 
+	/// Removes the future transactions of the senders whose nonce gap was not filled within the
+	/// `future_gap_timeout` of the policy.
+	///
+	/// The removed transactions are banned and reported as dropped, like the ones removed while
+	/// enforcing the limits.
+	pub fn evict_aged_futures(&self) {
+		if self.options.policy.future_gap_timeout.is_none() {
+			return
+		}
+
+		let now = Instant::now();
+		let removed = {
+			let mut pool = self.pool.write();
+			let removed =
+				pool.evict_aged_futures(now).into_iter().map(|x| x.hash).collect::<Vec<_>>();
+			self.rotator.ban(&now, removed.iter().copied());
+			removed
+		};
+
+		let mut listener = self.listener.write();
+		for h in &removed {
+			listener.dropped(h, None, true);
+		}
+	}
+
 	/// Import a single extrinsic and starts to watch their progress in the pool.
 	pub fn submit_and_watch(
 		&self,
@@ -576,6 +604,8 @@ impl<B: ChainApi> ValidatedPool<B> {
 		// removing old transactions
 		self.remove_invalid(&to_remove);
 		self.remove_invalid(&futures_to_remove);
+		// drop future transactions waiting for too long to have their gap filled
+		self.evict_aged_futures();
 		// clear banned transactions timeouts
 		self.rotator.clear_timeouts(&now);
 	}
@@ -640,6 +670,38 @@ impl<B: ChainApi> ValidatedPool<B> {
 		self.pool.read().ready()
 	}
 
+	/// Returns the details of the transactions matching the filter, as seen by the pool at block
+	/// `at`.
+	pub fn inspect(
+		&self,
+		at: &HashAndNumber<B::Block>,
+		filter: impl Fn(&base::Transaction<ExtrinsicHash<B>, ExtrinsicFor<B>>) -> bool,
+	) -> Vec<(ExtrinsicHash<B>, TransactionViewDetails<BlockHash<B>>)> {
+		let number = at.number.saturated_into::<u64>();
+		self.pool
+			.read()
+			.inspect(filter)
+			.into_iter()
+			.map(|(tx, missing_tags)| {
+				let queue = if missing_tags.is_empty() {
+					TransactionQueue::Ready
+				} else {
+					TransactionQueue::Future
+				};
+				let details = TransactionViewDetails {
+					at: at.hash,
+					queue,
+					priority: tx.priority,
+					longevity: tx.valid_till.saturating_sub(number),
+					requires: tx.requires.clone(),
+					provides: tx.provides.clone(),
+					missing_tags,
+				};
+				(tx.hash, details)
+			})
+			.collect()
+	}
+
 	/// Returns a Vec of hashes and extrinsics in the future pool.
 	pub fn futures(&self) -> Vec<(ExtrinsicHash<B>, ExtrinsicFor<B>)> {
 		self.pool.read().futures().map(|tx| (tx.hash, tx.data.clone())).collect()
@@ -671,6 +733,21 @@ impl<B: ChainApi> ValidatedPool<B> {
 	) -> super::listener::DroppedByLimitsStream<ExtrinsicHash<B>, BlockHash<B>> {
 		self.listener.write().create_dropped_by_limits_stream()
 	}
+
+	/// Starts keeping the reasons of the most recent removals of transactions from the pool.
+	pub fn track_removal_reasons(&self) {
+		self.listener.write().track_removal_reasons()
+	}
+
+	/// Returns the reason the given transaction was last removed from the pool, if it is known.
+	///
+	/// The reasons are only known if they are tracked, see [`Self::track_removal_reasons`].
+	pub fn removal_reason(
+		&self,
+		hash: &ExtrinsicHash<B>,
+	) -> Option<RemovalReason<ExtrinsicHash<B>>> {
+		self.listener.read().removal_reason(hash)
+	}
 }
 
 fn fire_events<H, B, Ex>(listener: &mut Listener<H, B>, imported: &base::Imported<H, Ex>)
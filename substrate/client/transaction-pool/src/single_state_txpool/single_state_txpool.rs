@@ -38,8 +38,8 @@ use parking_lot::Mutex;
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_transaction_pool_api::{
 	error::Error as TxPoolError, ChainEvent, ImportNotificationStream, MaintainedTransactionPool,
-	PoolStatus, TransactionFor, TransactionPool, TransactionSource, TransactionStatusStreamFor,
-	TxHash,
+	PoolStatus, TransactionDetails, TransactionFor, TransactionPool, TransactionSource,
+	TransactionStatusStreamFor, TxHash,
 };
 use sp_blockchain::{HashAndNumber, TreeRoute};
 use sp_core::traits::SpawnEssentialNamed;
@@ -142,6 +142,7 @@ where
 		options: graph::Options,
 	) -> (Self, Pin<Box<dyn Future<Output = ()> + Send>>) {
 		let pool = Arc::new(graph::Pool::new(options, true.into(), pool_api.clone()));
+		pool.validated_pool().track_removal_reasons();
 		let (revalidation_queue, background_task) = revalidation::RevalidationQueue::new_background(
 			pool_api.clone(),
 			pool.clone(),
@@ -178,6 +179,7 @@ where
 		finalized_hash: Block::Hash,
 	) -> Self {
 		let pool = Arc::new(graph::Pool::new(options, is_validator, pool_api.clone()));
+		pool.validated_pool().track_removal_reasons();
 		let (revalidation_queue, background_task) = match revalidation_type {
 			RevalidationType::Light =>
 				(revalidation::RevalidationQueue::new(pool_api.clone(), pool.clone()), None),
@@ -223,6 +225,30 @@ where
 		&self.api
 	}
 
+	/// Returns the details of the pool transactions matching the filter.
+	///
+	/// The pool has a single view, at the most recent best block it was maintained at. The details
+	/// include the reason of the last removal of the transaction from the pool, if any.
+	fn transaction_details_matching(
+		&self,
+		filter: impl Fn(
+			&graph::base_pool::Transaction<ExtrinsicHash<PoolApi>, graph::ExtrinsicFor<PoolApi>>,
+		) -> bool,
+	) -> Vec<TransactionDetails<ExtrinsicHash<PoolApi>, Block::Hash>> {
+		let hash = self.enactment_state.lock().recent_best_block();
+		let Ok(number) = self.api.resolve_block_number(hash) else { return Vec::new() };
+		let validated_pool = self.pool.validated_pool();
+		validated_pool
+			.inspect(&HashAndNumber { hash, number }, filter)
+			.into_iter()
+			.map(|(hash, details)| TransactionDetails {
+				hash,
+				views: vec![details],
+				removal_reason: validated_pool.removal_reason(&hash),
+			})
+			.collect()
+	}
+
 	async fn ready_at_with_timeout_internal(
 		&self,
 		at: Block::Hash,
@@ -372,6 +398,31 @@ where
 	) -> ReadyIteratorFor<PoolApi> {
 		self.ready_at_with_timeout_internal(at, timeout).await
 	}
+
+	/// Returns the details of the transaction with the given hash.
+	///
+	/// A transaction recently removed from the pool is reported without views.
+	fn transaction_details(
+		&self,
+		hash: &TxHash<Self>,
+	) -> Option<TransactionDetails<TxHash<Self>, Block::Hash>> {
+		self.transaction_details_matching(|tx| tx.hash == *hash).pop().or_else(|| {
+			self.pool.validated_pool().removal_reason(hash).map(|removal_reason| {
+				TransactionDetails {
+					hash: *hash,
+					views: Vec::new(),
+					removal_reason: Some(removal_reason),
+				}
+			})
+		})
+	}
+
+	fn sender_transaction_details(
+		&self,
+		sender: &[u8],
+	) -> Vec<TransactionDetails<TxHash<Self>, Block::Hash>> {
+		self.transaction_details_matching(|tx| graph::base_pool::is_sent_by(tx, sender))
+	}
 }
 
 impl<Block, Client> BasicPool<FullChainApi<Client, Block>, Block>
@@ -27,8 +27,8 @@ use crate::{
 use async_trait::async_trait;
 use sc_transaction_pool_api::{
 	ChainEvent, ImportNotificationStream, LocalTransactionFor, LocalTransactionPool,
-	MaintainedTransactionPool, PoolStatus, ReadyTransactions, TransactionFor, TransactionPool,
-	TransactionSource, TransactionStatusStreamFor, TxHash,
+	MaintainedTransactionPool, PoolStatus, ReadyTransactions, TransactionDetails, TransactionFor,
+	TransactionPool, TransactionSource, TransactionStatusStreamFor, TxHash,
 };
 use sp_runtime::traits::Block as BlockT;
 use std::{collections::HashMap, pin::Pin, sync::Arc};
@@ -142,6 +142,20 @@ where
 	) -> ReadyIteratorFor<FullChainApi<Client, Block>> {
 		self.0.ready_at_with_timeout(at, timeout).await
 	}
+
+	fn transaction_details(
+		&self,
+		hash: &TxHash<Self>,
+	) -> Option<TransactionDetails<TxHash<Self>, Block::Hash>> {
+		self.0.transaction_details(hash)
+	}
+
+	fn sender_transaction_details(
+		&self,
+		sender: &[u8],
+	) -> Vec<TransactionDetails<TxHash<Self>, Block::Hash>> {
+		self.0.sender_transaction_details(sender)
+	}
 }
 
 #[async_trait]
@@ -18,7 +18,7 @@
 
 //! Tests for fork-aware transaction pool.
 
-use sc_transaction_pool::{ChainApi, PoolLimit};
+use sc_transaction_pool::{ChainApi, Options, PoolLimit, PoolPolicy};
 use sc_transaction_pool_api::ChainEvent;
 use sp_runtime::transaction_validity::TransactionSource;
 use std::sync::Arc;
@@ -69,6 +69,7 @@ pub struct TestPoolBuilder {
 	use_default_limits: bool,
 	ready_limits: sc_transaction_pool::PoolLimit,
 	future_limits: sc_transaction_pool::PoolLimit,
+	policy: PoolPolicy,
 	mempool_max_transactions_count: usize,
 }
 
@@ -79,6 +80,7 @@ impl Default for TestPoolBuilder {
 			use_default_limits: true,
 			ready_limits: PoolLimit { count: 8192, total_bytes: 20 * 1024 * 1024 },
 			future_limits: PoolLimit { count: 512, total_bytes: 1 * 1024 * 1024 },
+			policy: Default::default(),
 			mempool_max_transactions_count: usize::MAX,
 		}
 	}
@@ -124,6 +126,12 @@ impl TestPoolBuilder {
 		self
 	}
 
+	pub fn with_policy(mut self, policy: PoolPolicy) -> Self {
+		self.policy = policy;
+		self.use_default_limits = false;
+		self
+	}
+
 	pub fn build(
 		self,
 	) -> (ForkAwareTxPool<TestApi, Block>, Arc<TestApi>, futures::executor::ThreadPool) {
@@ -142,12 +150,16 @@ impl TestPoolBuilder {
 		let (pool, txpool_task) = if self.use_default_limits {
 			ForkAwareTxPool::new_test(api.clone(), genesis_hash, genesis_hash)
 		} else {
-			ForkAwareTxPool::new_test_with_limits(
+			ForkAwareTxPool::new_test_with_options(
 				api.clone(),
 				genesis_hash,
 				genesis_hash,
-				self.ready_limits,
-				self.future_limits,
+				Options {
+					ready: self.ready_limits,
+					future: self.future_limits,
+					policy: self.policy,
+					..Default::default()
+				},
 				self.mempool_max_transactions_count,
 			)
 		};
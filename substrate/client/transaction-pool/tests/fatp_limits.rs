@@ -24,11 +24,11 @@ use fatp_common::{
 	finalized_block_event, invalid_hash, new_best_block_event, TestPoolBuilder, LOG_TARGET, SOURCE,
 };
 use futures::{executor::block_on, FutureExt};
-use sc_transaction_pool::ChainApi;
+use sc_transaction_pool::{ChainApi, PoolPolicy};
 use sc_transaction_pool_api::{
 	error::Error as TxPoolError, MaintainedTransactionPool, TransactionPool, TransactionStatus,
 };
-use std::thread::sleep;
+use std::{thread::sleep, time::Duration};
 use substrate_test_runtime_client::AccountKeyring::*;
 use substrate_test_runtime_transaction_pool::uxt;
 
@@ -641,3 +641,109 @@ fn fatp_limits_future_size_works() {
 	assert_pool_status!(header01.hash(), &pool, 0, 3);
 	assert_eq!(pool.mempool_len().0, 3);
 }
+
+#[test]
+fn fatp_limits_future_count_per_sender_works() {
+	sp_tracing::try_init_simple();
+
+	let builder = TestPoolBuilder::new();
+	let (pool, api, _) = builder
+		.with_policy(PoolPolicy { max_future_per_sender: Some(2), ..Default::default() })
+		.build();
+	api.set_nonce(api.genesis_hash(), Bob.into(), 200);
+
+	let header01 = api.push_block(1, vec![], true);
+
+	let event = new_best_block_event(&pool, None, header01.hash());
+	block_on(pool.maintain(event));
+
+	let xt0 = uxt(Alice, 202);
+	let xt1 = uxt(Alice, 203);
+	let xt2 = uxt(Alice, 204);
+	let xt3 = uxt(Bob, 202);
+
+	block_on(pool.submit_one(header01.hash(), SOURCE, xt0.clone())).unwrap();
+	block_on(pool.submit_one(header01.hash(), SOURCE, xt1.clone())).unwrap();
+	let result2 = block_on(pool.submit_one(header01.hash(), SOURCE, xt2.clone()));
+	assert!(matches!(result2.as_ref().unwrap_err().0, TxPoolError::ImmediatelyDropped));
+	block_on(pool.submit_one(header01.hash(), SOURCE, xt3.clone())).unwrap();
+
+	assert_pool_status!(header01.hash(), &pool, 0, 3);
+	assert_eq!(pool.mempool_len().0, 3);
+
+	//the view of the next block keeps enforcing the limit:
+	let header02 = api.push_block(2, vec![], true);
+	let event = new_best_block_event(&pool, Some(header01.hash()), header02.hash());
+	block_on(pool.maintain(event));
+
+	let result2 = block_on(pool.submit_one(header02.hash(), SOURCE, xt2.clone()));
+	assert!(matches!(result2.as_ref().unwrap_err().0, TxPoolError::ImmediatelyDropped));
+	assert_pool_status!(header02.hash(), &pool, 0, 3);
+	assert_eq!(pool.mempool_len().0, 3);
+}
+
+#[test]
+fn fatp_limits_no_views_future_count_per_sender_works() {
+	sp_tracing::try_init_simple();
+
+	let builder = TestPoolBuilder::new();
+	let (pool, api, _) = builder
+		.with_policy(PoolPolicy { max_future_per_sender: Some(1), ..Default::default() })
+		.build();
+
+	let header01 = api.push_block(1, vec![], true);
+
+	//without views, the transactions are only admitted to the mempool:
+	let xts = vec![uxt(Alice, 202), uxt(Alice, 203), uxt(Alice, 204)];
+	let results = block_on(pool.submit_at(header01.hash(), SOURCE, xts)).unwrap();
+	assert!(results.iter().all(Result::is_ok));
+	assert_eq!(pool.mempool_len().0, 3);
+
+	//the first view only accepts one of them, the others are removed from the mempool:
+	let event = new_best_block_event(&pool, None, header01.hash());
+	block_on(pool.maintain(event));
+
+	assert_pool_status!(header01.hash(), &pool, 0, 1);
+	assert_eq!(pool.mempool_len().0, 1);
+}
+
+#[test]
+fn fatp_limits_aged_futures_are_evicted_on_new_block() {
+	sp_tracing::try_init_simple();
+
+	let builder = TestPoolBuilder::new();
+	let (pool, api, _) = builder
+		.with_policy(PoolPolicy { future_gap_timeout: Some(Duration::ZERO), ..Default::default() })
+		.build();
+	api.set_nonce(api.genesis_hash(), Bob.into(), 200);
+
+	let header01 = api.push_block(1, vec![], true);
+
+	let event = new_best_block_event(&pool, None, header01.hash());
+	block_on(pool.maintain(event));
+
+	let xt0 = uxt(Alice, 202);
+	let xt1 = uxt(Bob, 200);
+
+	block_on(pool.submit_one(header01.hash(), SOURCE, xt0.clone())).unwrap();
+	block_on(pool.submit_one(header01.hash(), SOURCE, xt1.clone())).unwrap();
+	assert_pool_status!(header01.hash(), &pool, 1, 1);
+
+	let header02 = api.push_block(2, vec![], true);
+	let event = new_best_block_event(&pool, Some(header01.hash()), header02.hash());
+	block_on(pool.maintain(event));
+
+	assert_pool_status!(header02.hash(), &pool, 1, 0);
+	assert_ready_iterator!(header02.hash(), pool, [xt1]);
+
+	//todo: can we do better? We don't have API to check if event was processed internally.
+	let mut counter = 0;
+	while pool.mempool_len().0 == 2 {
+		sleep(Duration::from_millis(1));
+		counter = counter + 1;
+		if counter > 20 {
+			assert!(false, "timeout");
+		}
+	}
+	assert_eq!(pool.mempool_len().0, 1);
+}
@@ -28,7 +28,7 @@ use sc_block_builder::BlockBuilderBuilder;
 use sc_client_api::client::BlockchainEvents;
 use sc_transaction_pool::*;
 use sc_transaction_pool_api::{
-	ChainEvent, MaintainedTransactionPool, TransactionPool, TransactionStatus,
+	ChainEvent, MaintainedTransactionPool, RemovalReason, TransactionPool, TransactionStatus,
 };
 use sp_blockchain::HeaderBackend;
 use sp_consensus::BlockOrigin;
@@ -37,7 +37,7 @@ use sp_runtime::{
 	traits::Block as _,
 	transaction_validity::{TransactionSource, ValidTransaction},
 };
-use std::{collections::BTreeSet, pin::Pin, sync::Arc};
+use std::{collections::BTreeSet, pin::Pin, sync::Arc, time::Duration};
 use substrate_test_runtime_client::{
 	runtime::{Block, Extrinsic, ExtrinsicBuilder, Hash, Header, Nonce, Transfer, TransferData},
 	AccountKeyring::*,
@@ -199,6 +199,38 @@ fn should_ban_invalid_transactions() {
 	block_on(pool.submit_one(&api.expect_hash_and_number(0), SOURCE, uxt.clone())).unwrap_err();
 }
 
+#[test]
+fn transaction_details_should_report_removal_reason() {
+	let api = Arc::new(TestApi::with_alice_nonce(209));
+	let genesis_hash = api.genesis_hash();
+	let options = Options { ban_time: Duration::ZERO, ..Default::default() };
+	let (pool, _background_task) =
+		BasicPool::new_test(api.clone(), genesis_hash, genesis_hash, options);
+
+	let xt = uxt(Alice, 209);
+	let hash = block_on(pool.submit_one(genesis_hash, SOURCE, xt.clone())).unwrap();
+	let details = pool.transaction_details(&hash).unwrap();
+	assert_eq!(details.views.len(), 1);
+	assert_eq!(details.removal_reason, None);
+
+	// when
+	pool.remove_invalid(&[hash]);
+
+	// then
+	let details = pool.transaction_details(&hash).unwrap();
+	assert!(details.views.is_empty());
+	assert_eq!(details.removal_reason, Some(RemovalReason::Invalid));
+
+	// when
+	pool.pool().validated_pool().clear_stale(&api.expect_hash_and_number(0));
+	block_on(pool.submit_one(genesis_hash, SOURCE, xt)).unwrap();
+
+	// then
+	let details = pool.transaction_details(&hash).unwrap();
+	assert_eq!(details.views.len(), 1);
+	assert_eq!(details.removal_reason, None);
+}
+
 #[test]
 fn only_prune_on_new_best() {
 	let (pool, api, _) = maintained_pool();
@@ -263,6 +263,21 @@ pub mod pallet {
 	pub(crate) type ListBags<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::Score, list::Bag<T, I>>;
 
+	/// Set while the list is locked, see [`SortedListProvider::lock`].
+	///
+	/// While locked, nodes keep their position: the score of a node can be updated, but it is not
+	/// moved to another bag, and no node can be inserted or put in front of another one.
+	#[pallet::storage]
+	pub type Lock<T: Config<I>, I: 'static = ()> = StorageValue<_, (), OptionQuery>;
+
+	/// The nodes removed while the list is locked, as they were when removed.
+	///
+	/// They are kept until the list is unlocked, so that an iteration over the list can be resumed
+	/// with [`SortedListProvider::iter_from`] from a node that was removed in the meantime.
+	#[pallet::storage]
+	pub(crate) type RemovedWhileLocked<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, list::Node<T, I>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -296,12 +311,13 @@ pub mod pallet {
 		/// Will always update the stored score of `dislocated` to the correct score, based on
 		/// `ScoreProvider`.
 		///
-		/// If `dislocated` does not exists, it returns an error.
+		/// If `dislocated` does not exists, or if the list is locked, it returns an error.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::rebag_non_terminal().max(T::WeightInfo::rebag_terminal()))]
 		pub fn rebag(origin: OriginFor<T>, dislocated: AccountIdLookupOf<T>) -> DispatchResult {
 			ensure_signed(origin)?;
 			let dislocated = T::Lookup::lookup(dislocated)?;
+			ensure!(!Lock::<T, I>::exists(), Error::<T, I>::List(ListError::Locked));
 			let current_score = T::ScoreProvider::score(&dislocated);
 			let _ = Pallet::<T, I>::do_rebag(&dislocated, current_score)
 				.map_err::<Error<T, I>, _>(Into::into)?;
@@ -438,6 +454,19 @@ impl<T: Config<I>, I: 'static> SortedListProvider<T::AccountId> for Pallet<T, I>
 		List::<T, I>::remove(id)
 	}
 
+	fn lock() {
+		Lock::<T, I>::put(());
+	}
+
+	fn unlock() {
+		Lock::<T, I>::kill();
+		let _ = RemovedWhileLocked::<T, I>::clear(u32::MAX, None);
+	}
+
+	fn is_locked() -> bool {
+		Lock::<T, I>::exists()
+	}
+
 	fn unsafe_regenerate(
 		all: impl IntoIterator<Item = T::AccountId>,
 		score_of: Box<dyn Fn(&T::AccountId) -> T::Score>,
@@ -61,6 +61,8 @@ pub enum ListError {
 	NotInSameBag,
 	/// Given node id was not found.
 	NodeNotFound,
+	/// The list is locked, and nodes can neither be moved nor inserted.
+	Locked,
 }
 
 #[cfg(test)]
@@ -107,6 +109,7 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		crate::ListBags::<T, I>::remove_all(None);
 		#[allow(deprecated)]
 		crate::ListNodes::<T, I>::remove_all();
+		let _ = crate::RemovedWhileLocked::<T, I>::clear(u32::MAX, None);
 	}
 
 	/// Regenerate all of the data from the given ids.
@@ -271,6 +274,9 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 	/// Same as `iter`, but we start from a specific node.
 	///
 	/// All items after this node are returned, excluding `start` itself.
+	///
+	/// While the list is locked, `start` may also be a node removed since the list was locked, in
+	/// which case the items that were after it are returned.
 	pub(crate) fn iter_from(
 		start: &T::AccountId,
 	) -> Result<impl Iterator<Item = Node<T, I>>, ListError> {
@@ -278,9 +284,12 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		// 1. from the given `start` till the end of the bag
 		// 2. all the bags that come after `start`'s bag.
 
-		let start_node = Node::<T, I>::get(start).ok_or(ListError::NodeNotFound)?;
+		let start_node = Node::<T, I>::get(start)
+			.or_else(|| crate::RemovedWhileLocked::<T, I>::get(start))
+			.ok_or(ListError::NodeNotFound)?;
 		let start_node_upper = start_node.bag_upper;
-		let start_bag = core::iter::successors(start_node.next(), |prev| prev.next());
+		let start_bag =
+			core::iter::successors(Self::next_in_list(start_node.next), |prev| prev.next());
 
 		let thresholds = T::BagThresholds::get();
 		let idx = thresholds.partition_point(|&threshold| start_node_upper > threshold);
@@ -295,6 +304,22 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		Ok(start_bag.chain(leftover_bags))
 	}
 
+	/// The node with the given id if it is in the list, or else the first node of the list that was
+	/// after it in its bag when it was removed while the list is locked.
+	///
+	/// The nodes that are in the list are always linked to nodes in the list, so the nodes removed
+	/// while the list is locked only need to be followed from a removed `start` of
+	/// [`Self::iter_from`].
+	fn next_in_list(mut id: Option<T::AccountId>) -> Option<Node<T, I>> {
+		while let Some(next) = id {
+			if let Some(node) = Node::<T, I>::get(&next) {
+				return Some(node)
+			}
+			id = crate::RemovedWhileLocked::<T, I>::get(&next).and_then(|removed| removed.next);
+		}
+		None
+	}
+
 	/// Insert several ids into the appropriate bags in the list. Continues with insertions
 	/// if duplicates are detected.
 	///
@@ -316,11 +341,12 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 
 	/// Insert a new id into the appropriate bag in the list.
 	///
-	/// Returns an error if the list already contains `id`.
+	/// Returns an error if the list already contains `id`, or if the list is locked.
 	pub(crate) fn insert(id: T::AccountId, score: T::Score) -> Result<(), ListError> {
 		if Self::contains(&id) {
 			return Err(ListError::Duplicate)
 		}
+		ensure!(!crate::Lock::<T, I>::exists(), ListError::Locked);
 
 		let bag_score = notional_bag_for::<T, I>(score);
 		let mut bag = Bag::<T, I>::get_or_make(bag_score);
@@ -367,6 +393,11 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 			};
 			count += 1;
 
+			if crate::Lock::<T, I>::exists() {
+				// keep the node as it is, an iteration may still have to be resumed from it.
+				crate::RemovedWhileLocked::<T, I>::insert(&node.id, &node);
+			}
+
 			if !node.is_terminal() {
 				// this node is not a head or a tail and thus the bag does not need to be updated
 				node.excise()
@@ -398,6 +429,8 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 	/// Returns `Some((old_idx, new_idx))` if the node moved, otherwise `None`. In both cases, the
 	/// node's score is written to the `score` field. Thus, this is not a noop, even if `None`.
 	///
+	/// While the list is locked, the node is never moved, and only its score is written.
+	///
 	/// This operation is somewhat more efficient than simply calling [`self.remove`] followed by
 	/// [`self.insert`]. However, given large quantities of nodes to move, it may be more efficient
 	/// to call [`self.remove_many`] followed by [`self.insert_many`].
@@ -406,7 +439,7 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		new_score: T::Score,
 	) -> Option<(T::Score, T::Score)> {
 		node.score = new_score;
-		if node.is_misplaced(new_score) {
+		if node.is_misplaced(new_score) && !crate::Lock::<T, I>::exists() {
 			let old_bag_upper = node.bag_upper;
 
 			if !node.is_terminal() {
@@ -445,6 +478,7 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		lighter_id: &T::AccountId,
 		heavier_id: &T::AccountId,
 	) -> Result<(), ListError> {
+		ensure!(!crate::Lock::<T, I>::exists(), ListError::Locked);
 		let lighter_node = Node::<T, I>::get(&lighter_id).ok_or(ListError::NodeNotFound)?;
 		let heavier_node = Node::<T, I>::get(&heavier_id).ok_or(ListError::NodeNotFound)?;
 
@@ -533,6 +567,11 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		let nodes_count = crate::ListNodes::<T, I>::iter().count() as u32;
 		ensure!(iter_count == stored_count, "iter_count != stored_count");
 		ensure!(stored_count == nodes_count, "stored_count != nodes_count");
+		ensure!(
+			crate::Lock::<T, I>::exists() ||
+				crate::RemovedWhileLocked::<T, I>::iter_keys().next().is_none(),
+			"nodes removed while locked are kept after unlocking"
+		);
 
 		crate::log!(trace, "count of nodes: {}", stored_count);
 
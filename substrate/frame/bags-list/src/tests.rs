@@ -735,4 +735,62 @@ mod sorted_list_provider {
 			assert!(non_existent_ids.iter().all(|id| !BagsList::contains(id)));
 		})
 	}
+
+	#[test]
+	fn lock_keeps_the_order_of_the_list() {
+		ExtBuilder::default().build_and_execute(|| {
+			BagsList::lock();
+			assert!(BagsList::is_locked());
+
+			// the score of an id is updated, but it stays in its bag.
+			StakingMock::set_score_of(&1, 2_000);
+			BagsList::on_update(&1, 2_000).unwrap();
+			assert_eq!(List::<Runtime>::get_score(&1).unwrap(), 2_000);
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+			assert_noop!(
+				BagsList::rebag(RuntimeOrigin::signed(0), 1),
+				crate::Error::<Runtime>::List(ListError::Locked)
+			);
+
+			// ids can neither be inserted nor put in front of others,
+			assert_storage_noop!(assert_eq!(BagsList::on_insert(42, 20), Err(ListError::Locked)));
+			StakingMock::set_score_of(&4, 999);
+			assert_noop!(
+				BagsList::put_in_front_of(RuntimeOrigin::signed(4), 3),
+				crate::Error::<Runtime>::List(ListError::Locked)
+			);
+
+			// but they can be removed.
+			BagsList::on_remove(&3).unwrap();
+			assert_eq!(get_list_as_ids(), vec![2, 4, 1]);
+
+			// once unlocked, the list can be reordered again.
+			BagsList::unlock();
+			assert!(!BagsList::is_locked());
+			assert_ok!(BagsList::rebag(RuntimeOrigin::signed(0), 1));
+			assert_eq!(get_list_as_ids(), vec![1, 2, 4]);
+		});
+	}
+
+	#[test]
+	fn iter_from_a_node_removed_while_locked() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			assert_eq!(get_list_as_ids(), vec![2, 3, 4, 1]);
+			BagsList::lock();
+
+			// when the start and the node after it are removed
+			BagsList::on_remove(&2).unwrap();
+			BagsList::on_remove(&3).unwrap();
+
+			// then the iteration resumes from the nodes that were after them.
+			assert_eq!(BagsList::iter_from(&2).unwrap().collect::<Vec<_>>(), vec![4, 1]);
+			assert_eq!(BagsList::iter_from(&3).unwrap().collect::<Vec<_>>(), vec![4, 1]);
+			assert_eq!(BagsList::iter().collect::<Vec<_>>(), vec![4, 1]);
+
+			// the removed nodes are forgotten once unlocked.
+			BagsList::unlock();
+			assert_eq!(BagsList::iter_from(&2).map(|_| ()), Err(ListError::NodeNotFound));
+		});
+	}
 }
@@ -0,0 +1,52 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for conviction-voting pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+use pallet_conviction_voting::{PollVoter, TallyBreakdown};
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for accessing the votes of the polls conducted with conviction voting.
+	pub trait ConvictionVotingApi<AccountId, Balance, PollIndex>
+		where
+			AccountId: Codec,
+			Balance: Codec,
+			PollIndex: Codec,
+	{
+		/// Returns the breakdown of the tally of a poll into standard, split and delegated votes,
+		/// or `None` if nobody votes on the poll.
+		///
+		/// The breakdown is only updated while the poll is ongoing, as its tally.
+		fn tally_breakdown(poll_index: PollIndex) -> Option<TallyBreakdown<Balance>>;
+
+		/// Returns up to `limit` accounts voting directly on a poll, with their conviction-weighted
+		/// votes including the votes delegated to them.
+		///
+		/// To page through all voters, pass the last voter of the previous page as `start_after`.
+		fn poll_voters(
+			poll_index: PollIndex,
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<PollVoter<AccountId, Balance>>;
+	}
+}
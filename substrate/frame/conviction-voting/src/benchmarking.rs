@@ -244,6 +244,99 @@ benchmarks_instance_pallet! {
 		assert_matches!(VotingFor::<T, I>::get(&caller, &class), Voting::Casting(_));
 	}
 
+	delegate_split {
+		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let polls = &all_polls[&class];
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		// We need to create existing split delegations, leaving room for one more.
+		for i in 0..T::MaxVotes::get().saturating_sub(1) {
+			let other = funded_account::<T, I>("other", i);
+			ConvictionVoting::<T, I>::delegate_split(
+				RawOrigin::Signed(caller.clone()).into(),
+				class.clone(),
+				T::Lookup::unlookup(other),
+				Conviction::Locked1x,
+				delegated_balance,
+			)?;
+		}
+		// We need to create existing delegations
+		for i in polls.iter().take(r as usize) {
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+		}
+		assert_matches!(
+			VotingFor::<T, I>::get(&voter, &class),
+			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+		);
+
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), voter_lookup, Conviction::Locked1x, delegated_balance)
+	verify {
+		assert_matches!(
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::SplitDelegating(SplitDelegating { delegates, .. })
+				if delegates.len() == T::MaxVotes::get() as usize
+		);
+	}
+
+	undelegate_split {
+		let r in 0 .. T::MaxVotes::get().min(T::Polls::max_ongoing().1);
+
+		let all_polls = fill_voting::<T, I>().1;
+		let class = T::Polls::max_ongoing().0;
+		let polls = &all_polls[&class];
+		let voter = funded_account::<T, I>("voter", 0);
+		let voter_lookup = T::Lookup::unlookup(voter.clone());
+		let caller = funded_account::<T, I>("caller", 0);
+		whitelist_account!(caller);
+
+		let delegated_balance: BalanceOf<T, I> = 1000u32.into();
+		let delegate_vote = account_vote::<T, I>(delegated_balance);
+
+		// We need to create existing split delegations, including the one to remove.
+		for i in 0..T::MaxVotes::get().saturating_sub(1) {
+			let other = funded_account::<T, I>("other", i);
+			ConvictionVoting::<T, I>::delegate_split(
+				RawOrigin::Signed(caller.clone()).into(),
+				class.clone(),
+				T::Lookup::unlookup(other),
+				Conviction::Locked1x,
+				delegated_balance,
+			)?;
+		}
+		ConvictionVoting::<T, I>::delegate_split(
+			RawOrigin::Signed(caller.clone()).into(),
+			class.clone(),
+			voter_lookup.clone(),
+			Conviction::Locked1x,
+			delegated_balance,
+		)?;
+
+		// We need to create delegations
+		for i in polls.iter().take(r as usize) {
+			ConvictionVoting::<T, I>::vote(RawOrigin::Signed(voter.clone()).into(), *i, delegate_vote)?;
+		}
+		assert_matches!(
+			VotingFor::<T, I>::get(&voter, &class),
+			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
+		);
+	}: _(RawOrigin::Signed(caller.clone()), class.clone(), voter_lookup)
+	verify {
+		assert_matches!(
+			VotingFor::<T, I>::get(&caller, &class),
+			Voting::SplitDelegating(SplitDelegating { delegates, .. })
+				if delegates.len() == T::MaxVotes::get().saturating_sub(1) as usize
+		);
+	}
+
 	unlock {
 		let caller = funded_account::<T, I>("caller", 0);
 		let caller_lookup = T::Lookup::unlookup(caller.clone());
@@ -23,18 +23,27 @@
 //! ## Overview
 //!
 //! Pallet for managing actual voting in polls.
+//!
+//! An account can either vote directly in a class of polls, delegate its voting power to a single
+//! account with [`Call::delegate`], or delegate fractions of its balance to several accounts with
+//! [`Call::delegate_split`], each with its own conviction.
+//!
+//! Besides the tally of each poll, the pallet keeps track of the accounts voting directly on a poll
+//! in [`VotersOf`], and of the breakdown of its tally into standard, split and delegated votes in
+//! [`TallyBreakdownOf`]. Both can be queried through `pallet-conviction-voting-runtime-api`.
 
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+use alloc::vec::Vec;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	traits::{
 		fungible, Currency, Get, LockIdentifier, LockableCurrency, PollStatus, Polling,
-		ReservableCurrency, WithdrawReasons,
+		ReservableCurrency, StorageVersion, WithdrawReasons,
 	},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -44,6 +53,7 @@ use sp_runtime::{
 };
 
 mod conviction;
+pub mod migration;
 mod types;
 mod vote;
 pub mod weights;
@@ -51,8 +61,8 @@ pub mod weights;
 pub use self::{
 	conviction::Conviction,
 	pallet::*,
-	types::{Delegations, Tally, UnvoteScope},
-	vote::{AccountVote, Casting, Delegating, Vote, Voting},
+	types::{Delegations, PollVoter, Tally, TallyBreakdown, UnvoteScope, VoteTotals},
+	vote::{AccountVote, Casting, Delegating, Delegation, SplitDelegating, Vote, Voting},
 	weights::WeightInfo,
 };
 
@@ -64,6 +74,9 @@ pub mod benchmarking;
 
 const CONVICTION_VOTING_ID: LockIdentifier = *b"pyconvot";
 
+/// The in-code storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 type BalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -89,7 +102,8 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::{
-			DispatchResultWithPostInfo, IsType, StorageDoubleMap, StorageMap, ValueQuery,
+			DispatchResultWithPostInfo, IsType, OptionQuery, StorageDoubleMap, StorageMap,
+			ValueQuery,
 		},
 		traits::ClassCountOf,
 		Twox64Concat,
@@ -98,6 +112,7 @@ pub mod pallet {
 	use sp_runtime::BoundedVec;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -126,6 +141,9 @@ pub mod pallet {
 
 		/// The maximum number of concurrent votes an account may have.
 		///
+		/// Also bounds the number of accounts an account may delegate to with
+		/// [`Call::delegate_split`] within a class.
+		///
 		/// Also used to compute weight, an overly large value can lead to extrinsics with large
 		/// weight estimation: see `delegate` for instance.
 		#[pallet::constant]
@@ -164,6 +182,34 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The accounts voting directly on a poll, with the class of the poll. Accounts delegating
+	/// their voting power are accounted for with the vote of their target.
+	///
+	/// An account is recorded from the moment it votes until its vote is removed.
+	#[pallet::storage]
+	pub type VotersOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PollIndexOf<T, I>,
+		Twox64Concat,
+		T::AccountId,
+		ClassOf<T, I>,
+		OptionQuery,
+	>;
+
+	/// The breakdown of the tally of a poll by kind of voter, for polls with at least one voter in
+	/// [`VotersOf`].
+	///
+	/// As the tally, it is only updated while the poll is ongoing.
+	#[pallet::storage]
+	pub type TallyBreakdownOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		PollIndexOf<T, I>,
+		TallyBreakdown<BalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -171,6 +217,8 @@ pub mod pallet {
 		Delegated(T::AccountId, T::AccountId),
 		/// An \[account\] has cancelled a previous delegation operation.
 		Undelegated(T::AccountId),
+		/// An account has cancelled its delegation to `target`, keeping its other delegations.
+		UndelegatedFrom { who: T::AccountId, target: T::AccountId },
 		/// An account has voted
 		Voted { who: T::AccountId, vote: AccountVote<BalanceOf<T, I>> },
 		/// A vote has been removed
@@ -391,6 +439,75 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Delegate a fraction of the voting power (with some given conviction) of the sending
+		/// account to `to`, for a particular class of polls.
+		///
+		/// Unlike `delegate`, the account may delegate to several accounts within the same class,
+		/// each with its own balance and conviction, as long as the sum of the delegated balances
+		/// is not more than the balance of the account. The sum of the delegated balances is
+		/// locked for as long as it's delegated, and thereafter for the time appropriate for the
+		/// conviction's lock period.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the signing account must either:
+		///   - be delegating already with `delegate_split`, but not to `to`; or
+		///   - have no voting activity (if there is, then it will need to be removed through
+		///     `remove_vote`).
+		///
+		/// - `class`: The class of polls to delegate.
+		/// - `to`: The account whose voting `balance` of the sending account will follow.
+		/// - `conviction`: The conviction that will be attached to the delegated votes.
+		/// - `balance`: The amount of the account's balance to delegate to `to`.
+		///
+		/// Emits `Delegated`.
+		///
+		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
+		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::delegate_split(T::MaxVotes::get()))]
+		pub fn delegate_split(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			to: AccountIdLookupOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			let votes = Self::try_delegate_split(who, class, to, conviction, balance)?;
+
+			Ok(Some(T::WeightInfo::delegate_split(votes)).into())
+		}
+
+		/// Undelegate the voting power of the sending account delegated to `target` with
+		/// `delegate_split`, for a particular class of polls.
+		///
+		/// The other delegations of the account are kept. The balance delegated to `target` stays
+		/// locked, along with the balance of the remaining delegations, until the lock period of
+		/// the conviction with which it was delegated has passed.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the signing account must be
+		/// currently delegating to `target` with `delegate_split`.
+		///
+		/// - `class`: The class of polls to remove the delegation from.
+		/// - `target`: The account to remove the delegation to.
+		///
+		/// Emits `UndelegatedFrom`.
+		///
+		/// Weight: `O(R)` where R is the number of polls the voter delegating to has
+		///   voted on. Weight is initially charged as if maximum votes, but is refunded later.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::undelegate_split(T::MaxVotes::get()))]
+		pub fn undelegate_split(
+			origin: OriginFor<T>,
+			class: ClassOf<T, I>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let votes = Self::try_undelegate_split(who, class, target)?;
+			Ok(Some(T::WeightInfo::undelegate_split(votes)).into())
+		}
 	}
 }
 
@@ -416,12 +533,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							if let Some(approve) = votes[i].1.as_standard() {
 								tally.reduce(approve, *delegations);
 							}
+							Self::mutate_breakdown(poll_index, |breakdown| {
+								breakdown.remove(votes[i].1, *delegations)
+							});
 							votes[i].1 = vote;
 						},
 						Err(i) => {
 							votes
 								.try_insert(i, (poll_index, vote))
 								.map_err(|_| Error::<T, I>::MaxVotesReached)?;
+							VotersOf::<T, I>::insert(poll_index, who, &class);
+							Self::mutate_breakdown(poll_index, |breakdown| {
+								breakdown.voters.saturating_inc()
+							});
 						},
 					}
 					// Shouldn't be possible to fail, but we handle it gracefully.
@@ -429,6 +553,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					if let Some(approve) = vote.as_standard() {
 						tally.increase(approve, *delegations);
 					}
+					Self::mutate_breakdown(poll_index, |breakdown| {
+						breakdown.add(vote, *delegations)
+					});
 				} else {
 					return Err(Error::<T, I>::AlreadyDelegating.into())
 				}
@@ -471,6 +598,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						if let Some(approve) = v.1.as_standard() {
 							tally.reduce(approve, *delegations);
 						}
+						Self::mutate_breakdown(poll_index, |breakdown| {
+							breakdown.remove(v.1, *delegations)
+						});
 						Self::deposit_event(Event::VoteRemoved { who: who.clone(), vote: v.1 });
 						Ok(())
 					},
@@ -491,7 +621,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						Ok(())
 					},
 					PollStatus::None => Ok(()), // Poll was cancelled.
-				})
+				})?;
+
+				VotersOf::<T, I>::remove(poll_index, who);
+				Self::mutate_breakdown(poll_index, |breakdown| breakdown.voters.saturating_dec());
+				Ok(())
 			} else {
 				Ok(())
 			}
@@ -505,7 +639,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: Delegations<BalanceOf<T, I>>,
 	) -> u32 {
 		VotingFor::<T, I>::mutate(who, class, |voting| match voting {
-			Voting::Delegating(Delegating { delegations, .. }) => {
+			Voting::Delegating(Delegating { delegations, .. }) |
+			Voting::SplitDelegating(SplitDelegating { delegations, .. }) => {
 				// We don't support second level delegating, so we don't need to do anything more.
 				*delegations = delegations.saturating_add(amount);
 				1
@@ -517,6 +652,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						T::Polls::access_poll(poll_index, |poll_status| {
 							if let PollStatus::Ongoing(tally, _) = poll_status {
 								tally.increase(vote.aye, amount);
+								Self::mutate_breakdown(poll_index, |breakdown| {
+									breakdown.delegated.increase(vote.aye, amount)
+								});
 							}
 						});
 					}
@@ -533,7 +671,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: Delegations<BalanceOf<T, I>>,
 	) -> u32 {
 		VotingFor::<T, I>::mutate(who, class, |voting| match voting {
-			Voting::Delegating(Delegating { delegations, .. }) => {
+			Voting::Delegating(Delegating { delegations, .. }) |
+			Voting::SplitDelegating(SplitDelegating { delegations, .. }) => {
 				// We don't support second level delegating, so we don't need to do anything more.
 				*delegations = delegations.saturating_sub(amount);
 				1
@@ -545,6 +684,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						T::Polls::access_poll(poll_index, |poll_status| {
 							if let PollStatus::Ongoing(tally, _) = poll_status {
 								tally.reduce(vote.aye, amount);
+								Self::mutate_breakdown(poll_index, |breakdown| {
+									breakdown.delegated.reduce(vote.aye, amount)
+								});
 							}
 						});
 					}
@@ -580,7 +722,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					}),
 				);
 				match old {
-					Voting::Delegating(Delegating { .. }) =>
+					Voting::Delegating(_) | Voting::SplitDelegating(_) =>
 						return Err(Error::<T, I>::AlreadyDelegating.into()),
 					Voting::Casting(Casting { votes, delegations, prior }) => {
 						// here we just ensure that we're currently idling with no votes recorded.
@@ -632,13 +774,109 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 						Ok(votes)
 					},
-					Voting::Casting(_) => Err(Error::<T, I>::NotDelegating.into()),
+					Voting::Casting(_) | Voting::SplitDelegating(_) =>
+						Err(Error::<T, I>::NotDelegating.into()),
 				}
 			})?;
 		Self::deposit_event(Event::<T, I>::Undelegated(who));
 		Ok(votes)
 	}
 
+	/// Attempt to delegate `balance` times `conviction` of voting power from `who` to `target`,
+	/// in addition to the other split delegations of `who`.
+	///
+	/// Return the upstream number of votes.
+	fn try_delegate_split(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: T::AccountId,
+		conviction: Conviction,
+		balance: BalanceOf<T, I>,
+	) -> Result<u32, DispatchError> {
+		ensure!(who != target, Error::<T, I>::Nonsense);
+		T::Polls::classes().binary_search(&class).map_err(|_| Error::<T, I>::BadClass)?;
+		let votes =
+			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
+				let mut split = match core::mem::take(voting) {
+					Voting::Casting(Casting { votes, delegations, prior }) => {
+						// here we just ensure that we're currently idling with no votes recorded.
+						ensure!(votes.is_empty(), Error::<T, I>::AlreadyVoting);
+						SplitDelegating { delegates: Default::default(), delegations, prior }
+					},
+					Voting::Delegating(_) => return Err(Error::<T, I>::AlreadyDelegating.into()),
+					Voting::SplitDelegating(split) => split,
+				};
+
+				// the delegations are fractions of the balance of the account.
+				let total = split.balance().saturating_add(balance);
+				ensure!(
+					total <= T::Currency::total_balance(&who),
+					Error::<T, I>::InsufficientFunds
+				);
+				let i = split
+					.delegates
+					.binary_search_by(|d| d.target.cmp(&target))
+					.err()
+					.ok_or(Error::<T, I>::AlreadyDelegating)?;
+				split
+					.delegates
+					.try_insert(i, Delegation { target: target.clone(), balance, conviction })
+					.map_err(|_| Error::<T, I>::MaxVotesReached)?;
+				*voting = Voting::SplitDelegating(split);
+
+				let votes =
+					Self::increase_upstream_delegation(&target, &class, conviction.votes(balance));
+				// Extend the lock to the total delegated balance (rather than setting it) since we
+				// don't know what other votes are in place.
+				Self::extend_lock(&who, &class, total);
+				Ok(votes)
+			})?;
+		Self::deposit_event(Event::<T, I>::Delegated(who, target));
+		Ok(votes)
+	}
+
+	/// Attempt to end the split delegation of `who` to `target`.
+	///
+	/// Return the number of votes of upstream.
+	fn try_undelegate_split(
+		who: T::AccountId,
+		class: ClassOf<T, I>,
+		target: T::AccountId,
+	) -> Result<u32, DispatchError> {
+		let votes =
+			VotingFor::<T, I>::try_mutate(&who, &class, |voting| -> Result<u32, DispatchError> {
+				let Voting::SplitDelegating(split) = voting else {
+					return Err(Error::<T, I>::NotDelegating.into())
+				};
+				let i = split
+					.delegates
+					.binary_search_by(|d| d.target.cmp(&target))
+					.map_err(|_| Error::<T, I>::NotDelegating)?;
+				let Delegation { balance, conviction, .. } = split.delegates.remove(i);
+
+				// remove any delegation votes to this target.
+				let votes =
+					Self::reduce_upstream_delegation(&target, &class, conviction.votes(balance));
+				// the balance of the remaining delegations is distinct from the undelegated one,
+				// so both stay locked for the lock period of the conviction.
+				let now = frame_system::Pallet::<T>::block_number();
+				let lock_periods = conviction.lock_periods().into();
+				split.prior.accumulate(
+					now.saturating_add(T::VoteLockingPeriod::get().saturating_mul(lock_periods)),
+					split.balance().saturating_add(balance),
+				);
+				if split.delegates.is_empty() {
+					let (delegations, prior) = (split.delegations, split.prior);
+					*voting = Voting::default();
+					voting.set_common(delegations, prior);
+				}
+
+				Ok(votes)
+			})?;
+		Self::deposit_event(Event::<T, I>::UndelegatedFrom { who, target });
+		Ok(votes)
+	}
+
 	fn extend_lock(who: &T::AccountId, class: &ClassOf<T, I>, amount: BalanceOf<T, I>) {
 		ClassLocksFor::<T, I>::mutate(who, |locks| {
 			match locks.iter().position(|x| &x.0 == class) {
@@ -693,4 +931,65 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			);
 		}
 	}
+
+	/// Mutate the tally breakdown of a poll, removing it once the poll has no voters left.
+	fn mutate_breakdown(
+		poll_index: PollIndexOf<T, I>,
+		f: impl FnOnce(&mut TallyBreakdown<BalanceOf<T, I>>),
+	) {
+		TallyBreakdownOf::<T, I>::mutate_exists(poll_index, |maybe_breakdown| {
+			let breakdown = maybe_breakdown.get_or_insert_with(Default::default);
+			f(breakdown);
+			if breakdown.voters == 0 {
+				*maybe_breakdown = None;
+			}
+		});
+	}
+
+	/// The breakdown of the tally of a poll by kind of voter, or `None` if nobody votes on it.
+	///
+	/// This is meant to be used by runtime APIs.
+	pub fn api_tally_breakdown(
+		poll_index: PollIndexOf<T, I>,
+	) -> Option<TallyBreakdown<BalanceOf<T, I>>> {
+		TallyBreakdownOf::<T, I>::get(poll_index)
+	}
+
+	/// Up to `limit` accounts voting directly on a poll, with their effective votes.
+	///
+	/// The voters are returned in a stable order: to get the next page, pass the last voter of
+	/// the current page as `start_after`.
+	///
+	/// This is meant to be used by runtime APIs.
+	pub fn api_poll_voters(
+		poll_index: PollIndexOf<T, I>,
+		start_after: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<PollVoter<T::AccountId, BalanceOf<T, I>>> {
+		let voters = match start_after {
+			Some(who) => VotersOf::<T, I>::iter_prefix_from(
+				poll_index,
+				VotersOf::<T, I>::hashed_key_for(poll_index, who),
+			),
+			None => VotersOf::<T, I>::iter_prefix(poll_index),
+		};
+		voters
+			.filter_map(|(who, class)| {
+				let Voting::Casting(Casting { votes, delegations, .. }) =
+					VotingFor::<T, I>::get(&who, &class)
+				else {
+					return None
+				};
+				let i = votes.binary_search_by_key(&poll_index, |i| i.0).ok()?;
+				let vote = votes[i].1;
+				let mut totals = VoteTotals::default();
+				totals.add(vote);
+				if let Some(approve) = vote.as_standard() {
+					totals.increase(approve, delegations);
+				}
+				Some(PollVoter { who, vote, delegations, votes: totals })
+			})
+			.take(limit as usize)
+			.collect()
+	}
 }
@@ -0,0 +1,107 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the conviction voting pallet.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	pallet_prelude::ConstU32,
+	traits::GetStorageVersion,
+	weights::{Weight, WeightMeter},
+	BoundedVec,
+};
+
+/// The identifier of the migrations of this pallet.
+pub const PALLET_MIGRATIONS_ID: &[u8; 24] = b"pallet-conviction-voting";
+
+pub mod v1 {
+	use super::*;
+
+	type HashedKey = BoundedVec<u8, ConstU32<256>>;
+
+	/// Records the accounts voting on ongoing polls in [`VotersOf`], and the breakdown of the
+	/// tallies of these polls in [`TallyBreakdownOf`].
+	///
+	/// Votes on polls which already ended are not recorded. Removing them later is a no-op for
+	/// the new storage items.
+	///
+	/// Each step processes as many [`VotingFor`] entries as its weight allows, the cursor being
+	/// the raw key of the last processed entry.
+	pub struct LazyMigrationV0ToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> LazyMigrationV0ToV1<T, I> {
+		/// The worst case weight of processing a single [`VotingFor`] entry.
+		fn entry_weight() -> Weight {
+			let max_votes = T::MaxVotes::get() as u64;
+			T::DbWeight::get().reads_writes(1 + 2 * max_votes, 2 * max_votes)
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> SteppedMigration for LazyMigrationV0ToV1<T, I> {
+		type Cursor = HashedKey;
+		type Identifier = MigrationId<24>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T, I>::on_chain_storage_version() != Self::id().version_from as u16 {
+				return Ok(None)
+			}
+
+			let required = Self::entry_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required })
+			}
+
+			while meter.can_consume(required) {
+				let mut iter = match cursor {
+					Some(ref last_key) => VotingFor::<T, I>::iter_from(last_key.to_vec()),
+					None => VotingFor::<T, I>::iter(),
+				};
+				let Some((who, class, voting)) = iter.next() else {
+					StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T, I>>();
+					return Ok(None)
+				};
+
+				if let Voting::Casting(Casting { votes, delegations, .. }) = voting {
+					for (poll_index, vote) in votes {
+						if T::Polls::as_ongoing(poll_index).is_none() {
+							continue
+						}
+						VotersOf::<T, I>::insert(poll_index, &who, &class);
+						Pallet::<T, I>::mutate_breakdown(poll_index, |breakdown| {
+							breakdown.voters.saturating_inc();
+							breakdown.add(vote, delegations);
+						});
+					}
+				}
+
+				cursor = Some(HashedKey::truncate_from(iter.last_raw_key().to_vec()));
+				meter.consume(required);
+			}
+
+			Ok(cursor)
+		}
+	}
+}
@@ -19,6 +19,7 @@
 
 use std::collections::BTreeMap;
 
+use assert_matches::assert_matches;
 use frame_support::{
 	assert_noop, assert_ok, derive_impl, parameter_types,
 	traits::{ConstU32, ConstU64, Contains, Polling, VoteTally},
@@ -52,6 +53,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = BaseFilter;
 	type Block = Block;
 	type AccountData = pallet_balances::AccountData<u64>;
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -873,6 +875,106 @@ fn errors_with_delegating_work() {
 	});
 }
 
+#[test]
+fn split_delegation_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate_split(
+			RuntimeOrigin::signed(5),
+			0,
+			1,
+			Conviction::Locked1x,
+			20
+		));
+		assert_ok!(Voting::delegate_split(
+			RuntimeOrigin::signed(5),
+			0,
+			2,
+			Conviction::Locked2x,
+			10
+		));
+		// the sum of the delegated balances is locked.
+		assert_eq!(Balances::usable_balance(5), 20);
+		assert_eq!(ClassLocksFor::<Test>::get(5), vec![(0, 30)]);
+
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 3, nay(20, 0)));
+		assert_eq!(tally(3), Tally::from_parts(21, 22, 30));
+
+		// each delegate can be undelegated from on its own.
+		assert_ok!(Voting::undelegate_split(RuntimeOrigin::signed(5), 0, 1));
+		assert_eq!(tally(3), Tally::from_parts(1, 22, 10));
+		assert_matches!(
+			VotingFor::<Test>::get(5, 0),
+			Voting::SplitDelegating(SplitDelegating { delegates, .. }) if delegates.len() == 1
+		);
+
+		// the undelegated balance stays locked for the conviction's lock period.
+		run_to(3);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 20);
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 40);
+
+		assert_ok!(Voting::undelegate_split(RuntimeOrigin::signed(5), 0, 2));
+		assert_eq!(tally(3), Tally::from_parts(1, 2, 10));
+		assert_matches!(VotingFor::<Test>::get(5, 0), Voting::Casting(_));
+		run_to(10);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), 0, 5));
+		assert_eq!(Balances::usable_balance(5), 50);
+	});
+}
+
+#[test]
+fn errors_with_split_delegating_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::delegate_split(RuntimeOrigin::signed(6), 0, 6, Conviction::None, 10),
+			Error::<Test>::Nonsense
+		);
+		assert_noop!(
+			Voting::delegate_split(RuntimeOrigin::signed(6), 3, 1, Conviction::None, 10),
+			Error::<Test>::BadClass
+		);
+		assert_ok!(Voting::delegate_split(RuntimeOrigin::signed(6), 0, 1, Conviction::None, 30));
+		assert_noop!(
+			Voting::delegate_split(RuntimeOrigin::signed(6), 0, 2, Conviction::None, 31),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_noop!(
+			Voting::delegate_split(RuntimeOrigin::signed(6), 0, 1, Conviction::None, 10),
+			Error::<Test>::AlreadyDelegating
+		);
+		assert_noop!(
+			Voting::delegate(RuntimeOrigin::signed(6), 0, 2, Conviction::None, 10),
+			Error::<Test>::AlreadyDelegating
+		);
+		assert_noop!(Voting::undelegate(RuntimeOrigin::signed(6), 0), Error::<Test>::NotDelegating);
+		assert_noop!(
+			Voting::undelegate_split(RuntimeOrigin::signed(6), 0, 2),
+			Error::<Test>::NotDelegating
+		);
+
+		assert_ok!(Voting::delegate_split(RuntimeOrigin::signed(6), 0, 2, Conviction::None, 10));
+		assert_ok!(Voting::delegate_split(RuntimeOrigin::signed(6), 0, 3, Conviction::None, 10));
+		assert_noop!(
+			Voting::delegate_split(RuntimeOrigin::signed(6), 0, 4, Conviction::None, 10),
+			Error::<Test>::MaxVotesReached
+		);
+
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 1, Conviction::None, 10));
+		assert_noop!(
+			Voting::delegate_split(RuntimeOrigin::signed(5), 0, 2, Conviction::None, 10),
+			Error::<Test>::AlreadyDelegating
+		);
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(4), 3, aye(10, 0)));
+		assert_noop!(
+			Voting::delegate_split(RuntimeOrigin::signed(4), 0, 2, Conviction::None, 10),
+			Error::<Test>::AlreadyVoting
+		);
+	});
+}
+
 #[test]
 fn remove_other_vote_works() {
 	new_test_ext().execute_with(|| {
@@ -911,3 +1013,195 @@ fn errors_with_remove_vote_work() {
 		);
 	});
 }
+
+fn totals(ayes: u64, nays: u64, abstains: u64, support: u64) -> VoteTotals<u64> {
+	VoteTotals { ayes, nays, abstains, support }
+}
+
+#[test]
+fn tally_breakdown_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Voting::api_tally_breakdown(3), None);
+
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(2, 5)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 3, split(10, 10)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 3, split_abstain(10, 10, 10)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(4), 3, nay(40, 1)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 1, Conviction::Locked1x, 10));
+
+		assert_eq!(
+			Voting::api_tally_breakdown(3),
+			Some(TallyBreakdown {
+				voters: 4,
+				standard: totals(10, 40, 0, 2),
+				split: totals(2, 2, 10, 30),
+				delegated: totals(10, 0, 0, 10),
+			})
+		);
+		// the breakdown adds up to the tally.
+		assert_eq!(tally(3), Tally::from_parts(22, 42, 42));
+
+		// changing a vote moves the delegations along.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, nay(2, 5)));
+		assert_eq!(
+			Voting::api_tally_breakdown(3),
+			Some(TallyBreakdown {
+				voters: 4,
+				standard: totals(0, 50, 0, 0),
+				split: totals(2, 2, 10, 30),
+				delegated: totals(0, 10, 0, 0),
+			})
+		);
+
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), None, 3));
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(3), None, 3));
+		assert_eq!(
+			Voting::api_tally_breakdown(3),
+			Some(TallyBreakdown {
+				voters: 2,
+				standard: totals(0, 40, 0, 0),
+				split: totals(1, 1, 0, 10),
+				delegated: totals(0, 0, 0, 0),
+			})
+		);
+		assert_eq!(tally(3), Tally::from_parts(1, 41, 10));
+
+		// the breakdown is removed along with the last vote.
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(2), None, 3));
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(4), None, 3));
+		assert_eq!(Voting::api_tally_breakdown(3), None);
+		assert_eq!(VotersOf::<Test>::iter_prefix(3).count(), 0);
+	});
+}
+
+#[test]
+fn tally_breakdown_follows_delegations() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 1, Conviction::Locked2x, 10));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 1)));
+		assert_eq!(Voting::api_tally_breakdown(3).unwrap().delegated, totals(20, 0, 0, 10));
+
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(5), 0));
+		assert_eq!(Voting::api_tally_breakdown(3).unwrap().delegated, totals(0, 0, 0, 0));
+
+		// delegations to split votes are not counted, as in the tally.
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, split(10, 0)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 1, Conviction::Locked2x, 10));
+		assert_eq!(
+			Voting::api_tally_breakdown(3),
+			Some(TallyBreakdown {
+				voters: 1,
+				standard: totals(0, 0, 0, 0),
+				split: totals(1, 0, 0, 10),
+				delegated: totals(0, 0, 0, 0),
+			})
+		);
+	});
+}
+
+#[test]
+fn tally_breakdown_is_kept_until_votes_of_ended_poll_are_removed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 3, nay(20, 0)));
+		let breakdown = Voting::api_tally_breakdown(3).unwrap();
+
+		Polls::set(vec![(3, Completed(1, true))].into_iter().collect());
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(1), Some(0), 3));
+		assert_eq!(Voting::api_tally_breakdown(3), Some(TallyBreakdown { voters: 1, ..breakdown }));
+
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(2), Some(0), 3));
+		assert_eq!(Voting::api_tally_breakdown(3), None);
+	});
+}
+
+#[test]
+fn poll_voters_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 1)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 3, split_abstain(0, 10, 10)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 3, nay(30, 0)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 1, Conviction::Locked1x, 50));
+
+		let first_page = Voting::api_poll_voters(3, None, 2);
+		assert_eq!(first_page.len(), 2);
+		let second_page = Voting::api_poll_voters(3, Some(first_page[1].who), 2);
+		assert_eq!(second_page.len(), 1);
+
+		let voters = first_page
+			.into_iter()
+			.chain(second_page)
+			.map(|voter| (voter.who, voter))
+			.collect::<BTreeMap<_, _>>();
+		assert_eq!(voters.keys().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+		assert_eq!(
+			voters[&1],
+			PollVoter {
+				who: 1,
+				vote: aye(10, 1),
+				delegations: Delegations { votes: 50, capital: 50 },
+				votes: totals(60, 0, 0, 60),
+			}
+		);
+		assert_eq!(voters[&2].votes, totals(0, 1, 10, 10));
+		assert_eq!(voters[&3].votes, totals(0, 3, 0, 0));
+
+		assert!(Voting::api_poll_voters(3, Some(voters[&1].who), 0).is_empty());
+		assert!(Voting::api_poll_voters(2, None, 10).is_empty());
+	});
+}
+
+#[test]
+fn migration_v1_records_votes_of_ongoing_polls() {
+	use frame_support::{
+		migrations::SteppedMigration,
+		traits::{GetStorageVersion, StorageVersion},
+		weights::WeightMeter,
+	};
+
+	new_test_ext().execute_with(|| {
+		Polls::set(
+			vec![(3, Ongoing(Tally::new(0), 0)), (4, Ongoing(Tally::new(0), 0))]
+				.into_iter()
+				.collect(),
+		);
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, aye(10, 1)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 4, nay(10, 1)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 3, split_abstain(0, 10, 10)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(5), 0, 1, Conviction::Locked1x, 50));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 4, aye(30, 0)));
+		let breakdowns = (Voting::api_tally_breakdown(3), Voting::api_tally_breakdown(4));
+		let voters = VotersOf::<Test>::iter().collect::<Vec<_>>();
+
+		// poll `4` has ended, its votes are not recorded.
+		Polls::set(vec![(3, Ongoing(tally(3), 0)), (4, Completed(1, true))].into_iter().collect());
+		let _ = VotersOf::<Test>::clear(u32::MAX, None);
+		let _ = TallyBreakdownOf::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<Voting>();
+
+		let mut cursor = None;
+		loop {
+			// a meter which can process exactly one entry at a time.
+			let mut meter = WeightMeter::with_limit(
+				<Test as frame_system::Config>::DbWeight::get().reads_writes(7, 6),
+			);
+			cursor = migration::v1::LazyMigrationV0ToV1::<Test>::step(cursor, &mut meter).unwrap();
+			if cursor.is_none() {
+				break
+			}
+		}
+
+		assert_eq!(Voting::on_chain_storage_version(), 1);
+		assert_eq!(Voting::api_tally_breakdown(3), breakdowns.0);
+		assert_eq!(Voting::api_tally_breakdown(4), None);
+		assert!(breakdowns.1.is_some());
+		let mut migrated = VotersOf::<Test>::iter().collect::<Vec<_>>();
+		let mut expected = voters
+			.into_iter()
+			.filter(|(poll_index, _, _)| *poll_index == 3)
+			.collect::<Vec<_>>();
+		migrated.sort();
+		expected.sort();
+		assert_eq!(migrated, expected);
+	});
+}
@@ -249,6 +249,141 @@ impl<Balance: Saturating> Saturating for Delegations<Balance> {
 	}
 }
 
+/// The votes of a poll, of a given kind of voter.
+#[derive(
+	Encode, Decode, Default, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct VoteTotals<Votes> {
+	/// The number of aye votes, expressed in terms of post-conviction lock-vote.
+	pub ayes: Votes,
+	/// The number of nay votes, expressed in terms of post-conviction lock-vote.
+	pub nays: Votes,
+	/// The amount of balance abstaining.
+	pub abstains: Votes,
+	/// The basic number of aye and abstain votes, expressed pre-conviction.
+	pub support: Votes,
+}
+
+impl<Votes: AtLeast32BitUnsigned + Copy> VoteTotals<Votes> {
+	/// Add an account's vote into the totals.
+	pub fn add(&mut self, vote: AccountVote<Votes>) {
+		let (ayes, nays, abstains, support) = Self::parts_of(vote);
+		self.ayes = self.ayes.saturating_add(ayes);
+		self.nays = self.nays.saturating_add(nays);
+		self.abstains = self.abstains.saturating_add(abstains);
+		self.support = self.support.saturating_add(support);
+	}
+
+	/// Remove an account's vote from the totals.
+	pub fn remove(&mut self, vote: AccountVote<Votes>) {
+		let (ayes, nays, abstains, support) = Self::parts_of(vote);
+		self.ayes = self.ayes.saturating_sub(ayes);
+		self.nays = self.nays.saturating_sub(nays);
+		self.abstains = self.abstains.saturating_sub(abstains);
+		self.support = self.support.saturating_sub(support);
+	}
+
+	/// Increment some amount of votes.
+	pub fn increase(&mut self, approve: bool, delegations: Delegations<Votes>) {
+		match approve {
+			true => {
+				self.support = self.support.saturating_add(delegations.capital);
+				self.ayes = self.ayes.saturating_add(delegations.votes);
+			},
+			false => self.nays = self.nays.saturating_add(delegations.votes),
+		}
+	}
+
+	/// Decrement some amount of votes.
+	pub fn reduce(&mut self, approve: bool, delegations: Delegations<Votes>) {
+		match approve {
+			true => {
+				self.support = self.support.saturating_sub(delegations.capital);
+				self.ayes = self.ayes.saturating_sub(delegations.votes);
+			},
+			false => self.nays = self.nays.saturating_sub(delegations.votes),
+		}
+	}
+
+	/// The ayes, nays, abstains and support of a vote, in the same terms as [`Tally::add`].
+	fn parts_of(vote: AccountVote<Votes>) -> (Votes, Votes, Votes, Votes) {
+		match vote {
+			AccountVote::Standard { vote, balance } => {
+				let Delegations { votes, capital } = vote.conviction.votes(balance);
+				match vote.aye {
+					true => (votes, Zero::zero(), Zero::zero(), capital),
+					false => (Zero::zero(), votes, Zero::zero(), Zero::zero()),
+				}
+			},
+			AccountVote::Split { aye, nay } => {
+				let aye = Conviction::None.votes(aye);
+				let nay = Conviction::None.votes(nay);
+				(aye.votes, nay.votes, Zero::zero(), aye.capital)
+			},
+			AccountVote::SplitAbstain { aye, nay, abstain } => {
+				let aye = Conviction::None.votes(aye);
+				let nay = Conviction::None.votes(nay);
+				(aye.votes, nay.votes, abstain, aye.capital.saturating_add(abstain))
+			},
+		}
+	}
+}
+
+/// The breakdown of the tally of a poll by kind of voter.
+///
+/// The sum of the ayes, nays and support of all kinds of voters is the [`Tally`] of the poll.
+#[derive(
+	Encode, Decode, Default, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct TallyBreakdown<Votes> {
+	/// The number of accounts voting directly on the poll.
+	pub voters: u32,
+	/// The votes of the accounts casting a standard vote.
+	pub standard: VoteTotals<Votes>,
+	/// The votes of the accounts casting a split vote, with or without abstentions.
+	pub split: VoteTotals<Votes>,
+	/// The votes delegated to the accounts casting a standard vote.
+	pub delegated: VoteTotals<Votes>,
+}
+
+impl<Votes: AtLeast32BitUnsigned + Copy> TallyBreakdown<Votes> {
+	/// Add the vote of an account with the given delegations into the breakdown.
+	pub fn add(&mut self, vote: AccountVote<Votes>, delegations: Delegations<Votes>) {
+		match vote.as_standard() {
+			Some(approve) => {
+				self.standard.add(vote);
+				self.delegated.increase(approve, delegations);
+			},
+			None => self.split.add(vote),
+		}
+	}
+
+	/// Remove the vote of an account with the given delegations from the breakdown.
+	pub fn remove(&mut self, vote: AccountVote<Votes>, delegations: Delegations<Votes>) {
+		match vote.as_standard() {
+			Some(approve) => {
+				self.standard.remove(vote);
+				self.delegated.reduce(approve, delegations);
+			},
+			None => self.split.remove(vote),
+		}
+	}
+}
+
+/// A direct voter of a poll, and their effective votes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PollVoter<AccountId, Balance> {
+	/// The account voting.
+	pub who: AccountId,
+	/// The vote of the account.
+	pub vote: AccountVote<Balance>,
+	/// The delegations currently received by the account, which follow its vote if it is
+	/// standard.
+	pub delegations: Delegations<Balance>,
+	/// The conviction-weighted votes of the account, including its delegations.
+	pub votes: VoteTotals<Balance>,
+}
+
 /// Whether an `unvote` operation is able to make actions that are not strictly always in the
 /// interest of an account.
 pub enum UnvoteScope {
@@ -164,6 +164,48 @@ pub struct Delegating<Balance, AccountId, BlockNumber> {
 	pub prior: PriorLock<BlockNumber, Balance>,
 }
 
+/// A delegation of some voting power to a single account, as part of [`SplitDelegating`].
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Delegation<Balance, AccountId> {
+	/// The account to which the voting power is delegated.
+	pub target: AccountId,
+	/// The amount of balance delegated.
+	pub balance: Balance,
+	/// The conviction with which the voting power is delegated. When this gets undelegated, the
+	/// relevant lock begins.
+	pub conviction: Conviction,
+}
+
+/// Information concerning the delegation of fractions of some voting power to several accounts.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxVotes))]
+#[codec(mel_bound(Balance: MaxEncodedLen, AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct SplitDelegating<Balance, AccountId, BlockNumber, MaxVotes>
+where
+	MaxVotes: Get<u32>,
+{
+	/// The delegations of the account, sorted by target. The sum of the delegated balances is
+	/// never more than the balance of the account.
+	pub delegates: BoundedVec<Delegation<Balance, AccountId>, MaxVotes>,
+	/// The total amount of delegations that this account has received, post-conviction-weighting.
+	pub delegations: Delegations<Balance>,
+	/// Any pre-existing locks from past voting/delegating activity.
+	pub prior: PriorLock<BlockNumber, Balance>,
+}
+
+impl<Balance: Saturating + Zero + Copy, AccountId, BlockNumber, MaxVotes>
+	SplitDelegating<Balance, AccountId, BlockNumber, MaxVotes>
+where
+	MaxVotes: Get<u32>,
+{
+	/// The sum of the balances delegated.
+	pub fn balance(&self) -> Balance {
+		self.delegates
+			.iter()
+			.fold(Zero::zero(), |total, d| total.saturating_add(d.balance))
+	}
+}
+
 /// Information concerning the direct vote-casting of some voting power.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(MaxVotes))]
@@ -195,6 +237,8 @@ where
 	Casting(Casting<Balance, BlockNumber, PollIndex, MaxVotes>),
 	/// The account is delegating `balance` of its balance to a `target` account with `conviction`.
 	Delegating(Delegating<Balance, AccountId, BlockNumber>),
+	/// The account is delegating fractions of its balance to several accounts.
+	SplitDelegating(SplitDelegating<Balance, AccountId, BlockNumber, MaxVotes>),
 }
 
 impl<Balance: Default, AccountId, BlockNumber: Zero, PollIndex, MaxVotes> Default
@@ -220,6 +264,7 @@ where
 		match self {
 			Voting::Casting(Casting { prior, .. }) => prior,
 			Voting::Delegating(Delegating { prior, .. }) => prior,
+			Voting::SplitDelegating(SplitDelegating { prior, .. }) => prior,
 		}
 	}
 }
@@ -244,6 +289,7 @@ where
 			Voting::Casting(Casting { votes, prior, .. }) =>
 				votes.iter().map(|i| i.1.balance()).fold(prior.locked(), |a, i| a.max(i)),
 			Voting::Delegating(Delegating { balance, prior, .. }) => *balance.max(&prior.locked()),
+			Voting::SplitDelegating(split) => split.balance().max(split.prior.locked()),
 		}
 	}
 
@@ -257,6 +303,9 @@ where
 				(delegations, prior),
 			Voting::Delegating(Delegating { ref mut delegations, ref mut prior, .. }) =>
 				(delegations, prior),
+			Voting::SplitDelegating(SplitDelegating {
+				ref mut delegations, ref mut prior, ..
+			}) => (delegations, prior),
 		};
 		*d = delegations;
 		*p = prior;
@@ -57,6 +57,8 @@ pub trait WeightInfo {
 	fn remove_other_vote() -> Weight;
 	fn delegate(r: u32, ) -> Weight;
 	fn undelegate(r: u32, ) -> Weight;
+	fn delegate_split(r: u32, ) -> Weight;
+	fn undelegate_split(r: u32, ) -> Weight;
 	fn unlock() -> Weight;
 }
 
@@ -64,133 +66,119 @@ pub trait WeightInfo {
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn vote_new() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `13141`
-		//  Estimated: `219984`
-		// Minimum execution time: 135_295_000 picoseconds.
+		// Not benchmarked yet: measured before a first vote was also recorded in `VotersOf` and
+		// `TallyBreakdownOf`.
 		Weight::from_parts(142_897_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			// estimated cost of the accesses to `VotersOf` and `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn vote_existing() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `20283`
-		//  Estimated: `219984`
-		// Minimum execution time: 324_485_000 picoseconds.
+		// Not benchmarked yet: measured before the breakdown of the tally was updated along with
+		// the vote.
 		Weight::from_parts(337_467_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			// estimated cost of the accesses to `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn remove_vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `20035`
-		//  Estimated: `219984`
-		// Minimum execution time: 302_574_000 picoseconds.
+		// Not benchmarked yet: measured before the vote was also removed from `VotersOf` and from
+		// the breakdown of the tally.
 		Weight::from_parts(315_016_000, 219984)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			// estimated cost of the accesses to `VotersOf` and `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn remove_other_vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `12742`
-		//  Estimated: `30706`
-		// Minimum execution time: 65_548_000 picoseconds.
+		// Not benchmarked yet: measured before an expired vote was also dropped from `VotersOf` and
+		// from the breakdown of the tally.
 		Weight::from_parts(71_499_000, 30706)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			// estimated cost of the accesses to `VotersOf` and `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	/// The range of component `r` is `[0, 1]`.
 	fn delegate(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `306 + r * (1628 ±0)`
-		//  Estimated: `109992 + r * (109992 ±0)`
-		// Minimum execution time: 61_383_000 picoseconds.
+		// Not benchmarked yet: measured before the delegated votes were also added to the breakdown
+		// of each tally.
 		Weight::from_parts(70_695_789, 109992)
-			// Standard Error: 457_836
 			.saturating_add(Weight::from_parts(44_163_910, 0).saturating_mul(r.into()))
+			// estimated cost of the accesses to `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
-			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	/// The range of component `r` is `[0, 1]`.
 	fn undelegate(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `472 + r * (1377 ±0)`
-		//  Estimated: `109992 + r * (109992 ±0)`
-		// Minimum execution time: 33_466_000 picoseconds.
+		// Not benchmarked yet: measured before the delegated votes were also taken out of the
+		// breakdown of each tally.
 		Weight::from_parts(39_261_420, 109992)
-			// Standard Error: 358_545
 			.saturating_add(Weight::from_parts(43_197_579, 0).saturating_mul(r.into()))
+			// estimated cost of the accesses to `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
-			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
 	}
+	fn delegate_split(r: u32, ) -> Weight {
+		// Not benchmarked yet: a single delegate is added, and its `r` votes updated, as in
+		// `delegate`.
+		Self::delegate(r)
+	}
+	fn undelegate_split(r: u32, ) -> Weight {
+		// Not benchmarked yet: a single delegate is removed, and its `r` votes updated, as in
+		// `undelegate`.
+		Self::undelegate(r)
+	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
@@ -213,133 +201,119 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn vote_new() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `13141`
-		//  Estimated: `219984`
-		// Minimum execution time: 135_295_000 picoseconds.
+		// Not benchmarked yet: measured before a first vote was also recorded in `VotersOf` and
+		// `TallyBreakdownOf`.
 		Weight::from_parts(142_897_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			// estimated cost of the accesses to `VotersOf` and `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn vote_existing() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `20283`
-		//  Estimated: `219984`
-		// Minimum execution time: 324_485_000 picoseconds.
+		// Not benchmarked yet: measured before the breakdown of the tally was updated along with
+		// the vote.
 		Weight::from_parts(337_467_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			// estimated cost of the accesses to `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn remove_vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `20035`
-		//  Estimated: `219984`
-		// Minimum execution time: 302_574_000 picoseconds.
+		// Not benchmarked yet: measured before the vote was also removed from `VotersOf` and from
+		// the breakdown of the tally.
 		Weight::from_parts(315_016_000, 219984)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			// estimated cost of the accesses to `VotersOf` and `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:0)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::VotersOf` (r:0 w:1)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	fn remove_other_vote() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `12742`
-		//  Estimated: `30706`
-		// Minimum execution time: 65_548_000 picoseconds.
+		// Not benchmarked yet: measured before an expired vote was also dropped from `VotersOf` and
+		// from the breakdown of the tally.
 		Weight::from_parts(71_499_000, 30706)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			// estimated cost of the accesses to `VotersOf` and `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
-	/// Proof: `ConvictionVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	/// The range of component `r` is `[0, 1]`.
 	fn delegate(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `306 + r * (1628 ±0)`
-		//  Estimated: `109992 + r * (109992 ±0)`
-		// Minimum execution time: 61_383_000 picoseconds.
+		// Not benchmarked yet: measured before the delegated votes were also added to the breakdown
+		// of each tally.
 		Weight::from_parts(70_695_789, 109992)
-			// Standard Error: 457_836
 			.saturating_add(Weight::from_parts(44_163_910, 0).saturating_mul(r.into()))
+			// estimated cost of the accesses to `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
-			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
 	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:2 w:2)
-	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `Referenda::ReferendumInfoFor` (r:1 w:1)
-	/// Proof: `Referenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(366), added: 2841, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
-	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(107022), added: 109497, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Retries` (r:0 w:1)
-	/// Proof: `Scheduler::Retries` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `ConvictionVoting::TallyBreakdownOf` (r:1 w:1)
 	/// The range of component `r` is `[0, 1]`.
 	fn undelegate(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `472 + r * (1377 ±0)`
-		//  Estimated: `109992 + r * (109992 ±0)`
-		// Minimum execution time: 33_466_000 picoseconds.
+		// Not benchmarked yet: measured before the delegated votes were also taken out of the
+		// breakdown of each tally.
 		Weight::from_parts(39_261_420, 109992)
-			// Standard Error: 358_545
 			.saturating_add(Weight::from_parts(43_197_579, 0).saturating_mul(r.into()))
+			// estimated cost of the accesses to `TallyBreakdownOf`.
+			.saturating_add(Weight::from_parts(5_000_000, 2683).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
-			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 109992).saturating_mul(r.into()))
 	}
+	fn delegate_split(r: u32, ) -> Weight {
+		// Not benchmarked yet: a single delegate is added, and its `r` votes updated, as in
+		// `delegate`.
+		Self::delegate(r)
+	}
+	fn undelegate_split(r: u32, ) -> Weight {
+		// Not benchmarked yet: a single delegate is removed, and its `r` votes updated, as in
+		// `undelegate`.
+		Self::undelegate(r)
+	}
 	/// Storage: `ConvictionVoting::VotingFor` (r:1 w:1)
 	/// Proof: `ConvictionVoting::VotingFor` (`max_values`: None, `max_size`: Some(27241), added: 29716, mode: `MaxEncodedLen`)
 	/// Storage: `ConvictionVoting::ClassLocksFor` (r:1 w:1)
@@ -0,0 +1,223 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarking for pallet-delegated-staking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use sp_staking::OnStakingUpdate;
+
+const SEED: u32 = 0;
+// the nominations quota of `pallet-staking` in the runtimes.
+const MAX_NOMINATIONS: u32 = 16;
+// the number of slashing spans benchmarked by `pallet-staking`.
+const MAX_SPANS: u32 = 100;
+
+/// The amount delegated in the benchmarks, enough to nominate.
+fn stake<T: Config>() -> BalanceOf<T> {
+	T::CoreStaking::minimum_nominator_bond()
+		.max(T::Currency::minimum_balance())
+		.saturating_mul(10u32.into())
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, SEED);
+	T::Currency::set_balance(&who, stake::<T>().saturating_mul(10u32.into()));
+	who
+}
+
+/// Create an `Agent` managed by a new owner, with a single delegator delegating [`stake`] to it.
+///
+/// Returns the owner, the agent and the delegator.
+fn setup_managed_agent<T: Config>() -> (T::AccountId, T::AccountId, T::AccountId) {
+	let owner = funded_account::<T>("owner", 0);
+	assert_ok!(Pallet::<T>::create_agent(RawOrigin::Signed(owner.clone()).into()));
+	let agent = Pallet::<T>::derived_agent(&owner);
+
+	let delegator = funded_account::<T>("delegator", 0);
+	assert_ok!(Pallet::<T>::delegate_funds(
+		RawOrigin::Signed(delegator.clone()).into(),
+		T::Lookup::unlookup(agent.clone()),
+		stake::<T>(),
+	));
+
+	(owner, agent, delegator)
+}
+
+/// Pay staking rewards to the reward pot of `agent`, so that its delegators have rewards to claim.
+fn reward_agent<T: Config>(agent: &T::AccountId) {
+	assert_ok!(T::Currency::mint_into(&Pallet::<T>::reward_pot(agent), stake::<T>()));
+}
+
+fn nominate_targets<T: Config>(n: u32) -> Vec<AccountIdLookupOf<T>> {
+	(0..n).map(|i| T::Lookup::unlookup(account("target", i, SEED))).collect()
+}
+
+/// Unbond the whole stake of `agent`, and make it withdrawable.
+fn unbond_all<T: Config>(owner: &T::AccountId) {
+	assert_ok!(Pallet::<T>::unbond(RawOrigin::Signed(owner.clone()).into(), stake::<T>()));
+	T::CoreStaking::set_current_era(
+		T::CoreStaking::current_era().saturating_add(T::CoreStaking::bonding_duration()),
+	);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_agent() {
+		let owner = funded_account::<T>("owner", 0);
+		whitelist_account!(owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()));
+
+		assert!(ManagedAgents::<T>::contains_key(Pallet::<T>::derived_agent(&owner)));
+	}
+
+	#[benchmark]
+	fn delegate_funds() {
+		// the worst case is an existing delegator with pending rewards, delegating to an agent
+		// already bonded.
+		let (_, agent, delegator) = setup_managed_agent::<T>();
+		reward_agent::<T>(&agent);
+		whitelist_account!(delegator);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), T::Lookup::unlookup(agent), stake::<T>());
+
+		assert_eq!(
+			Pallet::<T>::delegated_amount(&delegator),
+			stake::<T>().saturating_mul(2u32.into())
+		);
+	}
+
+	#[benchmark]
+	fn release_funds(s: Linear<0, MAX_SPANS>) {
+		// the worst case releases the whole stake of the agent, which is then removed from
+		// `CoreStaking` along with its slashing spans.
+		let (owner, agent, delegator) = setup_managed_agent::<T>();
+		unbond_all::<T>(&owner);
+		T::CoreStaking::add_slashing_spans(&agent, s);
+		reward_agent::<T>(&agent);
+		whitelist_account!(owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), T::Lookup::unlookup(delegator.clone()), stake::<T>(), s);
+
+		assert!(Delegators::<T>::get(&delegator).is_none());
+	}
+
+	#[benchmark]
+	fn nominate(n: Linear<1, MAX_NOMINATIONS>) {
+		let (owner, agent, _) = setup_managed_agent::<T>();
+		whitelist_account!(owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), nominate_targets::<T>(n));
+
+		assert_eq!(
+			T::CoreStaking::nominations(&agent).map(|targets| targets.len() as u32),
+			Some(n)
+		);
+	}
+
+	#[benchmark]
+	fn chill() {
+		let (owner, agent, _) = setup_managed_agent::<T>();
+		assert_ok!(Pallet::<T>::nominate(
+			RawOrigin::Signed(owner.clone()).into(),
+			nominate_targets::<T>(MAX_NOMINATIONS)
+		));
+		whitelist_account!(owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner));
+
+		assert!(T::CoreStaking::nominations(&agent).is_none());
+	}
+
+	#[benchmark]
+	fn unbond() {
+		// the worst case is a nominating agent, which moves in the voter list.
+		let (owner, agent, _) = setup_managed_agent::<T>();
+		assert_ok!(Pallet::<T>::nominate(
+			RawOrigin::Signed(owner.clone()).into(),
+			nominate_targets::<T>(MAX_NOMINATIONS)
+		));
+		let amount = stake::<T>() / 2u32.into();
+		whitelist_account!(owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), amount);
+
+		assert_eq!(T::CoreStaking::active_stake(&agent), Ok(stake::<T>().saturating_sub(amount)));
+	}
+
+	#[benchmark]
+	fn apply_slash() {
+		let (_, agent, delegator) = setup_managed_agent::<T>();
+		let slash = stake::<T>() / 2u32.into();
+		<Pallet<T> as OnStakingUpdate<T::AccountId, BalanceOf<T>>>::on_slash(
+			&agent,
+			slash,
+			&Default::default(),
+			slash,
+		);
+		reward_agent::<T>(&agent);
+		let reporter = funded_account::<T>("reporter", 0);
+		whitelist_account!(reporter);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(reporter), T::Lookup::unlookup(delegator.clone()));
+
+		assert_eq!(Pallet::<T>::delegated_amount(&delegator), stake::<T>().saturating_sub(slash));
+	}
+
+	#[benchmark]
+	fn claim_rewards() {
+		let (_, agent, delegator) = setup_managed_agent::<T>();
+		reward_agent::<T>(&agent);
+		whitelist_account!(delegator);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator));
+
+		assert!(ManagedAgent::<T>::get(&agent).map_or(false, |m| !m.reward_counter.is_zero()));
+	}
+
+	#[benchmark]
+	fn withdraw_funds(s: Linear<0, MAX_SPANS>) {
+		let (owner, agent, delegator) = setup_managed_agent::<T>();
+		unbond_all::<T>(&owner);
+		T::CoreStaking::add_slashing_spans(&agent, s);
+		reward_agent::<T>(&agent);
+		whitelist_account!(delegator);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), stake::<T>(), s);
+
+		assert!(Delegators::<T>::get(&delegator).is_none());
+	}
+
+	impl_benchmark_test_suite!(Pallet, mock::ExtBuilder::default().build(), mock::Runtime);
+}
@@ -85,6 +85,15 @@ impl<T: Config> DelegationInterface for Pallet<T> {
 		)
 	}
 
+	/// Transfer delegation of `from` to `to`, keeping the funds delegated to the same `Agent`.
+	fn transfer_delegation(
+		from: Delegator<Self::AccountId>,
+		to: Delegator<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Pallet::<T>::transfer_delegation(RawOrigin::Signed(from.get()).into(), to.get(), amount)
+	}
+
 	/// Returns pending slash of the `agent`.
 	fn pending_slash(agent: Agent<Self::AccountId>) -> Option<Self::Balance> {
 		AgentLedgerOuter::<T>::get(&agent.get()).map(|d| d.ledger.pending_slash).ok()
@@ -148,7 +157,15 @@ impl<T: Config> OnStakingUpdate<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	) {
 		<Agents<T>>::mutate(who, |maybe_register| match maybe_register {
 			// if existing agent, register the slashed amount as pending slash.
-			Some(register) => register.pending_slash.saturating_accrue(slashed_total),
+			Some(register) => {
+				register.pending_slash.saturating_accrue(slashed_total);
+
+				// agents managed by accounts share the slash among their delegators.
+				if let Some(mut managed) = ManagedAgent::<T>::get(who) {
+					managed.record_slash(slashed_total, register.total_delegated);
+					managed.update(who);
+				}
+			},
 			None => {
 				// nothing to do
 			},
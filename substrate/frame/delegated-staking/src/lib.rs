@@ -22,9 +22,10 @@
 //! [`StakingInterface`] and relies on [`Config::CoreStaking`] to provide primitive staking
 //! functions.
 //!
-//! Currently, it does not expose any dispatchable calls but is written with a vision to expose them
-//! in the future such that it can be utilised by any external account, off-chain entity or xcm
-//! `MultiLocation` such as a parachain or a smart contract.
+//! Besides the pallets using it through [`sp_staking::DelegationInterface`], it exposes
+//! dispatchable calls so that any account, such as a custody provider or an account controlled by
+//! a smart contract, can operate an `agent`. See [Agents managed by
+//! accounts](#agents-managed-by-accounts).
 //!
 //! ## Key Terminologies
 //! - **Agent**: An account who accepts delegations from other accounts and act as an agent on their
@@ -74,6 +75,26 @@
 //!   agent, the funds are held in a proxy account. This function allows the delegator to claim
 //!   their share of the funds from the proxy account. See [`Pallet::migrate_delegation`].
 //!
+//! ## Agents managed by accounts
+//!
+//! Any account can create an `agent` with [`Pallet::create_agent`]. The `agent` is a keyless
+//! account derived from its owner, see [`Pallet::derived_agent`], and accepts delegations from any
+//! other account with [`Pallet::delegate_funds`]. Its owner manages the stake with
+//! [`Pallet::nominate`], [`Pallet::chill`] and [`Pallet::unbond`], and releases unbonded funds to
+//! the delegators with [`Pallet::release_funds`]. Delegators can also withdraw unbonded funds
+//! themselves with [`Pallet::withdraw_funds`].
+//!
+//! Unlike the `agents` of other pallets, which are free to apply their own strategies, the rewards
+//! and slashes of these `agents` are distributed among their delegators proportionally to their
+//! delegations:
+//! - The staking rewards are paid to a reward pot derived from the `agent`, see
+//!   [`Pallet::reward_pot`], and are claimed by each delegator with [`Pallet::claim_rewards`].
+//!   Pending rewards are also paid whenever the delegation changes.
+//! - The share of each delegator in a slash of the `agent` is applied by anyone with
+//!   [`Pallet::apply_slash`], the caller receiving [`Config::SlashRewardFraction`] of it. Until
+//!   then, the delegator can neither add to its delegation nor have it released. The shares are
+//!   rounded up, so that the slash is fully cleared once all of them are applied.
+//!
 //! ## Lazy Slashing
 //! One of the reasons why direct nominators on staking pallet cannot scale well is because all
 //! nominators are slashed at the same time. This is expensive and needs to be bounded operation.
@@ -125,6 +146,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod impls;
 pub mod migration;
 #[cfg(test)]
@@ -132,13 +155,16 @@ mod mock;
 #[cfg(test)]
 mod tests;
 mod types;
+pub mod weights;
 
 extern crate alloc;
 
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 use types::*;
 
+use alloc::vec::Vec;
 use core::convert::TryInto;
 use frame_support::{
 	pallet_prelude::*,
@@ -155,8 +181,11 @@ use frame_support::{
 };
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedSub, TrailingZeroInput, Zero},
-	ArithmeticError, DispatchResult, Perbill, RuntimeDebug, Saturating,
+	traits::{
+		CheckedAdd, CheckedSub, One, SaturatedConversion, StaticLookup, TrailingZeroInput, Zero,
+	},
+	ArithmeticError, DispatchResult, FixedPointNumber, FixedU128, Perbill, Rounding, RuntimeDebug,
+	Saturating,
 };
 use sp_staking::{Agent, Delegator, EraIndex, StakingInterface, StakingUnchecked};
 
@@ -174,6 +203,7 @@ macro_rules! log {
 }
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as FunInspect<<T as frame_system::Config>::AccountId>>::Balance;
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 use frame_system::{ensure_signed, pallet_prelude::*, RawOrigin};
 
@@ -213,6 +243,9 @@ pub mod pallet {
 
 		/// Core staking implementation.
 		type CoreStaking: StakingUnchecked<Balance = BalanceOf<Self>, AccountId = Self::AccountId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::error]
@@ -266,6 +299,17 @@ pub mod pallet {
 		Slashed { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
 		/// Unclaimed delegation funds migrated to delegator.
 		MigratedDelegation { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
+		/// Delegated funds transferred from a delegator to another.
+		DelegationTransferred {
+			agent: T::AccountId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// An `Agent` was created by its owner.
+		AgentCreated { owner: T::AccountId, agent: T::AccountId },
+		/// Staking rewards of an `Agent` were paid to a delegator.
+		RewardsClaimed { agent: T::AccountId, delegator: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	/// Map of Delegators to their `Delegation`.
@@ -281,9 +325,220 @@ pub mod pallet {
 	pub(crate) type Agents<T: Config> =
 		CountedStorageMap<_, Twox64Concat, T::AccountId, AgentLedger<T>, OptionQuery>;
 
-	// This pallet is not currently written with the intention of exposing any calls. But the
-	// functions defined in the following impl block should act as a good reference for how the
-	// exposed calls would look like when exposed.
+	/// Map of `Agent`s created with [`Pallet::create_agent`] to their `ManagedAgent`.
+	#[pallet::storage]
+	pub type ManagedAgents<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, ManagedAgent<T>, OptionQuery>;
+
+	/// Map of the delegators of `Agent`s created with [`Pallet::create_agent`] to the counters of
+	/// their agent as of the last settlement of their rewards and slashes.
+	#[pallet::storage]
+	pub type DelegatorCheckpoints<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, DelegatorCheckpoint, OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create an `Agent` account managed by the origin.
+		///
+		/// The agent is a keyless account derived from the origin, see [`Pallet::derived_agent`].
+		/// Its staking rewards are paid to a reward pot derived from the agent, which the origin
+		/// funds with the existential deposit.
+		///
+		/// Emits `AgentCreated`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_agent())]
+		pub fn create_agent(origin: OriginFor<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let agent = Self::derived_agent(&owner);
+			ensure!(!Self::is_agent(&agent) && !Self::is_delegator(&agent), Error::<T>::NotAllowed);
+
+			let reward_pot = Self::reward_pot(&agent);
+			T::Currency::transfer(
+				&owner,
+				&reward_pot,
+				T::Currency::minimum_balance(),
+				Preservation::Preserve,
+			)?;
+			let claimable = T::Currency::reducible_balance(
+				&reward_pot,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			);
+
+			Self::do_register_agent(&agent, &reward_pot);
+			ManagedAgent::<T>::new(&owner, claimable).update(&agent);
+
+			Self::deposit_event(Event::<T>::AgentCreated { owner, agent });
+			Ok(())
+		}
+
+		/// Delegate `amount` of the funds of the origin to an `Agent` created with
+		/// [`Pallet::create_agent`], which bonds them in [`Config::CoreStaking`].
+		///
+		/// Pending rewards of the origin are paid, and any slash of the origin must be applied
+		/// before it can delegate more.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::delegate_funds())]
+		pub fn delegate_funds(
+			origin: OriginFor<T>,
+			agent: AccountIdLookupOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let agent = T::Lookup::lookup(agent)?;
+			let mut managed = ManagedAgent::<T>::get(&agent).ok_or(Error::<T>::NotAgent)?;
+			ensure!(
+				Delegation::<T>::can_delegate(&delegator, &agent),
+				Error::<T>::InvalidDelegation
+			);
+
+			let mut checkpoint = Self::settle_rewards(&agent, &mut managed, &delegator)?;
+			let delegated = Self::delegated_amount(&delegator);
+			ensure!(
+				managed.unapplied_slash(&checkpoint, delegated).is_zero(),
+				Error::<T>::UnappliedSlash
+			);
+			// slashes posted while nothing was delegated do not apply to the new funds.
+			checkpoint.slash_counter = managed.slash_counter;
+			managed.update(&agent);
+
+			Self::delegate_to_agent(RawOrigin::Signed(delegator.clone()).into(), agent, amount)?;
+			checkpoint.update::<T>(&delegator);
+			Ok(())
+		}
+
+		/// Release `amount` of the funds delegated by `delegator` to the `Agent` managed by the
+		/// origin.
+		///
+		/// The funds need to be unbonded and withdrawable from [`Config::CoreStaking`], and any
+		/// slash of `delegator` must be applied first. Pending rewards of `delegator` are paid.
+		///
+		/// Delegators can also withdraw their funds themselves with [`Pallet::withdraw_funds`].
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::release_funds(*num_slashing_spans))]
+		pub fn release_funds(
+			origin: OriginFor<T>,
+			delegator: AccountIdLookupOf<T>,
+			amount: BalanceOf<T>,
+			num_slashing_spans: u32,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let delegator = T::Lookup::lookup(delegator)?;
+			let (agent, managed) = Self::managed_agent_of(&owner)?;
+			Self::do_release_managed(agent, managed, delegator, amount, num_slashing_spans)
+		}
+
+		/// Nominate `targets` with the stake of the `Agent` managed by the origin.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::nominate(targets.len() as u32))]
+		pub fn nominate(
+			origin: OriginFor<T>,
+			targets: Vec<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let (agent, _) = Self::managed_agent_of(&owner)?;
+			let targets =
+				targets.into_iter().map(T::Lookup::lookup).collect::<Result<Vec<_>, _>>()?;
+			T::CoreStaking::nominate(&agent, targets)
+		}
+
+		/// Stop nominating with the stake of the `Agent` managed by the origin.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::chill())]
+		pub fn chill(origin: OriginFor<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let (agent, _) = Self::managed_agent_of(&owner)?;
+			T::CoreStaking::chill(&agent)
+		}
+
+		/// Unbond `amount` of the stake of the `Agent` managed by the origin, so that it can be
+		/// released to its delegators once withdrawable.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::unbond())]
+		pub fn unbond(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let (agent, _) = Self::managed_agent_of(&owner)?;
+			T::CoreStaking::unbond(&agent, amount)
+		}
+
+		/// Apply the share of `delegator` in the pending slash of its `Agent`, proportional to its
+		/// delegation.
+		///
+		/// Can be called by any account, which receives [`Config::SlashRewardFraction`] of the
+		/// applied slash. Pending rewards of `delegator` are paid first.
+		///
+		/// Emits `Slashed`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::apply_slash())]
+		pub fn apply_slash(
+			origin: OriginFor<T>,
+			delegator: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+			let delegator = T::Lookup::lookup(delegator)?;
+			let agent = Delegation::<T>::get(&delegator).ok_or(Error::<T>::NotDelegator)?.agent;
+			let mut managed = ManagedAgent::<T>::get(&agent).ok_or(Error::<T>::NotAgent)?;
+
+			let mut checkpoint = Self::settle_rewards(&agent, &mut managed, &delegator)?;
+			let pending_slash = AgentLedgerOuter::<T>::get(&agent)?.ledger.pending_slash;
+			let slash = managed
+				.unapplied_slash(&checkpoint, Self::delegated_amount(&delegator))
+				.min(pending_slash);
+			ensure!(!slash.is_zero(), Error::<T>::NothingToSlash);
+			checkpoint.slash_counter = managed.slash_counter;
+			managed.update(&agent);
+
+			Self::do_slash(
+				Agent::from(agent),
+				Delegator::from(delegator.clone()),
+				slash,
+				Some(reporter),
+			)?;
+			checkpoint.update::<T>(&delegator);
+			Ok(())
+		}
+
+		/// Claim the pending staking rewards of the origin from its `Agent`.
+		///
+		/// Emits `RewardsClaimed` if there were rewards to pay.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::claim_rewards())]
+		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let agent = Delegation::<T>::get(&delegator).ok_or(Error::<T>::NotDelegator)?.agent;
+			let mut managed = ManagedAgent::<T>::get(&agent).ok_or(Error::<T>::NotAgent)?;
+
+			let checkpoint = Self::settle_rewards(&agent, &mut managed, &delegator)?;
+			managed.update(&agent);
+			checkpoint.update::<T>(&delegator);
+			Ok(())
+		}
+
+		/// Withdraw `amount` of the funds delegated by the origin to an `Agent` created with
+		/// [`Pallet::create_agent`].
+		///
+		/// Same as [`Pallet::release_funds`], but initiated by the delegator. The funds need to be
+		/// unbonded by the owner of the agent and withdrawable from [`Config::CoreStaking`], and
+		/// are released to the delegators in the order they withdraw them.
+		///
+		/// Emits `Released`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::withdraw_funds(*num_slashing_spans))]
+		pub fn withdraw_funds(
+			origin: OriginFor<T>,
+			amount: BalanceOf<T>,
+			num_slashing_spans: u32,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let agent = Delegation::<T>::get(&delegator).ok_or(Error::<T>::NotDelegator)?.agent;
+			let managed = ManagedAgent::<T>::get(&agent).ok_or(Error::<T>::NotAgent)?;
+			Self::do_release_managed(agent, managed, delegator, amount, num_slashing_spans)
+		}
+	}
+
+	// The functions defined in the following impl block are used by other pallets through
+	// [`DelegationInterface`] and [`DelegationMigrator`], and by the calls above. They take an
+	// origin to look alike the calls they could be exposed as.
 	impl<T: Config> Pallet<T> {
 		/// Register an account to become a stake `Agent`. Sometimes also called a `Delegatee`.
 		///
@@ -457,6 +712,33 @@ pub mod pallet {
 			// bond the newly delegated amount to `CoreStaking`.
 			Self::do_bond(Agent::from(agent), amount)
 		}
+
+		/// Transfer `amount` of the delegation of origin to `delegator`.
+		///
+		/// The funds stay delegated, and bonded, to the same `Agent`. Their hold is moved from the
+		/// origin to the `delegator` account.
+		///
+		/// Conditions:
+		/// - `delegator` should either be a new delegator or already delegate to the same agent.
+		/// - `delegator` cannot be a direct staker in [`Config::CoreStaking`].
+		pub fn transfer_delegation(
+			origin: OriginFor<T>,
+			delegator: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegation = Delegation::<T>::get(&who).ok_or(Error::<T>::NotDelegator)?;
+
+			// ensure delegator is sane.
+			ensure!(who != delegator, Error::<T>::InvalidDelegation);
+			ensure!(
+				Delegation::<T>::can_delegate(&delegator, &delegation.agent),
+				Error::<T>::InvalidDelegation
+			);
+			ensure!(!Self::is_direct_staker(&delegator), Error::<T>::AlreadyStaking);
+
+			Self::do_transfer_delegation(Delegator::from(who), Delegator::from(delegator), amount)
+		}
 	}
 
 	#[pallet::hooks]
@@ -482,6 +764,96 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
+	/// The `Agent` account created by `owner` with [`Pallet::create_agent`].
+	pub fn derived_agent(owner: &T::AccountId) -> T::AccountId {
+		Self::sub_account(AccountType::Agent, owner.clone())
+	}
+
+	/// The account where the staking rewards of an `Agent` created with [`Pallet::create_agent`]
+	/// are paid.
+	pub fn reward_pot(agent: &T::AccountId) -> T::AccountId {
+		Self::sub_account(AccountType::RewardPot, agent.clone())
+	}
+
+	/// The `Agent` managed by `owner`, along with its `ManagedAgent`.
+	fn managed_agent_of(
+		owner: &T::AccountId,
+	) -> Result<(T::AccountId, ManagedAgent<T>), DispatchError> {
+		let agent = Self::derived_agent(owner);
+		let managed = ManagedAgent::<T>::get(&agent).ok_or(Error::<T>::NotAgent)?;
+		Ok((agent, managed))
+	}
+
+	/// The amount delegated by `delegator`, or zero if it is not a delegator.
+	fn delegated_amount(delegator: &T::AccountId) -> BalanceOf<T> {
+		Delegation::<T>::get(delegator).map(|d| d.amount).unwrap_or_default()
+	}
+
+	/// Record the rewards of the managed `agent`, and pay the pending rewards of `delegator`.
+	///
+	/// Returns the checkpoint of `delegator` with an up to date reward counter. It still needs to
+	/// be saved, as does `managed`.
+	fn settle_rewards(
+		agent: &T::AccountId,
+		managed: &mut ManagedAgent<T>,
+		delegator: &T::AccountId,
+	) -> Result<DelegatorCheckpoint, DispatchError> {
+		let total_delegated = AgentLedgerOuter::<T>::get(agent)?.ledger.total_delegated;
+		managed.record_rewards(agent, total_delegated);
+
+		// new delegators are not entitled to past rewards and slashes.
+		let mut checkpoint =
+			DelegatorCheckpoints::<T>::get(delegator).unwrap_or_else(|| managed.checkpoint());
+		let rewards = managed.pending_rewards(&checkpoint, Self::delegated_amount(delegator));
+		checkpoint.reward_counter = managed.reward_counter;
+
+		if !rewards.is_zero() {
+			T::Currency::transfer(
+				&Self::reward_pot(agent),
+				delegator,
+				rewards,
+				Preservation::Preserve,
+			)?;
+			managed.last_recorded_rewards.saturating_reduce(rewards);
+			Self::deposit_event(Event::<T>::RewardsClaimed {
+				agent: agent.clone(),
+				delegator: delegator.clone(),
+				amount: rewards,
+			});
+		}
+
+		Ok(checkpoint)
+	}
+
+	/// Release `amount` of the funds delegated by `delegator` to the managed `agent`, after
+	/// paying its pending rewards.
+	///
+	/// Fails if `delegator` has a slash left to apply.
+	fn do_release_managed(
+		agent: T::AccountId,
+		mut managed: ManagedAgent<T>,
+		delegator: T::AccountId,
+		amount: BalanceOf<T>,
+		num_slashing_spans: u32,
+	) -> DispatchResult {
+		let checkpoint = Self::settle_rewards(&agent, &mut managed, &delegator)?;
+		let delegated = Self::delegated_amount(&delegator);
+		ensure!(
+			managed.unapplied_slash(&checkpoint, delegated).is_zero(),
+			Error::<T>::UnappliedSlash
+		);
+		managed.update(&agent);
+
+		Self::do_release(
+			Agent::from(agent),
+			Delegator::from(delegator.clone()),
+			amount,
+			num_slashing_spans,
+		)?;
+		checkpoint.update::<T>(&delegator);
+		Ok(())
+	}
+
 	/// Held balance of a delegator.
 	pub(crate) fn held_balance_of(who: Delegator<T::AccountId>) -> BalanceOf<T> {
 		T::Currency::balance_on_hold(&HoldReason::StakingDelegation.into(), &who.get())
@@ -710,6 +1082,68 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfers delegation of `amount` from `source` account to `destination` account.
+	///
+	/// Unlike [`Self::do_migrate_delegation`], the destination might already be a delegator of the
+	/// same agent, in which case its delegation is increased.
+	fn do_transfer_delegation(
+		source_delegator: Delegator<T::AccountId>,
+		destination_delegator: Delegator<T::AccountId>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		// get inner type
+		let source_delegator = source_delegator.get();
+		let destination_delegator = destination_delegator.get();
+
+		let mut source_delegation =
+			Delegation::<T>::get(&source_delegator).ok_or(Error::<T>::NotDelegator)?;
+
+		// ensure source has enough funds to transfer.
+		ensure!(source_delegation.amount >= amount, Error::<T>::NotEnoughFunds);
+
+		let agent = source_delegation.agent.clone();
+		// create or increase the delegation of destination delegator.
+		if let Some(mut existing_delegation) = Delegation::<T>::get(&destination_delegator) {
+			ensure!(existing_delegation.agent == agent, Error::<T>::InvalidDelegation);
+			existing_delegation.amount = existing_delegation
+				.amount
+				.checked_add(&amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			existing_delegation
+		} else {
+			Delegation::<T>::new(&agent, amount)
+		}
+		.update(&destination_delegator);
+
+		source_delegation.amount = source_delegation
+			.amount
+			.checked_sub(&amount)
+			.defensive_ok_or(Error::<T>::BadState)?;
+
+		// transfer the held amount in `source_delegator` to `destination_delegator`.
+		let _ = T::Currency::transfer_on_hold(
+			&HoldReason::StakingDelegation.into(),
+			&source_delegator,
+			&destination_delegator,
+			amount,
+			Precision::Exact,
+			Restriction::OnHold,
+			Fortitude::Polite,
+		)?;
+
+		// update source delegation.
+		source_delegation.update(&source_delegator);
+
+		Self::deposit_event(Event::<T>::DelegationTransferred {
+			agent,
+			from: source_delegator,
+			to: destination_delegator,
+			amount,
+		});
+
+		Ok(())
+	}
+
 	/// Take slash `amount` from agent's `pending_slash`counter and apply it to `delegator` account.
 	pub fn do_slash(
 		agent: Agent<T::AccountId>,
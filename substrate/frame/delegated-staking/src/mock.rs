@@ -125,6 +125,7 @@ impl delegated_staking::Config for Runtime {
 	type SlashRewardFraction = SlashRewardFraction;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type CoreStaking = Staking;
+	type WeightInfo = ();
 }
 
 pub struct BalanceToU256;
@@ -160,6 +161,9 @@ impl pallet_nomination_pools::Config for Runtime {
 	type StakeAdapter =
 		pallet_nomination_pools::adapter::DelegateStake<Self, Staking, DelegatedStaking>;
 	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ValidatorSelector = ();
+	type MaxAutoNominations = ConstU32<0>;
+	type MaxAutoNominationCandidates = ConstU32<0>;
 }
 
 frame_support::construct_runtime!(
@@ -177,7 +181,7 @@ frame_support::construct_runtime!(
 pub struct ExtBuilder {}
 
 impl ExtBuilder {
-	fn build(self) -> sp_io::TestExternalities {
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
 		sp_tracing::try_init_simple();
 		let mut storage =
 			frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
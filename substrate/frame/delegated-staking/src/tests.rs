@@ -1400,6 +1400,32 @@ mod pool_integration {
 		});
 	}
 
+	#[test]
+	fn transfer_pool_points() {
+		ExtBuilder::default().build_and_execute(|| {
+			let pool_id = create_pool(100, 200);
+			add_delegators_to_pool(pool_id, vec![300], 500);
+			let staked_amount = 200 + 500;
+
+			// WHEN: part of the points are transferred to a new account.
+			assert_ok!(Pools::transfer_points(RawOrigin::Signed(300).into(), 301, 200));
+
+			// THEN: the delegation is moved along with the points.
+			assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(300)), 300);
+			assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(301)), 200);
+			assert_eq!(get_pool_agent(pool_id).bonded_stake(), staked_amount);
+
+			// WHEN: all remaining points are transferred.
+			assert_ok!(Pools::transfer_points(RawOrigin::Signed(300).into(), 302, 300));
+
+			// THEN: the source is not a delegator anymore.
+			assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(300)), 0);
+			assert!(!DelegatedStaking::is_delegator(&300));
+			assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(302)), 300);
+			assert_eq!(get_pool_agent(pool_id).bonded_stake(), staked_amount);
+		});
+	}
+
 	fn create_pool(creator: AccountId, amount: Balance) -> u32 {
 		fund(&creator, amount * 2);
 		assert_ok!(Pools::create(
@@ -1424,3 +1450,325 @@ mod pool_integration {
 		get_agent_ledger(&Pools::generate_bonded_account(pool_id))
 	}
 }
+
+mod managed_agents {
+	use super::*;
+
+	fn setup_managed_agent(owner: AccountId, delegations: Vec<(AccountId, Balance)>) -> AccountId {
+		fund(&owner, 100);
+		assert_ok!(DelegatedStaking::create_agent(RawOrigin::Signed(owner).into()));
+		let agent = DelegatedStaking::derived_agent(&owner);
+
+		for (delegator, amount) in delegations {
+			fund(&delegator, amount + ExistentialDeposit::get());
+			assert_ok!(DelegatedStaking::delegate_funds(
+				RawOrigin::Signed(delegator).into(),
+				agent,
+				amount
+			));
+		}
+
+		agent
+	}
+
+	#[test]
+	fn create_agent_and_stake() {
+		ExtBuilder::default().build_and_execute(|| {
+			start_era(1);
+			let owner: AccountId = 100;
+			let agent = setup_managed_agent(owner, vec![(300, 100), (301, 300)]);
+
+			// the owner funded the reward pot.
+			let reward_pot = DelegatedStaking::reward_pot(&agent);
+			assert_eq!(Balances::free_balance(reward_pot), ExistentialDeposit::get());
+			assert_eq!(Balances::free_balance(owner), 100 - ExistentialDeposit::get());
+			assert_eq!(
+				events_since_last_call(),
+				vec![
+					Event::AgentCreated { owner, agent },
+					Event::Delegated { agent, delegator: 300, amount: 100 },
+					Event::Delegated { agent, delegator: 301, amount: 300 },
+				]
+			);
+
+			// delegations are bonded.
+			assert!(eq_stake(agent, 400, 400));
+
+			// an owner cannot create a second agent.
+			assert_noop!(
+				DelegatedStaking::create_agent(RawOrigin::Signed(owner).into()),
+				Error::<T>::NotAllowed
+			);
+
+			// only managed agents accept delegations through calls.
+			setup_delegation_stake(200, 201, vec![], 0, 0);
+			fund(&302, 100);
+			assert_noop!(
+				DelegatedStaking::delegate_funds(RawOrigin::Signed(302).into(), 200, 50),
+				Error::<T>::NotAgent
+			);
+			// delegators cannot delegate to another managed agent.
+			let other_agent = setup_managed_agent(101, vec![]);
+			assert_noop!(
+				DelegatedStaking::delegate_funds(RawOrigin::Signed(300).into(), other_agent, 1),
+				Error::<T>::InvalidDelegation
+			);
+
+			// only the owner manages the stake.
+			assert_noop!(
+				DelegatedStaking::nominate(RawOrigin::Signed(300).into(), vec![GENESIS_VALIDATOR]),
+				Error::<T>::NotAgent
+			);
+			assert_ok!(DelegatedStaking::nominate(
+				RawOrigin::Signed(owner).into(),
+				vec![GENESIS_VALIDATOR]
+			));
+			assert_eq!(
+				Staking::status(&agent),
+				Ok(StakerStatus::Nominator(vec![GENESIS_VALIDATOR]))
+			);
+			assert_ok!(DelegatedStaking::chill(RawOrigin::Signed(owner).into()));
+			assert_eq!(Staking::status(&agent), Ok(StakerStatus::Idle));
+
+			// unbond and release funds.
+			start_era(2);
+			assert_ok!(DelegatedStaking::unbond(RawOrigin::Signed(owner).into(), 100));
+			assert!(eq_stake(agent, 400, 300));
+			assert_noop!(
+				DelegatedStaking::release_funds(RawOrigin::Signed(owner).into(), 300, 100, 0),
+				Error::<T>::NotEnoughFunds
+			);
+
+			start_era(5);
+			assert_ok!(DelegatedStaking::release_funds(
+				RawOrigin::Signed(owner).into(),
+				300,
+				100,
+				0
+			));
+			assert_eq!(Balances::free_balance(300), 100 + ExistentialDeposit::get());
+			// 300 is not a delegator anymore.
+			assert!(Delegators::<T>::get(300).is_none());
+			assert!(DelegatorCheckpoints::<T>::get(300).is_none());
+		});
+	}
+
+	#[test]
+	fn rewards_are_shared_proportionally() {
+		ExtBuilder::default().build_and_execute(|| {
+			start_era(1);
+			let owner: AccountId = 100;
+			let agent = setup_managed_agent(owner, vec![(300, 100), (301, 300)]);
+			let reward_pot = DelegatedStaking::reward_pot(&agent);
+			let _ = events_since_last_call();
+
+			// staking rewards are paid to the reward pot.
+			fund(&reward_pot, 40);
+
+			assert_ok!(DelegatedStaking::claim_rewards(RawOrigin::Signed(300).into()));
+			assert_ok!(DelegatedStaking::claim_rewards(RawOrigin::Signed(301).into()));
+			assert_eq!(
+				events_since_last_call(),
+				vec![
+					Event::RewardsClaimed { agent, delegator: 300, amount: 10 },
+					Event::RewardsClaimed { agent, delegator: 301, amount: 30 },
+				]
+			);
+			assert_eq!(Balances::free_balance(reward_pot), ExistentialDeposit::get());
+
+			// nothing left to claim.
+			assert_ok!(DelegatedStaking::claim_rewards(RawOrigin::Signed(300).into()));
+			assert_eq!(events_since_last_call(), vec![]);
+
+			// a new delegator is not entitled to past rewards.
+			fund(&reward_pot, 40);
+			fund(&302, 400 + ExistentialDeposit::get());
+			assert_ok!(DelegatedStaking::delegate_funds(RawOrigin::Signed(302).into(), agent, 400));
+			assert_ok!(DelegatedStaking::claim_rewards(RawOrigin::Signed(302).into()));
+			assert_eq!(
+				events_since_last_call(),
+				vec![Event::Delegated { agent, delegator: 302, amount: 400 }]
+			);
+
+			// pending rewards are paid when delegating more.
+			fund(&300, 100);
+			assert_ok!(DelegatedStaking::delegate_funds(RawOrigin::Signed(300).into(), agent, 100));
+			assert_eq!(
+				events_since_last_call(),
+				vec![
+					Event::RewardsClaimed { agent, delegator: 300, amount: 10 },
+					Event::Delegated { agent, delegator: 300, amount: 100 },
+				]
+			);
+
+			// new rewards are shared among all delegators.
+			fund(&reward_pot, 90);
+			for delegator in [300, 301, 302] {
+				assert_ok!(DelegatedStaking::claim_rewards(RawOrigin::Signed(delegator).into()));
+			}
+			assert_eq!(
+				events_since_last_call(),
+				vec![
+					Event::RewardsClaimed { agent, delegator: 300, amount: 20 },
+					Event::RewardsClaimed { agent, delegator: 301, amount: 30 + 30 },
+					Event::RewardsClaimed { agent, delegator: 302, amount: 40 },
+				]
+			);
+
+			// only delegators of managed agents can claim.
+			assert_noop!(
+				DelegatedStaking::claim_rewards(RawOrigin::Signed(303).into()),
+				Error::<T>::NotDelegator
+			);
+		});
+	}
+
+	#[test]
+	fn slashes_are_shared_proportionally() {
+		ExtBuilder::default().build_and_execute(|| {
+			start_era(1);
+			let owner: AccountId = 100;
+			let reporter: AccountId = 400;
+			let agent = setup_managed_agent(owner, vec![(300, 100), (301, 300)]);
+
+			start_era(4);
+			// slash half of the stake.
+			pallet_staking::slashing::do_slash::<T>(
+				&agent,
+				200,
+				&mut Default::default(),
+				&mut Default::default(),
+				3,
+			);
+			assert_eq!(get_agent_ledger(&agent).ledger.pending_slash, 200);
+
+			// a new delegator does not share the past slash.
+			fund(&302, 50 + ExistentialDeposit::get());
+			assert_ok!(DelegatedStaking::delegate_funds(RawOrigin::Signed(302).into(), agent, 50));
+			assert_noop!(
+				DelegatedStaking::apply_slash(RawOrigin::Signed(reporter).into(), 302),
+				Error::<T>::NothingToSlash
+			);
+
+			// delegators with unapplied slash can neither delegate more nor be released.
+			fund(&300, 10);
+			assert_noop!(
+				DelegatedStaking::delegate_funds(RawOrigin::Signed(300).into(), agent, 10),
+				Error::<T>::UnappliedSlash
+			);
+			assert_noop!(
+				DelegatedStaking::release_funds(RawOrigin::Signed(owner).into(), 300, 10, 0),
+				Error::<T>::UnappliedSlash
+			);
+
+			// anyone can apply the slash.
+			let _ = events_since_last_call();
+			assert_ok!(DelegatedStaking::apply_slash(RawOrigin::Signed(reporter).into(), 300));
+			assert_ok!(DelegatedStaking::apply_slash(RawOrigin::Signed(reporter).into(), 301));
+			assert_eq!(
+				events_since_last_call(),
+				vec![
+					Event::Slashed { agent, delegator: 300, amount: 50 },
+					Event::Slashed { agent, delegator: 301, amount: 150 },
+				]
+			);
+			assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(300)), 50);
+			assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(301)), 150);
+			assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(302)), 50);
+			assert_eq!(get_agent_ledger(&agent).ledger.pending_slash, 0);
+			// the reporter is rewarded with a fraction of the slash.
+			assert_eq!(Balances::free_balance(reporter), SlashRewardFraction::get() * 200);
+
+			// the slash is applied only once.
+			assert_noop!(
+				DelegatedStaking::apply_slash(RawOrigin::Signed(reporter).into(), 300),
+				Error::<T>::NothingToSlash
+			);
+			// and 300 can delegate again.
+			assert_ok!(DelegatedStaking::delegate_funds(RawOrigin::Signed(300).into(), agent, 10));
+		});
+	}
+
+	#[test]
+	fn delegators_can_withdraw_unbonded_funds() {
+		ExtBuilder::default().build_and_execute(|| {
+			start_era(1);
+			let owner: AccountId = 100;
+			let agent = setup_managed_agent(owner, vec![(300, 100), (301, 300)]);
+
+			start_era(2);
+			assert_ok!(DelegatedStaking::unbond(RawOrigin::Signed(owner).into(), 200));
+			// funds are not withdrawable yet.
+			assert_noop!(
+				DelegatedStaking::withdraw_funds(RawOrigin::Signed(300).into(), 100, 0),
+				Error::<T>::NotEnoughFunds
+			);
+
+			start_era(5);
+			let _ = events_since_last_call();
+			assert_ok!(DelegatedStaking::withdraw_funds(RawOrigin::Signed(300).into(), 100, 0));
+			assert_ok!(DelegatedStaking::withdraw_funds(RawOrigin::Signed(301).into(), 100, 0));
+			assert_eq!(
+				events_since_last_call(),
+				vec![
+					Event::Released { agent, delegator: 300, amount: 100 },
+					Event::Released { agent, delegator: 301, amount: 100 },
+				]
+			);
+			assert_eq!(Balances::free_balance(300), 100 + ExistentialDeposit::get());
+			assert!(Delegators::<T>::get(300).is_none());
+			assert!(DelegatorCheckpoints::<T>::get(300).is_none());
+			assert_eq!(DelegatedStaking::held_balance_of(Delegator::from(301)), 200);
+
+			// only the unbonded funds can be withdrawn.
+			assert_noop!(
+				DelegatedStaking::withdraw_funds(RawOrigin::Signed(301).into(), 1, 0),
+				Error::<T>::NotEnoughFunds
+			);
+			assert_noop!(
+				DelegatedStaking::withdraw_funds(RawOrigin::Signed(302).into(), 1, 0),
+				Error::<T>::NotDelegator
+			);
+		});
+	}
+
+	#[test]
+	fn slash_rounding_leaves_no_dust() {
+		ExtBuilder::default().build_and_execute(|| {
+			start_era(1);
+			let owner: AccountId = 100;
+			let reporter: AccountId = 400;
+			let agent = setup_managed_agent(owner, vec![(300, 100), (301, 100), (302, 100)]);
+
+			start_era(4);
+			// the slash cannot be split evenly among the delegators.
+			pallet_staking::slashing::do_slash::<T>(
+				&agent,
+				100,
+				&mut Default::default(),
+				&mut Default::default(),
+				3,
+			);
+			assert_eq!(get_agent_ledger(&agent).ledger.pending_slash, 100);
+
+			// shares are rounded up, the last one is capped by what is left of the slash.
+			let _ = events_since_last_call();
+			for delegator in [300, 301, 302] {
+				assert_ok!(DelegatedStaking::apply_slash(
+					RawOrigin::Signed(reporter).into(),
+					delegator
+				));
+			}
+			assert_eq!(
+				events_since_last_call(),
+				vec![
+					Event::Slashed { agent, delegator: 300, amount: 34 },
+					Event::Slashed { agent, delegator: 301, amount: 34 },
+					Event::Slashed { agent, delegator: 302, amount: 32 },
+				]
+			);
+			// no dust is left to block the agent.
+			assert_eq!(get_agent_ledger(&agent).ledger.pending_slash, 0);
+		});
+	}
+}
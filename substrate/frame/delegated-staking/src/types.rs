@@ -28,6 +28,148 @@ pub(crate) enum AccountType {
 	///
 	/// Funds for unmigrated `delegator` accounts of the `Agent` are kept here.
 	ProxyDelegator,
+	/// An `Agent` account created by an account with [`Pallet::create_agent`].
+	Agent,
+	/// The account where the staking rewards of an `Agent` created with [`Pallet::create_agent`]
+	/// are paid, until they are claimed by its delegators.
+	RewardPot,
+}
+
+/// An `Agent` created with [`Pallet::create_agent`], managed by its owner.
+///
+/// The rewards and slashes of the agent are distributed to its delegators proportionally to their
+/// delegations, using counters of the rewards and slashes per unit of delegation.
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct ManagedAgent<T: Config> {
+	/// The account which created the agent and manages its stake.
+	pub owner: T::AccountId,
+	/// The rewards per unit of delegation received by the agent so far.
+	pub reward_counter: FixedU128,
+	/// The claimable balance of the reward pot as of the last time rewards were recorded.
+	pub last_recorded_rewards: BalanceOf<T>,
+	/// The slashes per unit of delegation posted to the agent so far.
+	pub slash_counter: FixedU128,
+}
+
+impl<T: Config> ManagedAgent<T> {
+	/// Create a new instance of `ManagedAgent`.
+	pub(crate) fn new(owner: &T::AccountId, last_recorded_rewards: BalanceOf<T>) -> Self {
+		ManagedAgent {
+			owner: owner.clone(),
+			reward_counter: Zero::zero(),
+			last_recorded_rewards,
+			slash_counter: Zero::zero(),
+		}
+	}
+
+	/// A checkpoint up to date with the counters of the agent.
+	pub(crate) fn checkpoint(&self) -> DelegatorCheckpoint {
+		DelegatorCheckpoint {
+			reward_counter: self.reward_counter,
+			slash_counter: self.slash_counter,
+		}
+	}
+
+	/// Record the rewards paid to the reward pot of `agent` since the last time they were
+	/// recorded, given the `total_delegated` to the agent.
+	///
+	/// If nothing is delegated to the agent, the rewards are left to be recorded later.
+	pub(crate) fn record_rewards(&mut self, agent: &T::AccountId, total_delegated: BalanceOf<T>) {
+		if total_delegated.is_zero() {
+			return
+		}
+		let balance = T::Currency::reducible_balance(
+			&Pallet::<T>::reward_pot(agent),
+			Preservation::Preserve,
+			Fortitude::Polite,
+		);
+		let new_rewards = balance.saturating_sub(self.last_recorded_rewards);
+		self.reward_counter = self
+			.reward_counter
+			.saturating_add(FixedU128::saturating_from_rational(new_rewards, total_delegated));
+		self.last_recorded_rewards = balance;
+	}
+
+	/// The rewards of a delegator of `amount` not yet paid since `checkpoint`.
+	pub(crate) fn pending_rewards(
+		&self,
+		checkpoint: &DelegatorCheckpoint,
+		amount: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		self.reward_counter
+			.saturating_sub(checkpoint.reward_counter)
+			.saturating_mul_int(amount)
+	}
+
+	/// The share of the slashes of the agent of a delegator of `amount` not yet applied since
+	/// `checkpoint`.
+	///
+	/// The share is rounded up, so that the shares of all delegators add up to at least the
+	/// pending slash of the agent, which is then fully cleared once all of them are applied.
+	pub(crate) fn unapplied_slash(
+		&self,
+		checkpoint: &DelegatorCheckpoint,
+		amount: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let counter = self.slash_counter.saturating_sub(checkpoint.slash_counter);
+		if amount.is_zero() || counter.is_zero() {
+			return Zero::zero()
+		}
+		let slash = counter.saturating_mul_int(amount);
+		// `slash / amount` is only below `counter` if the product was rounded down.
+		let slash = if FixedU128::saturating_from_rational(slash, amount) < counter {
+			slash.saturating_add(One::one())
+		} else {
+			slash
+		};
+		slash.min(amount)
+	}
+
+	/// Record a slash of `amount` posted to the agent, given the `total_delegated` to it.
+	///
+	/// The slash per unit of delegation is rounded up, in favour of the protocol.
+	pub(crate) fn record_slash(&mut self, amount: BalanceOf<T>, total_delegated: BalanceOf<T>) {
+		if total_delegated.is_zero() {
+			return
+		}
+		self.slash_counter.saturating_accrue(FixedU128::from_rational_with_rounding(
+			amount.saturated_into(),
+			total_delegated.saturated_into(),
+			Rounding::Up,
+		));
+	}
+
+	/// Get `ManagedAgent` from storage.
+	pub(crate) fn get(agent: &T::AccountId) -> Option<Self> {
+		<ManagedAgents<T>>::get(agent)
+	}
+
+	/// Save self to storage with the given key.
+	pub(crate) fn update(self, agent: &T::AccountId) {
+		<ManagedAgents<T>>::insert(agent, self)
+	}
+}
+
+/// The counters of a [`ManagedAgent`] as of the last time the rewards and slashes of one of its
+/// delegators were settled.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DelegatorCheckpoint {
+	/// The reward counter of the agent when the rewards of the delegator were last paid.
+	pub reward_counter: FixedU128,
+	/// The slash counter of the agent when the slash of the delegator was last applied.
+	pub slash_counter: FixedU128,
+}
+
+impl DelegatorCheckpoint {
+	/// Save self to storage if `delegator` still delegates, or remove it otherwise.
+	pub(crate) fn update<T: Config>(self, delegator: &T::AccountId) {
+		if <Delegators<T>>::contains_key(delegator) {
+			<DelegatorCheckpoints<T>>::insert(delegator, self)
+		} else {
+			<DelegatorCheckpoints<T>>::remove(delegator)
+		}
+	}
 }
 
 /// Information about delegation of a `delegator`.
@@ -0,0 +1,236 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_delegated_staking`.
+//!
+//! The benchmarks of this pallet have not been run on reference hardware yet. These weights are
+//! conservative estimates derived from the storage accessed by each call, including the accesses
+//! made by [`Config::CoreStaking`] (`pallet-staking`), and should be replaced by the output of the
+//! benchmarks.
+//!
+//! [`Config::CoreStaking`]: crate::Config::CoreStaking
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_delegated_staking`.
+pub trait WeightInfo {
+	fn create_agent() -> Weight;
+	fn delegate_funds() -> Weight;
+	fn release_funds(s: u32, ) -> Weight;
+	fn nominate(n: u32, ) -> Weight;
+	fn chill() -> Weight;
+	fn unbond() -> Weight;
+	fn apply_slash() -> Weight;
+	fn claim_rewards() -> Weight;
+	fn withdraw_funds(s: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_delegated_staking` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `DelegatedStaking::Agents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::Delegators` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:0 w:1)
+	fn create_agent() -> Weight {
+		Weight::from_parts(60_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::Delegators` (r:1 w:1)
+	/// Storage: `DelegatedStaking::Agents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::DelegatorCheckpoints` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::VirtualStakers` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:3 w:3)
+	/// Storage: `VoterList::ListBags` (r:2 w:2)
+	fn delegate_funds() -> Weight {
+		Weight::from_parts(150_000_000, 8_800)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::Delegators` (r:1 w:1)
+	/// Storage: `DelegatedStaking::Agents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::DelegatorCheckpoints` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Staking::SlashingSpans` (r:1 w:0)
+	/// The range of component `s` is `[0, 100]`.
+	fn release_funds(s: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 6_200)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:0)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::MinNominatorBond` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:1)
+	/// Storage: `Staking::MaxNominatorsCount` (r:1 w:0)
+	/// Storage: `Staking::Validators` (r:16 w:0)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:1 w:1)
+	/// Storage: `VoterList::ListBags` (r:1 w:1)
+	/// Storage: `VoterList::CounterForListNodes` (r:1 w:1)
+	/// Storage: `Staking::CounterForNominators` (r:1 w:1)
+	/// The range of component `n` is `[1, 16]`.
+	fn nominate(n: u32, ) -> Weight {
+		Weight::from_parts(70_000_000, 4_600)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 2_520).saturating_mul(n.into()))
+	}
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:0)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:1)
+	/// Storage: `Staking::CounterForNominators` (r:1 w:1)
+	/// Storage: `VoterList::ListNodes` (r:2 w:2)
+	/// Storage: `VoterList::ListBags` (r:1 w:1)
+	/// Storage: `VoterList::CounterForListNodes` (r:1 w:1)
+	fn chill() -> Weight {
+		Weight::from_parts(70_000_000, 6_200)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::MinNominatorBond` (r:1 w:0)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Staking::Nominators` (r:1 w:0)
+	/// Storage: `VoterList::ListNodes` (r:3 w:3)
+	/// Storage: `VoterList::ListBags` (r:2 w:2)
+	fn unbond() -> Weight {
+		Weight::from_parts(100_000_000, 8_800)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `DelegatedStaking::Delegators` (r:1 w:1)
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::Agents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::DelegatorCheckpoints` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn apply_slash() -> Weight {
+		Weight::from_parts(100_000_000, 6_200)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `DelegatedStaking::Delegators` (r:1 w:0)
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::Agents` (r:1 w:0)
+	/// Storage: `DelegatedStaking::DelegatorCheckpoints` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn claim_rewards() -> Weight {
+		Weight::from_parts(60_000_000, 6_200)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `DelegatedStaking::Delegators` (r:1 w:1)
+	/// Storage: `DelegatedStaking::ManagedAgents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::Agents` (r:1 w:1)
+	/// Storage: `DelegatedStaking::DelegatorCheckpoints` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Staking::Bonded` (r:1 w:0)
+	/// Storage: `Staking::Ledger` (r:1 w:1)
+	/// Storage: `Staking::CurrentEra` (r:1 w:0)
+	/// Storage: `Staking::SlashingSpans` (r:1 w:0)
+	/// The range of component `s` is `[0, 100]`.
+	fn withdraw_funds(s: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 6_200)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn create_agent() -> Weight {
+		Weight::from_parts(60_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn delegate_funds() -> Weight {
+		Weight::from_parts(150_000_000, 8_800)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	fn release_funds(s: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 6_200)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+	fn nominate(n: u32, ) -> Weight {
+		Weight::from_parts(70_000_000, 4_600)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 2_520).saturating_mul(n.into()))
+	}
+	fn chill() -> Weight {
+		Weight::from_parts(70_000_000, 6_200)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn unbond() -> Weight {
+		Weight::from_parts(100_000_000, 8_800)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn apply_slash() -> Weight {
+		Weight::from_parts(100_000_000, 6_200)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	fn claim_rewards() -> Weight {
+		Weight::from_parts(60_000_000, 6_200)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn withdraw_funds(s: u32, ) -> Weight {
+		Weight::from_parts(150_000_000, 6_200)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+}
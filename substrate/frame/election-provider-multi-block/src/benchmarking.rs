@@ -0,0 +1,312 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Multi-block election pallet benchmarking.
+
+use frame_benchmarking::{v2::*, BenchmarkError};
+use frame_support::{assert_ok, traits::Hooks};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+use crate::*;
+
+const SEED: u32 = 999;
+
+/// Populate the data provider with `t` targets, and `v` voters voting for all of them.
+fn set_up_data_provider<T: Config>(v: u32, t: u32) {
+	T::DataProvider::clear();
+	let mut targets = (0..t)
+		.map(|i| {
+			let target = frame_benchmarking::account::<T::AccountId>("Target", i, SEED);
+			T::DataProvider::add_target(target.clone());
+			target
+		})
+		.collect::<Vec<_>>();
+	targets.truncate(<T::DataProvider as ElectionDataProvider>::MaxVotesPerVoter::get() as usize);
+
+	(0..v).for_each(|i| {
+		let voter = frame_benchmarking::account::<T::AccountId>("Voter", i, SEED);
+		T::DataProvider::add_voter(voter, 1000, targets.clone().try_into().unwrap());
+	});
+}
+
+/// Create the target snapshot and all pages of the voter snapshot, with full pages.
+fn set_up_snapshot<T: Config>() -> Result<(), BenchmarkError> {
+	let voters = T::VoterSnapshotPerBlock::get().saturating_mul(T::Pages::get());
+	let desired_targets = T::DataProvider::desired_targets()?;
+	set_up_data_provider::<T>(voters, T::TargetSnapshotPerBlock::get().max(desired_targets));
+
+	Pallet::<T>::create_target_snapshot()?;
+	for page in 0..T::Pages::get() {
+		Pallet::<T>::create_voter_snapshot_page(page)?;
+	}
+	Ok(())
+}
+
+/// A page of a solution in which the first `b` voters of the given page of the snapshot back the
+/// first target of the snapshot.
+fn supports_of_page<T: Config>(page: PageIndex, b: u32) -> SupportsPageOf<T> {
+	let winner = TargetSnapshot::<T>::get().unwrap_or_default()[0].clone();
+	let voters = VoterSnapshot::<T>::get(page)
+		.unwrap_or_default()
+		.into_iter()
+		.take(b as usize)
+		.map(|(who, stake, _)| (who, ExtendedBalance::from(stake)))
+		.collect::<Vec<_>>();
+	let total = voters.iter().map(|(_, stake)| stake).sum();
+	let support = PageSupport { total, voters: BoundedVec::truncate_from(voters) };
+	BoundedVec::truncate_from(vec![(winner, support)])
+}
+
+/// A page of a solution with `b` backings, spread over as few winners as possible.
+///
+/// The page is not feasible, which does not matter upon submission.
+fn supports_with_backings<T: Config>(b: u32) -> SupportsPageOf<T> {
+	let per_winner = T::VoterSnapshotPerBlock::get();
+	let supports = (0..b.div_ceil(per_winner))
+		.map(|w| {
+			let voters = (w * per_winner..b.min((w + 1) * per_winner))
+				.map(|i| (account("voter", i, SEED), 1000))
+				.collect::<Vec<_>>();
+			let total = voters.len() as ExtendedBalance * 1000;
+			(
+				account("winner", w, SEED),
+				PageSupport { total, voters: BoundedVec::truncate_from(voters) },
+			)
+		})
+		.collect::<Vec<_>>();
+	BoundedVec::truncate_from(supports)
+}
+
+/// A funded account, registered in the signed phase with the given score.
+fn registered_submitter<T: Config>(index: u32, score: ElectionScore) -> T::AccountId {
+	let who: T::AccountId = account("submitter", index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	assert_ok!(Pallet::<T>::register(RawOrigin::Signed(who.clone()).into(), score));
+	who
+}
+
+fn score_of(minimal_stake: u32) -> ElectionScore {
+	ElectionScore { minimal_stake: minimal_stake.into(), ..Default::default() }
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn on_initialize_nothing() {
+		assert!(CurrentPhase::<T>::get().is_off());
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(1_u32.into());
+		}
+
+		assert!(CurrentPhase::<T>::get().is_off());
+	}
+
+	#[benchmark]
+	fn on_initialize_snapshot_targets() -> Result<(), BenchmarkError> {
+		let desired_targets = T::DataProvider::desired_targets()?;
+		set_up_data_provider::<T>(0, T::TargetSnapshotPerBlock::get().max(desired_targets));
+		assert!(TargetSnapshot::<T>::get().is_none());
+
+		#[block]
+		{
+			assert_ok!(Pallet::<T>::create_target_snapshot());
+			Pallet::<T>::phase_transition(Phase::Snapshot(T::Pages::get()));
+		}
+
+		assert!(TargetSnapshot::<T>::get().is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize_snapshot_voters() -> Result<(), BenchmarkError> {
+		// fetch the second page, if any, so that a cursor is used.
+		let voters = T::VoterSnapshotPerBlock::get().saturating_mul(2);
+		set_up_data_provider::<T>(voters, 1);
+		let page = if T::Pages::get() > 1 {
+			Pallet::<T>::create_voter_snapshot_page(0)?;
+			1
+		} else {
+			0
+		};
+
+		#[block]
+		{
+			assert_ok!(Pallet::<T>::create_voter_snapshot_page(page));
+			CurrentPhase::<T>::put(Phase::Snapshot(T::Pages::get().saturating_sub(page + 1)));
+		}
+
+		assert_eq!(
+			VoterSnapshot::<T>::get(page).ok_or("page missing")?.len() as u32,
+			T::VoterSnapshotPerBlock::get()
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize_verify_page(
+		// Number of backings in the page.
+		b: Linear<0, { T::VoterSnapshotPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		set_up_snapshot::<T>()?;
+		CurrentPhase::<T>::put(Phase::Signed);
+		let who = registered_submitter::<T>(0, score_of(1));
+		assert_ok!(Pallet::<T>::submit_page(
+			RawOrigin::Signed(who).into(),
+			0,
+			Some(Box::new(supports_of_page::<T>(0, b)))
+		));
+		CurrentPhase::<T>::put(Phase::SignedValidation);
+
+		#[block]
+		{
+			Pallet::<T>::verify_next_page();
+		}
+
+		assert!(QueuedSolutionPages::<T>::get(0).is_some() || T::Pages::get() == 1);
+		Ok(())
+	}
+
+	// A call to `<Pallet as ElectionProvider>::elect` where we only return the queued solution.
+	#[benchmark]
+	fn elect_queued(
+		// Number of voters backing the winners, over all pages.
+		v: Linear<1, { T::VoterSnapshotPerBlock::get() * T::Pages::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let winner: T::AccountId = account("winner", 0, SEED);
+		for page in 0..T::Pages::get() {
+			let voters = (page * T::VoterSnapshotPerBlock::get()..v)
+				.take(T::VoterSnapshotPerBlock::get() as usize)
+				.map(|i| (account("voter", i, SEED), 1000))
+				.collect::<Vec<_>>();
+			let total = voters.len() as ExtendedBalance * 1000;
+			let support = PageSupport::<T> { total, voters: BoundedVec::truncate_from(voters) };
+			QueuedSolutionPages::<T>::insert(
+				page,
+				BoundedVec::truncate_from(vec![(winner.clone(), support)]),
+			);
+		}
+		QueuedScore::<T>::put(score_of(1));
+		CurrentPhase::<T>::put(Phase::Export);
+
+		#[block]
+		{
+			assert_ok!(<Pallet<T> as ElectionProvider>::elect());
+		}
+
+		assert!(CurrentPhase::<T>::get().is_off());
+		assert!(QueuedScore::<T>::get().is_none());
+		Ok(())
+	}
+
+	// A call to `<Pallet as ElectionProvider>::elect_paged` for a page that does not finalize the
+	// election.
+	#[benchmark]
+	fn elect_queued_page(
+		b: Linear<0, { T::MaxWinners::get() * T::VoterSnapshotPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let page = T::Pages::get().saturating_sub(1).max(1);
+		QueuedSolutionPages::<T>::insert(page, supports_with_backings::<T>(b));
+		QueuedScore::<T>::put(score_of(1));
+		CurrentPhase::<T>::put(Phase::Export);
+
+		let supports;
+		#[block]
+		{
+			supports = <Pallet<T> as ElectionProvider>::elect_paged(page);
+		}
+
+		assert_eq!(supports.unwrap().iter().map(|(_, s)| s.voters.len() as u32).sum::<u32>(), b);
+		assert!(QueuedScore::<T>::get().is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register() {
+		CurrentPhase::<T>::put(Phase::Signed);
+		// fill the queue, so that the worst submission is discarded.
+		for i in 0..T::MaxSubmissions::get() {
+			registered_submitter::<T>(i, score_of(i + 1));
+		}
+		let who: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+		let score = score_of(T::MaxSubmissions::get() + 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), score);
+
+		assert!(Submissions::<T>::contains_key(&who));
+		assert_eq!(SortedScores::<T>::get().len() as u32, T::MaxSubmissions::get());
+	}
+
+	#[benchmark]
+	fn submit_page(
+		// Number of backings in the page.
+		b: Linear<0, { T::MaxWinners::get() * T::VoterSnapshotPerBlock::get() }>,
+	) -> Result<(), BenchmarkError> {
+		CurrentPhase::<T>::put(Phase::Signed);
+		let who = registered_submitter::<T>(0, score_of(1));
+		let supports = supports_with_backings::<T>(b);
+		assert_eq!(Pallet::<T>::backings_of(&supports), b);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), 0, Some(Box::new(supports)));
+
+		assert!(SubmissionPages::<T>::contains_key(&who, 0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn bail() -> Result<(), BenchmarkError> {
+		set_up_snapshot::<T>()?;
+		CurrentPhase::<T>::put(Phase::Signed);
+		let who = registered_submitter::<T>(0, score_of(1));
+		for page in 0..T::Pages::get() {
+			assert_ok!(Pallet::<T>::submit_page(
+				RawOrigin::Signed(who.clone()).into(),
+				page,
+				Some(Box::new(supports_of_page::<T>(page, T::VoterSnapshotPerBlock::get())))
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()));
+
+		assert!(!Submissions::<T>::contains_key(&who));
+		assert_eq!(SubmissionPages::<T>::iter_prefix(&who).count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_minimum_score() -> Result<(), BenchmarkError> {
+		let score = score_of(1);
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(score));
+
+		assert_eq!(MinimumScore::<T>::get(), Some(score));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, mock::ExtBuilder::default().build(), mock::Runtime);
+}
@@ -0,0 +1,1081 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Multi-block election provider pallet.
+//!
+//! A paged variant of `pallet-election-provider-multi-phase`. Instead of fitting the snapshot
+//! creation, the submission and the verification of a solution into single blocks, all of them
+//! are split in [`Config::Pages`] pages, each of which is processed in its own block. This allows
+//! elections over many more voters than what a single block can handle.
+//!
+//! ## Phases
+//!
+//! At each block, [`ElectionDataProvider::next_election_prediction`] is used to estimate the time
+//! remaining to the next call to [`ElectionProvider::elect`]. Based on this, a [`Phase`] is chosen.
+//!
+//! ```ignore
+//!                                                                                      elect()
+//!         +  <--Pages + 1-->  +  <--T::SignedPhase-->  +  <--T::SignedValidationPhase-->  +
+//!   +----------------------------------------------------------------------------------------+
+//!    Off  +  Phase::Snapshot  +     Phase::Signed      +     Phase::SignedValidation      +
+//! ```
+//!
+//! ### Snapshot
+//!
+//! In the first block of the snapshot phase, the targets and the desired number of targets are
+//! fetched from [`Config::DataProvider`]. In each of the following blocks, one page of at most
+//! [`Config::VoterSnapshotPerBlock`] voters is fetched through
+//! [`ElectionDataProvider::electing_voters_paged`], until [`Config::Pages`] pages are created or
+//! the data provider runs out of voters. The voters are locked with
+//! [`ElectionDataProvider::lock_voters`] meanwhile, so that none of them is fetched twice.
+//!
+//! ### Signed
+//!
+//! Submitters first [`Pallet::register`] the score they claim for their solution, reserving
+//! [`Config::DepositBase`]. Up to [`Config::MaxSubmissions`] registrations are kept, sorted by
+//! claimed score, and a better registration evicts the worst one when the queue is full. The pages
+//! of the solution are then submitted with [`Pallet::submit_page`], each reserving
+//! [`Config::DepositPerPage`]. Page `i` of a solution contains the supports that the voters of page
+//! `i` of the snapshot give to the winners. Pages that are never submitted are considered empty.
+//!
+//! A submitter can withdraw their registration with [`Pallet::bail`], losing the base deposit.
+//!
+//! ### Signed validation
+//!
+//! The registered solution with the best claimed score is verified, one page per block. Each page
+//! is checked against the corresponding page of the snapshot, and the backings of the winners are
+//! accumulated across pages. Once the last page is verified, the number of winners and the score
+//! computed from the accumulated backings are checked against the desired targets and the claimed
+//! score.
+//!
+//! If the solution is valid, it is queued, its submitter gets their deposit back as well as
+//! [`Config::RewardBase`], and the pallet waits for the election to be exported. Otherwise, the
+//! deposit of the submitter is slashed and the next best registered solution is verified.
+//!
+//! ### Export
+//!
+//! Upon [`ElectionProvider::elect`], the pages of the queued solution are merged into the supports
+//! of the winners. Alternatively, [`ElectionProvider::elect_paged`] exports the queued solution one
+//! page at a time, from page `Config::Pages - 1` down to page `0`, so that the caller does not have
+//! to process all of it in a single block. If no solution was queued, [`Config::Fallback`] is used
+//! instead, and its whole result is exported as page `0`. In all cases, once the election is
+//! finalized, the submissions that were never verified get their deposit back, and a new round
+//! starts.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+	boxed::Box,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec,
+	vec::Vec,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_election_provider_support::{
+	bounds::CountBound, data_provider, BoundedSupportsOf, DataProviderBounds, ElectionDataProvider,
+	ElectionProvider, ElectionProviderBase, InstantElectionProvider, PageIndex,
+};
+use frame_support::{
+	dispatch::DispatchClass,
+	ensure,
+	traits::{Currency, Get, OnUnbalanced, ReservableCurrency},
+	weights::Weight,
+	BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_npos_elections::{ElectionScore, EvaluateSupport, ExtendedBalance, Support, Supports};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::multiblock-election";
+
+// syntactic sugar for logging.
+#[macro_export]
+macro_rules! log {
+	($level:tt, $pattern:expr $(, $values:expr)* $(,)?) => {
+		log::$level!(
+			target: $crate::LOG_TARGET,
+			concat!("[#{:?}] 🗳📄  ", $pattern), frame_system::Pallet::<T>::block_number() $(, $values)*
+		)
+	};
+}
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::PositiveImbalance;
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+/// The fallback election type.
+pub type FallbackErrorOf<T> = <<T as Config>::Fallback as ElectionProviderBase>::Error;
+
+/// A voter, as provided by [`Config::DataProvider`].
+pub type VoterOf<T> = frame_election_provider_support::VoterOf<<T as Config>::DataProvider>;
+
+/// A page of the voter snapshot.
+pub type VoterPageOf<T> = BoundedVec<VoterOf<T>, <T as Config>::VoterSnapshotPerBlock>;
+
+/// The target snapshot.
+pub type TargetSnapshotOf<T> =
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::TargetSnapshotPerBlock>;
+
+/// A page of a solution: the supports that the voters of one page of the snapshot give to the
+/// winners.
+pub type SupportsPageOf<T> =
+	BoundedVec<(<T as frame_system::Config>::AccountId, PageSupport<T>), <T as Config>::MaxWinners>;
+
+/// The support that the voters of one page of the snapshot give to a winner.
+///
+/// Unlike [`Support`], the backing voters are bounded by the size of a page of the snapshot.
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	DefaultNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: Config))]
+pub struct PageSupport<T: Config> {
+	/// Total support.
+	pub total: ExtendedBalance,
+	/// Support from the voters of the page.
+	pub voters: BoundedVec<(T::AccountId, ExtendedBalance), T::VoterSnapshotPerBlock>,
+}
+
+impl<T: Config> PageSupport<T> {
+	/// Convert into an unbounded [`Support`].
+	pub fn into_support(self) -> Support<T::AccountId> {
+		Support { total: self.total, voters: self.voters.into_inner() }
+	}
+}
+
+/// Current phase of the pallet.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, MaxEncodedLen, Debug, TypeInfo, Default)]
+pub enum Phase {
+	/// Nothing, the election is not happening.
+	#[default]
+	Off,
+	/// The snapshot is being created. The inner value is the number of voter pages that are yet
+	/// to be fetched.
+	Snapshot(PageIndex),
+	/// Signed phase is open.
+	Signed,
+	/// The registered solutions are being verified, one page per block.
+	SignedValidation,
+	/// Nothing is left to do but to export the election result through
+	/// [`ElectionProvider::elect`].
+	Export,
+}
+
+impl Phase {
+	/// Whether the phase is off or not.
+	pub fn is_off(&self) -> bool {
+		matches!(self, Phase::Off)
+	}
+
+	/// Whether the phase is signed or not.
+	pub fn is_signed(&self) -> bool {
+		matches!(self, Phase::Signed)
+	}
+}
+
+/// The type of `Computation` that provided this election data.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, Debug, TypeInfo)]
+pub enum ElectionCompute {
+	/// Election was computed with a signed submission.
+	Signed,
+	/// Election was computed with the fallback.
+	Fallback,
+}
+
+/// The metadata of a registered signed submission.
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound(T: Config))]
+pub struct SubmissionMetadata<T: Config> {
+	/// The total deposit reserved for this submission.
+	pub deposit: BalanceOf<T>,
+	/// The score that the submitter claims for their solution.
+	pub claimed_score: ElectionScore,
+	/// Which pages of the solution were submitted.
+	pub pages: BoundedVec<bool, T::Pages>,
+}
+
+/// Errors that can happen in the feasibility check of a page of a solution.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub enum FeasibilityError {
+	/// The snapshot is not available.
+	SnapshotUnavailable,
+	/// A winner is not part of the target snapshot.
+	InvalidWinner,
+	/// A voter is not part of the corresponding page of the voter snapshot.
+	InvalidVoter,
+	/// A voter backs a winner that they did not vote for.
+	InvalidVote,
+	/// The total of a support is not the sum of its backings.
+	InvalidSupport,
+	/// A voter backs winners with more than their stake.
+	InvalidBacking,
+	/// The solution does not elect the desired number of targets.
+	WrongWinnerCount,
+	/// The claimed score does not match the score of the solution.
+	InvalidScore,
+	/// The score of the solution is below the minimum score.
+	ScoreTooLow,
+}
+
+/// Internal errors of the pallet, that can happen upon [`ElectionProvider::elect`].
+#[derive(frame_support::DebugNoBound)]
+pub enum ElectionError<T: Config> {
+	/// An error happened in the fallback.
+	Fallback(FallbackErrorOf<T>),
+	/// The queued solution elects more than [`Config::MaxWinners`] winners.
+	TooManyWinners,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency type.
+		type Currency: ReservableCurrency<Self::AccountId> + Currency<Self::AccountId>;
+
+		/// The number of pages of the voter snapshot, and thus of a solution.
+		#[pallet::constant]
+		type Pages: Get<PageIndex>;
+
+		/// The maximum number of voters in a page of the snapshot.
+		#[pallet::constant]
+		type VoterSnapshotPerBlock: Get<u32>;
+
+		/// The maximum number of targets in the snapshot.
+		///
+		/// All targets are fetched in a single block.
+		#[pallet::constant]
+		type TargetSnapshotPerBlock: Get<u32>;
+
+		/// The maximum number of winners that can be elected by this `ElectionProvider`
+		/// implementation.
+		///
+		/// Note: This must always be greater or equal to `T::DataProvider::desired_targets()`.
+		#[pallet::constant]
+		type MaxWinners: Get<u32>;
+
+		/// Duration of the signed phase.
+		type SignedPhase: Get<BlockNumberFor<Self>>;
+
+		/// Duration of the signed validation phase.
+		///
+		/// Verifying a solution takes [`Config::Pages`] blocks, so this should be a multiple of
+		/// it. The data provider may start fetching the pages of the result `Pages - 1` blocks
+		/// before the election, which ends the verification, so this must be at least
+		/// `2 * Pages - 1` for the verification of the best solution to complete.
+		type SignedValidationPhase: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of signed submissions that can be registered.
+		#[pallet::constant]
+		type MaxSubmissions: Get<u32>;
+
+		/// Deposit reserved upon registering a submission.
+		#[pallet::constant]
+		type DepositBase: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved for each page of a submission.
+		#[pallet::constant]
+		type DepositPerPage: Get<BalanceOf<Self>>;
+
+		/// Base reward for a valid signed solution.
+		#[pallet::constant]
+		type RewardBase: Get<BalanceOf<Self>>;
+
+		/// Handler for the slashed deposits.
+		type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Handler for the rewards.
+		type RewardHandler: OnUnbalanced<PositiveImbalanceOf<Self>>;
+
+		/// Something that will provide the election data.
+		type DataProvider: ElectionDataProvider<
+			AccountId = Self::AccountId,
+			BlockNumber = BlockNumberFor<Self>,
+		>;
+
+		/// Configuration for the fallback.
+		type Fallback: InstantElectionProvider<
+			AccountId = Self::AccountId,
+			BlockNumber = BlockNumberFor<Self>,
+			DataProvider = Self::DataProvider,
+			MaxWinners = Self::MaxWinners,
+		>;
+
+		/// Origin that can set the minimum score of signed solutions.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The weight of the pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let next_election = T::DataProvider::next_election_prediction(now).max(now);
+			let remaining = next_election - now;
+
+			let validation_deadline = T::SignedValidationPhase::get();
+			let signed_deadline = T::SignedPhase::get().saturating_add(validation_deadline);
+			let snapshot_deadline =
+				signed_deadline.saturating_add(T::Pages::get().saturating_add(1).into());
+
+			match CurrentPhase::<T>::get() {
+				Phase::Off if remaining <= snapshot_deadline && remaining > Zero::zero() =>
+					match Self::create_target_snapshot() {
+						Ok(()) => {
+							// keep the voters in place until all pages are fetched.
+							T::DataProvider::lock_voters();
+							Self::phase_transition(Phase::Snapshot(T::Pages::get()));
+							T::WeightInfo::on_initialize_snapshot_targets()
+						},
+						Err(why) => {
+							// Not much we can do about this at this point.
+							log!(warn, "failed to create the target snapshot due to {:?}", why);
+							T::WeightInfo::on_initialize_nothing()
+						},
+					},
+				Phase::Snapshot(remaining_pages) => {
+					let page = T::Pages::get().saturating_sub(remaining_pages);
+					match Self::create_voter_snapshot_page(page) {
+						Ok(count) => {
+							let remaining_pages = remaining_pages.saturating_sub(1);
+							if remaining_pages.is_zero() || count < T::VoterSnapshotPerBlock::get()
+							{
+								T::DataProvider::unlock_voters();
+								Self::phase_transition(Phase::Signed);
+							} else {
+								CurrentPhase::<T>::put(Phase::Snapshot(remaining_pages));
+							}
+						},
+						Err(why) => {
+							// Without a complete snapshot, only the fallback can be used.
+							log!(warn, "failed to create voter page {} due to {:?}", page, why);
+							T::DataProvider::unlock_voters();
+							Self::phase_transition(Phase::Export);
+						},
+					}
+					T::WeightInfo::on_initialize_snapshot_voters()
+				},
+				Phase::Signed if remaining <= validation_deadline => {
+					Self::phase_transition(Phase::SignedValidation);
+					T::WeightInfo::on_initialize_nothing()
+				},
+				Phase::SignedValidation => Self::verify_next_page(),
+				_ => T::WeightInfo::on_initialize_nothing(),
+			}
+		}
+
+		fn integrity_test() {
+			assert!(T::Pages::get() > 0, "a solution must have at least one page");
+			assert!(T::VoterSnapshotPerBlock::get() > 0, "a page must have at least one voter");
+			assert!(
+				T::SignedValidationPhase::get() >=
+					T::Pages::get().saturating_mul(2).saturating_sub(1).into(),
+				"a solution must be verified before its pages may be exported",
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a signed solution with the score that the origin claims for it.
+		///
+		/// The dispatch origin for this call must be __signed__, and the signed phase must be
+		/// open. [`Config::DepositBase`] is reserved.
+		///
+		/// If the queue of registered solutions is full, the worst registered solution is
+		/// discarded and its deposit returned, unless `claimed_score` is not better than it.
+		///
+		/// The pages of the solution must then be submitted with [`Pallet::submit_page`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register())]
+		pub fn register(origin: OriginFor<T>, claimed_score: ElectionScore) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(CurrentPhase::<T>::get().is_signed(), Error::<T>::PhaseNotSigned);
+			ensure!(!Submissions::<T>::contains_key(&who), Error::<T>::Duplicate);
+			ensure!(Self::is_above_minimum(claimed_score), Error::<T>::ScoreTooLow);
+
+			// The queue is sorted from the worst to the best claimed score. Among equal scores,
+			// earlier registrations come last, and are thus verified first.
+			let mut sorted = SortedScores::<T>::get().into_inner();
+			let index = sorted
+				.iter()
+				.position(|(_, score)| {
+					!claimed_score.strict_threshold_better(*score, Perbill::zero())
+				})
+				.unwrap_or(sorted.len());
+			sorted.insert(index, (who.clone(), claimed_score));
+
+			let maybe_discarded = if sorted.len() > T::MaxSubmissions::get() as usize {
+				let (discarded, _) = sorted.remove(0);
+				ensure!(discarded != who, Error::<T>::QueueFull);
+				Some(discarded)
+			} else {
+				None
+			};
+
+			let deposit = T::DepositBase::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			if let Some(discarded) = maybe_discarded {
+				Self::discard_submission(&discarded);
+			}
+
+			let pages = BoundedVec::truncate_from(vec![false; T::Pages::get() as usize]);
+			Submissions::<T>::insert(&who, SubmissionMetadata { deposit, claimed_score, pages });
+			SortedScores::<T>::put(BoundedVec::truncate_from(sorted));
+
+			Self::deposit_event(Event::Registered { round: Round::<T>::get(), who, claimed_score });
+			Ok(())
+		}
+
+		/// Submit, or remove if `maybe_supports` is `None`, a page of the registered solution of
+		/// the origin.
+		///
+		/// The dispatch origin for this call must be __signed__, and the signed phase must be
+		/// open. [`Config::DepositPerPage`] is reserved for each stored page, and returned when
+		/// the page is removed.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::submit_page(
+			maybe_supports.as_deref().map_or(0, Pallet::<T>::backings_of)
+		))]
+		pub fn submit_page(
+			origin: OriginFor<T>,
+			page: PageIndex,
+			maybe_supports: Option<Box<SupportsPageOf<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(CurrentPhase::<T>::get().is_signed(), Error::<T>::PhaseNotSigned);
+			ensure!(page < T::Pages::get(), Error::<T>::BadPageIndex);
+			let mut metadata = Submissions::<T>::get(&who).ok_or(Error::<T>::NotRegistered)?;
+
+			let submitted =
+				metadata.pages.get_mut(page as usize).ok_or(Error::<T>::BadPageIndex)?;
+			match (*submitted, maybe_supports.is_some()) {
+				(false, true) => {
+					T::Currency::reserve(&who, T::DepositPerPage::get())?;
+					metadata.deposit.saturating_accrue(T::DepositPerPage::get());
+				},
+				(true, false) => {
+					let _remaining = T::Currency::unreserve(&who, T::DepositPerPage::get());
+					debug_assert!(_remaining.is_zero());
+					metadata.deposit.saturating_reduce(T::DepositPerPage::get());
+				},
+				_ => {},
+			}
+			*submitted = maybe_supports.is_some();
+
+			match maybe_supports {
+				Some(supports) => SubmissionPages::<T>::insert(&who, page, *supports),
+				None => SubmissionPages::<T>::remove(&who, page),
+			}
+			Submissions::<T>::insert(&who, metadata);
+
+			Self::deposit_event(Event::Stored { round: Round::<T>::get(), who, page });
+			Ok(())
+		}
+
+		/// Withdraw the registered solution of the origin.
+		///
+		/// The dispatch origin for this call must be __signed__, and the signed phase must be
+		/// open. The deposit of the stored pages is returned, but [`Config::DepositBase`] is
+		/// slashed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::bail())]
+		pub fn bail(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(CurrentPhase::<T>::get().is_signed(), Error::<T>::PhaseNotSigned);
+			let metadata = Submissions::<T>::take(&who).ok_or(Error::<T>::NotRegistered)?;
+
+			SortedScores::<T>::mutate(|sorted| sorted.retain(|(submitter, _)| submitter != &who));
+			let _ = SubmissionPages::<T>::clear_prefix(&who, u32::MAX, None);
+
+			let slashed = T::DepositBase::get().min(metadata.deposit);
+			let _remaining = T::Currency::unreserve(&who, metadata.deposit.saturating_sub(slashed));
+			debug_assert!(_remaining.is_zero());
+			let (negative_imbalance, _remaining) = T::Currency::slash_reserved(&who, slashed);
+			debug_assert!(_remaining.is_zero());
+			T::SlashHandler::on_unbalanced(negative_imbalance);
+
+			Self::deposit_event(Event::Bailed { round: Round::<T>::get(), who });
+			Ok(())
+		}
+
+		/// Set a new value for `MinimumScore`.
+		///
+		/// Dispatch origin must be aligned with `T::ForceOrigin`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_minimum_score())]
+		pub fn set_minimum_score(
+			origin: OriginFor<T>,
+			maybe_score: Option<ElectionScore>,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			MinimumScore::<T>::set(maybe_score);
+			Ok(())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The election has entered a new phase.
+		PhaseTransitioned { from: Phase, to: Phase, round: u32 },
+		/// A signed solution was registered with the given claimed score.
+		Registered { round: u32, who: T::AccountId, claimed_score: ElectionScore },
+		/// A page of a registered solution was stored or removed.
+		Stored { round: u32, who: T::AccountId, page: PageIndex },
+		/// A registered solution was withdrawn by its submitter.
+		Bailed { round: u32, who: T::AccountId },
+		/// A registered solution was discarded without being verified, and its deposit returned.
+		Discarded { round: u32, who: T::AccountId },
+		/// All pages of a signed solution were verified, and it was queued.
+		Verified { round: u32, who: T::AccountId, score: ElectionScore },
+		/// A page of a signed solution failed verification, and the solution was rejected.
+		Rejected { round: u32, who: T::AccountId, page: PageIndex, error: FeasibilityError },
+		/// The submitter of a valid solution was rewarded.
+		Rewarded { round: u32, who: T::AccountId, amount: BalanceOf<T> },
+		/// The deposit of an invalid solution was slashed.
+		Slashed { round: u32, who: T::AccountId, amount: BalanceOf<T> },
+		/// The election has been finalized, with the given computation and score.
+		ElectionFinalized { compute: ElectionCompute, score: ElectionScore },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Submission was made outside of the signed phase.
+		PhaseNotSigned,
+		/// The origin has already registered a solution.
+		Duplicate,
+		/// The origin has not registered a solution.
+		NotRegistered,
+		/// The queue of registered solutions is full, and the claimed score is not better than
+		/// the worst one.
+		QueueFull,
+		/// The claimed score is below the minimum score.
+		ScoreTooLow,
+		/// The page index is out of bounds.
+		BadPageIndex,
+	}
+
+	/// Internal counter for the number of rounds.
+	///
+	/// This is useful for de-duplication of transactions submitted to the pool, and general
+	/// diagnostics of the pallet.
+	#[pallet::storage]
+	pub type Round<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Current phase.
+	#[pallet::storage]
+	pub type CurrentPhase<T: Config> = StorageValue<_, Phase, ValueQuery>;
+
+	/// Desired number of targets to elect for this round.
+	#[pallet::storage]
+	pub type DesiredTargets<T> = StorageValue<_, u32>;
+
+	/// The target snapshot of this round.
+	#[pallet::storage]
+	pub type TargetSnapshot<T: Config> = StorageValue<_, TargetSnapshotOf<T>>;
+
+	/// The pages of the voter snapshot of this round.
+	#[pallet::storage]
+	pub type VoterSnapshot<T: Config> = StorageMap<_, Twox64Concat, PageIndex, VoterPageOf<T>>;
+
+	/// The last voter of the voter snapshot so far, from which the next page is fetched.
+	#[pallet::storage]
+	pub type SnapshotCursor<T: Config> = StorageValue<_, T::AccountId>;
+
+	/// The minimum score that each signed solution must attain in order to be considered
+	/// feasible.
+	///
+	/// Can be set via `set_minimum_score`.
+	#[pallet::storage]
+	pub type MinimumScore<T: Config> = StorageValue<_, ElectionScore>;
+
+	/// The accounts that registered a solution in this round and their claimed score, sorted
+	/// from the worst to the best score.
+	#[pallet::storage]
+	pub type SortedScores<T: Config> =
+		StorageValue<_, BoundedVec<(T::AccountId, ElectionScore), T::MaxSubmissions>, ValueQuery>;
+
+	/// The metadata of the registered solutions of this round.
+	#[pallet::storage]
+	pub type Submissions<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SubmissionMetadata<T>>;
+
+	/// The pages of the registered solutions of this round.
+	#[pallet::storage]
+	pub type SubmissionPages<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, PageIndex, SupportsPageOf<T>>;
+
+	/// The next page of the best registered solution to verify, if its verification started.
+	#[pallet::storage]
+	pub type VerifyingPage<T: Config> = StorageValue<_, PageIndex>;
+
+	/// The total backing of each winner over the verified pages of the solution being verified,
+	/// or of the queued solution.
+	#[pallet::storage]
+	pub type QueuedBackings<T: Config> =
+		StorageValue<_, BoundedVec<(T::AccountId, ExtendedBalance), T::MaxWinners>, ValueQuery>;
+
+	/// The verified pages of the solution being verified, or of the queued solution.
+	#[pallet::storage]
+	pub type QueuedSolutionPages<T: Config> =
+		StorageMap<_, Twox64Concat, PageIndex, SupportsPageOf<T>>;
+
+	/// The score of the queued solution, present once all of its pages are verified.
+	#[pallet::storage]
+	pub type QueuedScore<T: Config> = StorageValue<_, ElectionScore>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+}
+
+impl<T: Config> Pallet<T> {
+	/// Transition to the given phase, emitting an event.
+	pub(crate) fn phase_transition(to: Phase) {
+		log!(info, "Starting phase {:?}, round {}.", to, Round::<T>::get());
+		Self::deposit_event(Event::PhaseTransitioned {
+			from: CurrentPhase::<T>::get(),
+			to,
+			round: Round::<T>::get(),
+		});
+		CurrentPhase::<T>::put(to);
+	}
+
+	/// Fetch the targets and the desired number of targets of this round.
+	pub(crate) fn create_target_snapshot() -> data_provider::Result<()> {
+		let desired_targets = Self::desired_targets_checked()?;
+		let bounds = DataProviderBounds {
+			count: Some(CountBound(T::TargetSnapshotPerBlock::get())),
+			size: None,
+		};
+		let targets = TargetSnapshotOf::<T>::try_from(T::DataProvider::electable_targets(bounds)?)
+			.map_err(|_| "the data provider returned too many targets")?;
+
+		log!(info, "created a target snapshot with {} targets", targets.len());
+		TargetSnapshot::<T>::put(targets);
+		DesiredTargets::<T>::put(desired_targets);
+		Ok(())
+	}
+
+	/// Fetch the given page of the voter snapshot, continuing after the last voter fetched so far.
+	///
+	/// Returns the number of voters in the page.
+	pub(crate) fn create_voter_snapshot_page(page: PageIndex) -> data_provider::Result<u32> {
+		let bounds = DataProviderBounds {
+			count: Some(CountBound(T::VoterSnapshotPerBlock::get())),
+			size: None,
+		};
+		let cursor = SnapshotCursor::<T>::get();
+		let voters = VoterPageOf::<T>::try_from(T::DataProvider::electing_voters_paged(
+			bounds,
+			cursor.as_ref(),
+		)?)
+		.map_err(|_| "the data provider returned too many voters")?;
+
+		if let Some((last, _, _)) = voters.last() {
+			SnapshotCursor::<T>::put(last);
+		}
+		let count = voters.len() as u32;
+		log!(info, "created voter page {} with {} voters", page, count);
+		VoterSnapshot::<T>::insert(page, voters);
+		Ok(count)
+	}
+
+	/// Verify the next page of the best registered solution, if any.
+	///
+	/// Once all pages of a solution are verified, or as soon as one of them is invalid, the
+	/// solution is respectively accepted or rejected.
+	pub(crate) fn verify_next_page() -> Weight {
+		let Some((who, claimed_score)) = SortedScores::<T>::get().last().cloned() else {
+			// nothing left to verify.
+			Self::phase_transition(Phase::Export);
+			return T::WeightInfo::on_initialize_nothing()
+		};
+
+		let page = VerifyingPage::<T>::get().unwrap_or_default();
+		let supports = SubmissionPages::<T>::take(&who, page).unwrap_or_default();
+		let backings = Self::backings_of(&supports);
+		let outcome = Self::feasibility_check_page(page, supports).and_then(|_| {
+			if page.saturating_add(1) < T::Pages::get() {
+				Ok(None)
+			} else {
+				Self::finalize_verification(claimed_score).map(Some)
+			}
+		});
+
+		match outcome {
+			Ok(None) => VerifyingPage::<T>::put(page.saturating_add(1)),
+			Ok(Some(score)) => Self::accept_submission(who, score),
+			Err(error) => Self::reject_submission(who, page, error),
+		}
+		T::WeightInfo::on_initialize_verify_page(backings)
+	}
+
+	/// The number of backings in a page of a solution, over all of its winners.
+	pub(crate) fn backings_of(supports: &SupportsPageOf<T>) -> u32 {
+		supports.iter().map(|(_, support)| support.voters.len() as u32).sum()
+	}
+
+	/// Check the feasibility of `supports`, as the given `page` of a solution, against the
+	/// corresponding page of the snapshot.
+	///
+	/// If feasible, the page is queued and the backings of its winners are added to
+	/// [`QueuedBackings`].
+	pub fn feasibility_check_page(
+		page: PageIndex,
+		supports: SupportsPageOf<T>,
+	) -> Result<(), FeasibilityError> {
+		let desired_targets =
+			DesiredTargets::<T>::get().ok_or(FeasibilityError::SnapshotUnavailable)?;
+		let targets = TargetSnapshot::<T>::get()
+			.ok_or(FeasibilityError::SnapshotUnavailable)?
+			.into_iter()
+			.collect::<BTreeSet<_>>();
+		let voters = VoterSnapshot::<T>::get(page)
+			.unwrap_or_default()
+			.into_iter()
+			.map(|(who, stake, votes)| (who, (ExtendedBalance::from(stake), votes)))
+			.collect::<BTreeMap<_, _>>();
+
+		let mut backings = QueuedBackings::<T>::get().into_iter().collect::<BTreeMap<_, _>>();
+		let mut used_stakes = BTreeMap::<T::AccountId, ExtendedBalance>::new();
+
+		for (winner, support) in supports.iter() {
+			ensure!(targets.contains(winner), FeasibilityError::InvalidWinner);
+
+			let mut total: ExtendedBalance = Zero::zero();
+			for (voter, backing) in support.voters.iter() {
+				let (_, votes) = voters.get(voter).ok_or(FeasibilityError::InvalidVoter)?;
+				ensure!(votes.contains(winner), FeasibilityError::InvalidVote);
+				used_stakes.entry(voter.clone()).or_default().saturating_accrue(*backing);
+				total.saturating_accrue(*backing);
+			}
+			ensure!(total == support.total, FeasibilityError::InvalidSupport);
+			backings.entry(winner.clone()).or_default().saturating_accrue(total);
+		}
+
+		for (voter, used) in used_stakes {
+			let stake = voters.get(&voter).map(|(stake, _)| *stake).unwrap_or_default();
+			ensure!(used <= stake, FeasibilityError::InvalidBacking);
+		}
+
+		ensure!(backings.len() as u32 <= desired_targets, FeasibilityError::WrongWinnerCount);
+		let backings =
+			BoundedVec::<_, T::MaxWinners>::try_from(backings.into_iter().collect::<Vec<_>>())
+				.map_err(|_| FeasibilityError::WrongWinnerCount)?;
+
+		QueuedBackings::<T>::put(backings);
+		QueuedSolutionPages::<T>::insert(page, supports);
+		Ok(())
+	}
+
+	/// Check the accumulated backings of all pages of a solution against the desired targets and
+	/// the `claimed_score`.
+	///
+	/// Returns the score of the solution.
+	fn finalize_verification(
+		claimed_score: ElectionScore,
+	) -> Result<ElectionScore, FeasibilityError> {
+		let desired_targets =
+			DesiredTargets::<T>::get().ok_or(FeasibilityError::SnapshotUnavailable)?;
+		let backings = QueuedBackings::<T>::get();
+		ensure!(backings.len() as u32 == desired_targets, FeasibilityError::WrongWinnerCount);
+
+		let score = backings
+			.into_iter()
+			.map(|(winner, total)| (winner, Support { total, voters: Vec::new() }))
+			.collect::<Supports<_>>()
+			.evaluate();
+		ensure!(score == claimed_score, FeasibilityError::InvalidScore);
+		ensure!(Self::is_above_minimum(score), FeasibilityError::ScoreTooLow);
+		Ok(score)
+	}
+
+	/// Queue the verified solution of `who`, and reward them.
+	fn accept_submission(who: T::AccountId, score: ElectionScore) {
+		let round = Round::<T>::get();
+		SortedScores::<T>::mutate(|sorted| sorted.pop());
+		VerifyingPage::<T>::kill();
+		QueuedScore::<T>::put(score);
+
+		if let Some(metadata) = Submissions::<T>::take(&who) {
+			let _remaining = T::Currency::unreserve(&who, metadata.deposit);
+			debug_assert!(_remaining.is_zero());
+		}
+		let reward = T::RewardBase::get();
+		let positive_imbalance = T::Currency::deposit_creating(&who, reward);
+		T::RewardHandler::on_unbalanced(positive_imbalance);
+
+		Self::deposit_event(Event::Verified { round, who: who.clone(), score });
+		Self::deposit_event(Event::Rewarded { round, who, amount: reward });
+		Self::phase_transition(Phase::Export);
+	}
+
+	/// Reject the solution of `who`, which failed verification at the given `page`, and slash
+	/// their deposit.
+	fn reject_submission(who: T::AccountId, page: PageIndex, error: FeasibilityError) {
+		let round = Round::<T>::get();
+		log!(warn, "solution of {:?} rejected at page {} due to {:?}", who, page, error);
+		SortedScores::<T>::mutate(|sorted| sorted.pop());
+		VerifyingPage::<T>::kill();
+		Self::clear_queued_solution();
+		let _ = SubmissionPages::<T>::clear_prefix(&who, u32::MAX, None);
+
+		let deposit = Submissions::<T>::take(&who).map(|m| m.deposit).unwrap_or_default();
+		let (negative_imbalance, _remaining) = T::Currency::slash_reserved(&who, deposit);
+		debug_assert!(_remaining.is_zero());
+		T::SlashHandler::on_unbalanced(negative_imbalance);
+
+		Self::deposit_event(Event::Rejected { round, who: who.clone(), page, error });
+		Self::deposit_event(Event::Slashed { round, who, amount: deposit });
+	}
+
+	/// Remove the solution of `who`, returning their deposit.
+	fn discard_submission(who: &T::AccountId) {
+		let _ = SubmissionPages::<T>::clear_prefix(who, u32::MAX, None);
+		if let Some(metadata) = Submissions::<T>::take(who) {
+			let _remaining = T::Currency::unreserve(who, metadata.deposit);
+			debug_assert!(_remaining.is_zero());
+		}
+		Self::deposit_event(Event::Discarded { round: Round::<T>::get(), who: who.clone() });
+	}
+
+	/// Remove the queued solution, or the pages verified so far.
+	fn clear_queued_solution() {
+		let _ = QueuedSolutionPages::<T>::clear(u32::MAX, None);
+		QueuedBackings::<T>::kill();
+		QueuedScore::<T>::kill();
+	}
+
+	/// Whether `score` is above the minimum score, if any.
+	fn is_above_minimum(score: ElectionScore) -> bool {
+		MinimumScore::<T>::get()
+			.map_or(true, |min| score.strict_threshold_better(min, Perbill::zero()))
+	}
+
+	/// Merge the pages of the queued solution into the supports of its winners.
+	fn export_queued_solution() -> Result<BoundedSupportsOf<Self>, ElectionError<T>> {
+		let mut supports = BTreeMap::<T::AccountId, Support<T::AccountId>>::new();
+		let mut voters = 0u32;
+		for page in 0..T::Pages::get() {
+			for (winner, support) in QueuedSolutionPages::<T>::take(page).unwrap_or_default() {
+				voters.saturating_accrue(support.voters.len() as u32);
+				let merged = supports.entry(winner).or_default();
+				merged.total.saturating_accrue(support.total);
+				merged.voters.extend(support.voters.into_inner());
+			}
+		}
+		Self::register_weight(T::WeightInfo::elect_queued(voters));
+
+		BoundedSupportsOf::<Self>::try_from(supports.into_iter().collect::<Vec<_>>())
+			.map_err(|_| ElectionError::TooManyWinners)
+	}
+
+	/// Export the given page of the queued solution on its own, registering the weight returned by
+	/// `weight_of` for the number of backings in the page.
+	fn export_queued_page(
+		page: PageIndex,
+		weight_of: fn(u32) -> Weight,
+	) -> Result<BoundedSupportsOf<Self>, ElectionError<T>> {
+		let supports = QueuedSolutionPages::<T>::take(page).unwrap_or_default();
+		Self::register_weight(weight_of(Self::backings_of(&supports)));
+
+		BoundedSupportsOf::<Self>::try_from(
+			supports
+				.into_iter()
+				.map(|(winner, support)| (winner, support.into_support()))
+				.collect::<Vec<_>>(),
+		)
+		.map_err(|_| ElectionError::TooManyWinners)
+	}
+
+	/// Compute the election with the fallback.
+	fn fallback_elect() -> Result<BoundedSupportsOf<Self>, ElectionError<T>> {
+		// default data provider bounds are unbounded. calling `instant_elect` with unbounded
+		// data provider bounds means that the on-chain `T:Bounds` configs will *not* be
+		// overwritten.
+		T::Fallback::instant_elect(DataProviderBounds::default(), DataProviderBounds::default())
+			.map_err(ElectionError::Fallback)
+	}
+
+	/// Return the unverified submissions, clear all the data of this round and start a new one.
+	fn rotate_round() {
+		for (who, _) in SortedScores::<T>::take() {
+			Self::discard_submission(&who);
+		}
+		Self::clear_queued_solution();
+		VerifyingPage::<T>::kill();
+
+		TargetSnapshot::<T>::kill();
+		let _ = VoterSnapshot::<T>::clear(u32::MAX, None);
+		SnapshotCursor::<T>::kill();
+		// in case the election happened before the voter snapshot was complete.
+		T::DataProvider::unlock_voters();
+		DesiredTargets::<T>::kill();
+
+		Round::<T>::mutate(|r| *r += 1);
+		Self::phase_transition(Phase::Off);
+	}
+
+	/// Register some amount of weight directly with the system pallet.
+	///
+	/// This is always mandatory weight.
+	fn register_weight(weight: Weight) {
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			weight,
+			DispatchClass::Mandatory,
+		);
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+impl<T: Config> Pallet<T> {
+	fn do_try_state() -> Result<(), TryRuntimeError> {
+		let sorted = SortedScores::<T>::get();
+		ensure!(
+			sorted.len() == Submissions::<T>::iter_keys().count(),
+			"every registered solution must be in the sorted queue"
+		);
+		for (who, score) in sorted.iter() {
+			let metadata =
+				Submissions::<T>::get(who).ok_or("sorted queue contains an unknown submitter")?;
+			ensure!(metadata.claimed_score == *score, "claimed score mismatch");
+		}
+		ensure!(
+			QueuedScore::<T>::get().is_none() || VerifyingPage::<T>::get().is_none(),
+			"a solution cannot be queued while another one is being verified"
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config> ElectionProviderBase for Pallet<T> {
+	type AccountId = T::AccountId;
+	type BlockNumber = BlockNumberFor<T>;
+	type Error = ElectionError<T>;
+	type MaxWinners = T::MaxWinners;
+	type DataProvider = T::DataProvider;
+}
+
+impl<T: Config> ElectionProvider for Pallet<T> {
+	fn ongoing() -> bool {
+		!CurrentPhase::<T>::get().is_off()
+	}
+
+	fn elect() -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		let result = match QueuedScore::<T>::get() {
+			Some(score) => Self::export_queued_solution()
+				.map(|supports| (supports, score, ElectionCompute::Signed)),
+			None => Self::fallback_elect()
+				.map(|supports| (supports, Default::default(), ElectionCompute::Fallback)),
+		};
+
+		Self::rotate_round();
+		result.map(|(supports, score, compute)| {
+			Self::deposit_event(Event::ElectionFinalized { compute, score });
+			supports
+		})
+	}
+
+	fn pages() -> PageIndex {
+		T::Pages::get()
+	}
+
+	fn elect_paged(page: PageIndex) -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		if !page.is_zero() {
+			// no other solution can be queued while the pages of this one are being exported.
+			if CurrentPhase::<T>::get() != Phase::Export {
+				Self::phase_transition(Phase::Export);
+			}
+			return match QueuedScore::<T>::get() {
+				Some(_) => Self::export_queued_page(page, T::WeightInfo::elect_queued_page),
+				// the fallback is only computed, all at once, as part of the last page.
+				None => Ok(Default::default()),
+			}
+		}
+
+		let result = match QueuedScore::<T>::get() {
+			Some(score) => Self::export_queued_page(page, T::WeightInfo::elect_queued)
+				.map(|supports| (supports, score, ElectionCompute::Signed)),
+			None => Self::fallback_elect()
+				.map(|supports| (supports, Default::default(), ElectionCompute::Fallback)),
+		};
+
+		Self::rotate_round();
+		result.map(|(supports, score, compute)| {
+			Self::deposit_event(Event::ElectionFinalized { compute, score });
+			supports
+		})
+	}
+}
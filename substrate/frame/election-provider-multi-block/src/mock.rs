@@ -0,0 +1,366 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{self as multi_block};
+use frame_election_provider_support::{
+	bounds::{ElectionBounds, ElectionBoundsBuilder},
+	onchain, SequentialPhragmen,
+};
+pub use frame_support::derive_impl;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Hooks},
+};
+use sp_runtime::{bounded_vec, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Balances: pallet_balances,
+		MultiBlock: multi_block,
+	}
+);
+
+pub(crate) type Balance = u64;
+pub(crate) type AccountId = u64;
+pub(crate) type BlockNumber = u64;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub static Targets: Vec<AccountId> = vec![10, 20, 30, 40];
+	pub static Voters: Vec<VoterOf<Runtime>> = vec![
+		(1, 10, bounded_vec![10, 20]),
+		(2, 10, bounded_vec![30, 40]),
+		(3, 10, bounded_vec![40]),
+		(4, 10, bounded_vec![10, 20, 30, 40]),
+		// self votes.
+		(10, 10, bounded_vec![10]),
+		(20, 20, bounded_vec![20]),
+		(30, 30, bounded_vec![30]),
+		(40, 40, bounded_vec![40]),
+	];
+
+	pub static VotersLocked: bool = false;
+	/// The voters removed while locked, with the position they had in `Voters`.
+	pub static RemovedVoters: Vec<(AccountId, usize)> = vec![];
+
+	pub static DesiredTargets: u32 = 2;
+	pub static EpochLength: BlockNumber = 30;
+	pub static Pages: PageIndex = 3;
+	pub static VoterSnapshotPerBlock: u32 = 3;
+	pub static SignedPhase: BlockNumber = 10;
+	pub static SignedValidationPhase: BlockNumber = 6;
+	pub static MaxSubmissions: u32 = 2;
+	pub static DepositBase: Balance = 5;
+	pub static DepositPerPage: Balance = 1;
+	pub static RewardBase: Balance = 7;
+	pub static OnChainFallback: bool = true;
+	pub static OnChainElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
+
+	#[derive(Debug)]
+	pub static MaxWinners: u32 = 4;
+}
+
+pub struct StakingMock;
+impl ElectionDataProvider for StakingMock {
+	type BlockNumber = BlockNumber;
+	type AccountId = AccountId;
+	type MaxVotesPerVoter = ConstU32<4>;
+
+	fn electable_targets(bounds: DataProviderBounds) -> data_provider::Result<Vec<AccountId>> {
+		let targets = Targets::get();
+		if bounds.count.map_or(false, |max_len| targets.len() > max_len.0 as usize) {
+			return Err("Targets too big")
+		}
+		Ok(targets)
+	}
+
+	fn electing_voters(bounds: DataProviderBounds) -> data_provider::Result<Vec<VoterOf<Runtime>>> {
+		Self::electing_voters_paged(bounds, None)
+	}
+
+	fn electing_voters_paged(
+		bounds: DataProviderBounds,
+		cursor: Option<&AccountId>,
+	) -> data_provider::Result<Vec<VoterOf<Runtime>>> {
+		let voters = Voters::get();
+		let start = match cursor {
+			None => 0,
+			Some(last) => match voters.iter().position(|(who, _, _)| who == last) {
+				Some(position) => position.saturating_add(1),
+				// the voters that came after a removed cursor have moved into its position.
+				None => RemovedVoters::get()
+					.into_iter()
+					.find_map(|(who, position)| (who == *last).then_some(position))
+					.ok_or("cursor is not a voter")?,
+			},
+		};
+		let count = bounds.count.map_or(voters.len(), |max_len| max_len.0 as usize);
+		Ok(voters.into_iter().skip(start).take(count).collect())
+	}
+
+	fn lock_voters() {
+		VotersLocked::set(true);
+	}
+
+	fn unlock_voters() {
+		VotersLocked::set(false);
+		RemovedVoters::set(vec![]);
+	}
+
+	fn desired_targets() -> data_provider::Result<u32> {
+		Ok(DesiredTargets::get())
+	}
+
+	fn next_election_prediction(now: BlockNumber) -> BlockNumber {
+		now + EpochLength::get() - now % EpochLength::get()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn put_snapshot(
+		voters: Vec<VoterOf<Runtime>>,
+		targets: Vec<AccountId>,
+		_target_stake: Option<sp_npos_elections::VoteWeight>,
+	) {
+		Targets::set(targets);
+		Voters::set(voters);
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn clear() {
+		Targets::set(vec![]);
+		Voters::set(vec![]);
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_voter(
+		voter: AccountId,
+		weight: sp_npos_elections::VoteWeight,
+		targets: BoundedVec<AccountId, Self::MaxVotesPerVoter>,
+	) {
+		let mut current = Voters::get();
+		current.push((voter, weight, targets));
+		Voters::set(current);
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_target(target: AccountId) {
+		let mut current = Targets::get();
+		current.push(target);
+		Targets::set(current);
+	}
+}
+
+/// Remove `who` from the voters, as the staking pallet does when a voter chills.
+pub fn remove_voter(who: AccountId) {
+	let mut voters = Voters::get();
+	if let Some(position) = voters.iter().position(|(voter, _, _)| *voter == who) {
+		voters.remove(position);
+		if VotersLocked::get() {
+			RemovedVoters::mutate(|removed| removed.push((who, position)));
+		}
+	}
+	Voters::set(voters);
+}
+
+pub struct OnChainSeqPhragmen;
+impl onchain::Config for OnChainSeqPhragmen {
+	type System = Runtime;
+	type Solver = SequentialPhragmen<AccountId, Perbill>;
+	type DataProvider = StakingMock;
+	type WeightInfo = ();
+	type MaxWinners = MaxWinners;
+	type Bounds = OnChainElectionsBounds;
+}
+
+pub struct MockFallback;
+impl ElectionProviderBase for MockFallback {
+	type BlockNumber = BlockNumber;
+	type AccountId = AccountId;
+	type Error = &'static str;
+	type DataProvider = StakingMock;
+	type MaxWinners = MaxWinners;
+}
+
+impl InstantElectionProvider for MockFallback {
+	fn instant_elect(
+		voters_bounds: DataProviderBounds,
+		targets_bounds: DataProviderBounds,
+	) -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		if OnChainFallback::get() {
+			onchain::OnChainExecution::<OnChainSeqPhragmen>::instant_elect(
+				voters_bounds,
+				targets_bounds,
+			)
+			.map_err(|_| "onchain::OnChainExecution failed.")
+		} else {
+			Err("NoFallback.")
+		}
+	}
+}
+
+impl crate::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Pages = Pages;
+	type VoterSnapshotPerBlock = VoterSnapshotPerBlock;
+	type TargetSnapshotPerBlock = ConstU32<8>;
+	type MaxWinners = MaxWinners;
+	type SignedPhase = SignedPhase;
+	type SignedValidationPhase = SignedValidationPhase;
+	type MaxSubmissions = MaxSubmissions;
+	type DepositBase = DepositBase;
+	type DepositPerPage = DepositPerPage;
+	type RewardBase = RewardBase;
+	type SlashHandler = ();
+	type RewardHandler = ();
+	type DataProvider = StakingMock;
+	type Fallback = MockFallback;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+/// All events of this pallet.
+pub(crate) fn multi_block_events() -> Vec<super::Event<Runtime>> {
+	System::read_events_for_pallet::<super::Event<Runtime>>()
+}
+
+/// To from `now` to block `n`.
+pub fn roll_to(n: BlockNumber) {
+	let now = System::block_number();
+	for i in now + 1..=n {
+		System::set_block_number(i);
+		MultiBlock::on_initialize(i);
+	}
+}
+
+pub fn roll_to_phase(phase: Phase) {
+	while CurrentPhase::<Runtime>::get() != phase {
+		roll_to(System::block_number() + 1);
+	}
+}
+
+/// A solution electing `winners`, in which each voter of the snapshot backs the first of their
+/// votes that is a winner with all of their stake.
+///
+/// Returns the pages of the solution and its score.
+pub fn solution_electing(winners: &[AccountId]) -> (Vec<SupportsPageOf<Runtime>>, ElectionScore) {
+	let mut backings = BTreeMap::<AccountId, ExtendedBalance>::new();
+	let pages = (0..Pages::get())
+		.map(|page| {
+			let mut supports = BTreeMap::<AccountId, PageSupport<Runtime>>::new();
+			for (voter, stake, votes) in VoterSnapshot::<Runtime>::get(page).unwrap_or_default() {
+				if let Some(winner) = votes.iter().find(|target| winners.contains(target)) {
+					let stake = ExtendedBalance::from(stake);
+					let support = supports.entry(*winner).or_default();
+					support.total += stake;
+					support.voters.try_push((voter, stake)).unwrap();
+					*backings.entry(*winner).or_default() += stake;
+				}
+			}
+			supports.into_iter().collect::<Vec<_>>().try_into().unwrap()
+		})
+		.collect::<Vec<_>>();
+
+	let score = backings
+		.into_iter()
+		.map(|(winner, total)| (winner, Support { total, voters: vec![] }))
+		.collect::<Supports<_>>()
+		.evaluate();
+	(pages, score)
+}
+
+/// Register and submit all the pages of the given solution as `who`.
+pub fn submit_solution(who: AccountId, pages: Vec<SupportsPageOf<Runtime>>, score: ElectionScore) {
+	frame_support::assert_ok!(MultiBlock::register(RuntimeOrigin::signed(who), score));
+	for (page, supports) in pages.into_iter().enumerate() {
+		frame_support::assert_ok!(MultiBlock::submit_page(
+			RuntimeOrigin::signed(who),
+			page as PageIndex,
+			Some(Box::new(supports))
+		));
+	}
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {}
+
+impl ExtBuilder {
+	pub fn pages(self, pages: PageIndex, voters_per_page: u32) -> Self {
+		Pages::set(pages);
+		VoterSnapshotPerBlock::set(voters_per_page);
+		self
+	}
+	pub fn max_submissions(self, count: u32) -> Self {
+		MaxSubmissions::set(count);
+		self
+	}
+	pub fn onchain_fallback(self, onchain: bool) -> Self {
+		OnChainFallback::set(onchain);
+		self
+	}
+	pub fn build(self) -> sp_io::TestExternalities {
+		sp_tracing::try_init_simple();
+		let mut storage =
+			frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+
+		let _ = pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![
+				// bunch of account for submitting stuff only.
+				(99, 100),
+				(100, 100),
+				(101, 100),
+				(102, 100),
+			],
+		}
+		.assimilate_storage(&mut storage);
+
+		sp_io::TestExternalities::from(storage)
+	}
+
+	pub fn build_and_execute(self, test: impl FnOnce() -> ()) {
+		let mut ext = self.build();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			test()
+		});
+
+		#[cfg(feature = "try-runtime")]
+		ext.execute_with(|| {
+			frame_support::assert_ok!(
+				<MultiBlock as Hooks<u64>>::try_state(System::block_number())
+			);
+		});
+	}
+}
+
+pub(crate) fn balances(who: &AccountId) -> (Balance, Balance) {
+	(Balances::free_balance(who), Balances::reserved_balance(who))
+}
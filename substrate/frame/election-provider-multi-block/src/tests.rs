@@ -0,0 +1,624 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo, traits::Hooks};
+use sp_runtime::{bounded_vec, DispatchError};
+
+/// The score of `solution_electing(&[30, 40])`.
+const SCORE_30_40: ElectionScore =
+	ElectionScore { minimal_stake: 50, sum_stake: 100, sum_stake_squared: 5000 };
+
+fn support(voters: Vec<(AccountId, ExtendedBalance)>) -> PageSupport<Runtime> {
+	PageSupport {
+		total: voters.iter().map(|(_, stake)| stake).sum(),
+		voters: voters.try_into().unwrap(),
+	}
+}
+
+#[test]
+fn phases_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		// the snapshot starts `SignedValidationPhase + SignedPhase + Pages + 1` blocks before the
+		// election at block 30.
+		roll_to(9);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Off);
+		assert!(!MultiBlock::ongoing());
+		assert!(!VotersLocked::get());
+
+		// the voters are locked while the voter snapshot is created.
+		roll_to(10);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Snapshot(3));
+		assert!(MultiBlock::ongoing());
+		assert!(VotersLocked::get());
+		assert_eq!(TargetSnapshot::<Runtime>::get().unwrap().into_inner(), vec![10, 20, 30, 40]);
+		assert_eq!(crate::DesiredTargets::<Runtime>::get(), Some(2));
+
+		roll_to(11);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Snapshot(2));
+		roll_to(12);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Snapshot(1));
+		assert!(VotersLocked::get());
+		roll_to(13);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Signed);
+		assert!(!VotersLocked::get());
+
+		let voters_of = |page| {
+			VoterSnapshot::<Runtime>::get(page)
+				.unwrap()
+				.into_iter()
+				.map(|(who, _, _)| who)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(voters_of(0), vec![1, 2, 3]);
+		assert_eq!(voters_of(1), vec![4, 10, 20]);
+		assert_eq!(voters_of(2), vec![30, 40]);
+		assert_eq!(SnapshotCursor::<Runtime>::get(), Some(40));
+
+		roll_to(23);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Signed);
+		roll_to(24);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::SignedValidation);
+
+		// nothing to verify.
+		roll_to(25);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Export);
+
+		assert_eq!(
+			multi_block_events(),
+			vec![
+				Event::PhaseTransitioned { from: Phase::Off, to: Phase::Snapshot(3), round: 0 },
+				Event::PhaseTransitioned { from: Phase::Snapshot(1), to: Phase::Signed, round: 0 },
+				Event::PhaseTransitioned {
+					from: Phase::Signed,
+					to: Phase::SignedValidation,
+					round: 0
+				},
+				Event::PhaseTransitioned {
+					from: Phase::SignedValidation,
+					to: Phase::Export,
+					round: 0
+				},
+			]
+		);
+	})
+}
+
+#[test]
+fn snapshot_is_limited_to_pages() {
+	ExtBuilder::default().pages(2, 3).build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+
+		assert_eq!(VoterSnapshot::<Runtime>::get(0).unwrap().len(), 3);
+		assert_eq!(VoterSnapshot::<Runtime>::get(1).unwrap().len(), 3);
+		assert!(VoterSnapshot::<Runtime>::get(2).is_none());
+		assert_eq!(SnapshotCursor::<Runtime>::get(), Some(20));
+	})
+}
+
+#[test]
+fn snapshot_stops_when_voters_run_out() {
+	ExtBuilder::default().pages(4, 4).build_and_execute(|| {
+		roll_to_phase(Phase::Snapshot(4));
+		roll_to(System::block_number() + 2);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Snapshot(2));
+
+		// the third page is empty, the fourth one is never fetched.
+		roll_to(System::block_number() + 1);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Signed);
+		assert!(VoterSnapshot::<Runtime>::get(2).unwrap().is_empty());
+		assert!(VoterSnapshot::<Runtime>::get(3).is_none());
+	})
+}
+
+#[test]
+fn signed_solution_is_verified_and_exported() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+
+		let (pages, score) = solution_electing(&[30, 40]);
+		assert_eq!(score, SCORE_30_40);
+		submit_solution(99, pages, score);
+		assert_eq!(balances(&99), (92, 8));
+
+		// one page is verified per block.
+		roll_to_phase(Phase::SignedValidation);
+		roll_to(System::block_number() + 2);
+		assert_eq!(VerifyingPage::<Runtime>::get(), Some(2));
+		assert!(QueuedScore::<Runtime>::get().is_none());
+
+		roll_to(System::block_number() + 1);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Export);
+		assert_eq!(QueuedScore::<Runtime>::get(), Some(score));
+		assert_eq!(balances(&99), (107, 0));
+		assert!(multi_block_events().contains(&Event::Verified { round: 0, who: 99, score }));
+		assert!(multi_block_events().contains(&Event::Rewarded { round: 0, who: 99, amount: 7 }));
+
+		// the pages are merged upon export.
+		let supports = MultiBlock::elect().unwrap();
+		assert_eq!(
+			supports.into_inner(),
+			vec![
+				(30, support(vec![(2, 10), (4, 10), (30, 30)]).into_support()),
+				(40, support(vec![(3, 10), (40, 40)]).into_support()),
+			]
+		);
+		assert_eq!(
+			multi_block_events().last(),
+			Some(&Event::ElectionFinalized { compute: ElectionCompute::Signed, score })
+		);
+
+		// everything is cleaned up for the next round.
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Off);
+		assert_eq!(Round::<Runtime>::get(), 1);
+		assert!(TargetSnapshot::<Runtime>::get().is_none());
+		assert_eq!(VoterSnapshot::<Runtime>::iter().count(), 0);
+		assert!(SnapshotCursor::<Runtime>::get().is_none());
+		assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 0);
+		assert!(QueuedBackings::<Runtime>::get().is_empty());
+		assert!(QueuedScore::<Runtime>::get().is_none());
+	})
+}
+
+#[test]
+fn invalid_solution_is_slashed_and_next_one_verified() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+
+		let (pages, score) = solution_electing(&[30, 40]);
+		// 99 claims a better score than the one of their solution, and is verified first.
+		let claimed = ElectionScore { minimal_stake: 60, ..score };
+		submit_solution(99, pages.clone(), claimed);
+		submit_solution(100, pages, score);
+		assert_eq!(SortedScores::<Runtime>::get().into_inner(), vec![(100, score), (99, claimed)]);
+
+		roll_to_phase(Phase::SignedValidation);
+		roll_to(System::block_number() + 3);
+		assert_eq!(balances(&99), (92, 0));
+		assert!(multi_block_events().contains(&Event::Rejected {
+			round: 0,
+			who: 99,
+			page: 2,
+			error: FeasibilityError::InvalidScore
+		}));
+		assert!(multi_block_events().contains(&Event::Slashed { round: 0, who: 99, amount: 8 }));
+		assert!(QueuedBackings::<Runtime>::get().is_empty());
+		assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 0);
+
+		roll_to(System::block_number() + 3);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Export);
+		assert_eq!(QueuedScore::<Runtime>::get(), Some(score));
+		assert_eq!(balances(&100), (107, 0));
+	})
+}
+
+#[test]
+fn invalid_page_is_rejected_early() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+
+		// the supports of the voters of page 1 are submitted as page 0.
+		let (mut pages, score) = solution_electing(&[30, 40]);
+		pages.swap(0, 1);
+		submit_solution(99, pages, score);
+
+		roll_to_phase(Phase::SignedValidation);
+		roll_to(System::block_number() + 1);
+		assert!(multi_block_events().contains(&Event::Rejected {
+			round: 0,
+			who: 99,
+			page: 0,
+			error: FeasibilityError::InvalidVoter
+		}));
+		assert_eq!(SubmissionPages::<Runtime>::iter_prefix(99).count(), 0);
+		assert_eq!(balances(&99), (92, 0));
+
+		roll_to(System::block_number() + 1);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Export);
+		assert!(QueuedScore::<Runtime>::get().is_none());
+	})
+}
+
+#[test]
+fn missing_pages_are_empty() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+
+		// only the voters of page 2 back the winners, page 1 is never submitted.
+		let supports: SupportsPageOf<Runtime> =
+			bounded_vec![(30, support(vec![(30, 30)])), (40, support(vec![(40, 40)]))];
+		let score = ElectionScore { minimal_stake: 30, sum_stake: 70, sum_stake_squared: 2500 };
+		assert_ok!(MultiBlock::register(RuntimeOrigin::signed(99), score));
+		assert_ok!(MultiBlock::submit_page(RuntimeOrigin::signed(99), 2, Some(Box::new(supports))));
+
+		roll_to_phase(Phase::Export);
+		assert_eq!(QueuedScore::<Runtime>::get(), Some(score));
+		assert_eq!(MultiBlock::elect().unwrap().len(), 2);
+	})
+}
+
+#[test]
+fn feasibility_check_page_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+
+		let check = |page, supports: Vec<(AccountId, PageSupport<Runtime>)>| {
+			MultiBlock::feasibility_check_page(page, supports.try_into().unwrap())
+		};
+
+		// winner is not a target.
+		assert_eq!(
+			check(0, vec![(50, support(vec![(1, 10)]))]),
+			Err(FeasibilityError::InvalidWinner)
+		);
+		// voter is not in this page.
+		assert_eq!(
+			check(0, vec![(30, support(vec![(4, 10)]))]),
+			Err(FeasibilityError::InvalidVoter)
+		);
+		// voter did not vote for the winner.
+		assert_eq!(
+			check(0, vec![(30, support(vec![(1, 10)]))]),
+			Err(FeasibilityError::InvalidVote)
+		);
+		// total does not match the backings.
+		assert_eq!(
+			check(0, vec![(30, PageSupport { total: 11, voters: bounded_vec![(2, 10)] })]),
+			Err(FeasibilityError::InvalidSupport)
+		);
+		// voter backs more than their stake, across winners.
+		assert_eq!(
+			check(0, vec![(30, support(vec![(2, 6)])), (40, support(vec![(2, 5)]))]),
+			Err(FeasibilityError::InvalidBacking)
+		);
+		// more winners than desired.
+		assert_eq!(
+			check(
+				0,
+				vec![
+					(10, support(vec![(1, 5)])),
+					(20, support(vec![(1, 5)])),
+					(30, support(vec![(2, 10)]))
+				]
+			),
+			Err(FeasibilityError::WrongWinnerCount)
+		);
+		assert!(QueuedBackings::<Runtime>::get().is_empty());
+
+		// backings are accumulated across pages.
+		assert_ok!(check(0, vec![(30, support(vec![(2, 5)])), (40, support(vec![(2, 5)]))]));
+		assert_ok!(check(2, vec![(30, support(vec![(30, 30)]))]));
+		assert_eq!(QueuedBackings::<Runtime>::get().into_inner(), vec![(30, 35), (40, 5)]);
+		assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 2);
+	})
+}
+
+#[test]
+fn register_works() {
+	ExtBuilder::default().max_submissions(2).build_and_execute(|| {
+		let score = |minimal_stake| ElectionScore { minimal_stake, ..Default::default() };
+
+		assert_noop!(
+			MultiBlock::register(RuntimeOrigin::signed(99), score(10)),
+			Error::<Runtime>::PhaseNotSigned
+		);
+		roll_to_phase(Phase::Signed);
+
+		assert_ok!(MultiBlock::register(RuntimeOrigin::signed(99), score(10)));
+		assert_eq!(balances(&99), (95, 5));
+		assert_noop!(
+			MultiBlock::register(RuntimeOrigin::signed(99), score(20)),
+			Error::<Runtime>::Duplicate
+		);
+
+		// equal scores are sorted after the earlier ones.
+		assert_ok!(MultiBlock::register(RuntimeOrigin::signed(100), score(10)));
+		assert_eq!(
+			SortedScores::<Runtime>::get().into_inner(),
+			vec![(100, score(10)), (99, score(10))]
+		);
+
+		// the queue is full, and the score must be better than the worst one.
+		assert_noop!(
+			MultiBlock::register(RuntimeOrigin::signed(101), score(10)),
+			Error::<Runtime>::QueueFull
+		);
+		assert_ok!(MultiBlock::register(RuntimeOrigin::signed(101), score(15)));
+		assert_eq!(
+			SortedScores::<Runtime>::get().into_inner(),
+			vec![(99, score(10)), (101, score(15))]
+		);
+		assert_eq!(balances(&100), (100, 0));
+		assert!(Submissions::<Runtime>::get(100).is_none());
+		assert!(multi_block_events().contains(&Event::Discarded { round: 0, who: 100 }));
+
+		// the minimum score is enforced.
+		assert_ok!(MultiBlock::set_minimum_score(RuntimeOrigin::root(), Some(score(20))));
+		assert_noop!(
+			MultiBlock::register(RuntimeOrigin::signed(102), score(20)),
+			Error::<Runtime>::ScoreTooLow
+		);
+		assert_noop!(
+			MultiBlock::set_minimum_score(RuntimeOrigin::signed(99), None),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn submit_page_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+		let supports: SupportsPageOf<Runtime> = bounded_vec![(30, support(vec![(30, 30)]))];
+
+		assert_noop!(
+			MultiBlock::submit_page(RuntimeOrigin::signed(99), 0, None),
+			Error::<Runtime>::NotRegistered
+		);
+		assert_ok!(MultiBlock::register(RuntimeOrigin::signed(99), SCORE_30_40));
+		assert_noop!(
+			MultiBlock::submit_page(RuntimeOrigin::signed(99), 3, None),
+			Error::<Runtime>::BadPageIndex
+		);
+
+		assert_ok!(MultiBlock::submit_page(
+			RuntimeOrigin::signed(99),
+			2,
+			Some(Box::new(supports.clone()))
+		));
+		assert_eq!(balances(&99), (94, 6));
+		assert_eq!(SubmissionPages::<Runtime>::get(99, 2), Some(supports.clone()));
+
+		// replacing a page does not take another deposit.
+		assert_ok!(MultiBlock::submit_page(RuntimeOrigin::signed(99), 2, Some(Box::new(supports))));
+		assert_eq!(balances(&99), (94, 6));
+		assert_eq!(
+			Submissions::<Runtime>::get(99).unwrap().pages.into_inner(),
+			vec![false, false, true]
+		);
+
+		// removing a page returns its deposit.
+		assert_ok!(MultiBlock::submit_page(RuntimeOrigin::signed(99), 2, None));
+		assert_eq!(balances(&99), (95, 5));
+		assert!(SubmissionPages::<Runtime>::get(99, 2).is_none());
+		assert_eq!(Submissions::<Runtime>::get(99).unwrap().deposit, 5);
+
+		roll_to_phase(Phase::SignedValidation);
+		assert_noop!(
+			MultiBlock::submit_page(RuntimeOrigin::signed(99), 0, None),
+			Error::<Runtime>::PhaseNotSigned
+		);
+	})
+}
+
+#[test]
+fn bail_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+		let (pages, score) = solution_electing(&[30, 40]);
+		submit_solution(99, pages, score);
+		assert_eq!(balances(&99), (92, 8));
+
+		assert_noop!(MultiBlock::bail(RuntimeOrigin::signed(100)), Error::<Runtime>::NotRegistered);
+		assert_ok!(MultiBlock::bail(RuntimeOrigin::signed(99)));
+
+		// the base deposit is slashed, the page deposits are returned.
+		assert_eq!(balances(&99), (95, 0));
+		assert!(SortedScores::<Runtime>::get().is_empty());
+		assert!(Submissions::<Runtime>::get(99).is_none());
+		assert_eq!(SubmissionPages::<Runtime>::iter_prefix(99).count(), 0);
+		assert_eq!(multi_block_events().last(), Some(&Event::Bailed { round: 0, who: 99 }));
+	})
+}
+
+#[test]
+fn signed_solution_is_exported_page_by_page() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+		let (pages, score) = solution_electing(&[30, 40]);
+		submit_solution(99, pages, score);
+		roll_to_phase(Phase::Export);
+		assert_eq!(MultiBlock::pages(), 3);
+
+		assert_eq!(
+			MultiBlock::elect_paged(2).unwrap().into_inner(),
+			vec![
+				(30, support(vec![(30, 30)]).into_support()),
+				(40, support(vec![(40, 40)]).into_support()),
+			]
+		);
+		assert_eq!(
+			MultiBlock::elect_paged(1).unwrap().into_inner(),
+			vec![(30, support(vec![(4, 10)]).into_support())]
+		);
+		// the election is not finalized before the last page.
+		assert_eq!(Round::<Runtime>::get(), 0);
+		assert_eq!(QueuedScore::<Runtime>::get(), Some(score));
+
+		assert_eq!(
+			MultiBlock::elect_paged(0).unwrap().into_inner(),
+			vec![
+				(30, support(vec![(2, 10)]).into_support()),
+				(40, support(vec![(3, 10)]).into_support()),
+			]
+		);
+		assert_eq!(
+			multi_block_events().last(),
+			Some(&Event::ElectionFinalized { compute: ElectionCompute::Signed, score })
+		);
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Off);
+		assert_eq!(Round::<Runtime>::get(), 1);
+		assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn paged_export_stops_the_signed_phase() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+
+		// without a queued solution, the fallback is exported as the last page.
+		assert!(MultiBlock::elect_paged(2).unwrap().is_empty());
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Export);
+		let (_, score) = solution_electing(&[30, 40]);
+		assert_noop!(
+			MultiBlock::register(RuntimeOrigin::signed(99), score),
+			Error::<Runtime>::PhaseNotSigned
+		);
+
+		assert!(MultiBlock::elect_paged(1).unwrap().is_empty());
+		assert_eq!(MultiBlock::elect_paged(0).unwrap().len(), 2);
+		assert_eq!(
+			multi_block_events().last(),
+			Some(&Event::ElectionFinalized {
+				compute: ElectionCompute::Fallback,
+				score: Default::default()
+			})
+		);
+		assert_eq!(Round::<Runtime>::get(), 1);
+	})
+}
+
+#[test]
+fn unverified_submissions_are_returned_on_elect() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+		let (pages, score) = solution_electing(&[30, 40]);
+		submit_solution(99, pages.clone(), score);
+		submit_solution(100, pages, score);
+
+		roll_to_phase(Phase::Export);
+		assert_eq!(balances(&99), (107, 0));
+		assert_eq!(balances(&100), (92, 8));
+
+		assert_ok!(MultiBlock::elect());
+		assert_eq!(balances(&100), (100, 0));
+		assert!(SortedScores::<Runtime>::get().is_empty());
+		assert_eq!(Submissions::<Runtime>::iter().count(), 0);
+		assert_eq!(SubmissionPages::<Runtime>::iter().count(), 0);
+	})
+}
+
+#[test]
+fn fallback_is_used_without_solution() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Export);
+
+		assert_eq!(MultiBlock::elect().unwrap().len(), 2);
+		assert_eq!(
+			multi_block_events().last(),
+			Some(&Event::ElectionFinalized {
+				compute: ElectionCompute::Fallback,
+				score: Default::default()
+			})
+		);
+		assert_eq!(Round::<Runtime>::get(), 1);
+	});
+
+	ExtBuilder::default().onchain_fallback(false).build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+
+		assert!(matches!(MultiBlock::elect(), Err(ElectionError::Fallback("NoFallback."))));
+		// a new round starts nonetheless.
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Off);
+		assert_eq!(Round::<Runtime>::get(), 1);
+	})
+}
+
+#[test]
+fn submit_page_is_weighed_by_its_backings() {
+	ExtBuilder::default().build_and_execute(|| {
+		let call = |supports: Option<SupportsPageOf<Runtime>>| {
+			RuntimeCall::MultiBlock(crate::Call::submit_page {
+				page: 0,
+				maybe_supports: supports.map(Box::new),
+			})
+			.get_dispatch_info()
+			.weight
+		};
+		let supports: SupportsPageOf<Runtime> =
+			bounded_vec![(30, support(vec![(2, 10), (4, 10)])), (40, support(vec![(3, 10)]))];
+
+		assert_eq!(MultiBlock::backings_of(&supports), 3);
+		assert_eq!(call(None), <() as WeightInfo>::submit_page(0));
+		assert_eq!(call(Some(supports)), <() as WeightInfo>::submit_page(3));
+		assert!(<() as WeightInfo>::submit_page(3).all_gt(<() as WeightInfo>::submit_page(0)));
+	})
+}
+
+#[test]
+fn verification_is_weighed_by_the_backings_of_the_page() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Signed);
+		let (pages, score) = solution_electing(&[30, 40]);
+		assert_eq!(MultiBlock::backings_of(&pages[0]), 2);
+		submit_solution(99, pages, score);
+
+		roll_to_phase(Phase::SignedValidation);
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		assert_eq!(
+			MultiBlock::on_initialize(next),
+			<() as WeightInfo>::on_initialize_verify_page(2)
+		);
+		assert_eq!(VerifyingPage::<Runtime>::get(), Some(1));
+	})
+}
+
+#[test]
+fn voters_are_unlocked_if_the_snapshot_is_interrupted() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Snapshot(2));
+		assert!(VotersLocked::get());
+
+		// the election happens before the voter snapshot is complete.
+		assert_ok!(MultiBlock::elect());
+		assert_eq!(CurrentPhase::<Runtime>::get(), Phase::Off);
+		assert!(!VotersLocked::get());
+	});
+}
+
+#[test]
+fn snapshot_continues_after_the_cursor_is_removed() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to_phase(Phase::Snapshot(2));
+		assert_eq!(SnapshotCursor::<Runtime>::get(), Some(3));
+
+		// the cursor chills while the voters are locked, the snapshot goes on after it.
+		remove_voter(3);
+		roll_to_phase(Phase::Signed);
+		assert!(!VotersLocked::get());
+		assert_eq!(
+			(0..Pages::get())
+				.flat_map(|page| VoterSnapshot::<Runtime>::get(page).unwrap_or_default())
+				.map(|(who, _, _)| who)
+				.collect::<Vec<_>>(),
+			vec![1, 2, 3, 4, 10, 20, 30, 40],
+		);
+	})
+}
+
+#[test]
+#[should_panic(expected = "a solution must be verified before its pages may be exported")]
+fn validation_must_end_before_the_pages_are_exported() {
+	ExtBuilder::default().build_and_execute(|| {
+		// with 3 pages, staking fetches page 2 two blocks before the election.
+		SignedValidationPhase::set(4);
+		<MultiBlock as Hooks<u64>>::integrity_test();
+	})
+}
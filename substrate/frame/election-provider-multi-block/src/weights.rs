@@ -0,0 +1,263 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_election_provider_multi_block`.
+//!
+//! The benchmarks of this pallet have not been run on reference hardware yet. These weights are
+//! estimates derived from the storage accessed by each call and hook, and should be replaced by
+//! the output of `benchmarking.rs`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_election_provider_multi_block`.
+pub trait WeightInfo {
+	fn on_initialize_nothing() -> Weight;
+	fn on_initialize_snapshot_targets() -> Weight;
+	fn on_initialize_snapshot_voters() -> Weight;
+	fn on_initialize_verify_page(b: u32, ) -> Weight;
+	fn elect_queued(v: u32, ) -> Weight;
+	fn elect_queued_page(b: u32, ) -> Weight;
+	fn register() -> Weight;
+	fn submit_page(b: u32, ) -> Weight;
+	fn bail() -> Weight;
+	fn set_minimum_score() -> Weight;
+}
+
+/// Weights for `pallet_election_provider_multi_block` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	fn on_initialize_nothing() -> Weight {
+		Weight::from_parts(6_420_000, 1598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	/// Storage: `MultiBlock::TargetSnapshot` (r:0 w:1)
+	/// Storage: `MultiBlock::DesiredTargets` (r:0 w:1)
+	fn on_initialize_snapshot_targets() -> Weight {
+		Weight::from_parts(14_587_000, 1598)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:1)
+	/// Storage: `MultiBlock::SnapshotCursor` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	/// Storage: `MultiBlock::VoterSnapshot` (r:0 w:1)
+	fn on_initialize_snapshot_voters() -> Weight {
+		Weight::from_parts(18_944_000, 1667)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::SortedScores` (r:1 w:0)
+	/// Storage: `MultiBlock::VerifyingPage` (r:1 w:1)
+	/// Storage: `MultiBlock::SubmissionPages` (r:1 w:1)
+	/// Storage: `MultiBlock::DesiredTargets` (r:1 w:0)
+	/// Storage: `MultiBlock::TargetSnapshot` (r:1 w:0)
+	/// Storage: `MultiBlock::VoterSnapshot` (r:1 w:0)
+	/// Storage: `MultiBlock::QueuedBackings` (r:1 w:1)
+	/// Storage: `MultiBlock::QueuedSolutionPages` (r:0 w:1)
+	/// `b` is the number of backings in the page, over all of its winners.
+	fn on_initialize_verify_page(b: u32, ) -> Weight {
+		Weight::from_parts(2_786_512_000, 490807)
+			.saturating_add(Weight::from_parts(150_000, 48).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `MultiBlock::QueuedScore` (r:1 w:1)
+	/// Storage: `MultiBlock::QueuedSolutionPages` (r:3 w:3)
+	/// Storage: `MultiBlock::SortedScores` (r:1 w:1)
+	/// Storage: `MultiBlock::QueuedBackings` (r:0 w:1)
+	/// Storage: `MultiBlock::VerifyingPage` (r:0 w:1)
+	/// Storage: `MultiBlock::TargetSnapshot` (r:0 w:1)
+	/// Storage: `MultiBlock::SnapshotCursor` (r:0 w:1)
+	/// Storage: `MultiBlock::DesiredTargets` (r:0 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:1)
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:1)
+	/// `v` is the number of voters backing the winners, over all pages.
+	fn elect_queued(v: u32, ) -> Weight {
+		Weight::from_parts(51_233_119, 8590)
+			.saturating_add(Weight::from_parts(12_148, 48).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::QueuedScore` (r:1 w:0)
+	/// Storage: `MultiBlock::QueuedSolutionPages` (r:1 w:1)
+	/// `b` is the number of backings in the page, over all of its winners.
+	fn elect_queued_page(b: u32, ) -> Weight {
+		Weight::from_parts(22_416_000, 4102)
+			.saturating_add(Weight::from_parts(12_148, 48).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::Submissions` (r:1 w:1)
+	/// Storage: `MultiBlock::MinimumScore` (r:1 w:0)
+	/// Storage: `MultiBlock::SortedScores` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	fn register() -> Weight {
+		Weight::from_parts(56_488_000, 3867)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::Submissions` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	/// Storage: `MultiBlock::SubmissionPages` (r:0 w:1)
+	/// `b` is the number of backings in the page, over all of its winners.
+	fn submit_page(b: u32, ) -> Weight {
+		Weight::from_parts(64_172_000, 3986)
+			.saturating_add(Weight::from_parts(40_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::Submissions` (r:1 w:1)
+	/// Storage: `MultiBlock::SortedScores` (r:1 w:1)
+	/// Storage: `MultiBlock::SubmissionPages` (r:3 w:3)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	fn bail() -> Weight {
+		Weight::from_parts(82_107_000, 9649)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `MultiBlock::MinimumScore` (r:0 w:1)
+	fn set_minimum_score() -> Weight {
+		Weight::from_parts(3_698_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	fn on_initialize_nothing() -> Weight {
+		Weight::from_parts(6_420_000, 1598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	/// Storage: `MultiBlock::TargetSnapshot` (r:0 w:1)
+	/// Storage: `MultiBlock::DesiredTargets` (r:0 w:1)
+	fn on_initialize_snapshot_targets() -> Weight {
+		Weight::from_parts(14_587_000, 1598)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:1)
+	/// Storage: `MultiBlock::SnapshotCursor` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	/// Storage: `MultiBlock::VoterSnapshot` (r:0 w:1)
+	fn on_initialize_snapshot_voters() -> Weight {
+		Weight::from_parts(18_944_000, 1667)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::SortedScores` (r:1 w:0)
+	/// Storage: `MultiBlock::VerifyingPage` (r:1 w:1)
+	/// Storage: `MultiBlock::SubmissionPages` (r:1 w:1)
+	/// Storage: `MultiBlock::DesiredTargets` (r:1 w:0)
+	/// Storage: `MultiBlock::TargetSnapshot` (r:1 w:0)
+	/// Storage: `MultiBlock::VoterSnapshot` (r:1 w:0)
+	/// Storage: `MultiBlock::QueuedBackings` (r:1 w:1)
+	/// Storage: `MultiBlock::QueuedSolutionPages` (r:0 w:1)
+	/// `b` is the number of backings in the page, over all of its winners.
+	fn on_initialize_verify_page(b: u32, ) -> Weight {
+		Weight::from_parts(2_786_512_000, 490807)
+			.saturating_add(Weight::from_parts(150_000, 48).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `MultiBlock::QueuedScore` (r:1 w:1)
+	/// Storage: `MultiBlock::QueuedSolutionPages` (r:3 w:3)
+	/// Storage: `MultiBlock::SortedScores` (r:1 w:1)
+	/// Storage: `MultiBlock::QueuedBackings` (r:0 w:1)
+	/// Storage: `MultiBlock::VerifyingPage` (r:0 w:1)
+	/// Storage: `MultiBlock::TargetSnapshot` (r:0 w:1)
+	/// Storage: `MultiBlock::SnapshotCursor` (r:0 w:1)
+	/// Storage: `MultiBlock::DesiredTargets` (r:0 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:1)
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:1)
+	/// `v` is the number of voters backing the winners, over all pages.
+	fn elect_queued(v: u32, ) -> Weight {
+		Weight::from_parts(51_233_119, 8590)
+			.saturating_add(Weight::from_parts(12_148, 48).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::QueuedScore` (r:1 w:0)
+	/// Storage: `MultiBlock::QueuedSolutionPages` (r:1 w:1)
+	/// `b` is the number of backings in the page, over all of its winners.
+	fn elect_queued_page(b: u32, ) -> Weight {
+		Weight::from_parts(22_416_000, 4102)
+			.saturating_add(Weight::from_parts(12_148, 48).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::Submissions` (r:1 w:1)
+	/// Storage: `MultiBlock::MinimumScore` (r:1 w:0)
+	/// Storage: `MultiBlock::SortedScores` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	fn register() -> Weight {
+		Weight::from_parts(56_488_000, 3867)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::Submissions` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	/// Storage: `MultiBlock::SubmissionPages` (r:0 w:1)
+	/// `b` is the number of backings in the page, over all of its winners.
+	fn submit_page(b: u32, ) -> Weight {
+		Weight::from_parts(64_172_000, 3986)
+			.saturating_add(Weight::from_parts(40_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `MultiBlock::CurrentPhase` (r:1 w:0)
+	/// Storage: `MultiBlock::Submissions` (r:1 w:1)
+	/// Storage: `MultiBlock::SortedScores` (r:1 w:1)
+	/// Storage: `MultiBlock::SubmissionPages` (r:3 w:3)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `MultiBlock::Round` (r:1 w:0)
+	fn bail() -> Weight {
+		Weight::from_parts(82_107_000, 9649)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `MultiBlock::MinimumScore` (r:0 w:1)
+	fn set_minimum_score() -> Weight {
+		Weight::from_parts(3_698_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
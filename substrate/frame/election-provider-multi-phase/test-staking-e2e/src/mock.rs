@@ -272,6 +272,9 @@ impl pallet_nomination_pools::Config for Runtime {
 	type MaxUnbonding = MaxUnbonding;
 	type MaxPointsToBalance = frame_support::traits::ConstU8<10>;
 	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ValidatorSelector = ();
+	type MaxAutoNominations = ConstU32<0>;
+	type MaxAutoNominationCandidates = ConstU32<0>;
 }
 
 parameter_types! {
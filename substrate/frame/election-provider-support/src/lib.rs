@@ -276,6 +276,9 @@ pub type IndexAssignmentOf<C> = IndexAssignment<
 	<C as NposSolution>::Accuracy,
 >;
 
+/// The index of a page of a paged election, e.g. of its snapshot or solution.
+pub type PageIndex = u32;
+
 /// Types that are used by the data provider trait.
 pub mod data_provider {
 	/// Alias for the result type of the election data provider.
@@ -309,6 +312,46 @@ pub trait ElectionDataProvider {
 	/// appropriate weight at the end of execution with the system pallet directly.
 	fn electing_voters(bounds: DataProviderBounds) -> data_provider::Result<Vec<VoterOf<Self>>>;
 
+	/// Same as [`Self::electing_voters`], but only returns the voters that come after `cursor`,
+	/// the last voter of the previously returned page, if any.
+	///
+	/// This allows an election provider to build its snapshot over multiple calls, typically in
+	/// multiple blocks. Voters must be returned in the same order as in [`Self::electing_voters`],
+	/// such that successive pages never contain the same voter twice. While the voters are locked
+	/// with [`Self::lock_voters`], `cursor` may have stopped being a voter since it was returned,
+	/// and the voters that came after it should still be returned. Otherwise, an error should be
+	/// returned if `cursor` is no longer a voter.
+	///
+	/// This should be implemented as a self-weighing function. The implementor should register its
+	/// appropriate weight at the end of execution with the system pallet directly.
+	///
+	/// The default implementation does not support paging: the first page is the same as
+	/// [`Self::electing_voters`], and all the following ones are empty.
+	fn electing_voters_paged(
+		bounds: DataProviderBounds,
+		cursor: Option<&Self::AccountId>,
+	) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		match cursor {
+			None => Self::electing_voters(bounds),
+			Some(_) => Ok(Vec::new()),
+		}
+	}
+
+	/// Freeze the order of the voters, until [`Self::unlock_voters`] is called.
+	///
+	/// Should be called before fetching the first page of voters with
+	/// [`Self::electing_voters_paged`], so that voters cannot move across the cursor between two
+	/// pages, and thus be returned twice or skipped. While locked, the implementor may reject the
+	/// operations that would reorder the voters.
+	///
+	/// The default implementation does nothing.
+	fn lock_voters() {}
+
+	/// Unfreeze the order of the voters, see [`Self::lock_voters`].
+	///
+	/// The default implementation does nothing.
+	fn unlock_voters() {}
+
 	/// The number of targets to elect.
 	///
 	/// This should be implemented as a self-weighing function. The implementor should register its
@@ -418,6 +461,27 @@ pub trait ElectionProvider: ElectionProviderBase {
 	/// implementor should register its appropriate weight at the end of execution with the
 	/// system pallet directly.
 	fn elect() -> Result<BoundedSupportsOf<Self>, Self::Error>;
+
+	/// The number of pages in which the result of an election is exported through
+	/// [`Self::elect_paged`].
+	fn pages() -> PageIndex {
+		1
+	}
+
+	/// Performs the election, returning only the given `page` of its result.
+	///
+	/// Pages are requested from `Self::pages() - 1` down to `0`, and requesting page `0` finalizes
+	/// the election. A winner can appear in several pages, each carrying a part of its backing, so
+	/// the caller is expected to merge the supports of all pages.
+	///
+	/// By default, the whole result of [`Self::elect`] is returned as page `0`.
+	fn elect_paged(page: PageIndex) -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		if page == 0 {
+			Self::elect()
+		} else {
+			Ok(Default::default())
+		}
+	}
 }
 
 /// A (almost) marker trait that signifies an election provider as working synchronously. i.e. being
@@ -548,6 +612,26 @@ pub trait SortedListProvider<AccountId> {
 	/// Returns `Ok(())` iff it successfully removes an item, an `Err(_)` otherwise.
 	fn on_remove(id: &AccountId) -> Result<(), Self::Error>;
 
+	/// Lock the list, so that the order of its ids is preserved while it is iterated over multiple
+	/// blocks with [`Self::iter_from`].
+	///
+	/// While locked, ids are not moved when their score is updated, and [`Self::on_insert`] may
+	/// fail. Removals are still possible, and an implementation that supports locking should keep
+	/// accepting an id removed while locked as the start of [`Self::iter_from`] until it is
+	/// unlocked.
+	///
+	/// The default implementation does nothing, which is only sound if the iteration order of the
+	/// list never changes for the ids that remain in it.
+	fn lock() {}
+
+	/// Unlock the list, see [`Self::lock`].
+	fn unlock() {}
+
+	/// Whether the list is locked, see [`Self::lock`].
+	fn is_locked() -> bool {
+		false
+	}
+
 	/// Regenerate this list from scratch. Returns the count of items inserted.
 	///
 	/// This should typically only be used at a runtime upgrade.
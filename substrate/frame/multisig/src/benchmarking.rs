@@ -45,6 +45,16 @@ fn setup_multi<T: Config>(
 	Ok((signatories, Box::new(call)))
 }
 
+/// Set up `s` signatories with an approval weight of 1 each, so that a threshold of `s` requires
+/// the approval of every signatory.
+fn setup_weighted_multi<T: Config>(
+	s: u32,
+	z: u32,
+) -> Result<(Vec<(T::AccountId, u16)>, Box<<T as Config>::RuntimeCall>), &'static str> {
+	let (signatories, call) = setup_multi::<T>(s, z)?;
+	Ok((signatories.into_iter().map(|signatory| (signatory, 1)).collect(), call))
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -298,5 +308,325 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn poke_deposit(s: Linear<2, { T::MaxSignatories::get() }>) -> Result<(), BenchmarkError> {
+		let (mut signatories, call) = setup_multi::<T>(s, 0)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories.clone(), None, call, Weight::zero())?;
+		// Simulate an increase of the deposit since the multi was created, such that the poke
+		// needs to reserve the difference.
+		let deposit = Multisig::<T>::deposit(s as u16);
+		let old_deposit = deposit / 2u32.into();
+		T::Currency::unreserve(&caller, deposit - old_deposit);
+		Multisigs::<T>::mutate(&multi_account_id, call_hash, |maybe_multisig| {
+			if let Some(multisig) = maybe_multisig {
+				multisig.deposit = old_deposit;
+			}
+		});
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), s as u16, signatories, call_hash);
+
+		let multisig =
+			Multisigs::<T>::get(multi_account_id, call_hash).ok_or("multisig not found")?;
+		assert_eq!(multisig.deposit, deposit);
+		assert_eq!(T::Currency::reserved_balance(&caller), deposit);
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length
+	/// `s`: Signatories, including the caller, need at least 2 people
+	#[benchmark]
+	fn as_multi_weighted_create(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s as u16);
+		let caller = signatories.last().ok_or("signatories should have len 2 or more")?.0.clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		as_multi_weighted(
+			RawOrigin::Signed(caller),
+			s as u16,
+			signatories,
+			None,
+			call,
+			Weight::zero(),
+		);
+
+		assert!(Multisigs::<T>::contains_key(multi_account_id, call_hash));
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length
+	/// `s`: Signatories, including the caller, need at least 3 people (so we don't complete the
+	/// multisig)
+	#[benchmark]
+	fn as_multi_weighted_approve(
+		s: Linear<3, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s as u16);
+		let caller = signatories.last().ok_or("signatories should have len 2 or more")?.0.clone();
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		Multisig::<T>::as_multi_weighted(
+			RawOrigin::Signed(caller).into(),
+			s as u16,
+			signatories.clone(),
+			None,
+			call.clone(),
+			Weight::zero(),
+		)?;
+		let caller2 = signatories[0].0.clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		as_multi_weighted(
+			RawOrigin::Signed(caller2),
+			s as u16,
+			signatories,
+			Some(timepoint),
+			call,
+			Weight::zero(),
+		);
+
+		let multisig =
+			Multisigs::<T>::get(multi_account_id, call_hash).ok_or("multisig not created")?;
+		assert_eq!(multisig.approvals.len(), 2);
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length
+	/// `s`: Signatories, including the caller, need at least 2 people
+	#[benchmark]
+	fn as_multi_weighted_complete(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s as u16);
+		let caller = signatories.last().ok_or("signatories should have len 2 or more")?.0.clone();
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		Multisig::<T>::as_multi_weighted(
+			RawOrigin::Signed(caller).into(),
+			s as u16,
+			signatories.clone(),
+			None,
+			call.clone(),
+			Weight::zero(),
+		)?;
+		// Everyone except the first person approves
+		for i in 1..s - 1 {
+			let caller_loop = signatories[i as usize].0.clone();
+			Multisig::<T>::as_multi_weighted(
+				RawOrigin::Signed(caller_loop).into(),
+				s as u16,
+				signatories.clone(),
+				Some(timepoint),
+				call.clone(),
+				Weight::zero(),
+			)?;
+		}
+		let caller2 = signatories[0].0.clone();
+		assert!(Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		as_multi_weighted(
+			RawOrigin::Signed(caller2),
+			s as u16,
+			signatories,
+			Some(timepoint),
+			call,
+			Weight::MAX,
+		);
+
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length, not a component
+	/// `s`: Signatories, including the caller, need at least 2 people
+	#[benchmark]
+	fn approve_as_multi_weighted_create(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s as u16);
+		let caller = signatories.last().ok_or("signatories should have len 2 or more")?.0.clone();
+		let call_hash = call.using_encoded(blake2_256);
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		// Create the multi
+		#[extrinsic_call]
+		approve_as_multi_weighted(
+			RawOrigin::Signed(caller),
+			s as u16,
+			signatories,
+			None,
+			call_hash,
+			Weight::zero(),
+		);
+
+		assert!(Multisigs::<T>::contains_key(multi_account_id, call_hash));
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length, not a component
+	/// `s`: Signatories, including the caller, need at least 3 people (so we don't reach the
+	/// threshold)
+	#[benchmark]
+	fn approve_as_multi_weighted_approve(
+		s: Linear<3, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s as u16);
+		let caller = signatories.last().ok_or("signatories should have len 2 or more")?.0.clone();
+		let call_hash = call.using_encoded(blake2_256);
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		Multisig::<T>::as_multi_weighted(
+			RawOrigin::Signed(caller).into(),
+			s as u16,
+			signatories.clone(),
+			None,
+			call,
+			Weight::zero(),
+		)?;
+		let caller2 = signatories[0].0.clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		approve_as_multi_weighted(
+			RawOrigin::Signed(caller2),
+			s as u16,
+			signatories,
+			Some(timepoint),
+			call_hash,
+			Weight::zero(),
+		);
+
+		let multisig =
+			Multisigs::<T>::get(multi_account_id, call_hash).ok_or("multisig not created")?;
+		assert_eq!(multisig.approvals.len(), 2);
+
+		Ok(())
+	}
+
+	/// `z`: Transaction Length, not a component
+	/// `s`: Signatories, including the caller, need at least 2 people
+	#[benchmark]
+	fn cancel_as_multi_weighted(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s as u16);
+		let caller = signatories.last().ok_or("signatories should have len 2 or more")?.0.clone();
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi_weighted(
+			o,
+			s as u16,
+			signatories.clone(),
+			None,
+			call,
+			Weight::zero(),
+		)?;
+		assert!(Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s as u16, signatories, timepoint, call_hash);
+
+		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
+
+		Ok(())
+	}
+
+	/// `s`: Signatories, including the caller, need at least 2 people
+	#[benchmark]
+	fn poke_deposit_weighted(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, call) = setup_weighted_multi::<T>(s, 0)?;
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s as u16);
+		let caller = signatories.last().ok_or("signatories should have len 2 or more")?.0.clone();
+		let call_hash = call.using_encoded(blake2_256);
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi_weighted(
+			o,
+			s as u16,
+			signatories.clone(),
+			None,
+			call,
+			Weight::zero(),
+		)?;
+		// Simulate an increase of the deposit since the multi was created, such that the poke
+		// needs to reserve the difference.
+		let deposit = Multisig::<T>::deposit(s as u16);
+		let old_deposit = deposit / 2u32.into();
+		T::Currency::unreserve(&caller, deposit - old_deposit);
+		Multisigs::<T>::mutate(&multi_account_id, call_hash, |maybe_multisig| {
+			if let Some(multisig) = maybe_multisig {
+				multisig.deposit = old_deposit;
+			}
+		});
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), s as u16, signatories, call_hash);
+
+		let multisig =
+			Multisigs::<T>::get(multi_account_id, call_hash).ok_or("multisig not found")?;
+		assert_eq!(multisig.deposit, deposit);
+		assert_eq!(T::Currency::reserved_balance(&caller), deposit);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -39,6 +39,17 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `poke_deposit` - Re-evaluate the deposit of a pending call from a composite origin.
+//! * `as_multi_weighted`, `approve_as_multi_weighted`, `cancel_as_multi_weighted` and
+//!   `poke_deposit_weighted` - The same operations, for a composite origin whose signatories carry
+//!   different approval weights.
+//!
+//! ### Weighted multisig
+//!
+//! A weighted multisig account is derived deterministically from the set of account IDs, the
+//! approval weight of each account and the threshold. A call is dispatched once the sum of the
+//! weights of its approvals reaches the threshold. The derivation is distinct from the one of
+//! equal-weight multisig accounts, such that both kinds of accounts never collide.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -117,6 +128,44 @@ enum CallOrHash<T: Config> {
 	Hash([u8; 32]),
 }
 
+/// A multisig account and the rules to approve its operations.
+struct MultisigAccount<AccountId> {
+	/// The deterministic account ID of the multisig.
+	id: AccountId,
+	/// The signatories and the weight of their approval, sorted by account ID.
+	signatories: Vec<(AccountId, u16)>,
+	/// The total approval weight required to dispatch a call.
+	threshold: u16,
+	/// The maximum number of approvals recorded for an operation, on which its deposit is based.
+	max_approvals: u16,
+	/// Whether the signatories were given with the weight of their approval.
+	weighted: bool,
+}
+
+impl<AccountId: Ord> MultisigAccount<AccountId> {
+	/// The number of signatories other than the caller.
+	fn other_signatories_len(&self) -> u32 {
+		self.signatories.len().saturating_sub(1) as u32
+	}
+
+	/// The number of signatories, including the caller.
+	fn signatories_len(&self) -> u32 {
+		self.signatories.len() as u32
+	}
+
+	/// The approval weight of `who`, zero if it is not a signatory.
+	fn weight_of(&self, who: &AccountId) -> u32 {
+		self.signatories
+			.binary_search_by(|(signatory, _)| signatory.cmp(who))
+			.map_or(0, |index| self.signatories[index].1.into())
+	}
+
+	/// The total approval weight of the given approvals.
+	fn approval_weight(&self, approvals: &[AccountId]) -> u32 {
+		approvals.iter().map(|who| self.weight_of(who)).sum()
+	}
+}
+
 #[frame::pallet]
 pub mod pallet {
 	use super::*;
@@ -209,6 +258,12 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// A signatory of a weighted multisig has a zero approval weight.
+		ZeroWeight,
+		/// The sender is not part of the signatories of the weighted multisig.
+		SenderNotInSignatories,
+		/// The sum of the approval weights of the signatories is below the threshold.
+		UnreachableThreshold,
 	}
 
 	#[pallet::event]
@@ -238,6 +293,14 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// The deposit of a multisig operation has been updated.
+		DepositPoked {
+			who: T::AccountId,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			old_deposit: BalanceOf<T>,
+			new_deposit: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -364,14 +427,8 @@ pub mod pallet {
 			max_weight: Weight,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::operate(
-				who,
-				threshold,
-				other_signatories,
-				maybe_timepoint,
-				CallOrHash::Call(*call),
-				max_weight,
-			)
+			let account = Self::multisig_account(&who, threshold, other_signatories)?;
+			Self::operate(who, account, maybe_timepoint, CallOrHash::Call(*call), max_weight)
 		}
 
 		/// Register approval for a dispatch to be made from a deterministic composite account if
@@ -421,14 +478,8 @@ pub mod pallet {
 			max_weight: Weight,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::operate(
-				who,
-				threshold,
-				other_signatories,
-				maybe_timepoint,
-				CallOrHash::Hash(call_hash),
-				max_weight,
-			)
+			let account = Self::multisig_account(&who, threshold, other_signatories)?;
+			Self::operate(who, account, maybe_timepoint, CallOrHash::Hash(call_hash), max_weight)
 		}
 
 		/// Cancel a pre-existing, on-going multisig transaction. Any deposit reserved previously
@@ -462,29 +513,183 @@ pub mod pallet {
 			call_hash: [u8; 32],
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
-			let max_sigs = T::MaxSignatories::get() as usize;
-			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
-			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
-			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+			let account = Self::multisig_account(&who, threshold, other_signatories)?;
+			Self::cancel(who, account, timepoint, call_hash)
+		}
 
-			let id = Self::multi_account_id(&signatories, threshold);
+		/// Re-evaluate the deposit reserved for a pending multisig operation, after a change of
+		/// `DepositBase` or `DepositFactor`.
+		///
+		/// The difference with the current deposit is reserved or unreserved. The transaction
+		/// fee is waived if the deposit was updated.
+		///
+		/// The dispatch origin for this call must be _Signed_, and must be the account who opened
+		/// the operation.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call_hash`: The hash of the call of the operation.
+		///
+		/// Emits `DepositPoked` if the deposit was updated.
+		///
+		/// ## Complexity
+		/// - `O(S)`.
+		/// - Up to one balance-reserve or unreserve operation.
+		/// - One encode & hash, both of complexity `O(S)`.
+		/// - I/O: 1 read `O(S)`, up to 1 mutate `O(S)`.
+		/// - Up to one event.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::poke_deposit(other_signatories.len() as u32))]
+		pub fn poke_deposit(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call_hash: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let account = Self::multisig_account(&who, threshold, other_signatories)?;
+			Self::poke(who, account, call_hash)
+		}
 
-			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
-			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
-			ensure!(m.depositor == who, Error::<T>::NotOwner);
+		/// Register approval for a dispatch to be made from a deterministic weighted composite
+		/// account, once the approval weights of the approving `signatories` reach `threshold`.
+		///
+		/// This is the weighted counterpart of `as_multi`, and it follows the same rules. The
+		/// deposit reserved on the first approval is `DepositBase` plus `DepositFactor` times the
+		/// maximum number of approvals that may be recorded before reaching `threshold`. If the
+		/// weight of the first approval alone reaches `threshold`, the call is executed right away
+		/// and nothing is reserved.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total approval weight required before this dispatch is executed.
+		/// - `signatories`: All the accounts who can approve this dispatch, including the sender,
+		/// with the weight of their approval. Must be sorted by account ID.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		///
+		/// ## Complexity
+		/// Same as `as_multi`.
+		#[pallet::call_index(5)]
+		#[pallet::weight({
+			let s = signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
 
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
-			debug_assert!(err_amount.is_zero());
-			<Multisigs<T>>::remove(&id, &call_hash);
+			T::WeightInfo::as_multi_weighted_create(s, z)
+			.max(T::WeightInfo::as_multi_weighted_approve(s, z))
+			.max(T::WeightInfo::as_multi_weighted_complete(s, z))
+			.saturating_add(*max_weight)
+		})]
+		pub fn as_multi_weighted(
+			origin: OriginFor<T>,
+			threshold: u16,
+			signatories: Vec<(T::AccountId, u16)>,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let account = Self::weighted_multisig_account(&who, threshold, signatories)?;
+			Self::operate(who, account, maybe_timepoint, CallOrHash::Call(*call), max_weight)
+		}
 
-			Self::deposit_event(Event::MultisigCancelled {
-				cancelling: who,
-				timepoint,
-				multisig: id,
-				call_hash,
-			});
-			Ok(())
+		/// Register approval for a dispatch to be made from a deterministic weighted composite
+		/// account, once the approval weights of the approving `signatories` reach `threshold`.
+		///
+		/// This is the weighted counterpart of `approve_as_multi`, and it follows the same rules.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total approval weight required before this dispatch is executed.
+		/// - `signatories`: All the accounts who can approve this dispatch, including the sender,
+		/// with the weight of their approval. Must be sorted by account ID.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// ## Complexity
+		/// Same as `approve_as_multi`.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let s = signatories.len() as u32;
+
+			T::WeightInfo::approve_as_multi_weighted_create(s)
+				.max(T::WeightInfo::approve_as_multi_weighted_approve(s))
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_multi_weighted(
+			origin: OriginFor<T>,
+			threshold: u16,
+			signatories: Vec<(T::AccountId, u16)>,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let account = Self::weighted_multisig_account(&who, threshold, signatories)?;
+			Self::operate(who, account, maybe_timepoint, CallOrHash::Hash(call_hash), max_weight)
+		}
+
+		/// Cancel a pre-existing, on-going weighted multisig transaction. Any deposit reserved
+		/// previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total approval weight required before this dispatch is executed.
+		/// - `signatories`: All the accounts who can approve this dispatch, including the sender,
+		/// with the weight of their approval. Must be sorted by account ID.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// ## Complexity
+		/// Same as `cancel_as_multi`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_as_multi_weighted(signatories.len() as u32))]
+		pub fn cancel_as_multi_weighted(
+			origin: OriginFor<T>,
+			threshold: u16,
+			signatories: Vec<(T::AccountId, u16)>,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = Self::weighted_multisig_account(&who, threshold, signatories)?;
+			Self::cancel(who, account, timepoint, call_hash)
+		}
+
+		/// Re-evaluate the deposit reserved for a pending weighted multisig operation, after a
+		/// change of `DepositBase` or `DepositFactor`.
+		///
+		/// This is the weighted counterpart of `poke_deposit`, and it follows the same rules.
+		///
+		/// The dispatch origin for this call must be _Signed_, and must be the account who opened
+		/// the operation.
+		///
+		/// - `threshold`: The total approval weight required before this dispatch is executed.
+		/// - `signatories`: All the accounts who can approve this dispatch, including the sender,
+		/// with the weight of their approval. Must be sorted by account ID.
+		/// - `call_hash`: The hash of the call of the operation.
+		///
+		/// Emits `DepositPoked` if the deposit was updated.
+		///
+		/// ## Complexity
+		/// Same as `poke_deposit`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::poke_deposit_weighted(signatories.len() as u32))]
+		pub fn poke_deposit_weighted(
+			origin: OriginFor<T>,
+			threshold: u16,
+			signatories: Vec<(T::AccountId, u16)>,
+			call_hash: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let account = Self::weighted_multisig_account(&who, threshold, signatories)?;
+			Self::poke(who, account, call_hash)
 		}
 	}
 }
@@ -500,22 +705,94 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
-	fn operate(
-		who: T::AccountId,
+	/// Derive a weighted multi-account ID from the list of accounts sorted by account ID, the
+	/// approval weight of each account and the threshold that is required.
+	///
+	/// NOTE: `who` must be sorted. If it is not, then you'll get the wrong answer.
+	pub fn weighted_multi_account_id(who: &[(T::AccountId, u16)], threshold: u16) -> T::AccountId {
+		let entropy = (b"modlpy/utilisuwt", who, threshold).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// The deposit reserved for an operation which records up to `max_approvals` approvals.
+	pub fn deposit(max_approvals: u16) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * max_approvals.into()
+	}
+
+	/// The equal-weight multisig account of `who` and `other_signatories`.
+	fn multisig_account(
+		who: &T::AccountId,
 		threshold: u16,
 		other_signatories: Vec<T::AccountId>,
-		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
-		call_or_hash: CallOrHash<T>,
-		max_weight: Weight,
-	) -> DispatchResultWithPostInfo {
+	) -> Result<MultisigAccount<T::AccountId>, DispatchError> {
 		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
 		let max_sigs = T::MaxSignatories::get() as usize;
 		ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
-		let other_signatories_len = other_signatories.len();
-		ensure!(other_signatories_len < max_sigs, Error::<T>::TooManySignatories);
+		ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
 		let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
 
-		let id = Self::multi_account_id(&signatories, threshold);
+		Ok(MultisigAccount {
+			id: Self::multi_account_id(&signatories, threshold),
+			signatories: signatories.into_iter().map(|signatory| (signatory, 1)).collect(),
+			threshold,
+			max_approvals: threshold,
+			weighted: false,
+		})
+	}
+
+	/// The weighted multisig account of `signatories`, which must contain `who`.
+	fn weighted_multisig_account(
+		who: &T::AccountId,
+		threshold: u16,
+		signatories: Vec<(T::AccountId, u16)>,
+	) -> Result<MultisigAccount<T::AccountId>, DispatchError> {
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		let max_sigs = T::MaxSignatories::get() as usize;
+		ensure!(signatories.len() >= 2, Error::<T>::TooFewSignatories);
+		ensure!(signatories.len() <= max_sigs, Error::<T>::TooManySignatories);
+		ensure!(
+			signatories.windows(2).all(|pair| pair[0].0 < pair[1].0),
+			Error::<T>::SignatoriesOutOfOrder
+		);
+		ensure!(signatories.iter().all(|(_, weight)| *weight > 0), Error::<T>::ZeroWeight);
+		ensure!(
+			signatories.binary_search_by(|(signatory, _)| signatory.cmp(who)).is_ok(),
+			Error::<T>::SenderNotInSignatories
+		);
+
+		// At most, the lightest signatories approve before the threshold is reached.
+		let mut weights: Vec<u32> =
+			signatories.iter().map(|(_, weight)| (*weight).into()).collect();
+		weights.sort_unstable();
+		let mut total_weight = 0u32;
+		let max_approvals = weights
+			.iter()
+			.position(|weight| {
+				total_weight = total_weight.saturating_add(*weight);
+				total_weight >= u32::from(threshold)
+			})
+			.ok_or(Error::<T>::UnreachableThreshold)?
+			.saturating_add(1);
+
+		Ok(MultisigAccount {
+			id: Self::weighted_multi_account_id(&signatories, threshold),
+			signatories,
+			threshold,
+			max_approvals: max_approvals.saturated_into(),
+			weighted: true,
+		})
+	}
+
+	fn operate(
+		who: T::AccountId,
+		account: MultisigAccount<T::AccountId>,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let threshold = u32::from(account.threshold);
+		let id = account.id.clone();
 
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
 		let (call_hash, call_len, maybe_call) = match call_or_hash {
@@ -533,12 +810,12 @@ impl<T: Config> Pallet<T> {
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
 
 			// Ensure that either we have not yet signed or that it is at threshold.
-			let mut approvals = m.approvals.len() as u16;
+			let mut approvals = account.approval_weight(&m.approvals);
 			// We only bother with the approval if we're below threshold.
 			let maybe_pos = m.approvals.binary_search(&who).err().filter(|_| approvals < threshold);
 			// Bump approvals if not yet voted and the vote is needed.
 			if maybe_pos.is_some() {
-				approvals += 1;
+				approvals = approvals.saturating_add(account.weight_of(&who));
 			}
 
 			// We only bother fetching/decoding call if we know that we're ready to execute.
@@ -564,11 +841,8 @@ impl<T: Config> Pallet<T> {
 				});
 				Ok(get_result_weight(result)
 					.map(|actual_weight| {
-						T::WeightInfo::as_multi_complete(
-							other_signatories_len as u32,
-							call_len as u32,
-						)
-						.saturating_add(actual_weight)
+						Self::as_multi_complete_weight(&account, call_len as u32)
+							.saturating_add(actual_weight)
 					})
 					.into())
 			} else {
@@ -593,8 +867,7 @@ impl<T: Config> Pallet<T> {
 					Err(Error::<T>::AlreadyApproved)?
 				}
 
-				let final_weight =
-					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32);
+				let final_weight = Self::as_multi_approve_weight(&account, call_len as u32);
 				// Call is not made, so the actual weight does not include call
 				Ok(Some(final_weight).into())
 			}
@@ -602,8 +875,32 @@ impl<T: Config> Pallet<T> {
 			// Not yet started; there should be no timepoint given.
 			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
 
+			// The approval of a weighted signatory may reach the threshold on its own, in which
+			// case the call is executed right away, without recording the operation.
+			if let Some(call) = maybe_call.filter(|_| account.weight_of(&who) >= threshold) {
+				ensure!(
+					call.get_dispatch_info().call_weight.all_lte(max_weight),
+					Error::<T>::MaxWeightTooLow
+				);
+
+				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
+				Self::deposit_event(Event::MultisigExecuted {
+					approving: who,
+					timepoint: Self::timepoint(),
+					multisig: id,
+					call_hash,
+					result: result.map(|_| ()).map_err(|e| e.error),
+				});
+				return Ok(get_result_weight(result)
+					.map(|actual_weight| {
+						Self::as_multi_complete_weight(&account, call_len as u32)
+							.saturating_add(actual_weight)
+					})
+					.into())
+			}
+
 			// Just start the operation by recording it in storage.
-			let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
+			let deposit = Self::deposit(account.max_approvals);
 
 			T::Currency::reserve(&who, deposit)?;
 
@@ -622,13 +919,101 @@ impl<T: Config> Pallet<T> {
 			);
 			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
 
-			let final_weight =
-				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32);
+			let final_weight = Self::as_multi_create_weight(&account, call_len as u32);
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
 	}
 
+	/// The weight of creating an operation of `account` with a call of length `z`.
+	fn as_multi_create_weight(account: &MultisigAccount<T::AccountId>, z: u32) -> Weight {
+		if account.weighted {
+			T::WeightInfo::as_multi_weighted_create(account.signatories_len(), z)
+		} else {
+			T::WeightInfo::as_multi_create(account.other_signatories_len(), z)
+		}
+	}
+
+	/// The weight of approving an operation of `account` with a call of length `z`.
+	fn as_multi_approve_weight(account: &MultisigAccount<T::AccountId>, z: u32) -> Weight {
+		if account.weighted {
+			T::WeightInfo::as_multi_weighted_approve(account.signatories_len(), z)
+		} else {
+			T::WeightInfo::as_multi_approve(account.other_signatories_len(), z)
+		}
+	}
+
+	/// The weight of completing an operation of `account` with a call of length `z`, excluding
+	/// the weight of the call.
+	fn as_multi_complete_weight(account: &MultisigAccount<T::AccountId>, z: u32) -> Weight {
+		if account.weighted {
+			T::WeightInfo::as_multi_weighted_complete(account.signatories_len(), z)
+		} else {
+			T::WeightInfo::as_multi_complete(account.other_signatories_len(), z)
+		}
+	}
+
+	fn cancel(
+		who: T::AccountId,
+		account: MultisigAccount<T::AccountId>,
+		timepoint: Timepoint<BlockNumberFor<T>>,
+		call_hash: [u8; 32],
+	) -> DispatchResult {
+		let id = account.id;
+
+		let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+		ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+		let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		debug_assert!(err_amount.is_zero());
+		<Multisigs<T>>::remove(&id, &call_hash);
+
+		Self::deposit_event(Event::MultisigCancelled {
+			cancelling: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+		});
+		Ok(())
+	}
+
+	fn poke(
+		who: T::AccountId,
+		account: MultisigAccount<T::AccountId>,
+		call_hash: [u8; 32],
+	) -> DispatchResultWithPostInfo {
+		let id = account.id;
+
+		<Multisigs<T>>::try_mutate(&id, call_hash, |maybe_multisig| -> DispatchResultWithPostInfo {
+			let multisig = maybe_multisig.as_mut().ok_or(Error::<T>::NotFound)?;
+			ensure!(multisig.depositor == who, Error::<T>::NotOwner);
+
+			let old_deposit = multisig.deposit;
+			let new_deposit = Self::deposit(account.max_approvals);
+			if new_deposit == old_deposit {
+				return Ok(Pays::Yes.into())
+			}
+
+			if new_deposit > old_deposit {
+				T::Currency::reserve(&who, new_deposit - old_deposit)?;
+			} else {
+				let err_amount = T::Currency::unreserve(&who, old_deposit - new_deposit);
+				debug_assert!(err_amount.is_zero());
+			}
+			multisig.deposit = new_deposit;
+
+			Self::deposit_event(Event::DepositPoked {
+				who: who.clone(),
+				multisig: id.clone(),
+				call_hash,
+				old_deposit,
+				new_deposit,
+			});
+			Ok(Pays::No.into())
+		})
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -58,12 +58,17 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 		}
 	}
 }
+parameter_types! {
+	pub static DepositBase: u64 = 1;
+	pub static DepositFactor: u64 = 1;
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
-	type DepositBase = ConstU64<1>;
-	type DepositFactor = ConstU64<1>;
+	type DepositBase = DepositBase;
+	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<3>;
 	type WeightInfo = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
@@ -687,3 +692,268 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn poke_deposit_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// Unchanged deposit, the fee is paid.
+		let result = Multisig::poke_deposit(RuntimeOrigin::signed(1), 2, vec![2, 3], hash);
+		assert_eq!(result.unwrap().pays_fee, Pays::Yes);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// Only the depositor can poke the deposit of an existing operation.
+		assert_noop!(
+			Multisig::poke_deposit(RuntimeOrigin::signed(2), 2, vec![1, 3], hash),
+			Error::<Test>::NotOwner,
+		);
+		assert_noop!(
+			Multisig::poke_deposit(RuntimeOrigin::signed(1), 2, vec![2, 3], [0; 32]),
+			Error::<Test>::NotFound,
+		);
+
+		// Increased deposit.
+		DepositBase::set(2);
+		let result = Multisig::poke_deposit(RuntimeOrigin::signed(1), 2, vec![2, 3], hash);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		assert_eq!(Balances::reserved_balance(1), 4);
+		System::assert_last_event(
+			Event::DepositPoked {
+				who: 1,
+				multisig: multi,
+				call_hash: hash,
+				old_deposit: 3,
+				new_deposit: 4,
+			}
+			.into(),
+		);
+
+		// Decreased deposit.
+		DepositBase::set(1);
+		DepositFactor::set(0);
+		let result = Multisig::poke_deposit(RuntimeOrigin::signed(1), 2, vec![2, 3], hash);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		assert_eq!(Balances::reserved_balance(1), 1);
+		assert_eq!(Multisigs::<Test>::get(multi, hash).unwrap().deposit, 1);
+
+		// The updated deposit is returned once the operation is cancelled.
+		let timepoint = Multisigs::<Test>::get(multi, hash).unwrap().when;
+		assert_ok!(Multisig::cancel_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			timepoint,
+			hash
+		));
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn weighted_multisig_works() {
+	new_test_ext().execute_with(|| {
+		// Account 1 approval counts as 3, such that it needs a single other approval.
+		let signatories = vec![(1, 3), (2, 1), (3, 1)];
+		let multi = Multisig::weighted_multi_account_id(&signatories, 4);
+		assert_ne!(multi, Multisig::multi_account_id(&[1, 2, 3][..], 4));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), multi, 10));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+
+		// Up to 3 approvals are recorded before the threshold is reached.
+		assert_ok!(Multisig::approve_as_multi_weighted(
+			RuntimeOrigin::signed(2),
+			4,
+			signatories.clone(),
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(2), 4);
+
+		// The approvals of accounts 2 and 3 are not enough.
+		assert_ok!(Multisig::as_multi_weighted(
+			RuntimeOrigin::signed(3),
+			4,
+			signatories.clone(),
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_eq!(Multisigs::<Test>::get(multi, hash).unwrap().approvals.into_inner(), vec![2, 3]);
+
+		assert_ok!(Multisig::as_multi_weighted(
+			RuntimeOrigin::signed(1),
+			4,
+			signatories,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn weighted_multisig_executes_if_the_first_approval_reaches_the_threshold() {
+	new_test_ext().execute_with(|| {
+		// Account 1 approval counts as 2, which is the threshold.
+		let signatories = vec![(1, 2), (2, 1), (3, 1)];
+		let multi = Multisig::weighted_multi_account_id(&signatories, 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), multi, 10));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+
+		assert_noop!(
+			Multisig::as_multi_weighted(
+				RuntimeOrigin::signed(1),
+				2,
+				signatories.clone(),
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::MaxWeightTooLow,
+		);
+		assert_ok!(Multisig::as_multi_weighted(
+			RuntimeOrigin::signed(1),
+			2,
+			signatories.clone(),
+			None,
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		System::assert_last_event(
+			Event::MultisigExecuted {
+				approving: 1,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+				result: Ok(()),
+			}
+			.into(),
+		);
+
+		// The approval of account 2 alone does not reach the threshold.
+		assert_ok!(Multisig::as_multi_weighted(
+			RuntimeOrigin::signed(2),
+			2,
+			signatories,
+			None,
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert!(Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn weighted_multisig_cancel_and_poke_work() {
+	new_test_ext().execute_with(|| {
+		let signatories = vec![(1, 2), (2, 2), (3, 1)];
+		let multi = Multisig::weighted_multi_account_id(&signatories, 3);
+		let hash = blake2_256(&call_transfer(6, 5).encode());
+
+		assert_ok!(Multisig::approve_as_multi_weighted(
+			RuntimeOrigin::signed(1),
+			3,
+			signatories.clone(),
+			None,
+			hash,
+			Weight::zero()
+		));
+		// At most 2 approvals are recorded before the threshold is reached.
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		DepositFactor::set(2);
+		assert_ok!(Multisig::poke_deposit_weighted(
+			RuntimeOrigin::signed(1),
+			3,
+			signatories.clone(),
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		System::assert_last_event(
+			Event::DepositPoked {
+				who: 1,
+				multisig: multi,
+				call_hash: hash,
+				old_deposit: 3,
+				new_deposit: 5,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			Multisig::cancel_as_multi_weighted(
+				RuntimeOrigin::signed(2),
+				3,
+				signatories.clone(),
+				now(),
+				hash
+			),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::cancel_as_multi_weighted(
+			RuntimeOrigin::signed(1),
+			3,
+			signatories,
+			now(),
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn weighted_multisig_signatories_are_checked() {
+	new_test_ext().execute_with(|| {
+		let hash = [0; 32];
+		let approve = |who: u64, threshold: u16, signatories: Vec<(u64, u16)>| {
+			Multisig::approve_as_multi_weighted(
+				RuntimeOrigin::signed(who),
+				threshold,
+				signatories,
+				None,
+				hash,
+				Weight::zero(),
+			)
+		};
+
+		assert_noop!(approve(1, 1, vec![(1, 1), (2, 1)]), Error::<Test>::MinimumThreshold);
+		assert_noop!(approve(1, 2, vec![(1, 2)]), Error::<Test>::TooFewSignatories);
+		assert_noop!(
+			approve(1, 2, vec![(1, 1), (2, 1), (3, 1), (4, 1)]),
+			Error::<Test>::TooManySignatories
+		);
+		assert_noop!(approve(1, 2, vec![(2, 1), (1, 1)]), Error::<Test>::SignatoriesOutOfOrder);
+		assert_noop!(approve(1, 2, vec![(1, 1), (1, 1)]), Error::<Test>::SignatoriesOutOfOrder);
+		assert_noop!(approve(1, 2, vec![(1, 2), (2, 0)]), Error::<Test>::ZeroWeight);
+		assert_noop!(approve(3, 2, vec![(1, 1), (2, 1)]), Error::<Test>::SenderNotInSignatories);
+		assert_noop!(approve(1, 4, vec![(1, 2), (2, 1)]), Error::<Test>::UnreachableThreshold);
+		assert_ok!(approve(1, 3, vec![(1, 2), (2, 1)]));
+	});
+}
@@ -57,6 +57,14 @@ pub trait WeightInfo {
 	fn approve_as_multi_create(s: u32, ) -> Weight;
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
+	fn poke_deposit(s: u32, ) -> Weight;
+	fn as_multi_weighted_create(s: u32, z: u32, ) -> Weight;
+	fn as_multi_weighted_approve(s: u32, z: u32, ) -> Weight;
+	fn as_multi_weighted_complete(s: u32, z: u32, ) -> Weight;
+	fn approve_as_multi_weighted_create(s: u32, ) -> Weight;
+	fn approve_as_multi_weighted_approve(s: u32, ) -> Weight;
+	fn cancel_as_multi_weighted(s: u32, ) -> Weight;
+	fn poke_deposit_weighted(s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_multisig` using the Substrate node and recommended hardware.
@@ -176,6 +184,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Not benchmarked yet: it reads and writes the same multisig as `cancel_as_multi`, without
+		// removing it.
+		Self::cancel_as_multi(s)
+	}
+	fn as_multi_weighted_create(s: u32, z: u32, ) -> Weight {
+		// Not benchmarked yet: the threshold is checked against the sum of the approval weights,
+		// which costs no more than counting the approvals in `as_multi_create`.
+		Self::as_multi_create(s, z)
+	}
+	fn as_multi_weighted_approve(s: u32, z: u32, ) -> Weight {
+		// Not benchmarked yet: same as `as_multi_approve`, the weight of the approver is looked up
+		// in the signatories that are already read.
+		Self::as_multi_approve(s, z)
+	}
+	fn as_multi_weighted_complete(s: u32, z: u32, ) -> Weight {
+		// Not benchmarked yet: same as `as_multi_complete`, the multisig that is removed also holds
+		// the weights of its signatories.
+		Self::as_multi_complete(s, z)
+	}
+	fn approve_as_multi_weighted_create(s: u32, ) -> Weight {
+		// Not benchmarked yet: priced as `approve_as_multi_create`, which stores a multisig of the
+		// same bounded size.
+		Self::approve_as_multi_create(s)
+	}
+	fn approve_as_multi_weighted_approve(s: u32, ) -> Weight {
+		// Not benchmarked yet: priced as `approve_as_multi_approve`, the weights of the approvals
+		// are summed in memory.
+		Self::approve_as_multi_approve(s)
+	}
+	fn cancel_as_multi_weighted(s: u32, ) -> Weight {
+		// Not benchmarked yet: removing a weighted multisig is the same work as in
+		// `cancel_as_multi`.
+		Self::cancel_as_multi(s)
+	}
+	fn poke_deposit_weighted(s: u32, ) -> Weight {
+		// Not benchmarked yet: a weighted multisig is poked like any other, see `poke_deposit`.
+		Self::poke_deposit(s)
+	}
 }
 
 // For backwards compatibility and tests.
@@ -294,4 +341,43 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	fn poke_deposit(s: u32, ) -> Weight {
+		// Not benchmarked yet: it reads and writes the same multisig as `cancel_as_multi`, without
+		// removing it.
+		Self::cancel_as_multi(s)
+	}
+	fn as_multi_weighted_create(s: u32, z: u32, ) -> Weight {
+		// Not benchmarked yet: the threshold is checked against the sum of the approval weights,
+		// which costs no more than counting the approvals in `as_multi_create`.
+		Self::as_multi_create(s, z)
+	}
+	fn as_multi_weighted_approve(s: u32, z: u32, ) -> Weight {
+		// Not benchmarked yet: same as `as_multi_approve`, the weight of the approver is looked up
+		// in the signatories that are already read.
+		Self::as_multi_approve(s, z)
+	}
+	fn as_multi_weighted_complete(s: u32, z: u32, ) -> Weight {
+		// Not benchmarked yet: same as `as_multi_complete`, the multisig that is removed also holds
+		// the weights of its signatories.
+		Self::as_multi_complete(s, z)
+	}
+	fn approve_as_multi_weighted_create(s: u32, ) -> Weight {
+		// Not benchmarked yet: priced as `approve_as_multi_create`, which stores a multisig of the
+		// same bounded size.
+		Self::approve_as_multi_create(s)
+	}
+	fn approve_as_multi_weighted_approve(s: u32, ) -> Weight {
+		// Not benchmarked yet: priced as `approve_as_multi_approve`, the weights of the approvals
+		// are summed in memory.
+		Self::approve_as_multi_approve(s)
+	}
+	fn cancel_as_multi_weighted(s: u32, ) -> Weight {
+		// Not benchmarked yet: removing a weighted multisig is the same work as in
+		// `cancel_as_multi`.
+		Self::cancel_as_multi(s)
+	}
+	fn poke_deposit_weighted(s: u32, ) -> Weight {
+		// Not benchmarked yet: a weighted multisig is poked like any other, see `poke_deposit`.
+		Self::poke_deposit(s)
+	}
 }
\ No newline at end of file
@@ -31,10 +31,12 @@ use frame_support::{
 use frame_system::RawOrigin as RuntimeOrigin;
 use pallet_nomination_pools::{
 	adapter::{Member, Pool, StakeStrategy, StakeStrategyType},
-	BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
-	Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp, GlobalMaxCommission,
-	MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond,
-	Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools, SubPoolsStorage,
+	AutoNominatingPools, AutoNominationRound, BalanceOf, BondExtra, BondedPoolInner, BondedPools,
+	ClaimPermission, ClaimPermissions, Commission, CommissionChangeRate, CommissionClaimPermission,
+	ConfigOp, GlobalMaxCommission, MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata,
+	MinCreateBond, MinJoinBond, Pallet as Pools, PointsTransferPermission,
+	PointsTransferPermissions, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools,
+	SelectValidators, SubPoolsStorage,
 };
 use pallet_staking::MaxNominationsOf;
 use sp_runtime::{
@@ -1187,5 +1189,91 @@ mod benchmarks {
 		assert_eq!(PoolMembers::<T>::get(&depositor).unwrap().total_balance(), deposit_amount);
 	}
 
+	#[benchmark]
+	fn set_auto_nominate() {
+		// Create a pool
+		let (depositor, _pool_account) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+
+		whitelist_account!(depositor);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor.clone()), 1, true);
+
+		assert!(AutoNominatingPools::<T>::contains_key(1));
+	}
+
+	#[benchmark]
+	fn auto_nominate(
+		n: Linear<1, { <T as pallet_nomination_pools::Config>::MaxAutoNominations::get() }>,
+	) {
+		// Create a pool
+		let (_depositor, pool_account) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+
+		// Like in `nominate`, the selected accounts don't need to be actual validators.
+		let validators: Vec<_> = (0..n).map(|i| account("stash", USER_SEED, i)).collect();
+		let round = AutoNominationRound::<T> {
+			era: T::StakeAdapter::current_era(),
+			candidates: validators.clone().try_into().unwrap(),
+			last_pool: None,
+			completed: false,
+		};
+
+		#[block]
+		{
+			Pools::<T>::auto_nominate_pool(1, &round);
+		}
+
+		let targets = <T as pallet_nomination_pools::Config>::ValidatorSelector::targets_of(
+			1,
+			&validators,
+			n,
+		);
+		assert_eq!(T::StakeAdapter::nominations(Pool::from(pool_account)), Some(targets));
+	}
+
+	#[benchmark]
+	fn transfer_points() {
+		// Create a pool
+		let min_create_bond = Pools::<T>::depositor_min_bond();
+		let (_depositor, _pool_account) = create_pool_account::<T>(0, min_create_bond, None);
+
+		// Join pool
+		let min_join_bond = MinJoinBond::<T>::get().max(CurrencyOf::<T>::minimum_balance());
+		let joiner = create_funded_user_with_balance::<T>("joiner", 0, min_join_bond * 4u32.into());
+		Pools::<T>::join(
+			RuntimeOrigin::Signed(joiner.clone()).into(),
+			min_join_bond * 2u32.into(),
+			1,
+		)
+		.unwrap();
+
+		// Transfer half of the points to a new account, keeping both accounts in the pool.
+		let receiver: T::AccountId = account("receiver", USER_SEED, 0);
+		let receiver_lookup = T::Lookup::unlookup(receiver.clone());
+		whitelist_account!(joiner);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(joiner.clone()), receiver_lookup, min_join_bond);
+
+		assert_eq!(PoolMembers::<T>::get(&joiner).unwrap().points, min_join_bond);
+		assert_eq!(PoolMembers::<T>::get(&receiver).unwrap().points, min_join_bond);
+	}
+
+	#[benchmark]
+	fn set_points_transfer_permission() {
+		// Create a pool
+		let (depositor, _pool_account) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+
+		whitelist_account!(depositor);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor.clone()), 1, PointsTransferPermission::Disabled);
+
+		assert_eq!(PointsTransferPermissions::<T>::get(1), PointsTransferPermission::Disabled);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
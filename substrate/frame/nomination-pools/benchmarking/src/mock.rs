@@ -139,6 +139,9 @@ impl pallet_nomination_pools::Config for Runtime {
 	type PalletId = PoolsPalletId;
 	type MaxPointsToBalance = MaxPointsToBalance;
 	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ValidatorSelector = ();
+	type MaxAutoNominations = ConstU32<16>;
+	type MaxAutoNominationCandidates = ConstU32<16>;
 }
 
 parameter_types! {
@@ -153,6 +156,7 @@ impl pallet_delegated_staking::Config for Runtime {
 	type SlashRewardFraction = SlashRewardFraction;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type CoreStaking = Staking;
+	type WeightInfo = ();
 }
 
 impl crate::Config for Runtime {}
@@ -87,6 +87,30 @@
 //! For design docs see the [bonded pool](#bonded-pool) and [unbonding sub
 //! pools](#unbonding-sub-pools) sections.
 //!
+//! ### Transfer points
+//!
+//! Instead of leaving, a member can move all or a portion of their active points, along with the
+//! pending rewards of these points, to another account with [`Call::transfer_points`]. The other
+//! account becomes a member of the same pool without any unbonding period, which is useful for key
+//! rotations. The root and bouncer of a pool can disable this for their pool with
+//! [`Call::set_points_transfer_permission`].
+//!
+//! ### Governance
+//!
+//! When the pool uses [`adapter::DelegateStake`], the funds of a member are delegated to the pool
+//! but remain held in the account of the member. They are thus part of the total balance of the
+//! member, with which they can vote, or delegate their votes, in `pallet-conviction-voting`, just
+//! like a direct nominator can vote with their bonded funds.
+//!
+//! The lock of a vote applies to the account of the member, and thus to their delegated funds
+//! alike. Funds that are withdrawn with [`Call::withdraw_unbonded`] are released into the account
+//! of the member, where they stay frozen until the vote lock expires.
+//!
+//! Members of a pool using [`adapter::TransferStake`] cannot vote with their pooled funds, since
+//! those are transferred to the pool account. Once the pool is migrated with
+//! [`Call::migrate_pool_to_delegate_stake`], each member can claim their delegation with
+//! [`Call::migrate_delegation`] and vote with it.
+//!
 //! ### Slashes
 //!
 //! Slashes are distributed evenly across the bonded pool and the unbonding pools from slash era+1
@@ -114,6 +138,12 @@
 //! [`Call::pool_withdraw_unbonded`] will withdraw any unbonding chunks of the pool bonded account.
 //! The latter call is permissionless and can be called by anyone at any time.
 //!
+//! Instead of maintaining the validator selection manually, the nominator or root of a pool can
+//! opt into auto-nomination with [`Call::set_auto_nominate`]. Once per era, the validators chosen
+//! by [`Config::ValidatorSelector`] are nominated on behalf of all auto-nominating pools, each pool
+//! nominating its own share of them. This happens in `on_idle`, in as many blocks as needed. While
+//! auto-nomination is enabled, [`Call::nominate`] cannot be used.
+//!
 //! To help facilitate pool administration the pool has one of three states (see [`PoolState`]):
 //!
 //! * Open: Anyone can join the pool and no members can be permissionlessly removed.
@@ -343,9 +373,8 @@
 //!
 //! ### Limitations
 //!
-//! * PoolMembers cannot vote with their staked funds because they are transferred into the pools
-//!   account. In the future this can be overcome by allowing the members to vote with their bonded
-//!   funds via vote splitting.
+//! * PoolMembers of pools using [`adapter::TransferStake`] cannot vote with their staked funds
+//!   because they are transferred into the pools account. See [governance](#governance).
 //! * PoolMembers cannot quickly transfer to another pool if they do no like nominations, instead
 //!   they must wait for the unbonding duration.
 
@@ -366,6 +395,7 @@ use frame_support::{
 		tokens::{Fortitude, Preservation},
 		Defensive, DefensiveOption, DefensiveResult, DefensiveSaturating, Get,
 	},
+	weights::WeightMeter,
 	DefaultNoBound, PalletError,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -446,6 +476,21 @@ pub enum BondExtra<Balance> {
 	Rewards,
 }
 
+/// The permission of the members of a pool to transfer their points to other accounts.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum PointsTransferPermission {
+	/// Members can transfer their points to any account.
+	Permissionless,
+	/// Members cannot transfer their points.
+	Disabled,
+}
+
+impl Default for PointsTransferPermission {
+	fn default() -> Self {
+		Self::Permissionless
+	}
+}
+
 /// The type of account being created.
 #[derive(Encode, Decode)]
 enum AccountType {
@@ -486,6 +531,62 @@ impl ClaimPermission {
 	}
 }
 
+/// Selects the validators nominated by pools that opted into auto-nomination.
+///
+/// See [`Call::set_auto_nominate`].
+pub trait SelectValidators<AccountId> {
+	/// Select at most `max` candidates to be nominated during `era`.
+	///
+	/// Returning no candidates leaves the nominations of the pools untouched.
+	fn select(era: EraIndex, max: u32) -> Vec<AccountId>;
+
+	/// Pick at most `max` of the `candidates` selected for the era to be nominated by `pool_id`.
+	///
+	/// By default, all pools nominate the first `max` candidates.
+	fn targets_of(_pool_id: PoolId, candidates: &[AccountId], max: u32) -> Vec<AccountId>
+	where
+		AccountId: Clone,
+	{
+		candidates.iter().take(max as usize).cloned().collect()
+	}
+
+	/// The weight of [`Self::select`].
+	fn weight(max: u32) -> Weight;
+}
+
+/// Selects no validators, effectively disabling auto-nomination.
+impl<AccountId> SelectValidators<AccountId> for () {
+	fn select(_era: EraIndex, _max: u32) -> Vec<AccountId> {
+		Vec::new()
+	}
+
+	fn weight(_max: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The progress of auto-nomination in some era.
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebugNoBound,
+	CloneNoBound,
+	frame_support::PartialEqNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct AutoNominationRound<T: Config> {
+	/// The era in which `candidates` were selected.
+	pub era: EraIndex,
+	/// The validators selected by [`Config::ValidatorSelector`] for `era`.
+	pub candidates: BoundedVec<T::AccountId, T::MaxAutoNominationCandidates>,
+	/// The last pool of [`AutoNominatingPools`] that was processed, if any.
+	pub last_pool: Option<PoolId>,
+	/// Whether all the pools of [`AutoNominatingPools`] have been processed.
+	pub completed: bool,
+}
+
 /// A member in a pool.
 #[derive(
 	Encode,
@@ -1093,6 +1194,10 @@ impl<T: Config> BondedPool<T> {
 		self.is_root(who) || self.is_bouncer(who)
 	}
 
+	fn can_set_points_transfer_permission(&self, who: &T::AccountId) -> bool {
+		self.is_root(who) || self.is_bouncer(who)
+	}
+
 	fn can_manage_commission(&self, who: &T::AccountId) -> bool {
 		self.is_root(who)
 	}
@@ -1650,6 +1755,20 @@ pub mod pallet {
 
 		/// The origin that can manage pool configurations.
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Selects the validators nominated by auto-nominating pools every era.
+		type ValidatorSelector: SelectValidators<Self::AccountId>;
+
+		/// The maximum number of validators auto-nominating pools nominate.
+		///
+		/// Setting this to zero disables auto-nomination.
+		#[pallet::constant]
+		type MaxAutoNominations: Get<u32>;
+
+		/// The maximum number of validators selected every era, out of which each auto-nominating
+		/// pool nominates at most [`Config::MaxAutoNominations`].
+		#[pallet::constant]
+		type MaxAutoNominationCandidates: Get<u32>;
 	}
 
 	/// The sum of funds across all pools.
@@ -1740,6 +1859,19 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// The permission of the members of a pool to transfer their points, if not the default.
+	#[pallet::storage]
+	pub type PointsTransferPermissions<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, PointsTransferPermission, ValueQuery>;
+
+	/// Pools whose nominations are recomputed every era by [`Config::ValidatorSelector`].
+	#[pallet::storage]
+	pub type AutoNominatingPools<T: Config> = StorageMap<_, Twox64Concat, PoolId, (), OptionQuery>;
+
+	/// The latest auto-nomination round.
+	#[pallet::storage]
+	pub type AutoNomination<T: Config> = StorageValue<_, AutoNominationRound<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -1865,6 +1997,20 @@ pub mod pallet {
 		MinBalanceDeficitAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
 		/// Claimed excess frozen ED of af the reward pool.
 		MinBalanceExcessAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
+		/// Auto-nomination has been enabled or disabled for a pool.
+		AutoNominationSet { pool_id: PoolId, enabled: bool },
+		/// A pool has nominated the validators selected for `era`.
+		PoolAutoNominated { pool_id: PoolId, era: EraIndex },
+		/// A member has transferred `points`, worth `balance`, to another account of their pool.
+		PointsTransferred {
+			pool_id: PoolId,
+			from: T::AccountId,
+			to: T::AccountId,
+			points: BalanceOf<T>,
+			balance: BalanceOf<T>,
+		},
+		/// The permission of the members of a pool to transfer their points has been set.
+		PointsTransferPermissionSet { pool_id: PoolId, permission: PointsTransferPermission },
 	}
 
 	#[pallet::error]
@@ -1952,6 +2098,12 @@ pub mod pallet {
 		NotMigrated,
 		/// This call is not allowed in the current state of the pallet.
 		NotSupported,
+		/// The nominations of the pool are managed by auto-nomination.
+		AutoNominating,
+		/// The member has a pending slash that must be applied first.
+		PendingSlash,
+		/// The members of the pool are not allowed to transfer their points.
+		PointsTransferDisabled,
 	}
 
 	#[derive(Encode, Decode, PartialEq, TypeInfo, PalletError, RuntimeDebug)]
@@ -2528,17 +2680,9 @@ pub mod pallet {
 			// ensure pool is not in an un-migrated state.
 			ensure!(!Self::api_pool_needs_delegate_migration(pool_id), Error::<T>::NotMigrated);
 			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
+			ensure!(!AutoNominatingPools::<T>::contains_key(pool_id), Error::<T>::AutoNominating);
 
-			let depositor_points = PoolMembers::<T>::get(&bonded_pool.roles.depositor)
-				.ok_or(Error::<T>::PoolMemberNotFound)?
-				.active_points();
-
-			ensure!(
-				bonded_pool.points_to_balance(depositor_points) >= Self::depositor_min_bond(),
-				Error::<T>::MinimumBondNotMet
-			);
-
-			T::StakeAdapter::nominate(Pool::from(bonded_pool.bonded_account()), validators)
+			Self::do_nominate(&bonded_pool, validators)
 		}
 
 		/// Set a new state for the pool.
@@ -3089,10 +3233,213 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Enable or disable auto-nomination for a pool.
+		///
+		/// The dispatch origin of this call must be signed by the pool nominator or the pool
+		/// root role.
+		///
+		/// Once enabled, the pool nominates the validators selected by
+		/// [`Config::ValidatorSelector`] once per era. This might only happen in the next era if
+		/// the validators of the current era were already nominated by the other auto-nominating
+		/// pools. Disabling it keeps the current nominations of the pool.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_auto_nominate())]
+		pub fn set_auto_nominate(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			// ensure pool is not in an un-migrated state.
+			ensure!(!Self::api_pool_needs_delegate_migration(pool_id), Error::<T>::NotMigrated);
+			ensure!(bonded_pool.can_nominate(&who), Error::<T>::NotNominator);
+
+			if enabled {
+				ensure!(T::MaxAutoNominations::get() > 0, Error::<T>::NotSupported);
+				ensure!(!bonded_pool.is_destroying(), Error::<T>::CanNotChangeState);
+				AutoNominatingPools::<T>::insert(pool_id, ());
+			} else {
+				AutoNominatingPools::<T>::remove(pool_id);
+			}
+
+			Self::deposit_event(Event::<T>::AutoNominationSet { pool_id, enabled });
+			Ok(())
+		}
+
+		/// Transfer `points` of the active points of the origin to `member_account`.
+		///
+		/// `member_account` must not be a member of any pool. It becomes a member of the pool of
+		/// the origin, and is entitled to the pending rewards of the transferred points. No
+		/// unbonding is involved, which makes this suitable for custody migrations and key
+		/// rotations.
+		///
+		/// # Conditions
+		///
+		/// * The pool must be open. If the origin transfers all of its points and is not unbonding,
+		///   and thus leaves the pool, the pool can also be blocked.
+		/// * Both accounts must keep at least [`MinJoinBond`] in the pool, or
+		///   [`Pallet::depositor_min_bond`] if the origin is the depositor. The depositor can never
+		///   transfer all of its points.
+		/// * The origin must not have any pending slash.
+		/// * The [`PointsTransferPermission`] of the pool must allow it.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::transfer_points())]
+		pub fn transfer_points(
+			origin: OriginFor<T>,
+			member_account: AccountIdLookupOf<T>,
+			#[pallet::compact] points: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let member_account = T::Lookup::lookup(member_account)?;
+			// ensure member is not in an un-migrated state.
+			ensure!(
+				!Self::api_member_needs_delegate_migration(who.clone()),
+				Error::<T>::NotMigrated
+			);
+			// If a member already exists that means they already belong to a pool
+			ensure!(
+				!PoolMembers::<T>::contains_key(&member_account),
+				Error::<T>::AccountBelongsToOtherPool
+			);
+
+			let (mut member, mut bonded_pool, _) = Self::get_member_with_pools(&who)?;
+			ensure!(
+				PointsTransferPermissions::<T>::get(member.pool_id) ==
+					PointsTransferPermission::Permissionless,
+				Error::<T>::PointsTransferDisabled
+			);
+			ensure!(
+				Self::member_pending_slash(Member::from(who.clone()), member.clone())?.is_zero(),
+				Error::<T>::PendingSlash
+			);
+
+			let active_points = member.active_points();
+			ensure!(!points.is_zero() && points <= active_points, Error::<T>::MinimumBondNotMet);
+			let is_depositor = who == bonded_pool.roles.depositor;
+			let is_full_transfer = points == active_points;
+			let leaves = is_full_transfer && member.unbonding_eras.is_empty();
+
+			match bonded_pool.state {
+				PoolState::Open => (),
+				PoolState::Blocked => ensure!(leaves, Error::<T>::NotOpen),
+				PoolState::Destroying => return Err(Error::<T>::NotOpen.into()),
+			}
+
+			// both accounts must comply with the balance condition.
+			let balance = bonded_pool.points_to_balance(points);
+			ensure!(balance >= MinJoinBond::<T>::get(), Error::<T>::MinimumBondNotMet);
+			if is_depositor {
+				ensure!(!is_full_transfer, Error::<T>::MinimumBondNotMet);
+			}
+			ensure!(
+				is_full_transfer ||
+					bonded_pool.points_to_balance(active_points.saturating_sub(points)) >=
+						if is_depositor {
+							Self::depositor_min_bond()
+						} else {
+							MinJoinBond::<T>::get()
+						},
+				Error::<T>::MinimumBondNotMet
+			);
+
+			if !leaves {
+				bonded_pool.try_inc_members()?;
+			}
+
+			// a leaving member transfers all of its delegation so that none is left dangling.
+			let transferred = if leaves {
+				T::StakeAdapter::member_delegation_balance(Member::from(who.clone()))
+					.unwrap_or(balance)
+			} else {
+				balance
+			};
+			T::StakeAdapter::member_transfer(
+				Pool::from(bonded_pool.bonded_account()),
+				Member::from(who.clone()),
+				Member::from(member_account.clone()),
+				transferred,
+			)?;
+
+			let pool_id = member.pool_id;
+			PoolMembers::<T>::insert(
+				&member_account,
+				PoolMember::<T> {
+					pool_id,
+					points,
+					// keeping the reward counter of the origin carries over the pending rewards of
+					// the transferred points.
+					last_recorded_reward_counter: member.last_recorded_reward_counter,
+					unbonding_eras: Default::default(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::PointsTransferred {
+				pool_id,
+				from: who.clone(),
+				to: member_account,
+				points,
+				balance,
+			});
+
+			if leaves {
+				// remove any `ClaimPermission` associated with the member.
+				ClaimPermissions::<T>::remove(&who);
+				PoolMembers::<T>::remove(&who);
+
+				Self::deposit_event(Event::<T>::MemberRemoved {
+					pool_id,
+					member: who,
+					released_balance: Zero::zero(),
+				});
+			} else {
+				member.points = member.points.saturating_sub(points);
+				PoolMembers::<T>::insert(&who, member);
+				bonded_pool.put();
+			}
+
+			Ok(())
+		}
+
+		/// Set the permission of the members of a pool to transfer their points with
+		/// [`Call::transfer_points`].
+		///
+		/// The dispatch origin of this call must be signed by the bouncer or the root role of the
+		/// pool.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::set_points_transfer_permission())]
+		pub fn set_points_transfer_permission(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			permission: PointsTransferPermission,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(
+				bonded_pool.can_set_points_transfer_permission(&who),
+				Error::<T>::DoesNotHavePermission
+			);
+
+			if permission == PointsTransferPermission::default() {
+				PointsTransferPermissions::<T>::remove(pool_id);
+			} else {
+				PointsTransferPermissions::<T>::insert(pool_id, permission);
+			}
+
+			Self::deposit_event(Event::<T>::PointsTransferPermissionSet { pool_id, permission });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			Self::do_auto_nominate(&mut meter);
+			meter.consumed()
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
 			Self::do_try_state(u8::MAX)
@@ -3109,6 +3456,10 @@ pub mod pallet {
 				so a slash can be applied to relevant unbonding pools. (We assume /
 				the bonding duration > slash deffer duration.",
 			);
+			assert!(
+				T::MaxAutoNominationCandidates::get() >= T::MaxAutoNominations::get(),
+				"Auto-nominating pools must be able to nominate as many validators as allowed",
+			);
 		}
 	}
 }
@@ -3127,6 +3478,114 @@ impl<T: Config> Pallet<T> {
 			.max(MinJoinBond::<T>::get())
 			.max(T::Currency::minimum_balance())
 	}
+
+	/// Nominate `validators` on behalf of `bonded_pool`, provided that its depositor has enough
+	/// stake to nominate.
+	fn do_nominate(bonded_pool: &BondedPool<T>, validators: Vec<T::AccountId>) -> DispatchResult {
+		let depositor_points = PoolMembers::<T>::get(&bonded_pool.roles.depositor)
+			.ok_or(Error::<T>::PoolMemberNotFound)?
+			.active_points();
+
+		ensure!(
+			bonded_pool.points_to_balance(depositor_points) >= Self::depositor_min_bond(),
+			Error::<T>::MinimumBondNotMet
+		);
+
+		T::StakeAdapter::nominate(Pool::from(bonded_pool.bonded_account()), validators)
+	}
+
+	/// Nominate the validators selected by [`Config::ValidatorSelector`] on behalf of the
+	/// [`AutoNominatingPools`], as far as `meter` allows.
+	///
+	/// Validators are selected once per era, in the first call happening in that era. Pools are
+	/// then processed in storage order, resuming from the last processed pool in the following
+	/// calls, until all of them nominated the selected validators.
+	pub(crate) fn do_auto_nominate(meter: &mut WeightMeter) {
+		// read `AutoNomination`, the current era and the first auto-nominating pool.
+		if meter.try_consume(T::DbWeight::get().reads(3)).is_err() {
+			return
+		}
+
+		let era = T::StakeAdapter::current_era();
+		let mut round = match AutoNomination::<T>::get() {
+			Some(round) if round.era >= era => round,
+			_ => {
+				if AutoNominatingPools::<T>::iter_keys().next().is_none() {
+					return
+				}
+
+				let max = T::MaxAutoNominationCandidates::get();
+				let selection_weight =
+					T::ValidatorSelector::weight(max).saturating_add(T::DbWeight::get().writes(1));
+				if meter.try_consume(selection_weight).is_err() {
+					return
+				}
+
+				let candidates = BoundedVec::truncate_from(T::ValidatorSelector::select(era, max));
+				AutoNominationRound { era, candidates, last_pool: None, completed: false }
+			},
+		};
+
+		if round.completed {
+			return
+		}
+
+		let targets = (round.candidates.len() as u32).min(T::MaxAutoNominations::get());
+		let pool_weight = T::WeightInfo::auto_nominate(targets);
+		let mut pools = match round.last_pool {
+			Some(last) => AutoNominatingPools::<T>::iter_keys_from(
+				AutoNominatingPools::<T>::hashed_key_for(last),
+			),
+			None => AutoNominatingPools::<T>::iter_keys(),
+		};
+
+		while meter.try_consume(pool_weight).is_ok() {
+			match pools.next() {
+				Some(pool_id) => {
+					Self::auto_nominate_pool(pool_id, &round);
+					round.last_pool = Some(pool_id);
+				},
+				None => {
+					round.last_pool = None;
+					round.completed = true;
+					break
+				},
+			}
+		}
+
+		AutoNomination::<T>::put(round);
+	}
+
+	/// Nominate the targets picked by [`Config::ValidatorSelector`] out of the candidates of
+	/// `round` on behalf of the pool `pool_id`.
+	///
+	/// Pools that cannot nominate, for example because their depositor does not have enough
+	/// stake, are skipped. Nothing happens if no targets are picked.
+	pub fn auto_nominate_pool(pool_id: PoolId, round: &AutoNominationRound<T>) {
+		let max = T::MaxAutoNominations::get();
+		let mut targets = T::ValidatorSelector::targets_of(pool_id, &round.candidates, max);
+		targets.truncate(max as usize);
+		if targets.is_empty() {
+			return
+		}
+
+		let Some(bonded_pool) = BondedPool::<T>::get(pool_id) else {
+			defensive!("auto-nominating pools are removed with their bonded pool");
+			AutoNominatingPools::<T>::remove(pool_id);
+			return
+		};
+
+		if bonded_pool.is_destroying() || Self::api_pool_needs_delegate_migration(pool_id) {
+			return
+		}
+
+		match Self::do_nominate(&bonded_pool, targets) {
+			Ok(()) =>
+				Self::deposit_event(Event::<T>::PoolAutoNominated { pool_id, era: round.era }),
+			Err(e) => log!(debug, "pool {} could not auto-nominate: {:?}", pool_id, e),
+		}
+	}
+
 	/// Remove everything related to the given bonded pool.
 	///
 	/// Metadata and all of the sub-pools are also deleted. All accounts are dusted and the leftover
@@ -3189,6 +3648,8 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::<T>::Destroyed { pool_id: bonded_pool.id });
 		// Remove bonded pool metadata.
 		Metadata::<T>::remove(bonded_pool.id);
+		AutoNominatingPools::<T>::remove(bonded_pool.id);
+		PointsTransferPermissions::<T>::remove(bonded_pool.id);
 
 		bonded_pool.remove();
 	}
@@ -3705,6 +4166,14 @@ impl<T: Config> Pallet<T> {
 			Metadata::<T>::iter_keys().all(|k| bonded_pools.contains(&k)),
 			"`Metadata` keys must be a subset of the above superset."
 		);
+		ensure!(
+			AutoNominatingPools::<T>::iter_keys().all(|k| bonded_pools.contains(&k)),
+			"`AutoNominatingPools` keys must be a subset of the above superset."
+		);
+		ensure!(
+			PointsTransferPermissions::<T>::iter_keys().all(|k| bonded_pools.contains(&k)),
+			"`PointsTransferPermissions` keys must be a subset of the above superset."
+		);
 
 		ensure!(
 			MaxPools::<T>::get().map_or(true, |max| bonded_pools.len() <= (max as usize)),
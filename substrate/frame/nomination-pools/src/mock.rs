@@ -229,6 +229,11 @@ impl sp_staking::StakingInterface for StakingMock {
 		unimplemented!("method currently not used in testing")
 	}
 
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_slashing_spans(_who: &Self::AccountId, _spans: u32) {
+		unimplemented!("method currently not used in testing")
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn max_exposure_page_size() -> sp_staking::Page {
 		unimplemented!("method currently not used in testing")
@@ -281,6 +286,33 @@ parameter_types! {
 	pub static MaxMetadataLen: u32 = 2;
 	pub static CheckLevel: u8 = 255;
 	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub static SelectedValidators: Vec<AccountId> = vec![];
+	#[derive(Clone, PartialEq)]
+	pub static MaxAutoNominations: u32 = 3;
+	pub static MaxAutoNominationCandidates: u32 = 5;
+}
+
+pub struct ValidatorSelectorMock;
+impl SelectValidators<AccountId> for ValidatorSelectorMock {
+	fn select(_era: EraIndex, _max: u32) -> Vec<AccountId> {
+		SelectedValidators::get()
+	}
+
+	// every pool starts at a different candidate, wrapping around.
+	fn targets_of(pool_id: PoolId, candidates: &[AccountId], max: u32) -> Vec<AccountId> {
+		let count = candidates.len().min(max as usize);
+		candidates
+			.iter()
+			.cycle()
+			.skip(pool_id as usize - 1)
+			.take(count)
+			.copied()
+			.collect()
+	}
+
+	fn weight(_max: u32) -> Weight {
+		Weight::zero()
+	}
 }
 
 ord_parameter_types! {
@@ -302,6 +334,9 @@ impl pools::Config for Runtime {
 	type MaxUnbonding = MaxUnbonding;
 	type MaxPointsToBalance = frame_support::traits::ConstU8<10>;
 	type AdminOrigin = EnsureSignedBy<Admin, AccountId>;
+	type ValidatorSelector = ValidatorSelectorMock;
+	type MaxAutoNominations = MaxAutoNominations;
+	type MaxAutoNominationCandidates = MaxAutoNominationCandidates;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -20,7 +20,7 @@ use crate::{mock::*, Event};
 use frame_support::{assert_err, assert_noop, assert_ok, assert_storage_noop};
 use pallet_balances::Event as BEvent;
 use sp_runtime::{
-	bounded_btree_map,
+	bounded_btree_map, bounded_vec,
 	traits::{BadOrigin, Dispatchable},
 	FixedU128,
 };
@@ -4959,6 +4959,467 @@ mod nominate {
 	}
 }
 
+mod auto_nominate {
+	use super::*;
+
+	fn auto_nominate_all() {
+		Pools::on_idle(System::block_number(), Weight::MAX);
+	}
+
+	#[test]
+	fn set_auto_nominate_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// Depositor and bouncer can't toggle auto-nomination
+			assert_noop!(
+				Pools::set_auto_nominate(RuntimeOrigin::signed(10), 1, true),
+				Error::<Runtime>::NotNominator
+			);
+			assert_noop!(
+				Pools::set_auto_nominate(RuntimeOrigin::signed(902), 1, true),
+				Error::<Runtime>::NotNominator
+			);
+			assert_noop!(
+				Pools::set_auto_nominate(RuntimeOrigin::signed(901), 123, true),
+				Error::<Runtime>::PoolNotFound
+			);
+
+			// Nominator can enable it
+			assert_ok!(Pools::set_auto_nominate(RuntimeOrigin::signed(901), 1, true));
+			assert!(AutoNominatingPools::<Runtime>::contains_key(1));
+
+			// Nominations cannot be set manually anymore
+			assert_noop!(
+				Pools::nominate(RuntimeOrigin::signed(901), 1, vec![21]),
+				Error::<Runtime>::AutoNominating
+			);
+
+			// Root can disable it
+			assert_ok!(Pools::set_auto_nominate(RuntimeOrigin::signed(900), 1, false));
+			assert!(!AutoNominatingPools::<Runtime>::contains_key(1));
+			assert_ok!(Pools::nominate(RuntimeOrigin::signed(901), 1, vec![21]));
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 10, pool_id: 1 },
+					Event::Bonded { member: 10, pool_id: 1, bonded: 10, joined: true },
+					Event::AutoNominationSet { pool_id: 1, enabled: true },
+					Event::AutoNominationSet { pool_id: 1, enabled: false },
+				]
+			);
+
+			// Destroying pools cannot enable it
+			unsafe_set_state(1, PoolState::Destroying);
+			assert_noop!(
+				Pools::set_auto_nominate(RuntimeOrigin::signed(901), 1, true),
+				Error::<Runtime>::CanNotChangeState
+			);
+			unsafe_set_state(1, PoolState::Open);
+
+			// Cannot be enabled if the runtime does not allow any auto-nomination
+			MaxAutoNominations::set(0);
+			assert_noop!(
+				Pools::set_auto_nominate(RuntimeOrigin::signed(901), 1, true),
+				Error::<Runtime>::NotSupported
+			);
+		});
+	}
+
+	#[test]
+	fn auto_nominate_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// Given
+			SelectedValidators::set(vec![21, 31, 41, 51, 61, 71]);
+			assert_ok!(Pools::set_auto_nominate(RuntimeOrigin::signed(901), 1, true));
+			pool_events_since_last_call();
+
+			// When
+			auto_nominate_all();
+
+			// Then the candidates are truncated to `MaxAutoNominationCandidates` and the targets of
+			// the pool to `MaxAutoNominations`
+			assert_eq!(Nominations::get().unwrap(), vec![21, 31, 41]);
+			assert_eq!(
+				AutoNomination::<Runtime>::get().unwrap(),
+				AutoNominationRound {
+					era: 0,
+					candidates: bounded_vec![21, 31, 41, 51, 61],
+					last_pool: None,
+					completed: true
+				}
+			);
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PoolAutoNominated { pool_id: 1, era: 0 }]
+			);
+
+			// Validators are only selected once per era
+			SelectedValidators::set(vec![61]);
+			auto_nominate_all();
+			assert_eq!(Nominations::get().unwrap(), vec![21, 31, 41]);
+			assert_eq!(pool_events_since_last_call(), vec![]);
+
+			// When a new era starts
+			CurrentEra::set(1);
+			auto_nominate_all();
+
+			// Then
+			assert_eq!(Nominations::get().unwrap(), vec![61]);
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PoolAutoNominated { pool_id: 1, era: 1 }]
+			);
+
+			// Pools that disabled auto-nomination keep their nominations
+			assert_ok!(Pools::set_auto_nominate(RuntimeOrigin::signed(901), 1, false));
+			SelectedValidators::set(vec![71]);
+			CurrentEra::set(2);
+			auto_nominate_all();
+			assert_eq!(Nominations::get().unwrap(), vec![61]);
+		});
+	}
+
+	#[test]
+	fn auto_nominate_picks_the_targets_of_each_pool() {
+		ExtBuilder::default().build_and_execute(|| {
+			// Given two pools and the candidates of a round
+			Currency::set_balance(&20, 100);
+			assert_ok!(Pools::create(RuntimeOrigin::signed(20), 10, 20, 20, 20));
+			let round = AutoNominationRound::<Runtime> {
+				era: 0,
+				candidates: bounded_vec![21, 31, 41, 51],
+				last_pool: None,
+				completed: false,
+			};
+
+			// Then each pool nominates the targets the selector picked for it
+			Pools::auto_nominate_pool(1, &round);
+			assert_eq!(Nominations::get().unwrap(), vec![21, 31, 41]);
+			Pools::auto_nominate_pool(2, &round);
+			assert_eq!(Nominations::get().unwrap(), vec![31, 41, 51]);
+		});
+	}
+
+	#[test]
+	fn auto_nominate_skips_pools_that_cannot_nominate() {
+		ExtBuilder::default().build_and_execute(|| {
+			// Given
+			assert_ok!(Pools::set_auto_nominate(RuntimeOrigin::signed(901), 1, true));
+			pool_events_since_last_call();
+
+			// Nothing happens when no validators are selected
+			auto_nominate_all();
+			assert_eq!(Nominations::get(), None);
+			assert!(AutoNomination::<Runtime>::get().unwrap().completed);
+
+			// Depositor's stake is less than the `MinimumNominatorBond`
+			SelectedValidators::set(vec![21]);
+			StakingMinBond::set(20);
+			CurrentEra::set(1);
+			auto_nominate_all();
+			assert_eq!(Nominations::get(), None);
+			assert!(AutoNomination::<Runtime>::get().unwrap().completed);
+
+			// Pool is destroying
+			StakingMinBond::set(10);
+			unsafe_set_state(1, PoolState::Destroying);
+			CurrentEra::set(2);
+			auto_nominate_all();
+			assert_eq!(Nominations::get(), None);
+
+			assert_eq!(pool_events_since_last_call(), vec![]);
+		});
+	}
+
+	#[test]
+	fn auto_nominate_respects_weight_limit() {
+		ExtBuilder::default().build_and_execute(|| {
+			// Given two auto-nominating pools
+			Currency::set_balance(&20, 100);
+			assert_ok!(Pools::create(RuntimeOrigin::signed(20), 10, 20, 20, 20));
+			assert_ok!(Pools::set_auto_nominate(RuntimeOrigin::signed(901), 1, true));
+			assert_ok!(Pools::set_auto_nominate(RuntimeOrigin::signed(20), 2, true));
+			SelectedValidators::set(vec![21, 31]);
+			pool_events_since_last_call();
+
+			let pool_weight = <() as WeightInfo>::auto_nominate(2);
+
+			// Not enough weight to process any pool
+			Pools::on_idle(System::block_number(), pool_weight / 2);
+			assert_eq!(pool_events_since_last_call(), vec![]);
+			assert_eq!(AutoNomination::<Runtime>::get().unwrap().last_pool, None);
+
+			// When
+			Pools::on_idle(System::block_number(), pool_weight);
+
+			// Then a single pool is processed
+			let events = pool_events_since_last_call();
+			assert_eq!(events.len(), 1);
+			let Event::PoolAutoNominated { pool_id: first, era: 0 } = events[0] else {
+				panic!("unexpected event {:?}", events[0])
+			};
+			assert_eq!(AutoNomination::<Runtime>::get().unwrap().last_pool, Some(first));
+
+			// When
+			Pools::on_idle(System::block_number(), pool_weight);
+
+			// Then the other pool is processed
+			let second = if first == 1 { 2 } else { 1 };
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PoolAutoNominated { pool_id: second, era: 0 }]
+			);
+			assert!(!AutoNomination::<Runtime>::get().unwrap().completed);
+
+			// And the round completes on the next call
+			Pools::on_idle(System::block_number(), pool_weight);
+			assert_eq!(pool_events_since_last_call(), vec![]);
+			assert!(AutoNomination::<Runtime>::get().unwrap().completed);
+		});
+	}
+}
+
+mod transfer_points {
+	use super::*;
+
+	#[test]
+	fn transfer_points_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+
+			// When
+			assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(20), 21, 5));
+
+			// Then
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PointsTransferred {
+					pool_id: 1,
+					from: 20,
+					to: 21,
+					points: 5,
+					balance: 5
+				}]
+			);
+			assert_eq!(PoolMembers::<Runtime>::get(20).unwrap().points, 15);
+			assert_eq!(PoolMembers::<Runtime>::get(21).unwrap().points, 5);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 3);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().points, 30);
+
+			// When all points are transferred
+			assert_ok!(Pools::set_claim_permission(
+				RuntimeOrigin::signed(20),
+				ClaimPermission::PermissionlessAll
+			));
+			assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(20), 22, 15));
+
+			// Then the member leaves the pool
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::PointsTransferred {
+						pool_id: 1,
+						from: 20,
+						to: 22,
+						points: 15,
+						balance: 15
+					},
+					Event::MemberRemoved { pool_id: 1, member: 20, released_balance: 0 },
+				]
+			);
+			assert!(!PoolMembers::<Runtime>::contains_key(20));
+			assert!(!ClaimPermissions::<Runtime>::contains_key(20));
+			assert_eq!(PoolMembers::<Runtime>::get(22).unwrap().points, 15);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 3);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().points, 30);
+		});
+	}
+
+	#[test]
+	fn transfer_points_carries_pending_rewards() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			// Given the reward pool has earned 50 in rewards, i.e. 1 per point.
+			deposit_rewards(50);
+
+			// When
+			assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(40), 41, 20));
+			let _ = pool_events_since_last_call();
+
+			// Then both accounts receive the rewards of their points.
+			assert_ok!(Pools::claim_payout(RuntimeOrigin::signed(40)));
+			assert_ok!(Pools::claim_payout(RuntimeOrigin::signed(41)));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::PaidOut { member: 40, pool_id: 1, payout: 20 },
+					Event::PaidOut { member: 41, pool_id: 1, payout: 20 },
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_points_keeps_unbonding_points() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			// Given
+			assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, 10));
+
+			// When all active points are transferred
+			assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(20), 21, 10));
+
+			// Then the member is still unbonding
+			let member = PoolMembers::<Runtime>::get(20).unwrap();
+			assert_eq!(member.active_points(), 0);
+			assert_eq!(member.unbonding_points(), 10);
+			assert_eq!(PoolMembers::<Runtime>::get(21).unwrap().points, 10);
+			assert_eq!(BondedPool::<Runtime>::get(1).unwrap().member_counter, 3);
+		});
+	}
+
+	#[test]
+	fn transfer_points_checks() {
+		ExtBuilder::default()
+			.add_members(vec![(20, 20), (30, 30)])
+			.build_and_execute(|| {
+				// Not a member
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(21), 22, 5),
+					Error::<Runtime>::PoolMemberNotFound
+				);
+
+				// Receiver is already a member
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(20), 30, 5),
+					Error::<Runtime>::AccountBelongsToOtherPool
+				);
+
+				// Invalid amount of points
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(20), 21, 0),
+					Error::<Runtime>::MinimumBondNotMet
+				);
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(20), 21, 21),
+					Error::<Runtime>::MinimumBondNotMet
+				);
+
+				// Both accounts must keep `MinJoinBond`
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(20), 21, 1),
+					Error::<Runtime>::MinimumBondNotMet
+				);
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(20), 21, 19),
+					Error::<Runtime>::MinimumBondNotMet
+				);
+
+				// The depositor cannot leave nor go below the depositor min bond
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(10), 11, 10),
+					Error::<Runtime>::MinimumBondNotMet
+				);
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(10), 11, 2),
+					Error::<Runtime>::MinimumBondNotMet
+				);
+
+				// The pool is full
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(20), 21, 5),
+					Error::<Runtime>::MaxPoolMembers
+				);
+
+				// A blocked pool only allows the member to leave
+				unsafe_set_state(1, PoolState::Blocked);
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(30), 31, 5),
+					Error::<Runtime>::NotOpen
+				);
+				assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(30), 31, 30));
+
+				// A destroying pool does not allow any transfer
+				unsafe_set_state(1, PoolState::Destroying);
+				assert_noop!(
+					Pools::transfer_points(RuntimeOrigin::signed(20), 21, 20),
+					Error::<Runtime>::NotOpen
+				);
+			});
+	}
+
+	#[test]
+	fn set_points_transfer_permission_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			let _ = pool_events_since_last_call();
+
+			// Only the root and the bouncer can set the permission
+			assert_noop!(
+				Pools::set_points_transfer_permission(
+					RuntimeOrigin::signed(901),
+					1,
+					PointsTransferPermission::Disabled
+				),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+			assert_noop!(
+				Pools::set_points_transfer_permission(
+					RuntimeOrigin::signed(902),
+					123,
+					PointsTransferPermission::Disabled
+				),
+				Error::<Runtime>::PoolNotFound
+			);
+
+			// When the bouncer disables transfers
+			assert_ok!(Pools::set_points_transfer_permission(
+				RuntimeOrigin::signed(902),
+				1,
+				PointsTransferPermission::Disabled
+			));
+
+			// Then members cannot transfer their points
+			assert_eq!(
+				PointsTransferPermissions::<Runtime>::get(1),
+				PointsTransferPermission::Disabled
+			);
+			assert_noop!(
+				Pools::transfer_points(RuntimeOrigin::signed(20), 21, 5),
+				Error::<Runtime>::PointsTransferDisabled
+			);
+
+			// When the root enables them again
+			assert_ok!(Pools::set_points_transfer_permission(
+				RuntimeOrigin::signed(900),
+				1,
+				PointsTransferPermission::Permissionless
+			));
+
+			// Then the default permission is not stored and transfers work again
+			assert!(!PointsTransferPermissions::<Runtime>::contains_key(1));
+			assert_ok!(Pools::transfer_points(RuntimeOrigin::signed(20), 21, 5));
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::PointsTransferPermissionSet {
+						pool_id: 1,
+						permission: PointsTransferPermission::Disabled
+					},
+					Event::PointsTransferPermissionSet {
+						pool_id: 1,
+						permission: PointsTransferPermission::Permissionless
+					},
+					Event::PointsTransferred {
+						pool_id: 1,
+						from: 20,
+						to: 21,
+						points: 5,
+						balance: 5
+					},
+				]
+			);
+		});
+	}
+}
+
 mod set_state {
 	use super::*;
 
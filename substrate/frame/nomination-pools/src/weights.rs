@@ -75,6 +75,10 @@ pub trait WeightInfo {
 	fn apply_slash_fail() -> Weight;
 	fn pool_migrate() -> Weight;
 	fn migrate_delegation() -> Weight;
+	fn set_auto_nominate() -> Weight;
+	fn auto_nominate(n: u32, ) -> Weight;
+	fn transfer_points() -> Weight;
+	fn set_points_transfer_permission() -> Weight;
 }
 
 /// Weights for `pallet_nomination_pools` using the Substrate node and recommended hardware.
@@ -729,6 +733,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(37_038_000, 27847)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 	}
+	fn set_auto_nominate() -> Weight {
+		// Not benchmarked yet: one pool is read and one flag written, as in `set_state`.
+		Self::set_state()
+	}
+	/// Storage: `NominationPools::AutoNominatingPools` (r:1 w:0)
+	/// Proof: `NominationPools::AutoNominatingPools` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn auto_nominate(n: u32, ) -> Weight {
+		// Not benchmarked yet: the pool nominates `n` validators exactly as in `nominate`, after
+		// the next auto-nominating pool is read.
+		Self::nominate(n)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `NominationPools::PointsTransferPermissions` (r:1 w:0)
+	/// Proof: `NominationPools::PointsTransferPermissions` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn transfer_points() -> Weight {
+		// Not benchmarked yet: the receiving member is created and the stake delegated as in
+		// `join`; the sending member and the permission of the pool are read on top.
+		Self::join()
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_points_transfer_permission() -> Weight {
+		// Not benchmarked yet: like `set_state`, it reads the pool and writes a single value.
+		Self::set_state()
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1382,4 +1411,29 @@ impl WeightInfo for () {
 		Weight::from_parts(37_038_000, 27847)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 	}
-}
\ No newline at end of file
+	fn set_auto_nominate() -> Weight {
+		// Not benchmarked yet: one pool is read and one flag written, as in `set_state`.
+		Self::set_state()
+	}
+	/// Storage: `NominationPools::AutoNominatingPools` (r:1 w:0)
+	/// Proof: `NominationPools::AutoNominatingPools` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn auto_nominate(n: u32, ) -> Weight {
+		// Not benchmarked yet: the pool nominates `n` validators exactly as in `nominate`, after
+		// the next auto-nominating pool is read.
+		Self::nominate(n)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `NominationPools::PointsTransferPermissions` (r:1 w:0)
+	/// Proof: `NominationPools::PointsTransferPermissions` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn transfer_points() -> Weight {
+		// Not benchmarked yet: the receiving member is created and the stake delegated as in
+		// `join`; the sending member and the permission of the pool are read on top.
+		Self::join()
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_points_transfer_permission() -> Weight {
+		// Not benchmarked yet: like `set_state`, it reads the pool and writes a single value.
+		Self::set_state()
+	}
+}
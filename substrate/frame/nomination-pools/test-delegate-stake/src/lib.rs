@@ -32,6 +32,9 @@ use pallet_staking::{
 	CurrentEra, Error as StakingError, Event as StakingEvent, Payee, RewardDestination,
 };
 
+use pallet_conviction_voting::{
+	AccountVote, ClassLocksFor, Conviction, Error as ConvictionVotingError, Vote,
+};
 use pallet_delegated_staking::Event as DelegatedStakingEvent;
 
 use sp_runtime::{bounded_btree_map, traits::Zero, Perbill};
@@ -1703,3 +1706,88 @@ fn pool_no_dangling_delegation() {
 		assert_eq!(Balances::total_balance_on_hold(&charlie), 0);
 	});
 }
+
+fn aye(balance: Balance) -> AccountVote<Balance> {
+	AccountVote::Standard { vote: Vote { aye: true, conviction: Conviction::Locked1x }, balance }
+}
+
+fn ayes_of(poll: u8) -> Balance {
+	match Polls::get().get(&poll) {
+		Some(TestPollState::Ongoing(tally, _)) => tally.ayes,
+		_ => panic!("poll is not ongoing"),
+	}
+}
+
+#[test]
+fn pool_members_vote_with_delegated_stake() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 50, 10, 10, 10));
+
+		// the delegated funds of the members stay held in their own accounts.
+		assert_ok!(Pools::join(RuntimeOrigin::signed(20), 90, 1));
+		assert_ok!(Pools::join(RuntimeOrigin::signed(21), 50, 1));
+		assert_eq!(Balances::total_balance_on_hold(&20), 90);
+		assert_eq!(Balances::total_balance(&20), 100);
+
+		// 20 votes with their free and pooled funds, but not more.
+		assert_noop!(
+			ConvictionVoting::vote(RuntimeOrigin::signed(20), 0, aye(101)),
+			ConvictionVotingError::<Runtime>::InsufficientFunds
+		);
+		assert_ok!(ConvictionVoting::vote(RuntimeOrigin::signed(20), 0, aye(100)));
+		assert_eq!(ayes_of(0), 100);
+		assert_eq!(ClassLocksFor::<Runtime>::get(20).into_inner(), vec![(0, 100)]);
+		assert_eq!(Balances::usable_balance(&20), 0);
+
+		// 21 delegates their votes, including their pooled funds.
+		assert_ok!(ConvictionVoting::delegate(
+			RuntimeOrigin::signed(21),
+			0,
+			22,
+			Conviction::Locked1x,
+			100
+		));
+		assert_ok!(ConvictionVoting::vote(RuntimeOrigin::signed(22), 0, aye(10)));
+		assert_eq!(ayes_of(0), 100 + 10 + 100);
+
+		// 20 leaves the pool, the withdrawn funds are still locked by the vote.
+		assert_ok!(Pools::unbond(RuntimeOrigin::signed(20), 20, 90));
+		CurrentEra::<Runtime>::set(Some(BondingDuration::get()));
+		assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(20), 20, 0));
+		assert_eq!(Balances::total_balance_on_hold(&20), 0);
+		assert_eq!(Balances::free_balance(20), 100);
+		assert_eq!(Balances::usable_balance(&20), 0);
+
+		// once the poll is over and the lock expired, the funds can be unlocked.
+		Polls::set(vec![(0, TestPollState::Completed(1, true))].into_iter().collect());
+		System::set_block_number(12);
+		assert_ok!(ConvictionVoting::remove_vote(RuntimeOrigin::signed(20), Some(0), 0));
+		assert_ok!(ConvictionVoting::unlock(RuntimeOrigin::signed(20), 0, 20));
+		assert!(ClassLocksFor::<Runtime>::get(20).is_empty());
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(20), 30, 90));
+	})
+}
+
+#[test]
+fn legacy_pool_members_vote_after_migrating_delegation() {
+	new_test_ext().execute_with(|| {
+		LegacyAdapter::set(true);
+		assert_ok!(Pools::create(RuntimeOrigin::signed(10), 50, 10, 10, 10));
+		assert_ok!(Pools::join(RuntimeOrigin::signed(20), 90, 1));
+
+		// with `TransferStake`, the pooled funds are not in the account of the member.
+		assert_eq!(Balances::total_balance(&20), 10);
+		assert_noop!(
+			ConvictionVoting::vote(RuntimeOrigin::signed(20), 0, aye(100)),
+			ConvictionVotingError::<Runtime>::InsufficientFunds
+		);
+
+		// once the pool and the member delegation are migrated, the member can vote with them.
+		LegacyAdapter::set(false);
+		assert_ok!(Pools::migrate_pool_to_delegate_stake(RuntimeOrigin::signed(10), 1));
+		assert_ok!(Pools::migrate_delegation(RuntimeOrigin::signed(10), 20));
+		assert_eq!(Balances::total_balance(&20), 100);
+		assert_ok!(ConvictionVoting::vote(RuntimeOrigin::signed(20), 0, aye(100)));
+		assert_eq!(ayes_of(0), 100);
+	})
+}
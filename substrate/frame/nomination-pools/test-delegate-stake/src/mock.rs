@@ -15,15 +15,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use frame_election_provider_support::VoteWeight;
 use frame_support::{
 	assert_ok, derive_impl,
 	pallet_prelude::*,
 	parameter_types,
-	traits::{ConstU64, ConstU8, VariantCountOf},
+	traits::{ConstU64, ConstU8, PollStatus, Polling, TotalIssuanceOf, VariantCountOf},
 	PalletId,
 };
 use frame_system::EnsureRoot;
+use pallet_conviction_voting::{Tally, TallyOf};
 use pallet_nomination_pools::{
 	adapter::{Member, Pool, StakeStrategyType},
 	BondType,
@@ -36,7 +39,7 @@ use sp_runtime::{
 type AccountId = u128;
 type Nonce = u32;
 type BlockNumber = u64;
-type Balance = u128;
+pub(crate) type Balance = u128;
 
 pub(crate) type T = Runtime;
 
@@ -265,6 +268,9 @@ impl pallet_nomination_pools::Config for Runtime {
 	type MaxPointsToBalance = ConstU8<10>;
 	type PalletId = PoolsPalletId;
 	type AdminOrigin = EnsureRoot<AccountId>;
+	type ValidatorSelector = ();
+	type MaxAutoNominations = ConstU32<0>;
+	type MaxAutoNominationCandidates = ConstU32<0>;
 }
 
 parameter_types! {
@@ -279,7 +285,81 @@ impl pallet_delegated_staking::Config for Runtime {
 	type SlashRewardFraction = SlashRewardFraction;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type CoreStaking = Staking;
+	type WeightInfo = ();
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TestPollState {
+	Ongoing(TallyOf<Runtime>, u8),
+	Completed(BlockNumber, bool),
+}
+
+parameter_types! {
+	pub static Polls: BTreeMap<u8, TestPollState> =
+		vec![(0, TestPollState::Ongoing(Tally::from_parts(0, 0, 0), 0))].into_iter().collect();
+}
+
+pub struct TestPolls;
+impl Polling<TallyOf<Runtime>> for TestPolls {
+	type Index = u8;
+	type Votes = Balance;
+	type Moment = BlockNumber;
+	type Class = u8;
+
+	fn classes() -> Vec<u8> {
+		vec![0]
+	}
+
+	fn as_ongoing(index: u8) -> Option<(TallyOf<Runtime>, u8)> {
+		match Polls::get().remove(&index) {
+			Some(TestPollState::Ongoing(tally, class)) => Some((tally, class)),
+			_ => None,
+		}
+	}
+
+	fn access_poll<R>(
+		index: u8,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Runtime>, BlockNumber, u8>) -> R,
+	) -> R {
+		let mut polls = Polls::get();
+		let r = match polls.get_mut(&index) {
+			Some(TestPollState::Ongoing(ref mut tally, class)) =>
+				f(PollStatus::Ongoing(tally, *class)),
+			Some(TestPollState::Completed(when, approved)) =>
+				f(PollStatus::Completed(*when, *approved)),
+			None => f(PollStatus::None),
+		};
+		Polls::set(polls);
+		r
+	}
+
+	fn try_access_poll<R>(
+		index: u8,
+		f: impl FnOnce(PollStatus<&mut TallyOf<Runtime>, BlockNumber, u8>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		let mut polls = Polls::get();
+		let r = match polls.get_mut(&index) {
+			Some(TestPollState::Ongoing(ref mut tally, class)) =>
+				f(PollStatus::Ongoing(tally, *class)),
+			Some(TestPollState::Completed(when, approved)) =>
+				f(PollStatus::Completed(*when, *approved)),
+			None => f(PollStatus::None),
+		}?;
+		Polls::set(polls);
+		Ok(r)
+	}
 }
+
+impl pallet_conviction_voting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type Polls = TestPolls;
+	type MaxTurnout = TotalIssuanceOf<Balances, Self::AccountId>;
+	type MaxVotes = ConstU32<3>;
+	type VoteLockingPeriod = ConstU64<10>;
+}
+
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
 frame_support::construct_runtime!(
@@ -291,6 +371,7 @@ frame_support::construct_runtime!(
 		VoterList: pallet_bags_list::<Instance1>,
 		Pools: pallet_nomination_pools,
 		DelegatedStaking: pallet_delegated_staking,
+		ConvictionVoting: pallet_conviction_voting,
 	}
 );
 
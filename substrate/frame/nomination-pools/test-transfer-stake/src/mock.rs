@@ -145,6 +145,9 @@ impl pallet_nomination_pools::Config for Runtime {
 	type MaxPointsToBalance = ConstU8<10>;
 	type PalletId = PoolsPalletId;
 	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ValidatorSelector = ();
+	type MaxAutoNominations = ConstU32<0>;
+	type MaxAutoNominationCandidates = ConstU32<0>;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
@@ -21,7 +21,7 @@
 
 use super::*;
 use crate::Pallet as Proxy;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use frame::benchmarking::prelude::{
 	account, benchmarks, impl_test_function, whitelisted_caller, BenchmarkError, RawOrigin,
 };
@@ -341,5 +341,84 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn poke_deposit() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		add_proxies::<T>(T::MaxProxies::get(), Some(caller.clone()))?;
+		add_announcements::<T>(T::MaxPending::get(), Some(caller.clone()), None)?;
+
+		// Make both deposits outdated.
+		let (_, proxies_deposit) = Proxies::<T>::get(&caller);
+		let (_, announcements_deposit) = Announcements::<T>::get(&caller);
+		Proxies::<T>::mutate(&caller, |(_, deposit)| *deposit = Zero::zero());
+		Announcements::<T>::mutate(&caller, |(_, deposit)| *deposit = Zero::zero());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_eq!(Proxies::<T>::get(&caller).1, proxies_deposit);
+		assert_eq!(Announcements::<T>::get(&caller).1, announcements_deposit);
+		assert_last_event::<T>(
+			Event::DepositPoked {
+				who: caller,
+				kind: DepositKind::Announcements,
+				old_deposit: Zero::zero(),
+				new_deposit: announcements_deposit,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_custom_proxy_type(
+		c: Linear<1, { T::MaxCustomProxyCalls::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin = T::CustomProxyTypeOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let calls: BoundedVec<_, T::MaxCustomProxyCalls> = (0..c)
+			.map(|i| CallIndices { pallet_index: i as u8, call_index: Some(0) })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, calls);
+
+		assert!(CustomProxyTypes::<T>::contains_key(0));
+		assert_last_event::<T>(Event::CustomProxyTypeSet { id: 0 }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn custom_proxy_type_allows() -> Result<(), BenchmarkError> {
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let mut indices = CallIndicesOutput::default();
+		call.encode_to(&mut indices);
+		let [pallet_index, call_index] = indices.bytes;
+
+		// The worst case searches all the allowed calls, and only the last one matches.
+		let mut calls: Vec<_> = (1..T::MaxCustomProxyCalls::get())
+			.map(|_| CallIndices { pallet_index: pallet_index.wrapping_add(1), call_index: None })
+			.collect();
+		calls.push(CallIndices { pallet_index, call_index: Some(call_index) });
+		let calls: BoundedVec<_, T::MaxCustomProxyCalls> =
+			calls.try_into().map_err(|_| BenchmarkError::Weightless)?;
+		CustomProxyTypes::<T>::insert(0, calls);
+
+		let allowed;
+		#[block]
+		{
+			allowed = Proxy::<T>::custom_proxy_type_allows(0, &call);
+		}
+
+		assert!(allowed);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
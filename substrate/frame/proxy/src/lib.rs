@@ -23,6 +23,11 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! Besides the proxy types known at compile time, a runtime may expose custom proxy types, whose
+//! allowed calls are set on-chain by `CustomProxyTypeOrigin` through `set_custom_proxy_type`. To
+//! do so, its `ProxyType` needs a variant carrying the id of the custom proxy type, filtered with
+//! [`Pallet::custom_proxy_type_allows`].
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -36,6 +41,7 @@ pub mod weights;
 extern crate alloc;
 use alloc::{boxed::Box, vec};
 use frame::{
+	deps::codec::Output,
 	prelude::*,
 	traits::{Currency, ReservableCurrency},
 };
@@ -88,6 +94,42 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// A call, or all the calls of a pallet, allowed by a custom proxy type.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CallIndices {
+	/// The index of the pallet in the runtime.
+	pub pallet_index: u8,
+	/// The index of the call in the pallet, or `None` to allow all the calls of the pallet.
+	pub call_index: Option<u8>,
+}
+
+/// An [`Output`] keeping only the first two bytes of an encoded call, which are the index of its
+/// pallet followed by its own index in the pallet.
+///
+/// Used to get the indices of a call without allocating its whole encoding.
+#[derive(Default)]
+struct CallIndicesOutput {
+	bytes: [u8; 2],
+	len: usize,
+}
+
+impl Output for CallIndicesOutput {
+	fn write(&mut self, bytes: &[u8]) {
+		let n = bytes.len().min(self.bytes.len() - self.len);
+		self.bytes[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+		self.len += n;
+	}
+}
+
+/// The kind of deposit held by the pallet.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DepositKind {
+	/// The deposit held for the proxies of an account.
+	Proxies,
+	/// The deposit held for the announcements of a proxy.
+	Announcements,
+}
+
 #[frame::pallet]
 pub mod pallet {
 	use super::*;
@@ -169,6 +211,13 @@ pub mod pallet {
 
 		/// Provider for the block number. Normally this is the `frame_system` pallet.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// The origin which may set the calls allowed by custom proxy types.
+		type CustomProxyTypeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of call indices allowed by a single custom proxy type.
+		#[pallet::constant]
+		type MaxCustomProxyCalls: Get<u32>;
 	}
 
 	#[pallet::call]
@@ -188,6 +237,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy(T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // The filter of the proxy type may check a custom proxy type.
+				.saturating_add(T::WeightInfo::custom_proxy_type_allows())
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -480,6 +531,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // The filter of the proxy type may check a custom proxy type.
+				.saturating_add(T::WeightInfo::custom_proxy_type_allows())
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -508,6 +561,82 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Re-evaluate the deposits held for the proxies and the announcements of the sender.
+		///
+		/// The deposits are updated to the current values of `ProxyDepositBase`,
+		/// `ProxyDepositFactor`, `AnnouncementDepositBase` and `AnnouncementDepositFactor`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The transaction fee is waived if any deposit is changed.
+		///
+		/// Emits `DepositPoked` for each updated deposit.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::poke_deposit())]
+		pub fn poke_deposit(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut poked = false;
+
+			let (proxies, old_deposit) = Proxies::<T>::get(&who);
+			if let Some(new_deposit) = Self::poke(
+				&who,
+				DepositKind::Proxies,
+				old_deposit,
+				T::ProxyDepositBase::get(),
+				T::ProxyDepositFactor::get(),
+				proxies.len(),
+			)? {
+				Proxies::<T>::insert(&who, (proxies, new_deposit));
+				poked = true;
+			}
+
+			let (pending, old_deposit) = Announcements::<T>::get(&who);
+			if let Some(new_deposit) = Self::poke(
+				&who,
+				DepositKind::Announcements,
+				old_deposit,
+				T::AnnouncementDepositBase::get(),
+				T::AnnouncementDepositFactor::get(),
+				pending.len(),
+			)? {
+				Announcements::<T>::insert(&who, (pending, new_deposit));
+				poked = true;
+			}
+
+			Ok(if poked { Pays::No } else { Pays::Yes }.into())
+		}
+
+		/// Set the calls allowed by a custom proxy type.
+		///
+		/// The change applies to all the existing proxies of this type.
+		///
+		/// The dispatch origin for this call must be `CustomProxyTypeOrigin`.
+		///
+		/// Parameters:
+		/// - `id`: The id of the custom proxy type.
+		/// - `calls`: The calls allowed by the custom proxy type. If empty, the custom proxy type
+		/// is removed and allows no call anymore.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_custom_proxy_type(calls.len() as u32))]
+		pub fn set_custom_proxy_type(
+			origin: OriginFor<T>,
+			id: u32,
+			calls: BoundedVec<CallIndices, T::MaxCustomProxyCalls>,
+		) -> DispatchResult {
+			T::CustomProxyTypeOrigin::ensure_origin(origin)?;
+
+			if calls.is_empty() {
+				CustomProxyTypes::<T>::remove(id);
+				Self::deposit_event(Event::CustomProxyTypeRemoved { id });
+			} else {
+				CustomProxyTypes::<T>::insert(id, calls);
+				Self::deposit_event(Event::CustomProxyTypeSet { id });
+			}
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -539,6 +668,17 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: BlockNumberFor<T>,
 		},
+		/// A deposit held for proxies or announcements was updated.
+		DepositPoked {
+			who: T::AccountId,
+			kind: DepositKind,
+			old_deposit: BalanceOf<T>,
+			new_deposit: BalanceOf<T>,
+		},
+		/// The calls allowed by a custom proxy type were set.
+		CustomProxyTypeSet { id: u32 },
+		/// A custom proxy type was removed.
+		CustomProxyTypeRemoved { id: u32 },
 	}
 
 	#[pallet::error]
@@ -590,6 +730,11 @@ pub mod pallet {
 		),
 		ValueQuery,
 	>;
+
+	/// The calls allowed by each custom proxy type, keyed by the id of the custom proxy type.
+	#[pallet::storage]
+	pub type CustomProxyTypes<T: Config> =
+		StorageMap<_, Twox64Concat, u32, BoundedVec<CallIndices, T::MaxCustomProxyCalls>>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -749,6 +894,54 @@ impl<T: Config> Pallet<T> {
 		Ok(if len == 0 { None } else { Some(new_deposit) })
 	}
 
+	/// Update a deposit of `who` to the current `base` and `factor`, for `len` items.
+	///
+	/// Returns the new deposit if it changed.
+	fn poke(
+		who: &T::AccountId,
+		kind: DepositKind,
+		old_deposit: BalanceOf<T>,
+		base: BalanceOf<T>,
+		factor: BalanceOf<T>,
+		len: usize,
+	) -> Result<Option<BalanceOf<T>>, DispatchError> {
+		if len == 0 {
+			return Ok(None)
+		}
+
+		let new_deposit = Self::rejig_deposit(who, old_deposit, base, factor, len)?
+			.expect("len > 0; rejig_deposit returns Some; qed");
+		if new_deposit == old_deposit {
+			return Ok(None)
+		}
+
+		Self::deposit_event(Event::DepositPoked {
+			who: who.clone(),
+			kind,
+			old_deposit,
+			new_deposit,
+		});
+		Ok(Some(new_deposit))
+	}
+
+	/// Whether the custom proxy type `id` allows `call`.
+	///
+	/// Meant for the `InstanceFilter` implementation of the runtime's `ProxyType`. A custom proxy
+	/// type which was never set, or was removed, allows no call.
+	///
+	/// Its weight is charged by `proxy` and `proxy_announced` as
+	/// [`WeightInfo::custom_proxy_type_allows`]. The filter checking the calls nested in the
+	/// proxied call reads the allowed calls again, from the storage overlay.
+	pub fn custom_proxy_type_allows(id: u32, call: &<T as Config>::RuntimeCall) -> bool {
+		let Some(allowed) = CustomProxyTypes::<T>::get(id) else { return false };
+		let mut indices = CallIndicesOutput::default();
+		call.encode_to(&mut indices);
+		let [pallet_index, call_index] = indices.bytes;
+		allowed.iter().any(|a| {
+			a.pallet_index == pallet_index && a.call_index.map_or(true, |i| i == call_index)
+		})
+	}
+
 	fn edit_announcements<
 		F: FnMut(&Announcement<T::AccountId, CallHashOf<T>, BlockNumberFor<T>>) -> bool,
 	>(
@@ -72,6 +72,7 @@ pub enum ProxyType {
 	Any,
 	JustTransfer,
 	JustUtility,
+	Custom(u32),
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -89,6 +90,7 @@ impl frame::traits::InstanceFilter<RuntimeCall> for ProxyType {
 				)
 			},
 			ProxyType::JustUtility => matches!(c, RuntimeCall::Utility { .. }),
+			ProxyType::Custom(id) => Proxy::custom_proxy_type_allows(*id, c),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -106,20 +108,26 @@ impl Contains<RuntimeCall> for BaseFilter {
 		}
 	}
 }
+parameter_types! {
+	pub static ProxyDepositBase: u64 = 1;
+	pub static AnnouncementDepositFactor: u64 = 1;
+}
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
 	type ProxyType = ProxyType;
-	type ProxyDepositBase = ConstU64<1>;
+	type ProxyDepositBase = ProxyDepositBase;
 	type ProxyDepositFactor = ConstU64<1>;
 	type MaxProxies = ConstU32<4>;
 	type WeightInfo = ();
 	type CallHasher = BlakeTwo256;
 	type MaxPending = ConstU32<2>;
 	type AnnouncementDepositBase = ConstU64<1>;
-	type AnnouncementDepositFactor = ConstU64<1>;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type CustomProxyTypeOrigin = EnsureRoot<u64>;
+	type MaxCustomProxyCalls = ConstU32<2>;
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -584,3 +592,130 @@ fn pure_works() {
 		);
 	});
 }
+
+#[test]
+fn poke_deposit_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 1));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 1));
+		assert_ok!(Proxy::announce(RuntimeOrigin::signed(3), 1, [1; 32].into()));
+		assert_eq!(Proxies::<Test>::get(1).1, 3);
+		assert_eq!(Announcements::<Test>::get(3).1, 2);
+
+		// Nothing to update, the fee is paid.
+		let post_info = Proxy::poke_deposit(RuntimeOrigin::signed(1)).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::Yes);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Proxy(ProxyEvent::DepositPoked { .. })
+		)));
+
+		// The proxy deposit increases.
+		ProxyDepositBase::set(2);
+		let post_info = Proxy::poke_deposit(RuntimeOrigin::signed(1)).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		System::assert_last_event(
+			ProxyEvent::DepositPoked {
+				who: 1,
+				kind: DepositKind::Proxies,
+				old_deposit: 3,
+				new_deposit: 4,
+			}
+			.into(),
+		);
+		assert_eq!(Proxies::<Test>::get(1).1, 4);
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		// The announcement deposit decreases.
+		AnnouncementDepositFactor::set(0);
+		let post_info = Proxy::poke_deposit(RuntimeOrigin::signed(3)).unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		System::assert_last_event(
+			ProxyEvent::DepositPoked {
+				who: 3,
+				kind: DepositKind::Announcements,
+				old_deposit: 2,
+				new_deposit: 1,
+			}
+			.into(),
+		);
+		assert_eq!(Announcements::<Test>::get(3).1, 1);
+		assert_eq!(Balances::reserved_balance(3), 1);
+
+		// The deposit can't be increased without enough balance.
+		ProxyDepositBase::set(10);
+		assert_noop!(
+			Proxy::poke_deposit(RuntimeOrigin::signed(1)),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn custom_proxy_type_works() {
+	new_test_ext().execute_with(|| {
+		let balances = <Balances as PalletInfoAccess>::index() as u8;
+		let utility = <Utility as PalletInfoAccess>::index() as u8;
+		// `transfer_allow_death` is the first call of `pallet_balances`.
+		let transfer = CallIndices { pallet_index: balances, call_index: Some(0) };
+		let calls: BoundedVec<_, _> = vec![transfer].try_into().unwrap();
+
+		assert_noop!(
+			Proxy::set_custom_proxy_type(RuntimeOrigin::signed(1), 0, calls.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Custom(0), 0));
+
+		// The custom proxy type was not set yet, so it allows nothing.
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+
+		assert_ok!(Proxy::set_custom_proxy_type(RuntimeOrigin::root(), 0, calls));
+		System::assert_last_event(ProxyEvent::CustomProxyTypeSet { id: 0 }.into());
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+
+		// Other calls of the pallet are not allowed.
+		let keep_alive = Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive {
+			dest: 6,
+			value: 1,
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, keep_alive));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+
+		// All the calls of a pallet can be allowed, the inner calls are still filtered.
+		let calls = vec![transfer, CallIndices { pallet_index: utility, call_index: None }];
+		assert_ok!(Proxy::set_custom_proxy_type(
+			RuntimeOrigin::root(),
+			0,
+			calls.try_into().unwrap()
+		));
+		let inner = call_transfer(6, 1);
+		let batch = Box::new(RuntimeCall::Utility(UtilityCall::batch { calls: vec![inner] }));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, batch));
+		assert_eq!(Balances::free_balance(6), 2);
+		let remark = RuntimeCall::System(SystemCall::remark { remark: vec![] });
+		let batch = Box::new(RuntimeCall::Utility(UtilityCall::batch { calls: vec![remark] }));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, batch));
+		expect_events(vec![
+			UtilityEvent::BatchInterrupted { index: 0, error: SystemError::CallFiltered.into() }
+				.into(),
+			ProxyEvent::ProxyExecuted { result: Ok(()) }.into(),
+		]);
+
+		// Removing the custom proxy type disallows all calls.
+		assert_ok!(Proxy::set_custom_proxy_type(RuntimeOrigin::root(), 0, BoundedVec::new()));
+		System::assert_last_event(ProxyEvent::CustomProxyTypeRemoved { id: 0 }.into());
+		assert!(!CustomProxyTypes::<Test>::contains_key(0));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+	});
+}
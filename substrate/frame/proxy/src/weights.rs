@@ -60,6 +60,9 @@ pub trait WeightInfo {
 	fn remove_proxies(p: u32, ) -> Weight;
 	fn create_pure(p: u32, ) -> Weight;
 	fn kill_pure(p: u32, ) -> Weight;
+	fn poke_deposit() -> Weight;
+	fn set_custom_proxy_type(c: u32, ) -> Weight;
+	fn custom_proxy_type_allows() -> Weight;
 }
 
 /// Weights for `pallet_proxy` using the Substrate node and recommended hardware.
@@ -236,6 +239,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	fn poke_deposit() -> Weight {
+		// Not benchmarked yet: bounded by re-reserving the deposits of full proxy and announcement
+		// queues, as `add_proxy` and `announce` do.
+		Self::add_proxy(31).saturating_add(Self::announce(31, 31))
+	}
+	fn set_custom_proxy_type(_c: u32, ) -> Weight {
+		// Not benchmarked yet: the custom type is written once, like the single proxy removed by
+		// `remove_proxies(1)`; its calls are bounded and only copied.
+		Self::remove_proxies(1)
+	}
+	/// Storage: `Proxy::CustomProxyTypes` (r:1 w:0)
+	/// Proof: `Proxy::CustomProxyTypes` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn custom_proxy_type_allows() -> Weight {
+		// Not benchmarked yet: one read of the custom type, and a linear search of its bounded list
+		// of calls.
+		Weight::from_parts(5_000_000, 2584)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -411,4 +432,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	fn poke_deposit() -> Weight {
+		// Not benchmarked yet: bounded by re-reserving the deposits of full proxy and announcement
+		// queues, as `add_proxy` and `announce` do.
+		Self::add_proxy(31).saturating_add(Self::announce(31, 31))
+	}
+	fn set_custom_proxy_type(_c: u32, ) -> Weight {
+		// Not benchmarked yet: the custom type is written once, like the single proxy removed by
+		// `remove_proxies(1)`; its calls are bounded and only copied.
+		Self::remove_proxies(1)
+	}
+	/// Storage: `Proxy::CustomProxyTypes` (r:1 w:0)
+	/// Proof: `Proxy::CustomProxyTypes` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn custom_proxy_type_allows() -> Weight {
+		// Not benchmarked yet: one read of the custom type, and a linear search of its bounded list
+		// of calls.
+		Weight::from_parts(5_000_000, 2584)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
 }
\ No newline at end of file
@@ -16,8 +16,9 @@
 // limitations under the License.
 //! The Ethereum JSON-RPC server.
 use crate::{
-	client::Client, EthRpcServer, EthRpcServerImpl, SystemHealthRpcServer,
-	SystemHealthRpcServerImpl,
+	client::Client, receipt_index::ReceiptIndex, DebugRpcServer, DebugRpcServerImpl,
+	EthPubSubRpcServer, EthPubSubRpcServerImpl, EthRpcServer, EthRpcServerImpl,
+	SystemHealthRpcServer, SystemHealthRpcServerImpl,
 };
 use clap::Parser;
 use futures::{pin_mut, FutureExt};
@@ -27,6 +28,7 @@ use sc_service::{
 	config::{PrometheusConfig, RpcConfiguration},
 	start_rpc_servers, TaskManager,
 };
+use std::path::PathBuf;
 
 // Default port if --prometheus-port is not specified
 const DEFAULT_PROMETHEUS_PORT: u16 = 9616;
@@ -42,6 +44,14 @@ pub struct CliCommand {
 	#[clap(long, default_value = "ws://127.0.0.1:9944")]
 	pub node_rpc_url: String,
 
+	/// The path of an on-disk index of the receipts of all the finalized blocks.
+	///
+	/// When set, receipts older than the in-memory cache are served from the index, which is
+	/// backfilled from the node on startup. Backfilling requires the node to keep the state of
+	/// all the blocks (e.g. an archive node).
+	#[clap(long)]
+	pub receipt_index_path: Option<PathBuf>,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub shared_params: SharedParams,
@@ -78,7 +88,14 @@ fn init_logger(params: &SharedParams) -> anyhow::Result<()> {
 
 /// Start the JSON-RPC server using the given command line arguments.
 pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
-	let CliCommand { rpc_params, prometheus_params, node_rpc_url, shared_params, .. } = cmd;
+	let CliCommand {
+		rpc_params,
+		prometheus_params,
+		node_rpc_url,
+		receipt_index_path,
+		shared_params,
+		..
+	} = cmd;
 
 	#[cfg(not(test))]
 	init_logger(&shared_params)?;
@@ -114,9 +131,12 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 	let mut task_manager = TaskManager::new(tokio_handle.clone(), prometheus_registry)?;
 	let essential_spawn_handle = task_manager.spawn_essential_handle();
 
+	let receipt_index = receipt_index_path.map(|path| ReceiptIndex::open(&path)).transpose()?;
+
 	let gen_rpc_module = || {
 		let signals = tokio_runtime.block_on(async { Signals::capture() })?;
-		let fut = Client::from_url(&node_rpc_url, &essential_spawn_handle).fuse();
+		let fut =
+			Client::from_url(&node_rpc_url, &essential_spawn_handle, receipt_index.clone()).fuse();
 		pin_mut!(fut);
 
 		match tokio_handle.block_on(signals.try_until_signal(fut)) {
@@ -152,10 +172,14 @@ fn rpc_module(is_dev: bool, client: Client) -> Result<RpcModule<()>, sc_service:
 		.with_accounts(if is_dev { vec![crate::Account::default()] } else { vec![] })
 		.into_rpc();
 
+	let pubsub_api = EthPubSubRpcServerImpl::new(client.clone()).into_rpc();
+	let debug_api = DebugRpcServerImpl::new(client.clone()).into_rpc();
 	let health_api = SystemHealthRpcServerImpl::new(client).into_rpc();
 
 	let mut module = RpcModule::new(());
 	module.merge(eth_api).map_err(|e| sc_service::Error::Application(e.into()))?;
+	module.merge(pubsub_api).map_err(|e| sc_service::Error::Application(e.into()))?;
+	module.merge(debug_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	module.merge(health_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	Ok(module)
 }
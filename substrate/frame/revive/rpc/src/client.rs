@@ -17,6 +17,8 @@
 //! The client connects to the source substrate chain
 //! and is used by the rpc server to query and send transactions to the substrate chain.
 use crate::{
+	fee_history,
+	receipt_index::ReceiptIndex,
 	runtime::GAS_PRICE,
 	subxt_client::{
 		revive::{calls::types::EthTransact, events::ContractEmitted},
@@ -24,17 +26,20 @@ use crate::{
 	},
 	LOG_TARGET,
 };
+use codec::{Compact, Encode};
 use futures::{stream, StreamExt};
 use jsonrpsee::types::{error::CALL_EXECUTION_FAILED_CODE, ErrorObjectOwned};
 use pallet_revive::{
 	create1,
 	evm::{
-		Block, BlockNumberOrTag, BlockNumberOrTagOrHash, Bytes256, GenericTransaction, Log,
-		ReceiptInfo, SyncingProgress, SyncingStatus, TransactionSigned, H160, H256, U256,
+		Block, BlockNumberOrTag, BlockNumberOrTagOrHash, BlockTag, Bytes256, FeeHistoryResult,
+		Filter, GenericTransaction, Log, ReceiptInfo, SyncingProgress, SyncingStatus, Trace,
+		TracerConfig, TransactionSigned, TransactionTrace, H160, H256, U256,
 	},
 	EthContractResult,
 };
 use sp_core::keccak_256;
+use sp_runtime::FixedU128;
 use sp_weights::Weight;
 use std::{
 	collections::{HashMap, VecDeque},
@@ -56,7 +61,7 @@ use subxt::{
 };
 use subxt_client::transaction_payment::events::TransactionFeePaid;
 use thiserror::Error;
-use tokio::sync::{watch::Sender, RwLock};
+use tokio::sync::{broadcast, watch::Sender, RwLock};
 
 use crate::subxt_client::{self, system::events::ExtrinsicSuccess, SrcChainConfig};
 
@@ -96,6 +101,9 @@ struct BlockCache<const N: usize> {
 
 	/// A map of receipt hashes by block hash.
 	tx_hashes_by_block_and_index: HashMap<H256, HashMap<U256, H256>>,
+
+	/// A map of the logs emitted in a block, ordered by transaction and log index, by block hash.
+	logs_by_block_hash: HashMap<H256, Arc<[Log]>>,
 }
 
 /// Unwrap the original `jsonrpsee::core::client::Error::Call` error.
@@ -164,6 +172,24 @@ pub enum ClientError {
 	/// The cache is empty.
 	#[error("Cache is empty")]
 	CacheEmpty,
+	/// The log filter is invalid.
+	#[error("Invalid filter: {0}")]
+	InvalidFilter(&'static str),
+	/// The block range of the log query is too large.
+	#[error("Block range exceeds the maximum of {LOG_QUERY_MAX_BLOCK_RANGE} blocks")]
+	BlockRangeTooLarge,
+	/// The receipt of the transaction was not found.
+	#[error("Receipt not found")]
+	ReceiptNotFound,
+	/// The runtime did not return a trace.
+	#[error("Trace not found")]
+	TraceNotFound,
+	/// The reward percentiles of the fee history query are invalid.
+	#[error("Reward percentiles must be in ascending order and within [0, 100]")]
+	InvalidRewardPercentiles,
+	/// A [`std::io::Error`] wrapper error, returned by the receipt index.
+	#[error(transparent)]
+	IoError(#[from] std::io::Error),
 }
 
 // TODO convert error code to https://eips.ethereum.org/EIPS/eip-1474#error-codes
@@ -190,19 +216,38 @@ impl From<ClientError> for ErrorObjectOwned {
 /// For each block in the cache, we also store the EVM transaction receipts.
 pub const CACHE_SIZE: usize = 256;
 
+/// The maximum number of blocks that can be scanned by a single log query.
+pub const LOG_QUERY_MAX_BLOCK_RANGE: u32 = 1024;
+
+/// The number of blocks between two progress logs while indexing receipts.
+const RECEIPT_INDEX_LOG_INTERVAL: SubstrateBlockNumber = 10_000;
+
+/// The capacity of the new block notification channel.
+const BLOCK_NOTIFICATION_CAPACITY: usize = 64;
+
+/// A notification sent for every new block added to the cache.
+#[derive(Clone)]
+pub struct BlockNotification {
+	/// The new block.
+	pub block: Arc<SubstrateBlock>,
+	/// The logs emitted in the block, ordered by transaction and log index.
+	pub logs: Arc<[Log]>,
+}
+
 impl<const N: usize> BlockCache<N> {
 	fn latest_block(&self) -> Option<&Arc<SubstrateBlock>> {
 		self.buffer.back()
 	}
 
 	/// Insert an entry into the cache, and prune the oldest entry if the cache is full.
-	fn insert(&mut self, block: SubstrateBlock) {
+	fn insert(&mut self, block: SubstrateBlock) -> Arc<SubstrateBlock> {
 		if self.buffer.len() >= N {
 			if let Some(block) = self.buffer.pop_front() {
 				log::trace!(target: LOG_TARGET, "Pruning block: {}", block.number());
 				let hash = block.hash();
 				self.blocks_by_hash.remove(&hash);
 				self.blocks_by_number.remove(&block.number());
+				self.logs_by_block_hash.remove(&hash);
 				if let Some(entries) = self.tx_hashes_by_block_and_index.remove(&hash) {
 					for hash in entries.values() {
 						self.receipts_by_hash.remove(hash);
@@ -214,7 +259,8 @@ impl<const N: usize> BlockCache<N> {
 		let block = Arc::new(block);
 		self.buffer.push_back(block.clone());
 		self.blocks_by_number.insert(block.number(), block.clone());
-		self.blocks_by_hash.insert(block.hash(), block);
+		self.blocks_by_hash.insert(block.hash(), block.clone());
+		block
 	}
 }
 
@@ -225,6 +271,8 @@ pub struct Client {
 	inner: Arc<ClientInner>,
 	/// A watch channel to signal cache updates.
 	pub updates: tokio::sync::watch::Receiver<()>,
+	/// A broadcast channel to notify subscribers of new blocks.
+	block_notifications: broadcast::Sender<BlockNotification>,
 }
 
 /// The inner state of the client.
@@ -235,11 +283,12 @@ struct ClientInner {
 	cache: Shared<BlockCache<CACHE_SIZE>>,
 	chain_id: u64,
 	max_block_weight: Weight,
+	receipt_index: Option<ReceiptIndex>,
 }
 
 impl ClientInner {
 	/// Create a new client instance connecting to the substrate node at the given URL.
-	async fn from_url(url: &str) -> Result<Self, ClientError> {
+	async fn from_url(url: &str, receipt_index: Option<ReceiptIndex>) -> Result<Self, ClientError> {
 		let rpc_client = ReconnectingRpcClient::builder()
 			.retry_policy(ExponentialBackoff::from_millis(100).max_delay(Duration::from_secs(10)))
 			.build(url.to_string())
@@ -253,7 +302,7 @@ impl ClientInner {
 		let (chain_id, max_block_weight) =
 			tokio::try_join!(chain_id(&api), max_block_weight(&api))?;
 
-		Ok(Self { api, rpc_client, rpc, cache, chain_id, max_block_weight })
+		Ok(Self { api, rpc_client, rpc, cache, chain_id, max_block_weight, receipt_index })
 	}
 
 	/// Get the receipt infos from the extrinsics in a block.
@@ -263,6 +312,7 @@ impl ClientInner {
 	) -> Result<HashMap<H256, (TransactionSigned, ReceiptInfo)>, ClientError> {
 		// Get extrinsics from the block
 		let extrinsics = block.extrinsics().await?;
+		let base_fee = base_fee_per_gas(&self.api, block).await?;
 
 		// Filter extrinsics from pallet_revive
 		let extrinsics = extrinsics.iter().flat_map(|ext| {
@@ -287,7 +337,7 @@ impl ClientInner {
 				let tx_fees =
 					events.find_first::<TransactionFeePaid>()?.ok_or(ClientError::TxFeeNotFound)?;
 
-				let gas_price = tx_info.gas_price.unwrap_or_default();
+				let gas_price = tx_info.effective_gas_price(base_fee).unwrap_or_default();
 				let gas_used = (tx_fees.tip.saturating_add(tx_fees.actual_fee))
 					.checked_div(gas_price.as_u128())
 					.unwrap_or_default();
@@ -341,6 +391,81 @@ impl ClientInner {
 			.into_iter()
 			.collect::<Result<HashMap<_, _>, _>>()
 	}
+
+	/// Index the Ethereum transactions of all the blocks up to the given finalized block.
+	///
+	/// The blocks that are not indexed yet are fetched from the node and indexed in ascending
+	/// order, starting from the genesis block when the index is empty.
+	async fn index_blocks_up_to(
+		&self,
+		index: &ReceiptIndex,
+		block: &SubstrateBlock,
+	) -> Result<(), ClientError> {
+		let next = index.last_indexed_block()?.map_or(0, |n| n.saturating_add(1));
+		if next < block.number() {
+			log::info!(
+				target: LOG_TARGET,
+				"Indexing receipts of blocks #{next} to #{}",
+				block.number()
+			);
+		}
+
+		for number in next..block.number() {
+			let hash = self
+				.rpc
+				.chain_get_block_hash(Some(number.into()))
+				.await?
+				.ok_or(ClientError::BlockNotFound)?;
+			let block = self.api.blocks().at(hash).await?;
+			self.index_block(index, &block).await?;
+
+			if number % RECEIPT_INDEX_LOG_INTERVAL == 0 {
+				log::info!(target: LOG_TARGET, "Indexed receipts up to block #{number}");
+			}
+		}
+
+		if next <= block.number() {
+			self.index_block(index, block).await?;
+		}
+
+		Ok(())
+	}
+
+	/// Index the Ethereum transactions of a block.
+	async fn index_block(
+		&self,
+		index: &ReceiptIndex,
+		block: &SubstrateBlock,
+	) -> Result<(), ClientError> {
+		let receipts = self.receipt_infos(block).await?;
+		log::trace!(target: LOG_TARGET, "Indexing block #{}", block.number());
+		index.insert_block(block.hash(), block.number(), receipts.into_values())?;
+		Ok(())
+	}
+
+	/// Get the logs emitted in a block, ordered by transaction and log index.
+	async fn block_logs(&self, block: &SubstrateBlock) -> Result<Arc<[Log]>, ClientError> {
+		let receipts = self.receipt_infos(block).await?;
+		Ok(sorted_logs(receipts.values().map(|(_, receipt)| receipt)))
+	}
+}
+
+/// SCALE encode a block, as expected by the tracing runtime API methods.
+async fn encode_block(block: &SubstrateBlock) -> Result<Vec<u8>, ClientError> {
+	let extrinsics = block.extrinsics().await?;
+	let mut encoded = block.header().encode();
+	Compact(extrinsics.len() as u32).encode_to(&mut encoded);
+	for ext in extrinsics.iter() {
+		encoded.extend_from_slice(ext.bytes());
+	}
+	Ok(encoded)
+}
+
+/// Collect the logs of the given receipts, ordered by transaction and log index.
+fn sorted_logs<'a>(receipts: impl Iterator<Item = &'a ReceiptInfo>) -> Arc<[Log]> {
+	let mut logs = receipts.flat_map(|receipt| receipt.logs.iter().cloned()).collect::<Vec<_>>();
+	logs.sort_by_key(|log| (log.transaction_index, log.log_index));
+	logs.into()
 }
 
 /// Fetch the chain ID from the substrate chain.
@@ -357,6 +482,26 @@ async fn max_block_weight(api: &OnlineClient<SrcChainConfig>) -> Result<Weight,
 	Ok(max_block.0)
 }
 
+/// Fetch the base fee per gas of the block following the block whose storage is given, from the
+/// fee multiplier left in its state.
+async fn next_base_fee_per_gas(
+	storage: Storage<SrcChainConfig, OnlineClient<SrcChainConfig>>,
+) -> Result<U256, ClientError> {
+	let query = subxt_client::storage().transaction_payment().next_fee_multiplier();
+	let multiplier = storage.fetch_or_default(&query).await?;
+	Ok(fee_history::base_fee_per_gas(FixedU128::from_inner(multiplier.0)))
+}
+
+/// Fetch the base fee per gas paid by the transactions of the given block.
+async fn base_fee_per_gas(
+	api: &OnlineClient<SrcChainConfig>,
+	block: &SubstrateBlock,
+) -> Result<U256, ClientError> {
+	// The fees of a block are computed with the multiplier left by its parent.
+	let at = if block.number() == 0 { block.hash() } else { block.header().parent_hash };
+	next_base_fee_per_gas(api.storage().at(at)).await
+}
+
 /// Extract the block timestamp.
 async fn extract_block_timestamp(block: &SubstrateBlock) -> Option<u64> {
 	let extrinsics = block.extrinsics().await.ok()?;
@@ -370,20 +515,37 @@ async fn extract_block_timestamp(block: &SubstrateBlock) -> Option<u64> {
 impl Client {
 	/// Create a new client instance.
 	/// The client will subscribe to new blocks and maintain a cache of [`CACHE_SIZE`] blocks.
+	///
+	/// When a [`ReceiptIndex`] is provided, the client also indexes the receipts of all the
+	/// finalized blocks, and serves the receipts that are not in the cache from the index.
 	pub async fn from_url(
 		url: &str,
 		spawn_handle: &sc_service::SpawnEssentialTaskHandle,
+		receipt_index: Option<ReceiptIndex>,
 	) -> Result<Self, ClientError> {
 		log::info!(target: LOG_TARGET, "Connecting to node at: {url} ...");
-		let inner: Arc<ClientInner> = Arc::new(ClientInner::from_url(url).await?);
+		let inner: Arc<ClientInner> = Arc::new(ClientInner::from_url(url, receipt_index).await?);
 		log::info!(target: LOG_TARGET, "Connected to node at: {url}");
 
+		if let Some(index) = inner.receipt_index.clone() {
+			spawn_handle.spawn(
+				"index-finalized-blocks",
+				None,
+				Self::index_finalized_blocks(inner.clone(), index),
+			);
+		}
+
 		let (tx, mut updates) = tokio::sync::watch::channel(());
+		let (block_notifications, _) = broadcast::channel(BLOCK_NOTIFICATION_CAPACITY);
 
-		spawn_handle.spawn("subscribe-blocks", None, Self::subscribe_blocks(inner.clone(), tx));
+		spawn_handle.spawn(
+			"subscribe-blocks",
+			None,
+			Self::subscribe_blocks(inner.clone(), tx, block_notifications.clone()),
+		);
 
 		updates.changed().await.expect("tx is not dropped");
-		Ok(Self { inner, updates })
+		Ok(Self { inner, updates, block_notifications })
 	}
 
 	/// Expose the storage API.
@@ -438,8 +600,46 @@ impl Client {
 		}
 	}
 
+	/// Subscribe to finalized blocks and index their receipts.
+	async fn index_finalized_blocks(inner: Arc<ClientInner>, index: ReceiptIndex) {
+		log::info!(target: LOG_TARGET, "Subscribing to finalized blocks");
+		let mut block_stream = match inner.as_ref().api.blocks().subscribe_finalized().await {
+			Ok(s) => s,
+			Err(err) => {
+				log::error!(target: LOG_TARGET, "Failed to subscribe to finalized blocks: {err:?}");
+				return;
+			},
+		};
+
+		while let Some(block) = block_stream.next().await {
+			let block = match block {
+				Ok(block) => block,
+				Err(err) => {
+					if err.is_disconnected_will_reconnect() {
+						// Missed blocks are indexed along with the next finalized block.
+						log::warn!(target: LOG_TARGET, "The RPC connection was lost");
+						continue;
+					}
+
+					log::error!(target: LOG_TARGET, "Failed to fetch finalized block: {err:?}");
+					return;
+				},
+			};
+
+			// On failure, the blocks that are not indexed yet are retried with the next
+			// finalized block.
+			if let Err(err) = inner.index_blocks_up_to(&index, &block).await {
+				log::error!(target: LOG_TARGET, "Failed to index block #{}: {err:?}", block.number());
+			}
+		}
+	}
+
 	/// Subscribe to new blocks and update the cache.
-	async fn subscribe_blocks(inner: Arc<ClientInner>, tx: Sender<()>) {
+	async fn subscribe_blocks(
+		inner: Arc<ClientInner>,
+		tx: Sender<()>,
+		block_notifications: broadcast::Sender<BlockNotification>,
+	) {
 		log::info!(target: LOG_TARGET, "Subscribing to new blocks");
 		let mut block_stream = match inner.as_ref().api.blocks().subscribe_best().await {
 			Ok(s) => s,
@@ -477,7 +677,10 @@ impl Client {
 				})
 				.unwrap_or_default();
 
+			let logs = sorted_logs(receipts.values().map(|(_, receipt)| receipt));
 			if !receipts.is_empty() {
+				cache.logs_by_block_hash.insert(block.hash(), logs.clone());
+
 				let values = receipts
 					.iter()
 					.map(|(hash, (_, receipt))| (receipt.transaction_index, *hash))
@@ -494,8 +697,12 @@ impl Client {
 				)
 			}
 
-			cache.insert(block);
+			let block = cache.insert(block);
+			drop(cache);
+
 			tx.send_replace(());
+			// An error only means that there are currently no subscribers.
+			let _ = block_notifications.send(BlockNotification { block, logs });
 		}
 
 		log::info!(target: LOG_TARGET, "Block subscription ended");
@@ -523,7 +730,11 @@ impl Client {
 	/// Get an EVM transaction receipt by hash.
 	pub async fn receipt(&self, tx_hash: &H256) -> Option<ReceiptInfo> {
 		let cache = self.inner.cache.read().await;
-		cache.receipts_by_hash.get(tx_hash).cloned()
+		if let Some(receipt) = cache.receipts_by_hash.get(tx_hash) {
+			return Some(receipt.clone());
+		}
+
+		self.inner.receipt_index.as_ref()?.receipt(tx_hash)
 	}
 
 	/// Get the syncing status of the chain.
@@ -555,21 +766,234 @@ impl Client {
 		transaction_index: &U256,
 	) -> Option<ReceiptInfo> {
 		let cache = self.inner.cache.read().await;
-		let receipt_hash =
-			cache.tx_hashes_by_block_and_index.get(block_hash)?.get(transaction_index)?;
-		let receipt = cache.receipts_by_hash.get(receipt_hash)?;
-		Some(receipt.clone())
+		if let Some(receipt_hash) = cache
+			.tx_hashes_by_block_and_index
+			.get(block_hash)
+			.and_then(|v| v.get(transaction_index))
+		{
+			return cache.receipts_by_hash.get(receipt_hash).cloned();
+		}
+
+		self.inner
+			.receipt_index
+			.as_ref()?
+			.receipt_by_block_and_index(block_hash, transaction_index)
 	}
 
 	pub async fn signed_tx_by_hash(&self, tx_hash: &H256) -> Option<TransactionSigned> {
 		let cache = self.inner.cache.read().await;
-		cache.signed_tx_by_hash.get(tx_hash).cloned()
+		if let Some(signed_tx) = cache.signed_tx_by_hash.get(tx_hash) {
+			return Some(signed_tx.clone());
+		}
+
+		self.inner.receipt_index.as_ref()?.signed_tx(tx_hash)
 	}
 
 	/// Get receipts count per block.
 	pub async fn receipts_count_per_block(&self, block_hash: &SubstrateBlockHash) -> Option<usize> {
 		let cache = self.inner.cache.read().await;
-		cache.tx_hashes_by_block_and_index.get(block_hash).map(|v| v.len())
+		if let Some(v) = cache.tx_hashes_by_block_and_index.get(block_hash) {
+			return Some(v.len());
+		}
+
+		self.inner.receipt_index.as_ref()?.receipts_count(block_hash)
+	}
+
+	/// Subscribe to the blocks added to the cache.
+	pub fn subscribe_new_blocks(&self) -> broadcast::Receiver<BlockNotification> {
+		self.block_notifications.subscribe()
+	}
+
+	/// Get the logs matching the given filter.
+	pub async fn logs(&self, filter: &Filter) -> Result<Vec<Log>, ClientError> {
+		if let Some(hash) = filter.block_hash {
+			if filter.from_block.is_some() || filter.to_block.is_some() {
+				return Err(ClientError::InvalidFilter(
+					"blockHash cannot be combined with fromBlock or toBlock",
+				));
+			}
+
+			let block = self.block_by_hash(&hash).await?.ok_or(ClientError::BlockNotFound)?;
+			let logs = self.logs_at(&block).await?;
+			return Ok(logs.iter().filter(|log| filter.matches(log)).cloned().collect());
+		}
+
+		let latest = self.block_number().await?;
+		let from = match &filter.from_block {
+			Some(block) => self.resolve_block_number(block, latest)?,
+			None => latest,
+		};
+		let to = match &filter.to_block {
+			Some(block) => self.resolve_block_number(block, latest)?,
+			None => latest,
+		};
+
+		self.logs_in_range(filter, from, to).await
+	}
+
+	/// Get the logs matching the address and topics of the given filter, emitted in the blocks
+	/// `from..=to`.
+	pub async fn logs_in_range(
+		&self,
+		filter: &Filter,
+		from: SubstrateBlockNumber,
+		to: SubstrateBlockNumber,
+	) -> Result<Vec<Log>, ClientError> {
+		if from > to {
+			return Ok(Vec::new());
+		}
+
+		if to - from >= LOG_QUERY_MAX_BLOCK_RANGE {
+			return Err(ClientError::BlockRangeTooLarge);
+		}
+
+		let mut logs = Vec::new();
+		for number in from..=to {
+			let Some(block) = self.block_by_number(number).await? else {
+				continue;
+			};
+
+			let block_logs = self.logs_at(&block).await?;
+			logs.extend(block_logs.iter().filter(|log| filter.matches(log)).cloned());
+		}
+
+		Ok(logs)
+	}
+
+	/// Get the logs emitted in the given block.
+	///
+	/// Logs are served from the cache when possible, and are otherwise rebuilt from the
+	/// `ContractEmitted` events of the block.
+	async fn logs_at(&self, block: &SubstrateBlock) -> Result<Arc<[Log]>, ClientError> {
+		let hash = block.hash();
+		{
+			let cache = self.inner.cache.read().await;
+			if cache.blocks_by_hash.contains_key(&hash) {
+				let logs = cache.logs_by_block_hash.get(&hash).cloned();
+				return Ok(logs.unwrap_or_else(|| Vec::new().into()));
+			}
+		}
+
+		self.inner.block_logs(block).await
+	}
+
+	/// Resolve a block number or tag to a block number, given the latest block number.
+	fn resolve_block_number(
+		&self,
+		block: &BlockNumberOrTag,
+		latest: SubstrateBlockNumber,
+	) -> Result<SubstrateBlockNumber, ClientError> {
+		match block {
+			BlockNumberOrTag::U256(n) => (*n).try_into().map_err(|_| ClientError::ConversionFailed),
+			BlockNumberOrTag::BlockTag(BlockTag::Earliest) => Ok(0),
+			BlockNumberOrTag::BlockTag(_) => Ok(latest),
+		}
+	}
+
+	/// Get the receipts of the given block.
+	///
+	/// Receipts are served from the cache when possible, and are otherwise rebuilt from the
+	/// events of the block.
+	async fn block_receipts(
+		&self,
+		block: &SubstrateBlock,
+	) -> Result<Vec<ReceiptInfo>, ClientError> {
+		let hash = block.hash();
+		{
+			let cache = self.inner.cache.read().await;
+			if cache.blocks_by_hash.contains_key(&hash) {
+				let receipts = cache
+					.tx_hashes_by_block_and_index
+					.get(&hash)
+					.into_iter()
+					.flat_map(|tx_hashes| tx_hashes.values())
+					.filter_map(|tx_hash| cache.receipts_by_hash.get(tx_hash).cloned())
+					.collect();
+				return Ok(receipts);
+			}
+		}
+
+		let receipts = self.inner.receipt_infos(block).await?;
+		Ok(receipts.into_values().map(|(_, receipt)| receipt).collect())
+	}
+
+	/// Get the base fees, gas used ratios and priority fees of the `block_count` blocks ending at
+	/// `newest_block`.
+	///
+	/// At most [`fee_history::MAX_FEE_HISTORY_BLOCKS`] blocks are returned.
+	pub async fn fee_history(
+		&self,
+		block_count: u32,
+		newest_block: &BlockNumberOrTag,
+		reward_percentiles: Option<Vec<f64>>,
+	) -> Result<FeeHistoryResult, ClientError> {
+		if reward_percentiles
+			.as_ref()
+			.is_some_and(|percentiles| !fee_history::is_valid_percentiles(percentiles))
+		{
+			return Err(ClientError::InvalidRewardPercentiles);
+		}
+
+		let latest = self.block_number().await?;
+		let newest = self.resolve_block_number(newest_block, latest)?.min(latest);
+		let block_count = block_count
+			.min(fee_history::MAX_FEE_HISTORY_BLOCKS)
+			.min(newest.saturating_add(1));
+		let oldest = newest.saturating_add(1) - block_count;
+
+		let mut base_fees = Vec::with_capacity(block_count as usize + 1);
+		let mut gas_used_ratio = Vec::with_capacity(block_count as usize);
+		let mut reward = Vec::with_capacity(block_count as usize);
+		let mut newest_hash = None;
+		for number in oldest..oldest + block_count {
+			let block = self.block_by_number(number).await?.ok_or(ClientError::BlockNotFound)?;
+			let base_fee = base_fee_per_gas(&self.inner.api, &block).await?;
+			base_fees.push(base_fee);
+			newest_hash = Some(block.hash());
+
+			let query = subxt_client::storage().system().block_weight();
+			let block_weight =
+				self.inner.api.storage().at(block.hash()).fetch_or_default(&query).await?;
+			gas_used_ratio
+				.push(fee_history::gas_used_ratio(block_weight.normal.0, self.max_block_weight()));
+
+			if let Some(percentiles) = &reward_percentiles {
+				let receipts = self.block_receipts(&block).await?;
+				reward.push(fee_history::rewards(&receipts, base_fee, percentiles));
+			}
+		}
+
+		// The base fee of the block following the newest block is also returned.
+		if let Some(hash) = newest_hash {
+			base_fees.push(next_base_fee_per_gas(self.inner.api.storage().at(hash)).await?);
+		}
+
+		Ok(FeeHistoryResult {
+			oldest_block: oldest.into(),
+			base_fee_per_gas: base_fees,
+			gas_used_ratio,
+			reward: reward_percentiles.map(|_| reward),
+		})
+	}
+
+	/// Get the base fee per gas of the next block.
+	pub async fn base_fee_per_gas(&self) -> Result<U256, ClientError> {
+		next_base_fee_per_gas(self.inner.api.storage().at_latest().await?).await
+	}
+
+	/// Suggest a priority fee per gas, from the priority fees paid in the recent blocks.
+	pub async fn max_priority_fee_per_gas(&self) -> Result<U256, ClientError> {
+		let history = self
+			.fee_history(
+				fee_history::PRIORITY_FEE_BLOCKS,
+				&BlockTag::Latest.into(),
+				Some(vec![fee_history::PRIORITY_FEE_PERCENTILE]),
+			)
+			.await?;
+
+		let mut fees = history.reward.into_iter().flatten().flatten().collect::<Vec<_>>();
+		fees.sort();
+		Ok(fees.get(fees.len() / 2).copied().unwrap_or_default())
 	}
 
 	/// Get the system health.
@@ -768,6 +1192,86 @@ impl Client {
 		self.block_by_hash(&hash).await
 	}
 
+	/// Trace the Ethereum transactions of the given block.
+	pub async fn trace_block_by_number(
+		&self,
+		block: BlockNumberOrTag,
+		config: TracerConfig,
+	) -> Result<Vec<TransactionTrace>, ClientError> {
+		let block = self.block_by_number_or_tag(&block).await?.ok_or(ClientError::BlockNotFound)?;
+
+		let mut params = encode_block(&block).await?;
+		config.encode_to(&mut params);
+		let runtime_api = self.inner.api.runtime_api().at(block.header().parent_hash);
+		let traces: Vec<(u32, Trace)> =
+			runtime_api.call_raw("ReviveApi_trace_block", Some(&params)).await?;
+
+		// Only keep the traces of the Ethereum transactions.
+		let tx_hashes = self
+			.receipt_infos(&block)
+			.await?
+			.into_values()
+			.map(|(_, receipt)| (receipt.transaction_index, receipt.transaction_hash))
+			.collect::<HashMap<_, _>>();
+
+		let traces = traces
+			.into_iter()
+			.filter_map(|(index, result)| {
+				let tx_hash = *tx_hashes.get(&index.into())?;
+				Some(TransactionTrace { tx_hash, result })
+			})
+			.collect();
+		Ok(traces)
+	}
+
+	/// Trace the transaction with the given hash.
+	pub async fn trace_transaction(
+		&self,
+		tx_hash: H256,
+		config: TracerConfig,
+	) -> Result<Trace, ClientError> {
+		let receipt = self.receipt(&tx_hash).await.ok_or(ClientError::ReceiptNotFound)?;
+		let block = self
+			.block_by_hash(&receipt.block_hash)
+			.await?
+			.ok_or(ClientError::BlockNotFound)?;
+		let tx_index: u32 = receipt
+			.transaction_index
+			.try_into()
+			.map_err(|_| ClientError::ConversionFailed)?;
+
+		let mut params = encode_block(&block).await?;
+		(tx_index, config).encode_to(&mut params);
+		let runtime_api = self.inner.api.runtime_api().at(block.header().parent_hash);
+		let trace: Option<Trace> =
+			runtime_api.call_raw("ReviveApi_trace_tx", Some(&params)).await?;
+		trace.ok_or(ClientError::TraceNotFound)
+	}
+
+	/// Trace the dry run of a transaction.
+	pub async fn trace_call(
+		&self,
+		tx: GenericTransaction,
+		block: BlockNumberOrTagOrHash,
+		config: TracerConfig,
+	) -> Result<Trace, ClientError> {
+		let runtime_api = self.runtime_api(&block).await?;
+		let params = (
+			tx.from.unwrap_or_default(),
+			tx.to,
+			tx.value.unwrap_or_default(),
+			tx.input.unwrap_or_default().0,
+			None::<Weight>,
+			None::<Balance>,
+			config,
+		)
+			.encode();
+
+		let trace: Option<Trace> =
+			runtime_api.call_raw("ReviveApi_trace_call", Some(&params)).await?;
+		trace.ok_or(ClientError::TraceNotFound)
+	}
+
 	/// Get the EVM block for the given hash.
 	pub async fn evm_block(&self, block: Arc<SubstrateBlock>) -> Result<Block, ClientError> {
 		let runtime_api = self.inner.api.runtime_api().at(block.hash());
@@ -791,6 +1295,7 @@ impl Client {
 			timestamp: timestamp.into(),
 			difficulty: Some(0u32.into()),
 			gas_limit,
+			base_fee_per_gas: Some(fee_history::base_fee_per_gas()),
 			logs_bloom: Bytes256([0u8; 256]),
 			receipts_root: extrinsics_root,
 			..Default::default()
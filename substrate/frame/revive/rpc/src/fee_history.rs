@@ -0,0 +1,163 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Helpers to compute the `eth_feeHistory` and `eth_maxPriorityFeePerGas` results.
+//!
+//! The Ethereum base fee per gas of a block is [`GAS_PRICE`], scaled by the fee multiplier of
+//! `pallet_transaction_payment` with which the fees of the block are computed, so that it follows
+//! the congestion of the chain.
+use crate::runtime::GAS_PRICE;
+use pallet_revive::evm::{ReceiptInfo, U256};
+use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_weights::Weight;
+
+/// The maximum number of blocks that can be queried with `eth_feeHistory`.
+pub const MAX_FEE_HISTORY_BLOCKS: u32 = 1024;
+
+/// The number of recent blocks used to suggest a priority fee.
+pub const PRIORITY_FEE_BLOCKS: u32 = 20;
+
+/// The percentile of the recent priority fees suggested by `eth_maxPriorityFeePerGas`.
+pub const PRIORITY_FEE_PERCENTILE: f64 = 60.0;
+
+/// The base fee per gas of a block whose fees are computed with the given fee multiplier.
+///
+/// It is never lower than one unit, since gas is derived from the fees by dividing by it.
+pub fn base_fee_per_gas(multiplier: FixedU128) -> U256 {
+	U256::from(multiplier.saturating_mul_int(u128::from(GAS_PRICE)).max(1))
+}
+
+/// Returns `true` if the percentiles are within `[0, 100]` and in ascending order.
+pub fn is_valid_percentiles(percentiles: &[f64]) -> bool {
+	percentiles.iter().all(|p| (0.0..=100.0).contains(p)) &&
+		percentiles.windows(2).all(|w| w[0] <= w[1])
+}
+
+/// The ratio between the weight consumed by a block and the maximum block weight.
+///
+/// The most constrained dimension of the weight is used.
+pub fn gas_used_ratio(used: Weight, max: Weight) -> f64 {
+	let ratio = |used: u64, max: u64| if max == 0 { 0.0 } else { used as f64 / max as f64 };
+	ratio(used.ref_time(), max.ref_time())
+		.max(ratio(used.proof_size(), max.proof_size()))
+		.min(1.0)
+}
+
+/// The priority fees paid by the transactions of a block with the given base fee, at the given
+/// percentiles.
+///
+/// As in Geth, each transaction is weighted by the gas it used, and zeroes are returned for blocks
+/// without transactions.
+pub fn rewards<'a>(
+	receipts: impl IntoIterator<Item = &'a ReceiptInfo>,
+	base_fee: U256,
+	percentiles: &[f64],
+) -> Vec<U256> {
+	let mut fees = receipts
+		.into_iter()
+		.map(|receipt| (receipt.effective_gas_price.saturating_sub(base_fee), receipt.gas_used))
+		.collect::<Vec<_>>();
+
+	if fees.is_empty() {
+		return vec![U256::zero(); percentiles.len()];
+	}
+
+	fees.sort_by_key(|(fee, _)| *fee);
+	let max_fee = fees[fees.len() - 1].0;
+	let total_gas_used = fees.iter().fold(U256::zero(), |acc, (_, gas)| acc.saturating_add(*gas));
+
+	percentiles
+		.iter()
+		.map(|percentile| {
+			// Percentiles are expressed with up to 2 decimals.
+			let threshold =
+				total_gas_used.saturating_mul(U256::from((percentile * 100.0) as u64)) / 10_000u32;
+			let mut cumulative_gas = U256::zero();
+			fees.iter()
+				.find(|(_, gas)| {
+					cumulative_gas = cumulative_gas.saturating_add(*gas);
+					cumulative_gas >= threshold
+				})
+				.map_or(max_fee, |(fee, _)| *fee)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn receipt(base_fee: U256, priority_fee: u32, gas_used: u32) -> ReceiptInfo {
+		ReceiptInfo {
+			effective_gas_price: base_fee + priority_fee,
+			gas_used: gas_used.into(),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn base_fee_per_gas_follows_the_multiplier() {
+		assert_eq!(base_fee_per_gas(FixedU128::saturating_from_integer(1)), U256::from(GAS_PRICE));
+		assert_eq!(
+			base_fee_per_gas(FixedU128::saturating_from_integer(3)),
+			U256::from(3 * GAS_PRICE)
+		);
+		assert_eq!(
+			base_fee_per_gas(FixedU128::saturating_from_rational(1, 1_000_000_000)),
+			U256::one()
+		);
+	}
+
+	#[test]
+	fn rewards_works() {
+		let base_fee = base_fee_per_gas(FixedU128::saturating_from_integer(1));
+		let percentiles = [0.0, 25.0, 50.0, 100.0];
+		assert_eq!(rewards(&[], base_fee, &percentiles), vec![U256::zero(); 4]);
+
+		let receipts =
+			[receipt(base_fee, 30, 100), receipt(base_fee, 10, 100), receipt(base_fee, 20, 200)];
+		assert_eq!(
+			rewards(&receipts, base_fee, &percentiles),
+			vec![10u32.into(), 10u32.into(), 20u32.into(), 30u32.into()]
+		);
+	}
+
+	#[test]
+	fn rewards_are_above_the_base_fee_of_the_block() {
+		// the multiplier doubled the base fee of the block.
+		let base_fee = base_fee_per_gas(FixedU128::saturating_from_integer(2));
+		let receipts = [receipt(base_fee, 5, 100), receipt(base_fee, 0, 100)];
+		assert_eq!(rewards(&receipts, base_fee, &[0.0, 100.0]), vec![0u32.into(), 5u32.into()]);
+	}
+
+	#[test]
+	fn is_valid_percentiles_works() {
+		assert!(is_valid_percentiles(&[]));
+		assert!(is_valid_percentiles(&[0.0, 50.0, 50.0, 100.0]));
+		assert!(!is_valid_percentiles(&[50.0, 25.0]));
+		assert!(!is_valid_percentiles(&[101.0]));
+		assert!(!is_valid_percentiles(&[-1.0]));
+	}
+
+	#[test]
+	fn gas_used_ratio_works() {
+		let max = Weight::from_parts(100, 1000);
+		assert_eq!(gas_used_ratio(Weight::from_parts(50, 100), max), 0.5);
+		assert_eq!(gas_used_ratio(Weight::from_parts(10, 800), max), 0.8);
+		assert_eq!(gas_used_ratio(Weight::from_parts(200, 0), max), 1.0);
+		assert_eq!(gas_used_ratio(Weight::zero(), Weight::zero()), 0.0);
+	}
+}
@@ -0,0 +1,184 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Polling filters installed by `eth_newFilter` and `eth_newBlockFilter`.
+use crate::client::SubstrateBlockNumber;
+use pallet_revive::evm::{Filter, U256};
+use std::{
+	collections::HashMap,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+/// Filters that are not polled for this long are uninstalled.
+pub const FILTER_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// The kind of an installed filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterKind {
+	/// Reports the logs matching the filter.
+	Logs(Filter),
+	/// Reports the hashes of new blocks.
+	Blocks,
+}
+
+/// An installed filter.
+struct InstalledFilter {
+	/// The kind of the filter.
+	kind: FilterKind,
+	/// The last block reported to the client.
+	last_block: SubstrateBlockNumber,
+	/// The last time the filter was accessed.
+	last_poll: Instant,
+}
+
+/// The polling filters installed by the clients of the server.
+#[derive(Default)]
+pub struct FilterStore {
+	inner: Mutex<FilterStoreInner>,
+}
+
+#[derive(Default)]
+struct FilterStoreInner {
+	/// The id assigned to the next installed filter.
+	next_id: u64,
+	/// The installed filters by id.
+	filters: HashMap<U256, InstalledFilter>,
+}
+
+impl FilterStoreInner {
+	/// Uninstall the filters that have not been polled within [`FILTER_TIMEOUT`].
+	fn prune(&mut self, now: Instant) {
+		self.filters
+			.retain(|_, filter| now.saturating_duration_since(filter.last_poll) < FILTER_TIMEOUT);
+	}
+}
+
+impl FilterStore {
+	/// Install a new filter, reporting changes for the blocks after `current_block`.
+	pub fn install(&self, kind: FilterKind, current_block: SubstrateBlockNumber) -> U256 {
+		let now = Instant::now();
+		let mut inner = self.inner.lock().expect("lock is not poisoned; qed");
+		inner.prune(now);
+
+		inner.next_id += 1;
+		let id = U256::from(inner.next_id);
+		inner
+			.filters
+			.insert(id, InstalledFilter { kind, last_block: current_block, last_poll: now });
+		id
+	}
+
+	/// Get the kind of the filter with the given id.
+	pub fn get(&self, id: &U256) -> Option<FilterKind> {
+		let now = Instant::now();
+		let mut inner = self.inner.lock().expect("lock is not poisoned; qed");
+		inner.prune(now);
+
+		let filter = inner.filters.get_mut(id)?;
+		filter.last_poll = now;
+		Some(filter.kind.clone())
+	}
+
+	/// Poll the filter with the given id.
+	///
+	/// Returns the kind of the filter and the first block that was not reported yet. The blocks
+	/// are only marked as reported by [`Self::mark_reported`], once the changes were fetched.
+	pub fn poll(&self, id: &U256) -> Option<(FilterKind, SubstrateBlockNumber)> {
+		let now = Instant::now();
+		let mut inner = self.inner.lock().expect("lock is not poisoned; qed");
+		inner.prune(now);
+
+		let filter = inner.filters.get_mut(id)?;
+		filter.last_poll = now;
+		Some((filter.kind.clone(), filter.last_block.saturating_add(1)))
+	}
+
+	/// Mark all the blocks up to `block` as reported by the filter with the given id.
+	pub fn mark_reported(&self, id: &U256, block: SubstrateBlockNumber) {
+		let mut inner = self.inner.lock().expect("lock is not poisoned; qed");
+		if let Some(filter) = inner.filters.get_mut(id) {
+			filter.last_block = filter.last_block.max(block);
+		}
+	}
+
+	/// Uninstall the filter with the given id.
+	///
+	/// Returns `true` if the filter was installed.
+	pub fn uninstall(&self, id: &U256) -> bool {
+		let mut inner = self.inner.lock().expect("lock is not poisoned; qed");
+		inner.filters.remove(id).is_some()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn poll_reports_each_block_once() {
+		let store = FilterStore::default();
+		let id = store.install(FilterKind::Blocks, 10);
+
+		assert_eq!(store.poll(&id), Some((FilterKind::Blocks, 11)));
+		store.mark_reported(&id, 12);
+		assert_eq!(store.poll(&id), Some((FilterKind::Blocks, 13)));
+		store.mark_reported(&id, 12);
+		assert_eq!(store.poll(&id), Some((FilterKind::Blocks, 13)));
+		store.mark_reported(&id, 15);
+		assert_eq!(store.poll(&id), Some((FilterKind::Blocks, 16)));
+		store.mark_reported(&id, 14);
+		assert_eq!(store.poll(&id), Some((FilterKind::Blocks, 16)));
+	}
+
+	#[test]
+	fn failed_poll_does_not_skip_blocks() {
+		let store = FilterStore::default();
+		let id = store.install(FilterKind::Blocks, 10);
+
+		// The changes could not be fetched, so the blocks are not marked as reported.
+		assert_eq!(store.poll(&id), Some((FilterKind::Blocks, 11)));
+		assert_eq!(store.poll(&id), Some((FilterKind::Blocks, 11)));
+	}
+
+	#[test]
+	fn uninstall_works() {
+		let store = FilterStore::default();
+		let id = store.install(FilterKind::Logs(Filter::default()), 0);
+		let other = store.install(FilterKind::Blocks, 0);
+		assert_ne!(id, other);
+
+		assert_eq!(store.get(&id), Some(FilterKind::Logs(Filter::default())));
+		assert!(store.uninstall(&id));
+		assert!(!store.uninstall(&id));
+		assert_eq!(store.get(&id), None);
+		assert_eq!(store.poll(&id), None);
+		assert_eq!(store.get(&other), Some(FilterKind::Blocks));
+	}
+
+	#[test]
+	fn stale_filters_are_pruned() {
+		let store = FilterStore::default();
+		let id = store.install(FilterKind::Blocks, 0);
+
+		let mut inner = store.inner.lock().unwrap();
+		inner.prune(Instant::now() + FILTER_TIMEOUT / 2);
+		assert!(inner.filters.contains_key(&id));
+
+		inner.prune(Instant::now() + FILTER_TIMEOUT);
+		assert!(!inner.filters.contains_key(&id));
+	}
+}
@@ -17,8 +17,8 @@
 //! The [`EthRpcServer`] RPC server implementation
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-use crate::runtime::GAS_PRICE;
 use client::ClientError;
+use filters::{FilterKind, FilterStore};
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
 	types::{ErrorCode, ErrorObjectOwned},
@@ -30,17 +30,26 @@ use thiserror::Error;
 pub mod cli;
 pub mod client;
 pub mod example;
+pub mod fee_history;
+pub mod filters;
+pub mod receipt_index;
 pub mod subxt_client;
 
 #[cfg(test)]
 mod tests;
 
+mod rpc_debug;
+pub use rpc_debug::*;
+
 mod rpc_health;
 pub use rpc_health::*;
 
 mod rpc_methods_gen;
 pub use rpc_methods_gen::*;
 
+mod rpc_pubsub;
+pub use rpc_pubsub::*;
+
 pub const LOG_TARGET: &str = "eth-rpc";
 
 /// An EVM RPC server implementation.
@@ -50,12 +59,15 @@ pub struct EthRpcServerImpl {
 
 	/// The accounts managed by the server.
 	accounts: Vec<Account>,
+
+	/// The polling filters installed by `eth_newFilter` and `eth_newBlockFilter`.
+	filters: FilterStore,
 }
 
 impl EthRpcServerImpl {
 	/// Creates a new [`EthRpcServerImpl`].
 	pub fn new(client: client::Client) -> Self {
-		Self { client, accounts: vec![] }
+		Self { client, accounts: vec![], filters: Default::default() }
 	}
 
 	/// Sets the accounts managed by the server.
@@ -89,6 +101,12 @@ pub enum EthRpcError {
 	/// Received an invalid transaction
 	#[error("Invalid transaction {0:?}")]
 	TransactionTypeNotSupported(Byte),
+	/// The filter was not found, or has expired.
+	#[error("Filter not found")]
+	FilterNotFound,
+	/// Received invalid subscription parameters
+	#[error("Invalid subscription parameters")]
+	InvalidSubscriptionParams,
 }
 
 // TODO use https://eips.ethereum.org/EIPS/eip-1474#error-codes
@@ -181,7 +199,16 @@ impl EthRpcServer for EthRpcServerImpl {
 			transaction.gas = Some(self.estimate_gas(transaction.clone(), None).await?);
 		}
 
-		if transaction.gas_price.is_none() {
+		if transaction.r#type == Some(TYPE_EIP1559.into()) {
+			if transaction.max_priority_fee_per_gas.is_none() {
+				transaction.max_priority_fee_per_gas = Some(self.max_priority_fee_per_gas().await?);
+			}
+			if transaction.max_fee_per_gas.is_none() {
+				let base_fee = self.client.base_fee_per_gas().await?;
+				transaction.max_fee_per_gas =
+					transaction.max_priority_fee_per_gas.map(|tip| base_fee.saturating_add(tip));
+			}
+		} else if transaction.gas_price.is_none() {
 			transaction.gas_price = Some(self.gas_price().await?);
 		}
 
@@ -222,7 +249,24 @@ impl EthRpcServer for EthRpcServerImpl {
 	}
 
 	async fn gas_price(&self) -> RpcResult<U256> {
-		Ok(U256::from(GAS_PRICE))
+		Ok(self.client.base_fee_per_gas().await?)
+	}
+
+	async fn fee_history(
+		&self,
+		block_count: U256,
+		newest_block: BlockNumberOrTag,
+		reward_percentiles: Option<Vec<f64>>,
+	) -> RpcResult<FeeHistoryResult> {
+		let block_count = block_count.try_into().unwrap_or(u32::MAX);
+		let result =
+			self.client.fee_history(block_count, &newest_block, reward_percentiles).await?;
+		Ok(result)
+	}
+
+	async fn max_priority_fee_per_gas(&self) -> RpcResult<U256> {
+		let fee = self.client.max_priority_fee_per_gas().await?;
+		Ok(fee)
 	}
 
 	async fn get_code(&self, address: H160, block: BlockNumberOrTagOrHash) -> RpcResult<Bytes> {
@@ -345,4 +389,68 @@ impl EthRpcServer for EthRpcServerImpl {
 		let nonce = self.client.nonce(address, block).await?;
 		Ok(nonce)
 	}
+
+	async fn get_logs(&self, filter: Option<Filter>) -> RpcResult<FilterResults> {
+		let logs = self.client.logs(&filter.unwrap_or_default()).await?;
+		Ok(FilterResults::Logs(logs))
+	}
+
+	async fn new_filter(&self, filter: Filter) -> RpcResult<U256> {
+		let current_block = self.client.block_number().await?;
+		Ok(self.filters.install(FilterKind::Logs(filter), current_block))
+	}
+
+	async fn new_block_filter(&self) -> RpcResult<U256> {
+		let current_block = self.client.block_number().await?;
+		Ok(self.filters.install(FilterKind::Blocks, current_block))
+	}
+
+	async fn get_filter_changes(&self, filter_id: U256) -> RpcResult<FilterResults> {
+		let latest = self.client.block_number().await?;
+		let (kind, from) = self.filters.poll(&filter_id).ok_or(EthRpcError::FilterNotFound)?;
+
+		let results = match kind {
+			FilterKind::Logs(filter) => {
+				let from = match &filter.from_block {
+					Some(BlockNumberOrTag::U256(n)) =>
+						from.max((*n).try_into().map_err(|_| EthRpcError::ConversionError)?),
+					_ => from,
+				};
+				let to = match &filter.to_block {
+					Some(BlockNumberOrTag::U256(n)) =>
+						latest.min((*n).try_into().map_err(|_| EthRpcError::ConversionError)?),
+					_ => latest,
+				};
+				let logs = self.client.logs_in_range(&filter, from, to).await?;
+				FilterResults::Logs(logs)
+			},
+			FilterKind::Blocks => {
+				let mut hashes = Vec::new();
+				for number in from..=latest {
+					if let Some(hash) = self.client.get_block_hash(number).await? {
+						hashes.push(hash);
+					}
+				}
+				FilterResults::Hashes(hashes)
+			},
+		};
+
+		// Only advance the filter once the changes were fetched, so that a failed poll can be
+		// retried without losing them.
+		self.filters.mark_reported(&filter_id, latest);
+		Ok(results)
+	}
+
+	async fn get_filter_logs(&self, filter_id: U256) -> RpcResult<FilterResults> {
+		let Some(FilterKind::Logs(filter)) = self.filters.get(&filter_id) else {
+			return Err(EthRpcError::FilterNotFound.into());
+		};
+
+		let logs = self.client.logs(&filter).await?;
+		Ok(FilterResults::Logs(logs))
+	}
+
+	async fn uninstall_filter(&self, filter_id: U256) -> RpcResult<bool> {
+		Ok(self.filters.uninstall(&filter_id))
+	}
 }
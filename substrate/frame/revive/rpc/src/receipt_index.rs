@@ -0,0 +1,197 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! An on-disk index of the Ethereum transactions included in finalized blocks.
+//!
+//! Unlike the in-memory cache of the [`crate::client::Client`], which only holds the most recent
+//! blocks, the index covers the whole history of the chain and persists across restarts.
+use crate::{client::SubstrateBlockNumber, LOG_TARGET};
+use codec::{Decode, Encode};
+use kvdb::{DBTransaction, KeyValueDB};
+use pallet_revive::evm::{ReceiptInfo, TransactionSigned, H256, U256};
+use std::{io, path::Path, sync::Arc};
+
+/// The number of columns of the database.
+pub const NUM_COLUMNS: u32 = 3;
+
+/// The columns of the database.
+mod columns {
+	/// Maps a transaction hash to its receipt and signed transaction.
+	pub const RECEIPTS: u32 = 0;
+	/// Maps a block hash to the transaction index and hash of its Ethereum transactions.
+	pub const BLOCK_TRANSACTIONS: u32 = 1;
+	/// Index metadata.
+	pub const META: u32 = 2;
+}
+
+/// The key of the number of the last indexed block, in the [`columns::META`] column.
+const LAST_INDEXED_BLOCK_KEY: &[u8] = b"last_indexed_block";
+
+/// An on-disk index of the receipts of the Ethereum transactions of finalized blocks.
+///
+/// Blocks are indexed in ascending order, so that all the blocks up to
+/// [`ReceiptIndex::last_indexed_block`] are indexed.
+#[derive(Clone)]
+pub struct ReceiptIndex {
+	db: Arc<dyn KeyValueDB>,
+}
+
+impl ReceiptIndex {
+	/// Open the index stored at the given path, creating it if it does not exist.
+	pub fn open(path: &Path) -> io::Result<Self> {
+		let config = kvdb_rocksdb::DatabaseConfig::with_columns(NUM_COLUMNS);
+		let db = kvdb_rocksdb::Database::open(&config, path)?;
+		Ok(Self::new(Arc::new(db)))
+	}
+
+	/// Create an index backed by the given database.
+	///
+	/// The database must have at least [`NUM_COLUMNS`] columns.
+	pub fn new(db: Arc<dyn KeyValueDB>) -> Self {
+		Self { db }
+	}
+
+	/// Get the number of the last indexed block, if any.
+	pub fn last_indexed_block(&self) -> io::Result<Option<SubstrateBlockNumber>> {
+		self.get(columns::META, LAST_INDEXED_BLOCK_KEY)
+	}
+
+	/// Index the Ethereum transactions of a block, and mark it as the last indexed block.
+	pub fn insert_block(
+		&self,
+		block_hash: H256,
+		block_number: SubstrateBlockNumber,
+		receipts: impl IntoIterator<Item = (TransactionSigned, ReceiptInfo)>,
+	) -> io::Result<()> {
+		let mut transaction = DBTransaction::new();
+		let mut block_transactions = Vec::new();
+
+		for (signed_tx, receipt) in receipts {
+			block_transactions.push((receipt.transaction_index, receipt.transaction_hash));
+			transaction.put_vec(
+				columns::RECEIPTS,
+				receipt.transaction_hash.as_bytes(),
+				(receipt, signed_tx).encode(),
+			);
+		}
+
+		if !block_transactions.is_empty() {
+			block_transactions.sort();
+			transaction.put_vec(
+				columns::BLOCK_TRANSACTIONS,
+				block_hash.as_bytes(),
+				block_transactions.encode(),
+			);
+		}
+
+		transaction.put_vec(columns::META, LAST_INDEXED_BLOCK_KEY, block_number.encode());
+		self.db.write(transaction)
+	}
+
+	/// Get the receipt and the signed transaction of the given transaction hash.
+	fn entry(&self, tx_hash: &H256) -> Option<(ReceiptInfo, TransactionSigned)> {
+		self.get(columns::RECEIPTS, tx_hash.as_bytes())
+			.inspect_err(|err| log::error!(target: LOG_TARGET, "Failed to read receipt: {err:?}"))
+			.ok()?
+	}
+
+	/// Get the transaction index and hash of the Ethereum transactions of the given block.
+	fn block_transactions(&self, block_hash: &H256) -> Option<Vec<(U256, H256)>> {
+		self.get(columns::BLOCK_TRANSACTIONS, block_hash.as_bytes())
+			.inspect_err(
+				|err| log::error!(target: LOG_TARGET, "Failed to read block transactions: {err:?}"),
+			)
+			.ok()?
+	}
+
+	/// Get the receipt of the given transaction hash.
+	pub fn receipt(&self, tx_hash: &H256) -> Option<ReceiptInfo> {
+		self.entry(tx_hash).map(|(receipt, _)| receipt)
+	}
+
+	/// Get the signed transaction of the given transaction hash.
+	pub fn signed_tx(&self, tx_hash: &H256) -> Option<TransactionSigned> {
+		self.entry(tx_hash).map(|(_, signed_tx)| signed_tx)
+	}
+
+	/// Get the receipt of the transaction at the given index in the given block.
+	pub fn receipt_by_block_and_index(
+		&self,
+		block_hash: &H256,
+		transaction_index: &U256,
+	) -> Option<ReceiptInfo> {
+		let transactions = self.block_transactions(block_hash)?;
+		let (_, tx_hash) = transactions.iter().find(|(index, _)| index == transaction_index)?;
+		self.receipt(tx_hash)
+	}
+
+	/// Get the number of Ethereum transactions in the given block.
+	pub fn receipts_count(&self, block_hash: &H256) -> Option<usize> {
+		self.block_transactions(block_hash).map(|transactions| transactions.len())
+	}
+
+	/// Read and decode a value from the database.
+	fn get<T: Decode>(&self, column: u32, key: &[u8]) -> io::Result<Option<T>> {
+		let Some(value) = self.db.get(column, key)? else { return Ok(None) };
+		let value = T::decode(&mut &value[..])
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+		Ok(Some(value))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn receipt(block_hash: H256, transaction_index: u32, tx_hash: H256) -> ReceiptInfo {
+		ReceiptInfo {
+			block_hash,
+			transaction_index: transaction_index.into(),
+			transaction_hash: tx_hash,
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn insert_block_works() {
+		let index = ReceiptIndex::new(Arc::new(kvdb_memorydb::create(NUM_COLUMNS)));
+		assert_eq!(index.last_indexed_block().unwrap(), None);
+
+		let block_hash = H256::repeat_byte(1);
+		let (tx1, tx2) = (H256::repeat_byte(2), H256::repeat_byte(3));
+		let receipts = vec![
+			(TransactionSigned::default(), receipt(block_hash, 4, tx2)),
+			(TransactionSigned::default(), receipt(block_hash, 1, tx1)),
+		];
+		index.insert_block(block_hash, 7, receipts).unwrap();
+
+		assert_eq!(index.last_indexed_block().unwrap(), Some(7));
+		assert_eq!(index.receipt(&tx1), Some(receipt(block_hash, 1, tx1)));
+		assert_eq!(index.signed_tx(&tx2), Some(TransactionSigned::default()));
+		assert_eq!(
+			index.receipt_by_block_and_index(&block_hash, &4.into()),
+			Some(receipt(block_hash, 4, tx2))
+		);
+		assert_eq!(index.receipt_by_block_and_index(&block_hash, &2.into()), None);
+		assert_eq!(index.receipts_count(&block_hash), Some(2));
+
+		// Blocks without Ethereum transactions only update the last indexed block.
+		index.insert_block(H256::repeat_byte(4), 8, vec![]).unwrap();
+		assert_eq!(index.last_indexed_block().unwrap(), Some(8));
+		assert_eq!(index.receipts_count(&H256::repeat_byte(4)), None);
+		assert_eq!(index.receipt(&H256::repeat_byte(5)), None);
+	}
+}
@@ -0,0 +1,97 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Geth compatible `debug_trace*` JSON-RPC methods.
+
+use super::*;
+use jsonrpsee::{proc_macros::rpc, Extensions};
+use sc_rpc_api::check_if_safe;
+
+/// Tracing methods, only available when unsafe RPC methods are allowed.
+#[rpc(server, client)]
+pub trait DebugRpc {
+	/// Returns the traces of the transactions of the given block.
+	#[method(name = "debug_traceBlockByNumber", with_extensions)]
+	async fn trace_block_by_number(
+		&self,
+		block: BlockNumberOrTag,
+		tracer_config: TracerConfig,
+	) -> RpcResult<Vec<TransactionTrace>>;
+
+	/// Returns the trace of the transaction with the given hash.
+	#[method(name = "debug_traceTransaction", with_extensions)]
+	async fn trace_transaction(
+		&self,
+		transaction_hash: H256,
+		tracer_config: TracerConfig,
+	) -> RpcResult<Trace>;
+
+	/// Returns the trace of the dry run of the given transaction.
+	#[method(name = "debug_traceCall", with_extensions)]
+	async fn trace_call(
+		&self,
+		transaction: GenericTransaction,
+		block: BlockNumberOrTagOrHash,
+		tracer_config: TracerConfig,
+	) -> RpcResult<Trace>;
+}
+
+pub struct DebugRpcServerImpl {
+	client: client::Client,
+}
+
+impl DebugRpcServerImpl {
+	pub fn new(client: client::Client) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl DebugRpcServer for DebugRpcServerImpl {
+	async fn trace_block_by_number(
+		&self,
+		ext: &Extensions,
+		block: BlockNumberOrTag,
+		tracer_config: TracerConfig,
+	) -> RpcResult<Vec<TransactionTrace>> {
+		check_if_safe(ext)?;
+		let traces = self.client.trace_block_by_number(block, tracer_config).await?;
+		Ok(traces)
+	}
+
+	async fn trace_transaction(
+		&self,
+		ext: &Extensions,
+		transaction_hash: H256,
+		tracer_config: TracerConfig,
+	) -> RpcResult<Trace> {
+		check_if_safe(ext)?;
+		let trace = self.client.trace_transaction(transaction_hash, tracer_config).await?;
+		Ok(trace)
+	}
+
+	async fn trace_call(
+		&self,
+		ext: &Extensions,
+		transaction: GenericTransaction,
+		block: BlockNumberOrTagOrHash,
+		tracer_config: TracerConfig,
+	) -> RpcResult<Trace> {
+		check_if_safe(ext)?;
+		let trace = self.client.trace_call(transaction, block, tracer_config).await?;
+		Ok(trace)
+	}
+}
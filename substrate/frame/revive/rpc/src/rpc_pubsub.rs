@@ -0,0 +1,122 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Pub/sub JSON-RPC methods.
+
+use super::*;
+use client::BlockNotification;
+use jsonrpsee::{
+	core::SubscriptionResult, proc_macros::rpc, PendingSubscriptionSink, SubscriptionMessage,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+
+/// The kind of events an `eth_subscribe` subscription is notified of.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SubscriptionKind {
+	/// New block headers.
+	#[serde(rename = "newHeads")]
+	NewHeads,
+	/// Logs matching the subscription filter.
+	#[serde(rename = "logs")]
+	Logs,
+}
+
+/// An item sent to `eth_subscribe` subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum SubscriptionItem {
+	/// A new block header, sent to `newHeads` subscribers.
+	Block(Block),
+	/// A log, sent to `logs` subscribers.
+	Log(Log),
+}
+
+#[rpc(server, client)]
+pub trait EthPubSubRpc {
+	/// Subscribes to new block headers, or to the logs matching the given filter.
+	#[subscription(
+		name = "eth_subscribe" => "eth_subscription",
+		unsubscribe = "eth_unsubscribe",
+		item = SubscriptionItem
+	)]
+	async fn subscribe(&self, kind: SubscriptionKind, filter: Option<Filter>)
+		-> SubscriptionResult;
+}
+
+pub struct EthPubSubRpcServerImpl {
+	client: client::Client,
+}
+
+impl EthPubSubRpcServerImpl {
+	pub fn new(client: client::Client) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl EthPubSubRpcServer for EthPubSubRpcServerImpl {
+	async fn subscribe(
+		&self,
+		pending: PendingSubscriptionSink,
+		kind: SubscriptionKind,
+		filter: Option<Filter>,
+	) -> SubscriptionResult {
+		if kind == SubscriptionKind::NewHeads && filter.is_some() {
+			pending.reject(EthRpcError::InvalidSubscriptionParams).await;
+			return Ok(());
+		}
+
+		let mut notifications = self.client.subscribe_new_blocks();
+		let sink = pending.accept().await?;
+		let filter = filter.unwrap_or_default();
+
+		loop {
+			let notification = tokio::select! {
+				_ = sink.closed() => break,
+				notification = notifications.recv() => notification,
+			};
+
+			let BlockNotification { block, logs } = match notification {
+				Ok(notification) => notification,
+				Err(RecvError::Lagged(skipped)) => {
+					log::warn!(target: LOG_TARGET, "eth_subscribe lagged, skipped {skipped} blocks");
+					continue;
+				},
+				Err(RecvError::Closed) => break,
+			};
+
+			let items = match kind {
+				SubscriptionKind::NewHeads =>
+					vec![SubscriptionItem::Block(self.client.evm_block(block).await?)],
+				SubscriptionKind::Logs => logs
+					.iter()
+					.filter(|log| filter.matches(log))
+					.map(|log| SubscriptionItem::Log(log.clone()))
+					.collect(),
+			};
+
+			for item in items {
+				let message = SubscriptionMessage::from_json(&item)?;
+				if sink.send(message).await.is_err() {
+					return Ok(());
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
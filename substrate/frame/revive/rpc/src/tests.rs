@@ -26,7 +26,10 @@ use ethabi::Token;
 use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
 use pallet_revive::{
 	create1,
-	evm::{Account, BlockTag, U256},
+	evm::{
+		Account, BlockTag, Filter, FilterResults, GenericTransaction, Transaction1559Unsigned,
+		H256, U256,
+	},
 };
 use static_init::dynamic;
 use std::thread;
@@ -142,6 +145,53 @@ async fn transfer() -> anyhow::Result<()> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn eip1559_transfer() -> anyhow::Result<()> {
+	let _lock = SHARED_RESOURCES.write();
+	let client = SharedResources::client().await;
+
+	let alith = Account::default();
+	let ethan = Account::from(subxt_signer::eth::dev::ethan());
+	let value = U256::from(1_000_000_000_000_000_000u128);
+	let gas = client
+		.estimate_gas(
+			GenericTransaction {
+				from: Some(alith.address()),
+				to: Some(ethan.address()),
+				value: Some(value),
+				..Default::default()
+			},
+			None,
+		)
+		.await?;
+
+	let base_fee = client.gas_price().await?;
+	let max_priority_fee_per_gas = client.max_priority_fee_per_gas().await? + 1;
+	let tx = Transaction1559Unsigned {
+		chain_id: client.chain_id().await?,
+		nonce: client.get_transaction_count(alith.address(), BlockTag::Latest.into()).await?,
+		to: Some(ethan.address()),
+		value,
+		gas,
+		max_fee_per_gas: base_fee * 2 + max_priority_fee_per_gas,
+		max_priority_fee_per_gas,
+		..Default::default()
+	};
+	let payload = alith.sign_transaction(tx.into()).signed_payload();
+	let hash = client.send_raw_transaction(payload.into()).await?;
+
+	let receipt = wait_for_successful_receipt(&client, hash).await?;
+	assert_eq!(receipt.effective_gas_price, base_fee + max_priority_fee_per_gas);
+
+	let history = client
+		.fee_history(1u32.into(), receipt.block_number.into(), Some(vec![0.0, 100.0]))
+		.await?;
+	assert_eq!(history.oldest_block, receipt.block_number);
+	assert_eq!(history.base_fee_per_gas, vec![base_fee; 2]);
+	assert_eq!(history.reward, Some(vec![vec![max_priority_fee_per_gas; 2]]));
+	Ok(())
+}
+
 #[tokio::test]
 async fn deploy_and_call() -> anyhow::Result<()> {
 	let _lock = SHARED_RESOURCES.write();
@@ -256,6 +306,24 @@ async fn event_logs() -> anyhow::Result<()> {
 		.send_and_wait_for_receipt(&client)
 		.await?;
 	assert_eq!(receipt.logs.len(), 1, "There should be one log.");
+
+	let filter = Filter {
+		address: receipt.to.map(Into::into),
+		from_block: Some(receipt.block_number.into()),
+		to_block: Some(receipt.block_number.into()),
+		..Default::default()
+	};
+	let logs = client.get_logs(Some(filter.clone())).await?;
+	assert_eq!(
+		logs,
+		FilterResults::Logs(receipt.logs.clone()),
+		"eth_getLogs should return the receipt logs."
+	);
+
+	// An empty result can't be told apart from an empty list of hashes when decoded.
+	let filter = Filter { topics: Some(vec![Some(H256::zero().into())]), ..filter };
+	let logs = client.get_logs(Some(filter)).await?;
+	assert_eq!(logs, FilterResults::default(), "No log should match the topic filter.");
 	Ok(())
 }
 
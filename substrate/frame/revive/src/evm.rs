@@ -20,3 +20,5 @@
 mod api;
 pub use api::*;
 pub mod runtime;
+mod tracing;
+pub use tracing::*;
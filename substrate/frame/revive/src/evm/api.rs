@@ -26,9 +26,14 @@ mod type_id;
 pub use type_id::*;
 
 mod rpc_types;
+pub use rpc_types::*;
+
 mod rpc_types_gen;
 pub use rpc_types_gen::*;
 
+mod debug_rpc_types;
+pub use debug_rpc_types::*;
+
 #[cfg(feature = "std")]
 mod account;
 
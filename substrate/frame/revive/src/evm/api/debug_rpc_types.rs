@@ -0,0 +1,252 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Types used by the Geth compatible `debug_trace*` JSON-RPC methods.
+
+use crate::evm::{Bytes, H160, H256, U256};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use codec::{Decode, Encode};
+use derive_more::From;
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+
+/// The tracer used to build the trace.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq)]
+pub enum TracerType {
+	/// Builds the tree of calls made during the execution, see [`CallTrace`].
+	#[serde(rename = "callTracer")]
+	CallTracer,
+	/// Collects the state of the accounts touched during the execution, see [`PrestateTrace`].
+	#[serde(rename = "prestateTracer")]
+	PrestateTracer,
+}
+
+/// Tracer options
+///
+/// Options that do not apply to the selected tracer are ignored.
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TracerOptions {
+	/// Include the logs emitted by each call in the call trace.
+	pub with_logs: bool,
+	/// Only trace the top-level call, and none of its sub-calls.
+	pub only_top_call: bool,
+	/// Return the state changed by the execution instead of the state before it.
+	pub diff_mode: bool,
+	/// Do not include the code of the accounts in the prestate trace.
+	pub disable_code: bool,
+	/// Do not include the storage of the accounts in the prestate trace.
+	pub disable_storage: bool,
+}
+
+/// Tracer configuration
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TracerConfig {
+	/// The tracer used to build the trace.
+	pub tracer: TracerType,
+	/// The tracer options.
+	#[serde(rename = "tracerConfig", default)]
+	pub tracer_config: TracerOptions,
+}
+
+impl From<TracerType> for TracerConfig {
+	fn from(tracer: TracerType) -> Self {
+		Self { tracer, tracer_config: Default::default() }
+	}
+}
+
+/// A trace built by one of the supported tracers.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum Trace {
+	/// A call trace.
+	Call(CallTrace),
+	/// A prestate trace.
+	Prestate(PrestateTrace),
+}
+
+/// The trace of a transaction, as returned by `debug_traceBlockByNumber`.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TransactionTrace {
+	/// The hash of the transaction.
+	#[serde(rename = "txHash")]
+	pub tx_hash: H256,
+	/// The trace of the transaction.
+	pub result: Trace,
+}
+
+/// The type of a call.
+#[derive(
+	Debug, Default, Clone, Copy, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CallType {
+	/// A regular call.
+	#[default]
+	Call,
+	/// A read-only call.
+	StaticCall,
+	/// A delegate call.
+	DelegateCall,
+	/// A contract instantiation.
+	Create,
+}
+
+/// A call trace, as built by the `callTracer`.
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct CallTrace {
+	/// The address of the caller.
+	pub from: H160,
+	/// The gas available to the call.
+	pub gas: U256,
+	/// The gas used by the call, including its sub-calls.
+	pub gas_used: U256,
+	/// The address of the callee, or of the instantiated contract.
+	pub to: H160,
+	/// The input data of the call.
+	pub input: Bytes,
+	/// The output data of the call.
+	#[serde(skip_serializing_if = "Bytes::is_empty", default)]
+	pub output: Bytes,
+	/// The error, if the call failed or reverted.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+	/// The reason passed to `revert`, if the call reverted with a Solidity `Error(string)`.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub revert_reason: Option<String>,
+	/// The sub-calls made by the call.
+	#[serde(skip_serializing_if = "Vec::is_empty", default)]
+	pub calls: Vec<CallTrace>,
+	/// The logs emitted by the call.
+	#[serde(skip_serializing_if = "Vec::is_empty", default)]
+	pub logs: Vec<CallLog>,
+	/// The value transferred by the call.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub value: Option<U256>,
+	/// The type of the call.
+	#[serde(rename = "type")]
+	pub call_type: CallType,
+}
+
+/// A log emitted during a call.
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct CallLog {
+	/// The address of the contract that emitted the log.
+	pub address: H160,
+	/// The topics of the log.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub topics: Vec<H256>,
+	/// The data of the log.
+	pub data: Bytes,
+	/// The number of sub-calls made by the emitting call before the log was emitted.
+	pub position: U256,
+}
+
+/// A prestate trace, as built by the `prestateTracer`.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PrestateTrace {
+	/// The state of the accounts touched by the execution, before it ran.
+	Prestate(BTreeMap<H160, PrestateTraceInfo>),
+	/// The state modified by the execution, before and after it ran.
+	DiffMode {
+		/// The modified state, before the execution.
+		pre: BTreeMap<H160, PrestateTraceInfo>,
+		/// The modified state, after the execution.
+		post: BTreeMap<H160, PrestateTraceInfo>,
+	},
+}
+
+/// The state of an account in a [`PrestateTrace`].
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct PrestateTraceInfo {
+	/// The balance of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub balance: Option<U256>,
+	/// The nonce of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub nonce: Option<u32>,
+	/// The code of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<Bytes>,
+	/// The storage slots accessed by the execution. A `None` value is an empty slot.
+	#[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+	pub storage: BTreeMap<Bytes, Option<Bytes>>,
+}
+
+impl PrestateTraceInfo {
+	/// Returns `true` if no field of the account is set.
+	pub fn is_empty(&self) -> bool {
+		self.balance.is_none() &&
+			self.nonce.is_none() &&
+			self.code.is_none() &&
+			self.storage.is_empty()
+	}
+}
+
+#[test]
+fn tracer_config_deserialization_works() {
+	let config: TracerConfig = serde_json::from_str(r#"{ "tracer": "callTracer" }"#).unwrap();
+	assert_eq!(config, TracerType::CallTracer.into());
+
+	let config: TracerConfig = serde_json::from_str(
+		r#"{ "tracer": "prestateTracer", "tracerConfig": { "diffMode": true }, "timeout": "10s" }"#,
+	)
+	.unwrap();
+	assert_eq!(
+		config,
+		TracerConfig {
+			tracer: TracerType::PrestateTracer,
+			tracer_config: TracerOptions { diff_mode: true, ..Default::default() },
+		}
+	);
+
+	assert!(serde_json::from_str::<TracerConfig>(r#"{ "tracer": "4byteTracer" }"#).is_err());
+}
+
+#[test]
+fn call_trace_serialization_works() {
+	let trace = CallTrace {
+		from: H160::repeat_byte(1),
+		to: H160::repeat_byte(2),
+		gas: 100.into(),
+		gas_used: 42.into(),
+		input: Bytes(vec![1, 2]),
+		call_type: CallType::StaticCall,
+		..Default::default()
+	};
+
+	assert_eq!(
+		serde_json::to_value(&trace).unwrap(),
+		serde_json::json!({
+			"from": "0x0101010101010101010101010101010101010101",
+			"gas": "0x64",
+			"gasUsed": "0x2a",
+			"to": "0x0202020202020202020202020202020202020202",
+			"input": "0x0102",
+			"type": "STATICCALL",
+		})
+	);
+}
@@ -14,11 +14,120 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-//! Utility impl for the RPC types.
+//! Utility impl for the RPC types, and the RPC types that are not part of the generated set.
 use super::*;
 use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use derive_more::{From, TryInto};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_core::{H160, U256};
 
+/// The filter of `eth_getLogs` and `eth_newFilter`.
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct Filter {
+	/// The address or addresses the logs must be emitted by.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub address: Option<AddressOrAddresses>,
+	/// Restricts the logs to the single block with this hash.
+	///
+	/// Cannot be combined with `fromBlock` or `toBlock`.
+	#[serde(rename = "blockHash", skip_serializing_if = "Option::is_none")]
+	pub block_hash: Option<H256>,
+	/// The first block of the range, inclusive.
+	#[serde(rename = "fromBlock", skip_serializing_if = "Option::is_none")]
+	pub from_block: Option<BlockNumberOrTag>,
+	/// The last block of the range, inclusive.
+	#[serde(rename = "toBlock", skip_serializing_if = "Option::is_none")]
+	pub to_block: Option<BlockNumberOrTag>,
+	/// The topics the logs must match, by position.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub topics: Option<FilterTopics>,
+}
+
+/// The result of `eth_getLogs`, `eth_getFilterLogs` and `eth_getFilterChanges`.
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum FilterResults {
+	/// The hashes of the new blocks, for block filters.
+	Hashes(Vec<H256>),
+	/// The matching logs, for log filters.
+	Logs(Vec<Log>),
+}
+
+impl Default for FilterResults {
+	fn default() -> Self {
+		FilterResults::Hashes(Default::default())
+	}
+}
+
+/// A single address or a list of addresses.
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum AddressOrAddresses {
+	/// A single address.
+	Address(Address),
+	/// A list of addresses, any of which matches.
+	Addresses(Addresses),
+}
+
+impl Default for AddressOrAddresses {
+	fn default() -> Self {
+		AddressOrAddresses::Address(Default::default())
+	}
+}
+
+/// A list of addresses.
+pub type Addresses = Vec<Address>;
+
+/// The topics of a [`Filter`].
+///
+/// A `null` entry matches any topic at that position.
+pub type FilterTopics = Vec<Option<FilterTopic>>;
+
+/// The topic of a [`Filter`] at a given position.
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum FilterTopic {
+	/// Matches this topic only.
+	Single(H256),
+	/// Matches any of these topics.
+	Multiple(Vec<H256>),
+}
+
+impl Default for FilterTopic {
+	fn default() -> Self {
+		FilterTopic::Single(Default::default())
+	}
+}
+
+/// The result of `eth_feeHistory`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeeHistoryResult {
+	/// The base fee per gas of the blocks of the range, followed by the base fee per gas of the
+	/// block after the newest block of the range.
+	#[serde(rename = "baseFeePerGas")]
+	pub base_fee_per_gas: Vec<U256>,
+	/// The ratio of the gas used to the gas limit of the blocks of the range.
+	#[serde(rename = "gasUsedRatio")]
+	pub gas_used_ratio: Vec<f64>,
+	/// The number of the oldest block of the range.
+	#[serde(rename = "oldestBlock")]
+	pub oldest_block: U256,
+	/// The effective priority fees per gas at the requested percentiles, for each block of the
+	/// range.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub reward: Option<Vec<Vec<U256>>>,
+}
+
 impl TransactionInfo {
 	/// Create a new [`TransactionInfo`] from a receipt and a signed transaction.
 	pub fn new(receipt: ReceiptInfo, transaction_signed: TransactionSigned) -> Self {
@@ -275,4 +384,114 @@ impl GenericTransaction {
 			_ => Err(()),
 		}
 	}
+
+	/// Returns the price per unit of gas paid by this transaction, given the base fee per gas.
+	///
+	/// For EIP-1559 transactions, this is `min(max_fee_per_gas, base_fee + max_priority_fee)`,
+	/// for other transactions, this is the `gas_price`.
+	pub fn effective_gas_price(&self, base_fee: U256) -> Option<U256> {
+		match (self.max_fee_per_gas, self.max_priority_fee_per_gas) {
+			(Some(max_fee), Some(max_priority_fee)) =>
+				Some(max_fee.min(base_fee.saturating_add(max_priority_fee))),
+			_ => self.gas_price,
+		}
+	}
+
+	/// Returns the price per unit of gas paid on top of the given base fee per gas.
+	pub fn effective_priority_fee(&self, base_fee: U256) -> U256 {
+		self.effective_gas_price(base_fee).unwrap_or_default().saturating_sub(base_fee)
+	}
+}
+
+#[test]
+fn effective_gas_price_works() {
+	let legacy = GenericTransaction { gas_price: Some(5u32.into()), ..Default::default() };
+	assert_eq!(legacy.effective_gas_price(1u32.into()), Some(5u32.into()));
+	assert_eq!(legacy.effective_priority_fee(1u32.into()), 4u32.into());
+
+	let tx = GenericTransaction {
+		max_fee_per_gas: Some(10u32.into()),
+		max_priority_fee_per_gas: Some(2u32.into()),
+		..Default::default()
+	};
+	assert_eq!(tx.effective_gas_price(1u32.into()), Some(3u32.into()));
+	assert_eq!(tx.effective_priority_fee(1u32.into()), 2u32.into());
+
+	// The price is capped by the max fee per gas.
+	assert_eq!(tx.effective_gas_price(9u32.into()), Some(10u32.into()));
+	assert_eq!(tx.effective_priority_fee(9u32.into()), 1u32.into());
+	assert_eq!(tx.effective_priority_fee(20u32.into()), 0u32.into());
+}
+
+impl Filter {
+	/// Returns `true` if the log matches the address and topics of this filter.
+	///
+	/// The block range of the filter is not taken into account.
+	pub fn matches(&self, log: &Log) -> bool {
+		let address_matches = match &self.address {
+			None => true,
+			Some(AddressOrAddresses::Address(address)) => *address == log.address,
+			Some(AddressOrAddresses::Addresses(addresses)) =>
+				addresses.is_empty() || addresses.contains(&log.address),
+		};
+
+		address_matches &&
+			self.topics.as_ref().map_or(true, |topics| {
+				topics.iter().enumerate().all(|(i, topic)| match topic {
+					None => true,
+					Some(topic) => topic.matches(log.topics.get(i)),
+				})
+			})
+	}
+}
+
+impl FilterTopic {
+	/// Returns `true` if the given log topic matches this filter topic.
+	fn matches(&self, topic: Option<&H256>) -> bool {
+		match self {
+			FilterTopic::Single(expected) => topic == Some(expected),
+			FilterTopic::Multiple(expected) =>
+				expected.is_empty() || topic.map_or(false, |topic| expected.contains(topic)),
+		}
+	}
+}
+
+#[test]
+fn filter_matches_works() {
+	let log = Log {
+		address: H160::repeat_byte(1),
+		topics: vec![H256::repeat_byte(1), H256::repeat_byte(2)],
+		..Default::default()
+	};
+
+	let filter: Filter = serde_json::from_str(
+		r#"
+		{
+			"address": "0x0101010101010101010101010101010101010101",
+			"topics": [
+				null,
+				["0x0202020202020202020202020202020202020202020202020202020202020202", "0x0303030303030303030303030303030303030303030303030303030303030303"]
+			]
+		}
+		"#,
+	)
+	.unwrap();
+	assert!(filter.matches(&log));
+	assert!(Filter::default().matches(&log));
+
+	let filter = Filter {
+		address: Some(vec![H160::repeat_byte(2), H160::repeat_byte(3)].into()),
+		..Default::default()
+	};
+	assert!(!filter.matches(&log));
+
+	let filter = Filter {
+		topics: Some(vec![None, None, Some(H256::repeat_byte(3).into())]),
+		..Default::default()
+	};
+	assert!(!filter.matches(&log), "log has no third topic");
+
+	let filter =
+		Filter { topics: Some(vec![Some(H256::repeat_byte(2).into())]), ..Default::default() };
+	assert!(!filter.matches(&log), "topics are positional");
 }
@@ -305,8 +305,9 @@ pub trait EthExtra {
 
 		let signer =
 			<Self::Config as crate::Config>::AddressMapper::to_fallback_account_id(&signer);
-		let GenericTransaction { nonce, chain_id, to, value, input, gas, gas_price, .. } =
-			GenericTransaction::from_signed(tx, None);
+		let tx = GenericTransaction::from_signed(tx, None);
+		let gas_price = tx.effective_gas_price(GAS_PRICE.into());
+		let GenericTransaction { nonce, chain_id, to, value, input, gas, .. } = tx;
 
 		if chain_id.unwrap_or_default() != <Self::Config as crate::Config>::ChainId::get().into() {
 			log::debug!(target: LOG_TARGET, "Invalid chain_id {chain_id:?}");
@@ -360,6 +361,7 @@ pub trait EthExtra {
 			.map_err(|_| InvalidTransaction::Call)?;
 
 		// Fees with the actual gas_price from the transaction.
+		// For EIP-1559 transactions, `GAS_PRICE` acts as the base fee per gas.
 		let eth_fee: BalanceOf<Self::Config> = U256::from(gas_price.unwrap_or_default())
 			.saturating_mul(gas.unwrap_or_default())
 			.try_into()
@@ -513,7 +515,15 @@ mod test {
 
 		/// Call `check` on the unchecked extrinsic, and `pre_dispatch` on the signed extension.
 		fn check(&self) -> Result<(RuntimeCall, SignedExtra), TransactionValidityError> {
-			let UncheckedExtrinsicBuilder { tx, gas_limit, storage_deposit_limit } = self.clone();
+			self.check_tx(self.tx.clone().into())
+		}
+
+		/// Same as [`Self::check`], but with the given transaction instead of the builder's one.
+		fn check_tx(
+			&self,
+			tx: TransactionUnsigned,
+		) -> Result<(RuntimeCall, SignedExtra), TransactionValidityError> {
+			let UncheckedExtrinsicBuilder { gas_limit, storage_deposit_limit, .. } = self.clone();
 
 			// Fund the account.
 			let account = Account::default();
@@ -522,7 +532,7 @@ mod test {
 				100_000_000_000_000,
 			);
 
-			let payload = account.sign_transaction(tx.into()).signed_payload();
+			let payload = account.sign_transaction(tx).signed_payload();
 			let call = RuntimeCall::Contracts(crate::Call::eth_transact {
 				payload,
 				gas_limit,
@@ -683,4 +693,34 @@ mod test {
 			assert_eq!(U256::from(extra.1.tip()), expected_tip);
 		});
 	}
+
+	#[test]
+	fn check_eip1559_transaction_tip() {
+		ExtBuilder::default().build().execute_with(|| {
+			let builder = UncheckedExtrinsicBuilder::call_with(H160::from([1u8; 20]));
+			let to_eip1559 =
+				|max_fee_per_gas: u32, max_priority_fee_per_gas: u32| Transaction1559Unsigned {
+					chain_id: builder.tx.chain_id.unwrap(),
+					to: builder.tx.to,
+					gas: builder.tx.gas,
+					max_fee_per_gas: max_fee_per_gas.into(),
+					max_priority_fee_per_gas: max_priority_fee_per_gas.into(),
+					..Default::default()
+				};
+
+			// The tip is the priority fee on top of the `GAS_PRICE` base fee.
+			let (_, extra) = builder.check_tx(to_eip1559(GAS_PRICE * 10, 1).into()).unwrap();
+			assert_eq!(U256::from(extra.1.tip()), builder.tx.gas);
+
+			// The priority fee is capped by the max fee per gas.
+			let (_, extra) = builder.check_tx(to_eip1559(GAS_PRICE, 1).into()).unwrap();
+			assert_eq!(extra.1.tip(), 0);
+
+			// The max fee per gas must cover the base fee.
+			assert_eq!(
+				builder.check_tx(to_eip1559(0, 1).into()),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+		});
+	}
 }
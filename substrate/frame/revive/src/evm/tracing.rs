@@ -0,0 +1,501 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Tracers building the Geth compatible traces returned by the `debug_trace*` methods.
+use crate::{
+	evm::{
+		Bytes, CallLog, CallTrace, CallType, PrestateTrace, PrestateTraceInfo, Trace, TracerConfig,
+		TracerOptions, TracerType,
+	},
+	exec::Key,
+	primitives::ExecReturnValue,
+	tracing::Tracer,
+	AddressMapper, BalanceOf, Config, ContractInfoOf, MomentOf, Pallet, PristineCode,
+};
+use alloc::{
+	collections::{BTreeMap, BTreeSet},
+	string::{String, ToString},
+	vec::Vec,
+};
+use core::marker::PhantomData;
+use frame_support::weights::Weight;
+use sp_core::{H160, H256, U256};
+use sp_runtime::{DispatchError, SaturatedConversion};
+
+/// Converts the weight consumed by a call into EVM gas.
+pub type GasMapper = fn(Weight) -> U256;
+
+/// A tracer configured from a [`TracerConfig`].
+pub enum EvmTracer<T> {
+	/// See [`CallTracer`].
+	CallTracer(CallTracer),
+	/// See [`PrestateTracer`].
+	PrestateTracer(PrestateTracer<T>),
+}
+
+impl<T: Config> EvmTracer<T>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256>,
+	MomentOf<T>: Into<U256>,
+	T::Hash: frame_support::traits::IsType<H256>,
+{
+	/// Create a new tracer from the given configuration.
+	pub fn new(config: TracerConfig, gas_mapper: GasMapper) -> Self {
+		match config.tracer {
+			TracerType::CallTracer =>
+				Self::CallTracer(CallTracer::new(config.tracer_config, gas_mapper)),
+			TracerType::PrestateTracer =>
+				Self::PrestateTracer(PrestateTracer::new(config.tracer_config)),
+		}
+	}
+
+	/// Returns the tracer to install with [`crate::tracing::trace`].
+	pub fn as_tracer(&mut self) -> &mut (dyn Tracer + 'static) {
+		match self {
+			Self::CallTracer(tracer) => tracer,
+			Self::PrestateTracer(tracer) => tracer,
+		}
+	}
+
+	/// Take the trace collected since the last call, if any.
+	pub fn collect_trace(&mut self) -> Option<Trace> {
+		match self {
+			Self::CallTracer(tracer) => tracer.collect_trace().map(Trace::Call),
+			Self::PrestateTracer(tracer) => tracer.collect_trace().map(Trace::Prestate),
+		}
+	}
+}
+
+/// Extract the revert reason from a Solidity `revert("reason")` output.
+fn revert_reason(data: &[u8]) -> Option<String> {
+	// keccak256("Error(string)")
+	const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+	let data = data.strip_prefix(&ERROR_SELECTOR)?;
+	let offset: usize = U256::from_big_endian(data.get(0..32)?).try_into().ok()?;
+	let len_start = offset.checked_add(32)?;
+	let len: usize = U256::from_big_endian(data.get(offset..len_start)?).try_into().ok()?;
+	let reason = data.get(len_start..len_start.checked_add(len)?)?;
+	String::from_utf8(reason.to_vec()).ok()
+}
+
+/// Builds the tree of the calls made during an execution.
+pub struct CallTracer {
+	/// The tracer options.
+	options: TracerOptions,
+	/// Converts weight into EVM gas.
+	gas_mapper: GasMapper,
+	/// The finished top-level call, if any.
+	trace: Option<CallTrace>,
+	/// The calls currently being executed, the innermost one last.
+	stack: Vec<CallTrace>,
+	/// The number of calls currently being executed that are not traced, because of
+	/// [`TracerOptions::only_top_call`].
+	skipped: u32,
+}
+
+impl CallTracer {
+	/// Create a new [`CallTracer`].
+	pub fn new(options: TracerOptions, gas_mapper: GasMapper) -> Self {
+		Self { options, gas_mapper, trace: None, stack: Vec::new(), skipped: 0 }
+	}
+
+	/// Take the trace of the last finished top-level call, if any.
+	pub fn collect_trace(&mut self) -> Option<CallTrace> {
+		self.stack.clear();
+		self.skipped = 0;
+		self.trace.take()
+	}
+
+	/// Finish the innermost call.
+	fn exit(&mut self, gas_used: Weight, finish: impl FnOnce(&mut CallTrace)) {
+		if self.skipped > 0 {
+			self.skipped -= 1;
+			return;
+		}
+
+		let Some(mut call) = self.stack.pop() else { return };
+		call.gas_used = (self.gas_mapper)(gas_used);
+		finish(&mut call);
+
+		match self.stack.last_mut() {
+			Some(parent) => parent.calls.push(call),
+			None => self.trace = Some(call),
+		}
+	}
+}
+
+impl Tracer for CallTracer {
+	fn enter_child_span(
+		&mut self,
+		from: H160,
+		to: H160,
+		call_type: CallType,
+		value: U256,
+		input: &[u8],
+		gas_limit: Weight,
+	) {
+		if self.skipped > 0 || (self.options.only_top_call && !self.stack.is_empty()) {
+			self.skipped += 1;
+			return;
+		}
+
+		self.stack.push(CallTrace {
+			from,
+			to,
+			call_type,
+			value: (call_type != CallType::DelegateCall).then_some(value),
+			input: Bytes(input.to_vec()),
+			gas: (self.gas_mapper)(gas_limit),
+			..Default::default()
+		});
+	}
+
+	fn exit_child_span(&mut self, output: &ExecReturnValue, gas_used: Weight) {
+		self.exit(gas_used, |call| {
+			call.output = Bytes(output.data.clone());
+			if output.did_revert() {
+				call.error = Some("execution reverted".to_string());
+				call.revert_reason = revert_reason(&output.data);
+			}
+		});
+	}
+
+	fn exit_child_span_with_error(&mut self, error: DispatchError, gas_used: Weight) {
+		self.exit(gas_used, |call| {
+			let error: &'static str = error.into();
+			call.error = Some(error.to_string());
+		});
+	}
+
+	fn log_event(&mut self, address: H160, topics: &[H256], data: &[u8]) {
+		if !self.options.with_logs || self.skipped > 0 {
+			return;
+		}
+
+		if let Some(call) = self.stack.last_mut() {
+			call.logs.push(CallLog {
+				address,
+				topics: topics.to_vec(),
+				data: Bytes(data.to_vec()),
+				position: call.calls.len().into(),
+			});
+		}
+	}
+}
+
+/// Collects the state of the accounts touched during an execution.
+///
+/// The state of an account is recorded when it is first touched by a call. The balance of the
+/// transaction sender is therefore reported after the transaction fee was withdrawn.
+pub struct PrestateTracer<T> {
+	/// The tracer options.
+	options: TracerOptions,
+	/// The state of the touched accounts, before they were first touched.
+	pre: BTreeMap<H160, PrestateTraceInfo>,
+	/// The storage keys touched in each account.
+	touched_keys: BTreeMap<H160, BTreeSet<Vec<u8>>>,
+	_phantom: PhantomData<T>,
+}
+
+impl<T: Config> PrestateTracer<T>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256>,
+	MomentOf<T>: Into<U256>,
+	T::Hash: frame_support::traits::IsType<H256>,
+{
+	/// Create a new [`PrestateTracer`].
+	pub fn new(options: TracerOptions) -> Self {
+		Self {
+			options,
+			pre: Default::default(),
+			touched_keys: Default::default(),
+			_phantom: Default::default(),
+		}
+	}
+
+	/// Take the trace of the accounts touched since the last call, if any.
+	pub fn collect_trace(&mut self) -> Option<PrestateTrace> {
+		let pre = core::mem::take(&mut self.pre);
+		let touched_keys = core::mem::take(&mut self.touched_keys);
+		if pre.is_empty() {
+			return None;
+		}
+
+		if !self.options.diff_mode {
+			return Some(PrestateTrace::Prestate(pre));
+		}
+
+		let mut pre_diff = BTreeMap::new();
+		let mut post_diff = BTreeMap::new();
+		for (address, pre_info) in pre {
+			let mut post_info = self.account_info(&address);
+			for key in touched_keys.get(&address).into_iter().flatten() {
+				post_info.storage.insert(Bytes(key.clone()), Self::read_storage(&address, key));
+			}
+
+			let (pre_info, post_info) = Self::diff(pre_info, post_info);
+			if !pre_info.is_empty() {
+				pre_diff.insert(address, pre_info);
+			}
+			if !post_info.is_empty() {
+				post_diff.insert(address, post_info);
+			}
+		}
+
+		Some(PrestateTrace::DiffMode { pre: pre_diff, post: post_diff })
+	}
+
+	/// Only keep the fields that differ between `pre` and `post`.
+	fn diff(
+		mut pre: PrestateTraceInfo,
+		mut post: PrestateTraceInfo,
+	) -> (PrestateTraceInfo, PrestateTraceInfo) {
+		if pre.balance == post.balance {
+			pre.balance = None;
+			post.balance = None;
+		}
+		if pre.nonce == post.nonce {
+			pre.nonce = None;
+			post.nonce = None;
+		}
+		if pre.code == post.code {
+			pre.code = None;
+			post.code = None;
+		}
+
+		let unchanged = pre
+			.storage
+			.iter()
+			.filter(|(key, value)| post.storage.get(*key) == Some(*value))
+			.map(|(key, _)| key.clone())
+			.collect::<Vec<_>>();
+		for key in unchanged {
+			pre.storage.remove(&key);
+			post.storage.remove(&key);
+		}
+
+		(pre, post)
+	}
+
+	/// Read the current balance, nonce and code of an account.
+	fn account_info(&self, address: &H160) -> PrestateTraceInfo {
+		let account_id = T::AddressMapper::to_account_id(address);
+		let code = if self.options.disable_code {
+			None
+		} else {
+			ContractInfoOf::<T>::get(address)
+				.and_then(|info| PristineCode::<T>::get(info.code_hash))
+				.map(|code| Bytes(code.into_inner()))
+		};
+
+		PrestateTraceInfo {
+			balance: Some(Pallet::<T>::evm_balance(address)),
+			nonce: Some(frame_system::Pallet::<T>::account_nonce(&account_id).saturated_into()),
+			code,
+			storage: Default::default(),
+		}
+	}
+
+	/// Read the current value of a storage slot of a contract.
+	fn read_storage(address: &H160, key: &[u8]) -> Option<Bytes> {
+		let key = match <[u8; 32]>::try_from(key) {
+			Ok(key) => Key::from_fixed(key),
+			Err(_) => Key::try_from_var(key.to_vec()).ok()?,
+		};
+		ContractInfoOf::<T>::get(address)?.read(&key).map(Bytes)
+	}
+
+	/// Record the state of an account, if it was not touched yet.
+	fn touch(&mut self, address: H160) {
+		if !self.pre.contains_key(&address) {
+			let info = self.account_info(&address);
+			self.pre.insert(address, info);
+		}
+	}
+}
+
+impl<T: Config> Tracer for PrestateTracer<T>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256>,
+	MomentOf<T>: Into<U256>,
+	T::Hash: frame_support::traits::IsType<H256>,
+{
+	fn enter_child_span(
+		&mut self,
+		from: H160,
+		to: H160,
+		_call_type: CallType,
+		_value: U256,
+		_input: &[u8],
+		_gas_limit: Weight,
+	) {
+		self.touch(from);
+		self.touch(to);
+	}
+
+	fn exit_child_span(&mut self, _output: &ExecReturnValue, _gas_used: Weight) {}
+
+	fn exit_child_span_with_error(&mut self, _error: DispatchError, _gas_used: Weight) {}
+
+	fn log_event(&mut self, _address: H160, _topics: &[H256], _data: &[u8]) {}
+
+	fn storage_access(&mut self, address: H160, key: &[u8], value: Option<&[u8]>) {
+		if self.options.disable_storage {
+			return;
+		}
+
+		self.touch(address);
+		if self.touched_keys.entry(address).or_default().insert(key.to_vec()) {
+			let storage = &mut self.pre.entry(address).or_default().storage;
+			storage.insert(Bytes(key.to_vec()), value.map(|value| Bytes(value.to_vec())));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn gas_mapper(weight: Weight) -> U256 {
+		weight.ref_time().into()
+	}
+
+	#[test]
+	fn revert_reason_works() {
+		// Encoded `Error("revert message")`.
+		let data = [
+			&[0x08, 0xc3, 0x79, 0xa0][..],
+			&U256::from(32).to_big_endian(),
+			&U256::from(14).to_big_endian(),
+			b"revert message",
+			&[0u8; 18],
+		]
+		.concat();
+		assert_eq!(revert_reason(&data), Some("revert message".to_string()));
+		assert_eq!(revert_reason(&data[..40]), None);
+		assert_eq!(revert_reason(&data[4..]), None);
+	}
+
+	#[test]
+	fn call_tracer_builds_call_tree() {
+		let mut tracer =
+			CallTracer::new(TracerOptions { with_logs: true, ..Default::default() }, gas_mapper);
+		let (alice, bob, charlie) =
+			(H160::repeat_byte(1), H160::repeat_byte(2), H160::repeat_byte(3));
+
+		tracer.enter_child_span(
+			alice,
+			bob,
+			CallType::Call,
+			1.into(),
+			&[1],
+			Weight::from_parts(100, 0),
+		);
+		tracer.log_event(bob, &[H256::repeat_byte(1)], &[1]);
+		tracer.enter_child_span(
+			bob,
+			charlie,
+			CallType::StaticCall,
+			0.into(),
+			&[2],
+			Weight::from_parts(50, 0),
+		);
+		tracer.exit_child_span_with_error(DispatchError::Other("boom"), Weight::from_parts(50, 0));
+		tracer.log_event(bob, &[], &[2]);
+		tracer.exit_child_span(
+			&ExecReturnValue { flags: Default::default(), data: vec![3] },
+			Weight::from_parts(80, 0),
+		);
+
+		assert_eq!(
+			tracer.collect_trace(),
+			Some(CallTrace {
+				from: alice,
+				to: bob,
+				gas: 100.into(),
+				gas_used: 80.into(),
+				input: Bytes(vec![1]),
+				output: Bytes(vec![3]),
+				value: Some(1.into()),
+				calls: vec![CallTrace {
+					from: bob,
+					to: charlie,
+					gas: 50.into(),
+					gas_used: 50.into(),
+					input: Bytes(vec![2]),
+					error: Some("boom".to_string()),
+					value: Some(0.into()),
+					call_type: CallType::StaticCall,
+					..Default::default()
+				}],
+				logs: vec![
+					CallLog {
+						address: bob,
+						topics: vec![H256::repeat_byte(1)],
+						data: Bytes(vec![1]),
+						position: 0.into(),
+					},
+					CallLog {
+						address: bob,
+						topics: vec![],
+						data: Bytes(vec![2]),
+						position: 1.into()
+					},
+				],
+				call_type: CallType::Call,
+				..Default::default()
+			})
+		);
+		assert_eq!(tracer.collect_trace(), None);
+	}
+
+	#[test]
+	fn call_tracer_only_top_call_works() {
+		let mut tracer = CallTracer::new(
+			TracerOptions { only_top_call: true, with_logs: true, ..Default::default() },
+			gas_mapper,
+		);
+		let (alice, bob, charlie) =
+			(H160::repeat_byte(1), H160::repeat_byte(2), H160::repeat_byte(3));
+
+		tracer.enter_child_span(
+			alice,
+			bob,
+			CallType::Call,
+			0.into(),
+			&[],
+			Weight::from_parts(100, 0),
+		);
+		tracer.enter_child_span(
+			bob,
+			charlie,
+			CallType::Call,
+			0.into(),
+			&[],
+			Weight::from_parts(50, 0),
+		);
+		tracer.log_event(charlie, &[], &[]);
+		tracer.exit_child_span(&Default::default(), Weight::from_parts(10, 0));
+		tracer.exit_child_span(&Default::default(), Weight::from_parts(20, 0));
+
+		let trace = tracer.collect_trace().unwrap();
+		assert_eq!(trace.to, bob);
+		assert_eq!(trace.gas_used, 20.into());
+		assert!(trace.calls.is_empty());
+		assert!(trace.logs.is_empty());
+	}
+}
@@ -18,11 +18,13 @@
 use crate::{
 	address::{self, AddressMapper},
 	debug::{CallInterceptor, CallSpan, Tracing},
+	evm::CallType,
 	gas::GasMeter,
 	limits,
 	primitives::{ExecReturnValue, StorageDeposit},
 	runtime_decl_for_revive_api::{Decode, Encode, RuntimeDebugNoBound, TypeInfo},
 	storage::{self, meter::Diff, WriteOutcome},
+	tracing::if_tracing,
 	transient_storage::TransientStorage,
 	BalanceOf, CodeInfo, CodeInfoOf, Config, ContractInfo, ContractInfoOf, DebugBuffer, Error,
 	Event, ImmutableData, ImmutableDataOf, Pallet as Contracts, LOG_TARGET,
@@ -83,8 +85,8 @@ impl Key {
 	///
 	/// # Note
 	///
-	/// Only used by benchmarking in order to generate storage collisions on purpose.
-	#[cfg(feature = "runtime-benchmarks")]
+	/// Used by benchmarking in order to generate storage collisions on purpose, and to report
+	/// storage accesses to the installed [`crate::tracing::Tracer`].
 	pub fn unhashed(&self) -> &[u8] {
 		match self {
 			Key::Fix(v) => v.as_ref(),
@@ -168,6 +170,14 @@ impl<T: Config> Origin<T> {
 		}
 	}
 
+	/// Returns the address of a Signed Origin, or the zero address if the origin is Root.
+	fn address(&self) -> H160 {
+		match self {
+			Origin::Signed(id) => T::AddressMapper::to_address(id),
+			Origin::Root => H160::zero(),
+		}
+	}
+
 	/// Make sure that this origin is mapped.
 	///
 	/// We require an origin to be mapped in order to be used in a `Stack`. Otherwise
@@ -780,6 +790,7 @@ where
 		debug_message: Option<&'a mut DebugBuffer>,
 	) -> ExecResult {
 		let dest = T::AddressMapper::to_account_id(&dest);
+		let gas_limit = gas_meter.gas_left();
 		if let Some((mut stack, executable)) = Self::new(
 			FrameArgs::Call { dest: dest.clone(), cached_info: None, delegated_call: None },
 			origin.clone(),
@@ -790,7 +801,7 @@ where
 		)? {
 			stack.run(executable, input_data).map(|_| stack.first_frame.last_frame_output)
 		} else {
-			Self::transfer_from_origin(&origin, &origin, &dest, value)
+			Self::transfer_to_account(&origin, &origin, &dest, value, &input_data, gas_limit)
 		}
 	}
 
@@ -1056,6 +1067,29 @@ where
 			*caller_frame = Default::default();
 		}
 
+		if_tracing(|tracer| {
+			let frame = self.top_frame();
+			let address = T::AddressMapper::to_address(&frame.account_id);
+			let (from, to, call_type) = match (&frame.delegate, entry_point) {
+				(Some(DelegateInfo { callee, .. }), _) =>
+					(address, *callee, CallType::DelegateCall),
+				(None, ExportedFunction::Constructor) =>
+					(self.caller().address(), address, CallType::Create),
+				(None, ExportedFunction::Call) if frame.read_only =>
+					(self.caller().address(), address, CallType::StaticCall),
+				(None, ExportedFunction::Call) =>
+					(self.caller().address(), address, CallType::Call),
+			};
+			tracer.enter_child_span(
+				from,
+				to,
+				call_type,
+				frame.value_transferred,
+				&input_data,
+				frame.nested_gas.gas_left(),
+			);
+		});
+
 		self.transient_storage.start_transaction();
 
 		let do_transaction = || {
@@ -1190,6 +1224,14 @@ where
 			self.transient_storage.rollback_transaction();
 		}
 
+		if_tracing(|tracer| {
+			let gas_used = self.top_frame().nested_gas.gas_consumed();
+			match &output {
+				Ok(output) => tracer.exit_child_span(output, gas_used),
+				Err(e) => tracer.exit_child_span_with_error(e.error, gas_used),
+			}
+		});
+
 		self.pop_frame(success);
 		output.map(|output| {
 			self.top_frame_mut().last_frame_output = output;
@@ -1333,6 +1375,39 @@ where
 		Self::transfer(origin, from, to, value)
 	}
 
+	/// Same as `transfer_from_origin` but the transfer is reported to the installed tracer.
+	///
+	/// Used when calling an account without code, as no frame is executed in this case.
+	fn transfer_to_account(
+		origin: &Origin<T>,
+		from: &Origin<T>,
+		to: &T::AccountId,
+		value: U256,
+		input_data: &[u8],
+		gas_limit: Weight,
+	) -> ExecResult {
+		if_tracing(|tracer| {
+			let to = T::AddressMapper::to_address(to);
+			tracer.enter_child_span(
+				from.address(),
+				to,
+				CallType::Call,
+				value,
+				input_data,
+				gas_limit,
+			);
+		});
+
+		let result = Self::transfer_from_origin(origin, from, to, value);
+
+		if_tracing(|tracer| match &result {
+			Ok(output) => tracer.exit_child_span(output, Weight::zero()),
+			Err(e) => tracer.exit_child_span_with_error(e.error, Weight::zero()),
+		});
+
+		result
+	}
+
 	/// Reference to the current (top) frame.
 	fn top_frame(&self) -> &Frame<T> {
 		top_frame!(self)
@@ -1458,11 +1533,13 @@ where
 			)? {
 				self.run(executable, input_data)
 			} else {
-				Self::transfer_from_origin(
+				Self::transfer_to_account(
 					&self.origin,
 					&Origin::from_account_id(self.account_id().clone()),
 					&dest,
 					value,
+					&input_data,
+					gas_limit,
 				)?;
 				Ok(())
 			}
@@ -1576,7 +1653,11 @@ where
 	}
 
 	fn get_storage(&mut self, key: &Key) -> Option<Vec<u8>> {
-		self.top_frame_mut().contract_info().read(key)
+		let value = self.top_frame_mut().contract_info().read(key);
+		if_tracing(|tracer| {
+			tracer.storage_access(self.address(), key.unhashed(), value.as_deref());
+		});
+		value
 	}
 
 	fn get_storage_size(&mut self, key: &Key) -> Option<u32> {
@@ -1589,6 +1670,10 @@ where
 		value: Option<Vec<u8>>,
 		take_old: bool,
 	) -> Result<WriteOutcome, DispatchError> {
+		if_tracing(|tracer| {
+			let old_value = self.top_frame_mut().contract_info().read(key);
+			tracer.storage_access(self.address(), key.unhashed(), old_value.as_deref());
+		});
 		let frame = self.top_frame_mut();
 		frame.contract_info.get(&frame.account_id).write(
 			key.into(),
@@ -1723,11 +1808,9 @@ where
 	}
 
 	fn deposit_event(&mut self, topics: Vec<H256>, data: Vec<u8>) {
-		Contracts::<Self::T>::deposit_event(Event::ContractEmitted {
-			contract: T::AddressMapper::to_address(self.account_id()),
-			data,
-			topics,
-		});
+		let contract = T::AddressMapper::to_address(self.account_id());
+		if_tracing(|tracer| tracer.log_event(contract, &topics, &data));
+		Contracts::<Self::T>::deposit_event(Event::ContractEmitted { contract, data, topics });
 	}
 
 	fn block_number(&self) -> U256 {
@@ -38,10 +38,11 @@ pub mod chain_extension;
 pub mod debug;
 pub mod evm;
 pub mod test_utils;
+pub mod tracing;
 pub mod weights;
 
 use crate::{
-	evm::{runtime::GAS_PRICE, TransactionLegacyUnsigned},
+	evm::{runtime::GAS_PRICE, EvmTracer, Trace, TracerConfig, TransactionLegacyUnsigned},
 	exec::{AccountIdOf, ExecError, Executable, Ext, Key, Origin, Stack as ExecStack},
 	gas::GasMeter,
 	storage::{meter::Meter as StorageMeter, ContractInfo, DeletionQueueManager},
@@ -1394,6 +1395,30 @@ where
 		Self::convert_native_to_evm(T::Currency::reducible_balance(&account, Preserve, Polite))
 	}
 
+	/// Create a tracer for the `debug_trace*` runtime API methods.
+	///
+	/// The gas reported by the tracer is derived from the consumed weight the same way the gas of
+	/// a transaction is derived from its fee in [`Self::bare_eth_transact`].
+	pub fn evm_tracer(config: TracerConfig) -> EvmTracer<T>
+	where
+		T: pallet_transaction_payment::Config,
+		OnChargeTransactionBalanceOf<T>: Into<BalanceOf<T>>,
+	{
+		EvmTracer::new(config, Self::evm_gas_from_weight)
+	}
+
+	/// Convert a weight into EVM gas.
+	fn evm_gas_from_weight(weight: Weight) -> U256
+	where
+		T: pallet_transaction_payment::Config,
+		OnChargeTransactionBalanceOf<T>: Into<BalanceOf<T>>,
+	{
+		let fee: BalanceOf<T> =
+			pallet_transaction_payment::Pallet::<T>::weight_to_fee(weight).into();
+		let fee: U256 = fee.into();
+		fee / GAS_PRICE
+	}
+
 	/// A generalized version of [`Self::upload_code`].
 	///
 	/// It is identical to [`Self::upload_code`] and only differs in the information it returns.
@@ -1484,7 +1509,7 @@ environmental!(executing_contract: bool);
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait ReviveApi<AccountId, Balance, Nonce, BlockNumber, EventRecord> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1554,5 +1579,40 @@ sp_api::decl_runtime_apis! {
 			address: H160,
 			key: [u8; 32],
 		) -> GetStorageResult;
+
+		/// Traces the execution of an entire block and returns the trace of each transaction,
+		/// along with its index in the block.
+		///
+		/// Must be called on the state of the parent block.
+		#[api_version(2)]
+		fn trace_block(
+			block: Block,
+			config: TracerConfig,
+		) -> Vec<(u32, Trace)>;
+
+		/// Traces the execution of the transaction at index `tx_index` in the given block.
+		///
+		/// The transactions before `tx_index` are executed first, without being traced.
+		/// Must be called on the state of the parent block.
+		#[api_version(2)]
+		fn trace_tx(
+			block: Block,
+			tx_index: u32,
+			config: TracerConfig,
+		) -> Option<Trace>;
+
+		/// Traces the dry-run of an Ethereum call.
+		///
+		/// See [`crate::Pallet::bare_eth_transact`]
+		#[api_version(2)]
+		fn trace_call(
+			origin: H160,
+			dest: Option<H160>,
+			value: U256,
+			input: Vec<u8>,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			config: TracerConfig,
+		) -> Option<Trace>;
 	}
 }
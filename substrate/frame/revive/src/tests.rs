@@ -417,6 +417,8 @@ impl pallet_proxy::Config for Test {
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type CustomProxyTypeOrigin = frame_system::EnsureRoot<AccountId32>;
+	type MaxCustomProxyCalls = ConstU32<32>;
 }
 
 parameter_types! {
@@ -4666,3 +4668,56 @@ fn mapped_address_works() {
 		assert_eq!(<Test as Config>::Currency::total_balance(&EVE), 1_100);
 	});
 }
+
+#[test]
+fn tracing_works() {
+	use crate::{
+		evm::{Bytes, CallTracer, CallType, PrestateTrace, PrestateTracer},
+		tracing::trace,
+	};
+	let (caller_code, _) = compile_module("call").unwrap();
+	let (callee_code, _) = compile_module("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+
+		let Contract { addr: addr_caller, .. } =
+			builder::bare_instantiate(Code::Upload(caller_code)).build_and_unwrap_contract();
+		let Contract { addr: addr_callee, .. } =
+			builder::bare_instantiate(Code::Upload(callee_code)).build_and_unwrap_contract();
+		let input = (42u32, &addr_callee).encode();
+
+		let mut tracer = CallTracer::new(Default::default(), |weight| weight.ref_time().into());
+		trace(&mut tracer, || {
+			builder::bare_call(addr_caller).data(input.clone()).build_and_unwrap_result();
+		});
+
+		let trace = tracer.collect_trace().unwrap();
+		assert_eq!(
+			(trace.from, trace.to, trace.call_type, trace.input),
+			(ALICE_ADDR, addr_caller, CallType::Call, Bytes(input.clone()))
+		);
+		assert_eq!(trace.calls.len(), 1);
+		let call = &trace.calls[0];
+		assert_eq!(
+			(call.from, call.to, call.call_type, &call.input),
+			(addr_caller, addr_callee, CallType::Call, &Bytes(42u32.encode()))
+		);
+		assert!(trace.gas_used > call.gas_used);
+
+		let mut tracer = PrestateTracer::<Test>::new(Default::default());
+		trace(&mut tracer, || {
+			builder::bare_call(addr_caller).data(input).build_and_unwrap_result();
+		});
+
+		let Some(PrestateTrace::Prestate(pre)) = tracer.collect_trace() else {
+			panic!("expected a prestate trace");
+		};
+		assert_eq!(pre.keys().copied().collect::<Vec<_>>(), {
+			let mut addresses = vec![ALICE_ADDR, addr_caller, addr_callee];
+			addresses.sort();
+			addresses
+		});
+		assert!(pre[&addr_callee].code.is_some());
+		assert_eq!(pre[&ALICE_ADDR].balance, Some(Pallet::<Test>::evm_balance(&ALICE_ADDR)));
+	});
+}
@@ -0,0 +1,80 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hooks to observe the execution of contracts from within the runtime.
+//!
+//! Unlike [`crate::debug::Tracing`], which is configured statically through
+//! [`crate::Config::Debug`], a [`Tracer`] is installed for the duration of a closure with
+//! [`trace`]. This is used by the runtime API to build the traces returned by the
+//! `debug_trace*` Ethereum RPC methods.
+
+use crate::{evm::CallType, primitives::ExecReturnValue};
+use environmental::environmental;
+use frame_support::weights::Weight;
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+
+environmental!(tracer: dyn Tracer + 'static);
+
+/// Run the closure `f` while the execution is observed by `tracer`.
+pub fn trace<R, F: FnOnce() -> R>(tracer: &mut (dyn Tracer + 'static), f: F) -> R {
+	tracer::using_once(tracer, f)
+}
+
+/// Run the closure `f` with the installed tracer, if any.
+pub(crate) fn if_tracing<F: FnOnce(&mut (dyn Tracer + 'static))>(f: F) {
+	tracer::with(f);
+}
+
+/// Defines methods to observe the execution of contracts.
+pub trait Tracer {
+	/// Called before a call or an instantiation is executed.
+	///
+	/// # Arguments
+	///
+	/// * `from` - The address of the caller.
+	/// * `to` - The address of the callee, or of the contract being instantiated.
+	/// * `call_type` - The type of the call.
+	/// * `value` - The value transferred, using EVM decimals.
+	/// * `input` - The input data of the call.
+	/// * `gas_limit` - The gas available to the call.
+	fn enter_child_span(
+		&mut self,
+		from: H160,
+		to: H160,
+		call_type: CallType,
+		value: U256,
+		input: &[u8],
+		gas_limit: Weight,
+	);
+
+	/// Called after a call or an instantiation returned.
+	///
+	/// A returned call may still have reverted, see [`ExecReturnValue::did_revert`].
+	fn exit_child_span(&mut self, output: &ExecReturnValue, gas_used: Weight);
+
+	/// Called after a call or an instantiation failed.
+	fn exit_child_span_with_error(&mut self, error: DispatchError, gas_used: Weight);
+
+	/// Called when a contract emits an event.
+	fn log_event(&mut self, address: H160, topics: &[H256], data: &[u8]);
+
+	/// Called when a storage slot of a contract is read, or before it is written.
+	///
+	/// `value` is the value of the slot before the access.
+	fn storage_access(&mut self, _address: H160, _key: &[u8], _value: Option<&[u8]>) {}
+}
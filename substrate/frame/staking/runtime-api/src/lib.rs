@@ -19,9 +19,13 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use codec::Codec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait StakingApi<Balance, AccountId>
 		where
 			Balance: Codec,
@@ -35,5 +39,18 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns true if validator `account` has pages to be claimed for the given era.
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool;
+
+		/// Returns the projected per-era rewards of a nominator bonding `amount` behind
+		/// `targets`, based on the last era with a known payout.
+		///
+		/// Only that single era is replayed: the projection is not averaged over several eras, so
+		/// it follows the era points and exposures of that era and can change considerably from
+		/// one era to the next. Targets that were not exposed in that era are not part of the
+		/// result.
+		#[api_version(2)]
+		fn simulate_nominator_rewards(
+			amount: Balance,
+			targets: Vec<AccountId>,
+		) -> Vec<sp_staking::RewardProjection<AccountId, Balance>>;
 	}
 }
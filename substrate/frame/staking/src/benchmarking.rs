@@ -691,6 +691,81 @@ mod benchmarks {
 		assert_eq!(UnappliedSlashes::<T>::get(&era).len(), (MAX_SLASHES - s) as usize);
 	}
 
+	#[benchmark]
+	fn appeal_slash(s: Linear<1, MAX_SLASHES>) {
+		let era = EraIndex::one();
+		let validator = create_funded_user_with_balance::<T>(
+			"validator",
+			USER_SEED,
+			T::SlashAppealDeposit::get()
+				.saturating_add(asset::existential_deposit::<T>() * 10u32.into()),
+		);
+		let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+		// the slash of the validator is the last one to be found.
+		let mut unapplied_slashes = (1..s)
+			.map(|_| UnappliedSlash::<T::AccountId, BalanceOf<T>>::default_from(dummy()))
+			.collect::<Vec<_>>();
+		unapplied_slashes.push(UnappliedSlash::default_from(validator.clone()));
+		UnappliedSlashes::<T>::insert(era, &unapplied_slashes);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(validator.clone()), era, validator.clone(), s);
+
+		assert!(SlashAppeals::<T>::contains_key(era, &validator));
+	}
+
+	#[benchmark]
+	fn reduce_deferred_slash(s: Linear<1, MAX_SLASHES>) -> Result<(), BenchmarkError> {
+		let era = EraIndex::one();
+		let validator = create_funded_user_with_balance::<T>(
+			"validator",
+			USER_SEED,
+			T::SlashAppealDeposit::get()
+				.saturating_add(asset::existential_deposit::<T>() * 10u32.into()),
+		);
+		// all slashes are reduced.
+		let unapplied_slashes = (0..s)
+			.map(|_| UnappliedSlash::<T::AccountId, BalanceOf<T>>::default_from(validator.clone()))
+			.collect::<Vec<_>>();
+		UnappliedSlashes::<T>::insert(era, &unapplied_slashes);
+		Staking::<T>::appeal_slash(
+			RawOrigin::Signed(validator.clone()).into(),
+			era,
+			validator.clone(),
+			s,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, era, validator.clone(), Perbill::from_percent(50), s);
+
+		assert!(!SlashAppeals::<T>::contains_key(era, &validator));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reject_slash_appeal() -> Result<(), BenchmarkError> {
+		let era = EraIndex::one();
+		let validator = create_funded_user_with_balance::<T>(
+			"validator",
+			USER_SEED,
+			T::SlashAppealDeposit::get()
+				.saturating_add(asset::existential_deposit::<T>() * 10u32.into()),
+		);
+		UnappliedSlashes::<T>::insert(era, vec![UnappliedSlash::default_from(validator.clone())]);
+		Staking::<T>::appeal_slash(
+			RawOrigin::Signed(validator.clone()).into(),
+			era,
+			validator.clone(),
+			1,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, era, validator.clone());
+
+		assert!(!SlashAppeals::<T>::contains_key(era, &validator));
+		Ok(())
+	}
+
 	#[benchmark]
 	fn payout_stakers_alive_staked(
 		n: Linear<0, { T::MaxExposurePageSize::get() as u32 }>,
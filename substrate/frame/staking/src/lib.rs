@@ -126,6 +126,14 @@
 //!
 //! Slashing logic is further described in the documentation of the `slashing` pallet.
 //!
+//! Slashes are deferred by [`Config::SlashDeferDuration`] eras before being applied. In the
+//! meantime, any slashed staker can [appeal](`Call::appeal_slash`) against the slashes of a
+//! validator by reserving [`Config::SlashAppealDeposit`]. The
+//! [`AdminOrigin`](`Config::AdminOrigin`) can then [reduce](`Call::reduce_deferred_slash`) the
+//! slashes by a percentage, which returns the deposit, or
+//! [reject](`Call::reject_slash_appeal`) the appeal, which slashes the deposit. Appeals left
+//! unanswered are refunded once the slashes are applied.
+//!
 //! Similar to slashing, rewards are also shared among a validator and its associated nominators.
 //! Yet, the reward funds are not always transferred to the stash account and can be configured. See
 //! [Reward Calculation](#reward-calculation) for more details.
@@ -812,6 +820,32 @@ impl<AccountId, Balance: HasCompact + Zero> UnappliedSlash<AccountId, Balance> {
 	}
 }
 
+impl<AccountId: PartialEq, Balance: HasCompact + AtLeast32BitUnsigned + Copy>
+	UnappliedSlash<AccountId, Balance>
+{
+	/// Whether `who` is slashed by this record, either as the validator or as a nominator.
+	pub(crate) fn is_slashed(&self, who: &AccountId) -> bool {
+		self.validator == *who || self.others.iter().any(|(nominator, _)| nominator == who)
+	}
+
+	/// Reduce all slashed amounts, and the reporters payout, by `reduction`.
+	pub(crate) fn reduce(&mut self, reduction: Perbill) {
+		let remaining = reduction.left_from_one();
+		self.own = remaining * self.own;
+		self.others.iter_mut().for_each(|(_, value)| *value = remaining * *value);
+		self.payout = remaining * self.payout;
+	}
+}
+
+/// An appeal against the unapplied slashes of a validator.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SlashAppeal<AccountId, Balance> {
+	/// The slashed staker who submitted the appeal.
+	pub appellant: AccountId,
+	/// The amount reserved from `appellant`.
+	pub deposit: Balance,
+}
+
 /// Something that defines the maximum number of nominations per nominator based on a curve.
 ///
 /// The method `curve` implements the nomination quota curve and should not be used directly.
@@ -20,7 +20,8 @@
 use crate::{self as pallet_staking, *};
 use frame_election_provider_support::{
 	bounds::{ElectionBounds, ElectionBoundsBuilder},
-	onchain, SequentialPhragmen, VoteWeight,
+	onchain, BoundedSupportsOf, ElectionProvider, ElectionProviderBase, PageIndex,
+	SequentialPhragmen, Support, VoteWeight,
 };
 use frame_support::{
 	assert_ok, derive_impl, ord_parameter_types, parameter_types,
@@ -207,6 +208,7 @@ parameter_types! {
 	pub static MaxWinners: u32 = 100;
 	pub static ElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
 	pub static AbsoluteMaxNominations: u32 = 16;
+	pub static SlashAppealDeposit: Balance = 10;
 }
 
 type VoterBagsListInstance = pallet_bags_list::Instance1;
@@ -229,6 +231,65 @@ impl onchain::Config for OnChainSeqPhragmen {
 	type Bounds = ElectionsBounds;
 }
 
+type OnChainElection = onchain::OnChainExecution<OnChainSeqPhragmen>;
+
+parameter_types! {
+	pub static ElectionPages: PageIndex = 1;
+	pub static ElectedPages: Vec<(BlockNumber, PageIndex)> = vec![];
+	pub static PagedElectionResult: Option<BoundedSupportsOf<OnChainElection>> = None;
+}
+
+/// The on-chain election, exported in [`ElectionPages`] pages over which the backings of each
+/// winner are spread.
+pub struct PagedOnChainElection;
+impl ElectionProviderBase for PagedOnChainElection {
+	type AccountId = AccountId;
+	type BlockNumber = BlockNumber;
+	type Error = onchain::Error;
+	type MaxWinners = MaxWinners;
+	type DataProvider = Staking;
+}
+
+impl ElectionProvider for PagedOnChainElection {
+	fn ongoing() -> bool {
+		false
+	}
+
+	fn elect() -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		OnChainElection::elect()
+	}
+
+	fn pages() -> PageIndex {
+		ElectionPages::get()
+	}
+
+	fn elect_paged(page: PageIndex) -> Result<BoundedSupportsOf<Self>, Self::Error> {
+		ElectedPages::mutate(|pages| pages.push((System::block_number(), page)));
+		let result = match PagedElectionResult::get() {
+			Some(result) => result,
+			None => OnChainElection::elect()?,
+		};
+		PagedElectionResult::set((page > 0).then(|| result.clone()));
+
+		let pages = ElectionPages::get() as usize;
+		let supports = result
+			.into_iter()
+			.filter_map(|(winner, support)| {
+				let voters = support
+					.voters
+					.into_iter()
+					.enumerate()
+					.filter(|(i, _)| i % pages == page as usize)
+					.map(|(_, backing)| backing)
+					.collect::<Vec<_>>();
+				let total = voters.iter().map(|(_, stake)| stake).sum();
+				(!voters.is_empty()).then(|| (winner, Support { total, voters }))
+			})
+			.collect::<Vec<_>>();
+		Ok(supports.try_into().expect("at most as many winners as in the result; qed"))
+	}
+}
+
 pub struct MockReward {}
 impl OnUnbalanced<PositiveImbalanceOf<Test>> for MockReward {
 	fn on_unbalanced(_: PositiveImbalanceOf<Test>) {
@@ -271,11 +332,12 @@ impl crate::pallet::pallet::Config for Test {
 	type SessionsPerEra = SessionsPerEra;
 	type SlashDeferDuration = SlashDeferDuration;
 	type AdminOrigin = EnsureOneOrRoot;
+	type SlashAppealDeposit = SlashAppealDeposit;
 	type SessionInterface = Self;
 	type EraPayout = ConvertCurve<RewardCurve>;
 	type NextNewSession = Session;
 	type MaxExposurePageSize = MaxExposurePageSize;
-	type ElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
+	type ElectionProvider = PagedOnChainElection;
 	type GenesisElectionProvider = Self::ElectionProvider;
 	// NOTE: consider a macro and use `UseNominatorsAndValidatorsMap<Self>` as well.
 	type VoterList = VoterBagsList;
@@ -20,7 +20,7 @@
 use frame_election_provider_support::{
 	bounds::{CountBound, SizeBound},
 	data_provider, BoundedSupportsOf, DataProviderBounds, ElectionDataProvider, ElectionProvider,
-	ScoreProvider, SortedListProvider, VoteWeight, VoterOf,
+	PageIndex, ScoreProvider, SortedListProvider, VoteWeight, VoterOf,
 };
 use frame_support::{
 	defensive,
@@ -28,7 +28,7 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		Defensive, DefensiveSaturating, EstimateNextNewSession, Get, Imbalance, Len, OnUnbalanced,
-		TryCollect, UnixTime,
+		ReservableCurrency, TryCollect, UnixTime,
 	},
 	weights::Weight,
 };
@@ -44,7 +44,7 @@ use sp_runtime::{
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
 	offence::{OffenceDetails, OnOffenceHandler},
-	EraIndex, OnStakingUpdate, Page, SessionIndex, Stake,
+	EraIndex, OnStakingUpdate, Page, RewardProjection, SessionIndex, Stake,
 	StakingAccount::{self, Controller, Stash},
 	StakingInterface,
 };
@@ -621,6 +621,16 @@ impl<T: Config> Pallet<T> {
 			MaxWinnersOf<T>,
 		>,
 	) -> BoundedVec<T::AccountId, MaxWinnersOf<T>> {
+		let new_planned_era = Self::plan_new_era(start_session_index);
+
+		// Set staking information for the new era.
+		Self::store_stakers_info(exposures, new_planned_era)
+	}
+
+	/// Bump the current era, store its start session index and clean old era information.
+	///
+	/// Returns the new planned era.
+	fn plan_new_era(start_session_index: SessionIndex) -> EraIndex {
 		// Increment or set current era.
 		let new_planned_era = CurrentEra::<T>::mutate(|s| {
 			*s = Some(s.map(|s| s + 1).unwrap_or(0));
@@ -633,8 +643,7 @@ impl<T: Config> Pallet<T> {
 			Self::clear_era_information(old_era);
 		}
 
-		// Set staking information for the new era.
-		Self::store_stakers_info(exposures, new_planned_era)
+		new_planned_era
 	}
 
 	/// Potentially plan a new era.
@@ -647,6 +656,10 @@ impl<T: Config> Pallet<T> {
 		start_session_index: SessionIndex,
 		is_genesis: bool,
 	) -> Option<BoundedVec<T::AccountId, MaxWinnersOf<T>>> {
+		if !is_genesis && <T::ElectionProvider>::pages() > 1 {
+			return Self::try_trigger_new_paged_era(start_session_index)
+		}
+
 		let election_result: BoundedVec<_, MaxWinnersOf<T>> = if is_genesis {
 			let result = <T::GenesisElectionProvider>::elect().map_err(|e| {
 				log!(warn, "genesis election provider failed due to {:?}", e);
@@ -668,34 +681,180 @@ impl<T: Config> Pallet<T> {
 		};
 
 		let exposures = Self::collect_exposures(election_result);
-		if (exposures.len() as u32) < MinimumValidatorCount::<T>::get().max(1) {
-			// Session will panic if we ever return an empty validator set, thus max(1) ^^.
-			match CurrentEra::<T>::get() {
-				Some(current_era) if current_era > 0 => log!(
-					warn,
-					"chain does not have enough staking candidates to operate for era {:?} ({} \
-					elected, minimum is {})",
-					CurrentEra::<T>::get().unwrap_or(0),
-					exposures.len(),
-					MinimumValidatorCount::<T>::get(),
-				),
-				None => {
-					// The initial era is allowed to have no exposures.
-					// In this case the SessionManager is expected to choose a sensible validator
-					// set.
-					// TODO: this should be simplified #8911
-					CurrentEra::<T>::put(0);
-					ErasStartSessionIndex::<T>::insert(&0, &start_session_index);
-				},
-				_ => (),
+		if !Self::has_enough_validators(exposures.len() as u32, start_session_index) {
+			return None
+		}
+
+		Self::deposit_event(Event::StakersElected);
+		Some(Self::trigger_new_era(start_session_index, exposures))
+	}
+
+	/// Potentially plan a new era with a paged election result.
+	///
+	/// The pages that were not fetched ahead of the election by
+	/// [`Self::maybe_fetch_election_page`] are fetched now, down to page `0`.
+	fn try_trigger_new_paged_era(
+		start_session_index: SessionIndex,
+	) -> Option<BoundedVec<T::AccountId, MaxWinnersOf<T>>> {
+		let planned_era = CurrentEra::<T>::get().map(|e| e.saturating_add(1)).unwrap_or(0);
+		let next_page = NextElectionPage::<T>::take()
+			.unwrap_or_else(|| <T::ElectionProvider>::pages().saturating_sub(1));
+
+		for page in (0..=next_page).rev() {
+			if Self::do_elect_page(page, planned_era).is_err() {
+				Self::abort_paged_election(page, planned_era);
+				return None
 			}
+		}
 
-			Self::deposit_event(Event::StakingElectionFailed);
+		let elected_stashes = ElectedStashes::<T>::take();
+		let elected = elected_stashes.len() as u32;
+		if elected < MinimumValidatorCount::<T>::get().max(1) {
+			// discard the exposures of the pages, before the initial era is possibly set up.
+			Self::clear_era_information(planned_era);
+		}
+		if !Self::has_enough_validators(elected, start_session_index) {
 			return None
 		}
 
 		Self::deposit_event(Event::StakersElected);
-		Some(Self::trigger_new_era(start_session_index, exposures))
+		let new_planned_era = Self::plan_new_era(start_session_index);
+		debug_assert_eq!(new_planned_era, planned_era);
+		log!(
+			info,
+			"new validator set of size {:?} has been processed for era {:?}",
+			elected_stashes.len(),
+			new_planned_era,
+		);
+		Some(elected_stashes)
+	}
+
+	/// Fetch the next page of a paged election result ahead of the election, if it is due.
+	///
+	/// Pages `ElectionProvider::pages() - 1` to `1` are fetched one per block, so that page `i`
+	/// is fetched no earlier than `i` blocks before the predicted election. Page `0`, which
+	/// finalizes the election, is always fetched when the new era is planned.
+	///
+	/// Returns the weight consumed, besides that registered by the election provider.
+	pub(crate) fn maybe_fetch_election_page(now: BlockNumberFor<T>) -> Weight {
+		if <T::ElectionProvider>::pages() <= 1 {
+			return Weight::zero()
+		}
+
+		let Some(current_era) = CurrentEra::<T>::get() else { return T::DbWeight::get().reads(1) };
+		let next_page = NextElectionPage::<T>::get()
+			.unwrap_or_else(|| <T::ElectionProvider>::pages().saturating_sub(1));
+		// reading `CurrentEra` and `NextElectionPage`, and predicting the election.
+		let prediction_weight = T::DbWeight::get().reads(6);
+		let remaining = Self::next_election_prediction(now).saturating_sub(now);
+		if next_page.is_zero() || remaining > next_page.into() {
+			return prediction_weight
+		}
+
+		let planned_era = current_era.saturating_add(1);
+		match Self::do_elect_page(next_page, planned_era) {
+			Ok(winners) => {
+				NextElectionPage::<T>::put(next_page.saturating_sub(1));
+				// for each winner, its exposure and prefs are read and written.
+				prediction_weight
+					.saturating_add(T::DbWeight::get().reads_writes(2, 3))
+					.saturating_add(
+						T::DbWeight::get().reads_writes(3, 3).saturating_mul(winners.into()),
+					)
+			},
+			Err(()) => {
+				Self::abort_paged_election(next_page, planned_era);
+				// the cost of clearing the planned era cannot be known in advance.
+				prediction_weight.saturating_add(T::DbWeight::get().reads_writes(1, 10))
+			},
+		}
+	}
+
+	/// Fetch the given page of the election result, and merge it into the exposures of the
+	/// validators elected for `planned_era`.
+	///
+	/// Returns the number of winners in the page.
+	fn do_elect_page(page: PageIndex, planned_era: EraIndex) -> Result<u32, ()> {
+		let supports = <T::ElectionProvider>::elect_paged(page).map_err(|e| {
+			log!(warn, "election provider failed to provide page {} due to {:?}", page, e);
+		})?;
+		let exposures = Self::collect_exposures(supports);
+		let winners = exposures.len() as u32;
+
+		let mut elected_stashes = ElectedStashes::<T>::get();
+		let mut total_stake = ErasTotalStake::<T>::get(planned_era);
+		for (stash, exposure) in exposures {
+			total_stake = total_stake.saturating_add(exposure.total);
+			let exposure = if ErasStakersOverview::<T>::contains_key(planned_era, &stash) {
+				// the stash was already elected by a previous page.
+				let mut merged = EraInfo::<T>::get_full_exposure(planned_era, &stash);
+				merged.own = merged.own.saturating_add(exposure.own);
+				merged.total = merged.total.saturating_add(exposure.total);
+				merged.others.extend(exposure.others);
+				merged
+			} else {
+				elected_stashes.try_push(stash.clone()).map_err(|_| {
+					log!(
+						warn,
+						"election result has more than {} winners",
+						MaxWinnersOf::<T>::get()
+					);
+				})?;
+				<ErasValidatorPrefs<T>>::insert(&planned_era, &stash, Validators::<T>::get(&stash));
+				exposure
+			};
+			EraInfo::<T>::set_exposure(planned_era, &stash, exposure);
+		}
+
+		ElectedStashes::<T>::put(elected_stashes);
+		EraInfo::<T>::set_total_stake(planned_era, total_stake);
+		Ok(winners)
+	}
+
+	/// Give up on the paged election of `planned_era`, after fetching `failed_page` failed.
+	///
+	/// The election is finalized on the side of the election provider, and the pages fetched so
+	/// far are discarded. A new election is attempted at the next session.
+	fn abort_paged_election(failed_page: PageIndex, planned_era: EraIndex) {
+		if !failed_page.is_zero() {
+			let _ = <T::ElectionProvider>::elect_paged(0);
+		}
+		NextElectionPage::<T>::kill();
+		ElectedStashes::<T>::kill();
+		Self::clear_era_information(planned_era);
+		Self::deposit_event(Event::StakingElectionFailed);
+	}
+
+	/// Whether `elected` validators are enough to plan a new era, emitting
+	/// [`Event::StakingElectionFailed`] if not.
+	fn has_enough_validators(elected: u32, start_session_index: SessionIndex) -> bool {
+		if elected >= MinimumValidatorCount::<T>::get().max(1) {
+			return true
+		}
+
+		// Session will panic if we ever return an empty validator set, thus max(1) ^^.
+		match CurrentEra::<T>::get() {
+			Some(current_era) if current_era > 0 => log!(
+				warn,
+				"chain does not have enough staking candidates to operate for era {:?} ({} \
+				elected, minimum is {})",
+				CurrentEra::<T>::get().unwrap_or(0),
+				elected,
+				MinimumValidatorCount::<T>::get(),
+			),
+			None => {
+				// The initial era is allowed to have no exposures.
+				// In this case the SessionManager is expected to choose a sensible validator
+				// set.
+				// TODO: this should be simplified #8911
+				CurrentEra::<T>::put(0);
+				ErasStartSessionIndex::<T>::insert(&0, &start_session_index);
+			},
+			_ => (),
+		}
+
+		Self::deposit_event(Event::StakingElectionFailed);
+		false
 	}
 
 	/// Process the output of the election.
@@ -840,6 +999,11 @@ impl<T: Config> Pallet<T> {
 			let slash_era = active_era.saturating_sub(T::SlashDeferDuration::get());
 			slashing::apply_slash::<T>(slash, slash_era);
 		}
+
+		// appeals that were not resolved in time are refunded.
+		for appeal in SlashAppeals::<T>::drain_prefix(&active_era).map(|(_, appeal)| appeal) {
+			T::Currency::unreserve(&appeal.appellant, appeal.deposit);
+		}
 	}
 
 	/// Add reward points to validators using their stash account ID.
@@ -894,6 +1058,21 @@ impl<T: Config> Pallet<T> {
 	///
 	/// This function is self-weighing as [`DispatchClass::Mandatory`].
 	pub fn get_npos_voters(bounds: DataProviderBounds) -> Vec<VoterOf<Self>> {
+		Self::get_npos_voters_from(bounds, T::VoterList::iter(), true)
+	}
+
+	/// Same as [`Self::get_npos_voters`], but iterates the voters of `sorted_voters`, which may
+	/// start in the middle of [`Config::VoterList`].
+	///
+	/// If this is not the `first_page` of the snapshot, `MinimumActiveStake` is only lowered to
+	/// the minimum active nominator stake of the returned set of nominators.
+	///
+	/// This function is self-weighing as [`DispatchClass::Mandatory`].
+	pub(crate) fn get_npos_voters_from(
+		bounds: DataProviderBounds,
+		mut sorted_voters: Box<dyn Iterator<Item = T::AccountId>>,
+		first_page: bool,
+	) -> Vec<VoterOf<Self>> {
 		let mut voters_size_tracker: StaticTracker<Self> = StaticTracker::default();
 
 		let final_predicted_len = {
@@ -911,7 +1090,6 @@ impl<T: Config> Pallet<T> {
 		let mut nominators_taken = 0u32;
 		let mut min_active_stake = u64::MAX;
 
-		let mut sorted_voters = T::VoterList::iter();
 		while all_voters.len() < final_predicted_len as usize &&
 			voters_seen < (NPOS_MAX_ITERATIONS_COEFFICIENT * final_predicted_len as u32)
 		{
@@ -992,7 +1170,11 @@ impl<T: Config> Pallet<T> {
 		let min_active_stake: T::CurrencyBalance =
 			if all_voters.is_empty() { Zero::zero() } else { min_active_stake.into() };
 
-		MinimumActiveStake::<T>::put(min_active_stake);
+		if first_page {
+			MinimumActiveStake::<T>::put(min_active_stake);
+		} else if !all_voters.is_empty() {
+			MinimumActiveStake::<T>::mutate(|current| *current = min_active_stake.min(*current));
+		}
 
 		log!(
 			info,
@@ -1187,6 +1369,81 @@ impl<T: Config> Pallet<T> {
 	pub fn api_pending_rewards(era: EraIndex, account: T::AccountId) -> bool {
 		EraInfo::<T>::pending_rewards(era, &account)
 	}
+
+	/// Returns the projected per-era rewards of a nominator bonding `amount` behind `targets`.
+	///
+	/// The payout of the last era with a known payout is replayed as if the nominator had been
+	/// part of the exposure of each target, with `amount` split evenly across the targets that
+	/// were exposed in that era. Other targets are not part of the result. The nominator is
+	/// assumed to be appended to the exposure of each target, possibly overflowing into a new
+	/// page.
+	///
+	/// No average over several eras is taken, so the projection only reflects the reward points
+	/// earned by the targets in that single era.
+	///
+	/// Used by the runtime API.
+	pub fn api_simulate_nominator_rewards(
+		amount: BalanceOf<T>,
+		mut targets: Vec<T::AccountId>,
+	) -> Vec<RewardProjection<T::AccountId, BalanceOf<T>>> {
+		let Some(era) =
+			ActiveEra::<T>::get().and_then(|active_era| active_era.index.checked_sub(1))
+		else {
+			return Vec::new()
+		};
+		let Some(era_payout) = ErasValidatorReward::<T>::get(&era) else { return Vec::new() };
+		let era_reward_points = ErasRewardPoints::<T>::get(&era);
+
+		targets.sort();
+		targets.dedup();
+		let exposed = targets
+			.into_iter()
+			.filter_map(|target| {
+				ErasStakersOverview::<T>::get(&era, &target).map(|overview| (target, overview))
+			})
+			.collect::<Vec<_>>();
+		if exposed.is_empty() {
+			return Vec::new()
+		}
+
+		let exposed_count: BalanceOf<T> = (exposed.len() as u32).into();
+		let stake_per_target = amount / exposed_count;
+		// the remainder of the split is assigned to the first target.
+		let mut remainder = amount.saturating_sub(stake_per_target.saturating_mul(exposed_count));
+		let page_size = T::MaxExposurePageSize::get().max(1);
+
+		exposed
+			.into_iter()
+			.map(|(validator, overview)| {
+				let stake = stake_per_target.saturating_add(core::mem::take(&mut remainder));
+				let page = overview.nominator_count / page_size;
+				let page_count = overview.page_count.max(page.saturating_add(1));
+
+				let validator_reward_points = era_reward_points
+					.individual
+					.get(&validator)
+					.copied()
+					.unwrap_or_else(Zero::zero);
+				let reward = if validator_reward_points.is_zero() {
+					Zero::zero()
+				} else {
+					// same as `do_payout_stakers_by_page`, with the stake of the nominator added to
+					// the exposure.
+					let validator_total_payout =
+						Perbill::from_rational(validator_reward_points, era_reward_points.total) *
+							era_payout;
+					let validator_commission =
+						EraInfo::<T>::get_validator_commission(era, &validator);
+					let validator_leftover_payout = validator_total_payout
+						.saturating_sub(validator_commission * validator_total_payout);
+					Perbill::from_rational(stake, overview.total.saturating_add(stake)) *
+						validator_leftover_payout
+				};
+
+				RewardProjection { validator, stake, reward, page, page_count }
+			})
+			.collect()
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1211,6 +1468,35 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		Ok(voters)
 	}
 
+	fn electing_voters_paged(
+		bounds: DataProviderBounds,
+		cursor: Option<&T::AccountId>,
+	) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		let voters = match cursor {
+			None => Self::get_npos_voters(bounds),
+			Some(last) => {
+				let sorted_voters = T::VoterList::iter_from(last)
+					.map_err(|_| "voter cursor is no longer in the voter list")?;
+				Self::get_npos_voters_from(bounds, sorted_voters, false)
+			},
+		};
+
+		debug_assert!(!bounds.exhausted(
+			SizeBound(voters.encoded_size() as u32).into(),
+			CountBound(voters.len() as u32).into()
+		));
+
+		Ok(voters)
+	}
+
+	fn lock_voters() {
+		T::VoterList::lock();
+	}
+
+	fn unlock_voters() {
+		T::VoterList::unlock();
+	}
+
 	fn electable_targets(bounds: DataProviderBounds) -> data_provider::Result<Vec<T::AccountId>> {
 		let targets = Self::get_npos_targets(bounds);
 
@@ -1918,6 +2204,10 @@ impl<T: Config> StakingInterface for Pallet<T> {
 			CurrentEra::<T>::put(era);
 		}
 
+		fn add_slashing_spans(who: &T::AccountId, spans: u32) {
+			crate::benchmarking::add_slashing_spans::<T>(who, spans);
+		}
+
 		fn max_exposure_page_size() -> Page {
 			T::MaxExposurePageSize::get()
 		}
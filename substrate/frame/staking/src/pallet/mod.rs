@@ -20,13 +20,13 @@
 use alloc::vec::Vec;
 use codec::Codec;
 use frame_election_provider_support::{
-	ElectionProvider, ElectionProviderBase, SortedListProvider, VoteWeight,
+	ElectionProvider, ElectionProviderBase, PageIndex, SortedListProvider, VoteWeight,
 };
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		Defensive, DefensiveSaturating, EnsureOrigin, EstimateNextNewSession, Get,
-		InspectLockableCurrency, LockableCurrency, OnUnbalanced, UnixTime,
+		InspectLockableCurrency, LockableCurrency, OnUnbalanced, ReservableCurrency, UnixTime,
 	},
 	weights::Weight,
 	BoundedVec,
@@ -51,9 +51,9 @@ pub use impls::*;
 use crate::{
 	asset, slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf,
 	DisablingStrategy, EraPayout, EraRewardPoints, Exposure, ExposurePage, Forcing,
-	LedgerIntegrityState, MaxNominationsOf, NegativeImbalanceOf, Nominations, NominationsQuota,
-	PositiveImbalanceOf, RewardDestination, SessionInterface, StakingLedger, UnappliedSlash,
-	UnlockChunk, ValidatorPrefs,
+	LedgerIntegrityState, MaxNominationsOf, MaxWinnersOf, NegativeImbalanceOf, Nominations,
+	NominationsQuota, PositiveImbalanceOf, RewardDestination, SessionInterface, SlashAppeal,
+	StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
 };
 
 // The speculative number of spans are used as an input of the weight annotation of
@@ -95,7 +95,8 @@ pub mod pallet {
 				Self::AccountId,
 				Moment = BlockNumberFor<Self>,
 				Balance = Self::CurrencyBalance,
-			> + InspectLockableCurrency<Self::AccountId>;
+			> + InspectLockableCurrency<Self::AccountId>
+			+ ReservableCurrency<Self::AccountId>;
 		/// Just the `Currency::Balance` type; we have this item to allow us to constrain it to
 		/// `From<u64>`.
 		type CurrencyBalance: sp_runtime::traits::AtLeast32BitUnsigned
@@ -124,6 +125,9 @@ pub mod pallet {
 		type CurrencyToVote: sp_staking::currency_to_vote::CurrencyToVote<BalanceOf<Self>>;
 
 		/// Something that provides the election functionality.
+		///
+		/// If the election result comes in several pages, all but the last page are fetched in
+		/// the blocks preceding the election, one per block.
 		#[pallet::no_default]
 		type ElectionProvider: ElectionProvider<
 			AccountId = Self::AccountId,
@@ -202,10 +206,17 @@ pub mod pallet {
 
 		/// The origin which can manage less critical staking parameters that does not require root.
 		///
-		/// Supported actions: (1) cancel deferred slash, (2) set minimum commission.
+		/// Supported actions: (1) cancel deferred slash, (2) set minimum commission, (3) reduce
+		/// deferred slash, (4) reject slash appeal.
 		#[pallet::no_default]
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The deposit reserved from a slashed staker to appeal against a deferred slash.
+		///
+		/// The deposit is slashed if the appeal is rejected.
+		#[pallet::constant]
+		type SlashAppealDeposit: Get<BalanceOf<Self>>;
+
 		/// Interface for interacting with a session pallet.
 		type SessionInterface: SessionInterface<Self::AccountId>;
 
@@ -337,6 +348,7 @@ pub mod pallet {
 			type SessionsPerEra = SessionsPerEra;
 			type BondingDuration = BondingDuration;
 			type SlashDeferDuration = ();
+			type SlashAppealDeposit = ();
 			type SessionInterface = ();
 			type NextNewSession = ();
 			type MaxExposurePageSize = ConstU32<64>;
@@ -619,6 +631,17 @@ pub mod pallet {
 	pub type ErasTotalStake<T: Config> =
 		StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>, ValueQuery>;
 
+	/// The next page of a paged election result to fetch for the upcoming era, once the pages
+	/// started to be fetched ahead of the election.
+	#[pallet::storage]
+	pub type NextElectionPage<T> = StorageValue<_, PageIndex, OptionQuery>;
+
+	/// The validators elected by the pages of the election result fetched so far for the
+	/// upcoming era.
+	#[pallet::storage]
+	pub type ElectedStashes<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, MaxWinnersOf<T>>, ValueQuery>;
+
 	/// Mode of era forcing.
 	#[pallet::storage]
 	pub type ForceEra<T> = StorageValue<_, Forcing, ValueQuery>;
@@ -651,6 +674,21 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Appeals against the unapplied slashes of a validator.
+	///
+	/// Keyed by the era in which the slashes are applied, as [`UnappliedSlashes`], and the stash
+	/// of the slashed validator.
+	#[pallet::storage]
+	pub type SlashAppeals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		SlashAppeal<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
 	/// A mapping from still-bonded eras to the first session index of that era.
 	///
 	/// Must contains information for eras for the range:
@@ -858,6 +896,13 @@ pub mod pallet {
 		ValidatorDisabled { stash: T::AccountId },
 		/// Validator has been re-enabled.
 		ValidatorReenabled { stash: T::AccountId },
+		/// The unapplied slashes of `validator` to be applied in `era` have been appealed.
+		SlashAppealed { era: EraIndex, validator: T::AccountId, appellant: T::AccountId },
+		/// The unapplied slashes of `validator` to be applied in `era` have been reduced.
+		SlashReduced { era: EraIndex, validator: T::AccountId, reduction: Perbill },
+		/// The appeal against the slashes of `validator` to be applied in `era` was rejected, and
+		/// the deposit of the appellant slashed.
+		SlashAppealRejected { era: EraIndex, validator: T::AccountId, appellant: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -929,13 +974,24 @@ pub mod pallet {
 		NotEnoughFunds,
 		/// Operation not allowed for virtual stakers.
 		VirtualStakerNotAllowed,
+		/// No unapplied slash of the validator slashes the account.
+		NotSlashed,
+		/// The slashes of the validator have already been appealed.
+		AlreadyAppealed,
+		/// The slashes of the validator have not been appealed.
+		NoAppeal,
+		/// The voter list is locked while the election snapshot is created, and voters cannot join
+		/// it or change their role until it is unlocked.
+		VoterListLocked,
+		/// Incorrect number of unapplied slashes provided.
+		IncorrectSlashCount,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
-			// just return the weight of the on_finalize.
-			T::DbWeight::get().reads(1)
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// the weight of the on_finalize, and of the page of the election result, if any.
+			T::DbWeight::get().reads(1).saturating_add(Self::maybe_fetch_election_page(now))
 		}
 
 		fn on_finalize(_n: BlockNumberFor<T>) {
@@ -1387,6 +1443,13 @@ pub mod pallet {
 				}
 			}
 
+			// joining the voter list, or moving to another position within it, would let the stash
+			// be counted twice or missed by a snapshot that is taken over multiple blocks.
+			ensure!(
+				Validators::<T>::contains_key(stash) || !T::VoterList::is_locked(),
+				Error::<T>::VoterListLocked
+			);
+
 			Self::do_remove_nominator(stash);
 			Self::do_add_validator(stash, prefs.clone());
 			Self::deposit_event(Event::<T>::ValidatorPrefsSet { stash: ledger.stash, prefs });
@@ -1461,6 +1524,12 @@ pub mod pallet {
 				suppressed: false,
 			};
 
+			// see `validate`.
+			ensure!(
+				Nominators::<T>::contains_key(stash) || !T::VoterList::is_locked(),
+				Error::<T>::VoterListLocked
+			);
+
 			Self::do_remove_validator(stash);
 			Self::do_add_nominator(stash, nominations);
 			Ok(())
@@ -2291,6 +2360,122 @@ pub mod pallet {
 			);
 			Ok(())
 		}
+
+		/// Appeal against the unapplied slashes of `validator` to be applied in `era`.
+		///
+		/// The origin must be slashed by one of them, either as the validator or as a nominator.
+		/// Only one appeal can be submitted per validator and era, which reserves
+		/// [`Config::SlashAppealDeposit`] from the origin until the appeal is resolved by the
+		/// [`Config::AdminOrigin`], or the slashes are applied.
+		///
+		/// - `slash_count`: the number of unapplied slashes to be applied in `era`. It must be
+		///   larger or equal to the actual number of slashes, and is used to charge the weight of
+		///   reading them.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::appeal_slash(*slash_count))]
+		pub fn appeal_slash(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			validator: T::AccountId,
+			slash_count: u32,
+		) -> DispatchResult {
+			let appellant = ensure_signed(origin)?;
+
+			let unapplied = UnappliedSlashes::<T>::get(&era);
+			ensure!(unapplied.len() as u32 <= slash_count, Error::<T>::IncorrectSlashCount);
+			ensure!(
+				unapplied
+					.iter()
+					.any(|slash| slash.validator == validator && slash.is_slashed(&appellant)),
+				Error::<T>::NotSlashed
+			);
+			ensure!(
+				!SlashAppeals::<T>::contains_key(&era, &validator),
+				Error::<T>::AlreadyAppealed
+			);
+
+			let deposit = T::SlashAppealDeposit::get();
+			T::Currency::reserve(&appellant, deposit)?;
+			SlashAppeals::<T>::insert(
+				&era,
+				&validator,
+				SlashAppeal { appellant: appellant.clone(), deposit },
+			);
+
+			Self::deposit_event(Event::<T>::SlashAppealed { era, validator, appellant });
+			Ok(())
+		}
+
+		/// Reduce the unapplied slashes of `validator` to be applied in `era` by `reduction`.
+		///
+		/// Can be called by the `T::AdminOrigin`.
+		///
+		/// The slashed amount of the validator and of each of its nominators is reduced, as well
+		/// as the payout to the reporters. A reduction of 100% is equivalent to
+		/// [`Call::cancel_deferred_slash`]. Any appeal against these slashes is resolved and the
+		/// deposit returned.
+		///
+		/// Like [`Call::cancel_deferred_slash`], this only changes the unapplied slashes. The
+		/// slashes recorded for the offence era and the slashing spans keep their original amount,
+		/// so a later slash of the same stakers in that era only applies the part exceeding the
+		/// original, unreduced slash.
+		///
+		/// - `slash_count`: the number of unapplied slashes to be applied in `era`, see
+		///   [`Call::appeal_slash`].
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::reduce_deferred_slash(*slash_count))]
+		pub fn reduce_deferred_slash(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			validator: T::AccountId,
+			reduction: Perbill,
+			slash_count: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut unapplied = UnappliedSlashes::<T>::get(&era);
+			ensure!(unapplied.len() as u32 <= slash_count, Error::<T>::IncorrectSlashCount);
+			let mut reduced = false;
+			for slash in unapplied.iter_mut().filter(|slash| slash.validator == validator) {
+				slash.reduce(reduction);
+				reduced = true;
+			}
+			ensure!(reduced, Error::<T>::NotSlashed);
+			UnappliedSlashes::<T>::insert(&era, &unapplied);
+
+			if let Some(appeal) = SlashAppeals::<T>::take(&era, &validator) {
+				T::Currency::unreserve(&appeal.appellant, appeal.deposit);
+			}
+
+			Self::deposit_event(Event::<T>::SlashReduced { era, validator, reduction });
+			Ok(())
+		}
+
+		/// Reject the appeal against the unapplied slashes of `validator` to be applied in `era`.
+		///
+		/// Can be called by the `T::AdminOrigin`.
+		///
+		/// The deposit of the appellant is slashed and handled by [`Config::Slash`].
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::reject_slash_appeal())]
+		pub fn reject_slash_appeal(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			validator: T::AccountId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let appeal = SlashAppeals::<T>::take(&era, &validator).ok_or(Error::<T>::NoAppeal)?;
+			let (imbalance, _) = T::Currency::slash_reserved(&appeal.appellant, appeal.deposit);
+			T::Slash::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::<T>::SlashAppealRejected {
+				era,
+				validator,
+				appellant: appeal.appellant,
+			});
+			Ok(())
+		}
 	}
 }
 
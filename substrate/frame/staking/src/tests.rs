@@ -39,7 +39,7 @@ use sp_runtime::{
 };
 use sp_staking::{
 	offence::{OffenceDetails, OnOffenceHandler},
-	SessionIndex,
+	RewardProjection, SessionIndex,
 };
 use substrate_test_utils::assert_eq_uvec;
 
@@ -3361,6 +3361,159 @@ fn remove_multi_deferred() {
 	})
 }
 
+#[test]
+fn appeal_and_reduce_deferred_slash() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+
+		let exposure = Staking::eras_stakers(active_era(), &11);
+		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
+
+		// deferred to start of era 4.
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure.clone()), reporters: vec![] }],
+			&[Perbill::from_percent(10)],
+		);
+		let _ = staking_events_since_last_call();
+
+		// only slashed stakers can appeal.
+		assert_noop!(
+			Staking::appeal_slash(RuntimeOrigin::signed(21), 4, 11, 1),
+			Error::<Test>::NotSlashed
+		);
+		assert_noop!(
+			Staking::appeal_slash(RuntimeOrigin::signed(101), 3, 11, 1),
+			Error::<Test>::NotSlashed
+		);
+		// the number of unapplied slashes must not be understated.
+		assert_noop!(
+			Staking::appeal_slash(RuntimeOrigin::signed(101), 4, 11, 0),
+			Error::<Test>::IncorrectSlashCount
+		);
+
+		// a nominator appeals, reserving the deposit.
+		assert_ok!(Staking::appeal_slash(RuntimeOrigin::signed(101), 4, 11, 1));
+		assert_eq!(Balances::reserved_balance(&101), 10);
+		assert_noop!(
+			Staking::appeal_slash(RuntimeOrigin::signed(11), 4, 11, 1),
+			Error::<Test>::AlreadyAppealed
+		);
+
+		// only the admin origin can reduce a slash.
+		assert_noop!(
+			Staking::reduce_deferred_slash(
+				RuntimeOrigin::signed(2),
+				4,
+				11,
+				Perbill::from_percent(50),
+				1
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Staking::reduce_deferred_slash(
+				RuntimeOrigin::root(),
+				4,
+				21,
+				Perbill::from_percent(50),
+				1
+			),
+			Error::<Test>::NotSlashed
+		);
+		assert_noop!(
+			Staking::reduce_deferred_slash(
+				RuntimeOrigin::root(),
+				4,
+				11,
+				Perbill::from_percent(50),
+				0
+			),
+			Error::<Test>::IncorrectSlashCount
+		);
+
+		// half of the slash is forgiven, and the deposit returned.
+		assert_ok!(Staking::reduce_deferred_slash(
+			RuntimeOrigin::root(),
+			4,
+			11,
+			Perbill::from_percent(50),
+			1
+		));
+		assert_eq!(Balances::reserved_balance(&101), 0);
+		assert!(!SlashAppeals::<Test>::contains_key(4, 11));
+		// the slash recorded for the offence era is left untouched, as when cancelling it.
+		assert_eq!(ValidatorSlashInEra::<Test>::get(1, 11), Some((Perbill::from_percent(10), 100)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::SlashAppealed { era: 4, validator: 11, appellant: 101 },
+				Event::SlashReduced { era: 4, validator: 11, reduction: Perbill::from_percent(50) },
+			]
+		);
+
+		mock::start_active_era(4);
+
+		// 5% slash processed instead of 10%.
+		assert_eq!(asset::stakeable_balance::<Test>(&11), 950);
+		assert_eq!(
+			asset::stakeable_balance::<Test>(&101),
+			2000 - Perbill::from_percent(50) * (Perbill::from_percent(10) * nominated_value)
+		);
+	})
+}
+
+#[test]
+fn reject_slash_appeal_slashes_deposit() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (21, Staking::eras_stakers(active_era(), &21)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+		let _ = staking_events_since_last_call();
+
+		assert_ok!(Staking::appeal_slash(RuntimeOrigin::signed(101), 4, 11, 2));
+		assert_ok!(Staking::appeal_slash(RuntimeOrigin::signed(21), 4, 21, 2));
+		assert_eq!(Balances::reserved_balance(&101), 10);
+		assert_eq!(Balances::reserved_balance(&21), 10);
+
+		assert_noop!(
+			Staking::reject_slash_appeal(RuntimeOrigin::root(), 3, 11),
+			Error::<Test>::NoAppeal
+		);
+
+		// the appeal against the slash of 11 is rejected.
+		assert_ok!(Staking::reject_slash_appeal(RuntimeOrigin::root(), 4, 11));
+		assert_eq!(Balances::reserved_balance(&101), 0);
+		assert_eq!(Balances::total_balance(&101), 2000 - 10);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::SlashAppealed { era: 4, validator: 11, appellant: 101 },
+				Event::SlashAppealed { era: 4, validator: 21, appellant: 21 },
+				Event::SlashAppealRejected { era: 4, validator: 11, appellant: 101 },
+			]
+		);
+
+		// the appeal against the slash of 21 is refunded when the slash is applied.
+		mock::start_active_era(4);
+		assert_eq!(Balances::reserved_balance(&21), 0);
+		assert!(SlashAppeals::<Test>::iter().next().is_none());
+		assert_eq!(asset::stakeable_balance::<Test>(&21), 1900);
+	})
+}
+
 #[test]
 fn slash_kicks_validators_not_nominators_and_disables_nominator_for_kicked_validator() {
 	ExtBuilder::default()
@@ -5292,6 +5445,41 @@ mod election_data_provider {
 		})
 	}
 
+	#[test]
+	fn electing_voters_paged_works() {
+		ExtBuilder::default()
+			.nominate(false)
+			.add_staker(61, 61, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(71, 71, 10, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(81, 81, 50, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.build_and_execute(|| {
+				let all = Staking::electing_voters(DataProviderBounds::default()).unwrap();
+				assert_eq!(all.len(), 6);
+
+				// fetch the same voters in pages of 4, the last voter of a page being the cursor
+				// of the next one.
+				let bounds = ElectionBoundsBuilder::default().voters_count(4.into()).build().voters;
+				let first = Staking::electing_voters_paged(bounds, None).unwrap();
+				assert_eq!(first.len(), 4);
+				let second =
+					Staking::electing_voters_paged(bounds, first.last().map(|(v, _, _)| v))
+						.unwrap();
+				assert_eq!(second.len(), 2);
+				assert_eq!(first.into_iter().chain(second.clone()).collect::<Vec<_>>(), all);
+
+				// the minimum active stake accounts for all the pages.
+				assert_eq!(MinimumActiveStake::<Test>::get(), 10);
+
+				// nothing is left after the last voter.
+				assert!(Staking::electing_voters_paged(bounds, second.last().map(|(v, _, _)| v))
+					.unwrap()
+					.is_empty());
+
+				// a cursor that is not in the voter list is an error.
+				assert!(Staking::electing_voters_paged(bounds, Some(&1_000)).is_err());
+			});
+	}
+
 	// Tests the criteria that in `ElectionDataProvider::voters` function, we try to get at most
 	// `maybe_max_len` voters, and if some of them end up being skipped, we iterate at most `2 *
 	// maybe_max_len`.
@@ -6120,6 +6308,128 @@ fn api_nominations_quota_works() {
 	})
 }
 
+#[test]
+fn api_simulate_nominator_rewards_works() {
+	ExtBuilder::default().nominate(true).build_and_execute(|| {
+		// no era with a known payout yet.
+		assert_eq!(Staking::api_simulate_nominator_rewards(1000, vec![11, 21]), vec![]);
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 100), (21, 50)]);
+		mock::start_active_era(1);
+		let era_payout = ErasValidatorReward::<Test>::get(0).unwrap();
+		ErasValidatorPrefs::<Test>::insert(
+			0,
+			21,
+			ValidatorPrefs { commission: Perbill::from_percent(50), ..Default::default() },
+		);
+
+		// 31 was not elected in era 0, and the remainder of the split goes to 11.
+		let reward_11 = Perbill::from_rational::<Balance>(501, 1125 + 501) *
+			(Perbill::from_rational::<u32>(100, 150) * era_payout);
+		let payout_21 = Perbill::from_rational::<u32>(50, 150) * era_payout;
+		let reward_21 = Perbill::from_rational::<Balance>(500, 1375 + 500) *
+			(payout_21 - Perbill::from_percent(50) * payout_21);
+		assert_eq!(
+			Staking::api_simulate_nominator_rewards(1001, vec![21, 11, 31, 11]),
+			vec![
+				RewardProjection {
+					validator: 11,
+					stake: 501,
+					reward: reward_11,
+					page: 0,
+					page_count: 1
+				},
+				RewardProjection {
+					validator: 21,
+					stake: 500,
+					reward: reward_21,
+					page: 0,
+					page_count: 1
+				},
+			]
+		);
+
+		// the nominator would overflow into a new page.
+		MaxExposurePageSize::set(1);
+		assert_eq!(
+			Staking::api_simulate_nominator_rewards(1001, vec![11]),
+			vec![RewardProjection {
+				validator: 11,
+				stake: 1001,
+				reward: Perbill::from_rational::<Balance>(1001, 1125 + 1001) *
+					(Perbill::from_rational::<u32>(100, 150) * era_payout),
+				page: 1,
+				page_count: 2,
+			}]
+		);
+		assert_eq!(Staking::api_simulate_nominator_rewards(1001, vec![31]), vec![]);
+	})
+}
+
+mod paged_election {
+	use super::*;
+
+	#[test]
+	fn pages_are_fetched_ahead_of_the_election() {
+		ExtBuilder::default().nominate(true).build_and_execute(|| {
+			ElectionPages::set(3);
+			run_to_block(7);
+			let expected = <Test as Config>::ElectionProvider::elect().unwrap();
+			assert!(ElectedPages::get().is_empty());
+
+			// pages 2 and 1 are fetched in the two blocks before the election at block 10.
+			run_to_block(9);
+			assert_eq!(ElectedPages::get(), vec![(8, 2), (9, 1)]);
+			assert_eq!(NextElectionPage::<Test>::get(), Some(0));
+			assert_eq!(current_era(), 0);
+
+			// the last page is fetched when the new era is planned.
+			start_session(2);
+			assert_eq!(ElectedPages::get(), vec![(8, 2), (9, 1), (10, 0)]);
+			assert_eq!(current_era(), 1);
+			assert_eq!(*staking_events().last().unwrap(), Event::StakersElected);
+			assert!(NextElectionPage::<Test>::get().is_none());
+			assert!(ElectedStashes::<Test>::get().is_empty());
+
+			// the pages are merged into the exposures of the whole result.
+			let mut total_stake = 0;
+			for (winner, support) in expected {
+				let exposure = EraInfo::<Test>::get_full_exposure(1, &winner);
+				assert_eq!(exposure.total, support.total);
+				assert_eq_uvec!(
+					exposure.others.iter().map(|i| i.who).chain(Some(winner)).collect::<Vec<_>>(),
+					support.voters.iter().map(|(who, _)| *who).collect::<Vec<_>>()
+				);
+				assert!(ErasValidatorPrefs::<Test>::contains_key(1, winner));
+				total_stake += exposure.total;
+			}
+			assert_eq!(ErasTotalStake::<Test>::get(1), total_stake);
+
+			// the pages of the next election are fetched ahead of it as well, at block 25.
+			start_active_era(2);
+			assert_eq!(ElectedPages::get()[3..], [(23, 2), (24, 1), (25, 0)]);
+		})
+	}
+
+	#[test]
+	fn fetched_pages_are_discarded_if_the_election_fails() {
+		ExtBuilder::default().nominate(true).build_and_execute(|| {
+			ElectionPages::set(3);
+			// only 2 validators are elected.
+			MinimumValidatorCount::<Test>::put(3);
+
+			start_session(2);
+			assert_eq!(ElectedPages::get(), vec![(8, 2), (9, 1), (10, 0)]);
+			assert_eq!(current_era(), 0);
+			assert_eq!(*staking_events().last().unwrap(), Event::StakingElectionFailed);
+			assert!(ElectedStashes::<Test>::get().is_empty());
+			assert_eq!(ErasStakersOverview::<Test>::iter_prefix(1).count(), 0);
+			assert_eq!(ErasValidatorPrefs::<Test>::iter_prefix(1).count(), 0);
+			assert_eq!(ErasTotalStake::<Test>::get(1), 0);
+		})
+	}
+}
+
 mod sorted_list_provider {
 	use super::*;
 	use frame_election_provider_support::SortedListProvider;
@@ -6169,6 +6479,47 @@ mod sorted_list_provider {
 			assert_eq!(<Test as Config>::VoterList::iter().collect::<Vec<_>>(), vec![11, 21, 31]);
 		});
 	}
+
+	#[test]
+	fn locked_voter_list_keeps_its_order() {
+		ExtBuilder::default().nominate(true).build_and_execute(|| {
+			let voters = || <Test as Config>::VoterList::iter().collect::<Vec<_>>();
+			assert_eq!(voters(), vec![11, 21, 31, 101]);
+			Staking::lock_voters();
+
+			// 101 becomes the heaviest voter, its score is updated but it keeps its position.
+			assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(101), 1_000));
+			assert_eq!(<Test as Config>::VoterList::get_score(&101), Ok(1_500));
+			assert_eq!(voters(), vec![11, 21, 31, 101]);
+			assert_noop!(
+				VoterBagsList::rebag(RuntimeOrigin::signed(1), 101),
+				pallet_bags_list::Error::<Test, pallet_bags_list::Instance1>::List(
+					pallet_bags_list::ListError::Locked
+				)
+			);
+
+			// existing voters can update their nominations and preferences,
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![21]));
+			assert_ok!(Staking::validate(RuntimeOrigin::signed(11), Default::default()));
+			// but they cannot change their role,
+			assert_noop!(
+				Staking::validate(RuntimeOrigin::signed(101), Default::default()),
+				Error::<Test>::VoterListLocked
+			);
+			// and new voters cannot join.
+			bond(61, 1_000);
+			assert_noop!(
+				Staking::nominate(RuntimeOrigin::signed(61), vec![11]),
+				Error::<Test>::VoterListLocked
+			);
+
+			// once unlocked, voters can move and join again.
+			Staking::unlock_voters();
+			assert_ok!(VoterBagsList::rebag(RuntimeOrigin::signed(1), 101));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(61), vec![11]));
+			assert_eq!(voters(), vec![101, 11, 21, 31, 61]);
+		});
+	}
 }
 
 #[test]
@@ -83,6 +83,9 @@ pub trait WeightInfo {
 	fn force_apply_min_commission() -> Weight;
 	fn set_min_commission() -> Weight;
 	fn restore_ledger() -> Weight;
+	fn appeal_slash(s: u32, ) -> Weight;
+	fn reduce_deferred_slash(s: u32, ) -> Weight;
+	fn reject_slash_appeal() -> Weight;
 }
 
 /// Weights for `pallet_staking` using the Substrate node and recommended hardware.
@@ -834,6 +837,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Staking::UnappliedSlashes` (r:1 w:0)
+	/// Proof: `Staking::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 1000]`.
+	fn appeal_slash(s: u32, ) -> Weight {
+		// Not benchmarked yet: the decoding of each unapplied slash is priced from the base
+		// weight of `cancel_deferred_slash`, measured with 1000 of them.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_167_895, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 67).saturating_mul(s.into()))
+	}
+	/// Storage: `Staking::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `Staking::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 1000]`.
+	fn reduce_deferred_slash(s: u32, ) -> Weight {
+		// Not benchmarked yet: like `appeal_slash`, with the unapplied slashes encoded again
+		// after their reduction.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(Weight::from_parts(2_335_790, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 67).saturating_mul(s.into()))
+	}
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_slash_appeal() -> Weight {
+		// Not benchmarked yet: priced as a reserved balance slash.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1584,4 +1629,46 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `Staking::UnappliedSlashes` (r:1 w:0)
+	/// Proof: `Staking::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 1000]`.
+	fn appeal_slash(s: u32, ) -> Weight {
+		// Not benchmarked yet: the decoding of each unapplied slash is priced from the base
+		// weight of `cancel_deferred_slash`, measured with 1000 of them.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(Weight::from_parts(1_167_895, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 67).saturating_mul(s.into()))
+	}
+	/// Storage: `Staking::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `Staking::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 1000]`.
+	fn reduce_deferred_slash(s: u32, ) -> Weight {
+		// Not benchmarked yet: like `appeal_slash`, with the unapplied slashes encoded again
+		// after their reduction.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(Weight::from_parts(2_335_790, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 67).saturating_mul(s.into()))
+	}
+	/// Storage: `Staking::SlashAppeals` (r:1 w:1)
+	/// Proof: `Staking::SlashAppeals` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_slash_appeal() -> Weight {
+		// Not benchmarked yet: priced as a reserved balance slash.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
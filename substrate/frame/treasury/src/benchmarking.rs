@@ -96,6 +96,17 @@ fn setup_pot_account<T: Config<I>, I: 'static>() {
 	let _ = T::Currency::make_free_balance_be(&pot_account, value);
 }
 
+// Fill the budget of `origin`, if it has one, with as many charges as possible while leaving room
+// for one more, the worst case of charging and refunding it.
+fn fill_budget_charges<T: Config<I>, I: 'static>(origin: &T::RuntimeOrigin) {
+	let Some(id) = T::SpendBudget::budget_of(origin) else { return };
+	let now = T::BlockNumberProvider::current_block_number();
+	let charges: alloc::vec::Vec<_> = (1..T::MaxBudgetCharges::get())
+		.map(|_| BudgetCharge { approved_at: now, amount: Zero::zero() })
+		.collect();
+	BudgetCharges::<T, I>::insert(id, BoundedVec::truncate_from(charges));
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -121,6 +132,7 @@ mod benchmarks {
 		let (_, value, beneficiary_lookup) = setup_proposal::<T, _>(SEED);
 		let origin =
 			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		fill_budget_charges::<T, I>(&origin);
 		let beneficiary = T::Lookup::lookup(beneficiary_lookup.clone()).unwrap();
 
 		#[extrinsic_call]
@@ -185,6 +197,7 @@ mod benchmarks {
 	fn spend() -> Result<(), BenchmarkError> {
 		let origin =
 			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		fill_budget_charges::<T, I>(&origin);
 		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
 			create_spend_arguments::<T, _>(SEED);
 		T::BalanceConverter::ensure_successful(asset_kind.clone());
@@ -318,6 +331,7 @@ mod benchmarks {
 		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
 		T::BalanceConverter::ensure_successful(asset_kind.clone());
 		let spend_exists = if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+			fill_budget_charges::<T, I>(&origin);
 			Treasury::<T, _>::spend(
 				origin,
 				Box::new(asset_kind.clone()),
@@ -350,6 +364,125 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn spend_recurring() -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		fill_budget_charges::<T, I>(&origin);
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let period: BlockNumberFor<T, I> = One::one();
+		let limit = RecurringSpendLimit::Total(amount);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind.clone()),
+			amount,
+			Box::new(beneficiary_lookup),
+			None,
+			period,
+			limit.clone(),
+		);
+
+		let valid_from = T::BlockNumberProvider::current_block_number();
+		assert_last_event::<T, I>(
+			Event::RecurringSpendApproved {
+				index: 0,
+				asset_kind,
+				amount,
+				beneficiary,
+				valid_from,
+				period,
+				limit,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn release_instalment() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let spend_exists = if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+			Treasury::<T, _>::spend_recurring(
+				origin,
+				Box::new(asset_kind),
+				amount,
+				Box::new(beneficiary_lookup),
+				None,
+				One::one(),
+				RecurringSpendLimit::Total(amount),
+			)?;
+			assert!(RecurringSpends::<T, I>::get(0).is_some());
+
+			true
+		} else {
+			false
+		};
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::release_instalment(RawOrigin::Signed(caller).into(), 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if spend_exists {
+			assert!(Spends::<T, I>::get(0).is_some());
+		}
+		assert!(RecurringSpends::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn void_recurring_spend() -> Result<(), BenchmarkError> {
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let spend_exists = if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+			fill_budget_charges::<T, I>(&origin);
+			Treasury::<T, _>::spend_recurring(
+				origin,
+				Box::new(asset_kind),
+				amount,
+				Box::new(beneficiary_lookup),
+				None,
+				One::one(),
+				RecurringSpendLimit::Total(amount),
+			)?;
+			assert!(RecurringSpends::<T, I>::get(0).is_some());
+
+			true
+		} else {
+			false
+		};
+
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::void_recurring_spend(origin as T::RuntimeOrigin, 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		assert!(RecurringSpends::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
@@ -69,6 +69,18 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! Recurring spends, approved with the `spend_recurring` dispatchable, pay an asset kind to a
+//! beneficiary in instalments, every given number of blocks, until either a total amount or a
+//! given block number is reached. Each instalment is released as a regular spend with the
+//! `release_instalment` dispatchable, and is then claimed with `payout` like any other spend.
+//! Instalments that are not released before the end of their payout period are skipped.
+//!
+//! Spend origins can additionally be given a budget with [`pallet::Config::SpendBudget`], capping
+//! the total native value of the spends they approve within any [`pallet::Config::BudgetPeriod`]
+//! blocks, whatever the number of spends. The spends charged to a budget within the last budget
+//! period are kept in [`pallet::BudgetCharges`], and the remaining budget can be queried with
+//! [`Pallet::remaining_budget`]. Voided spends are refunded to the budget they were charged to.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -90,10 +102,10 @@ use scale_info::TypeInfo;
 use alloc::{boxed::Box, collections::btree_map::BTreeMap};
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
-		UniqueSaturatedInto, Zero,
+		AccountIdConversion, AtLeast32BitUnsigned, BlockNumberProvider, CheckedAdd, One,
+		Saturating, StaticLookup, UniqueSaturatedInto, Zero,
 	},
-	PerThing, Permill, RuntimeDebug,
+	DispatchError, PerThing, Perbill, Permill, RuntimeDebug,
 };
 
 use frame_support::{
@@ -104,7 +116,7 @@ use frame_support::{
 		ReservableCurrency, WithdrawReasons,
 	},
 	weights::Weight,
-	BoundedVec, PalletId,
+	BoundedVec, PalletId, Parameter,
 };
 use frame_system::pallet_prelude::BlockNumberFor as SystemBlockNumberFor;
 
@@ -124,6 +136,10 @@ type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup
 type BeneficiaryLookupOf<T, I> = <<T as Config<I>>::BeneficiaryLookup as StaticLookup>::Source;
 pub type BlockNumberFor<T, I = ()> =
 	<<T as Config<I>>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
+pub type BudgetIdOf<T, I = ()> = <<T as Config<I>>::SpendBudget as SpendBudget<
+	<T as frame_system::Config>::RuntimeOrigin,
+	BalanceOf<T, I>,
+>>::Id;
 
 /// A trait to allow the Treasury Pallet to spend it's funds for other purposes.
 /// There is an expectation that the implementer of this trait will correctly manage
@@ -146,6 +162,33 @@ pub trait SpendFunds<T: Config<I>, I: 'static = ()> {
 	);
 }
 
+/// The budgets of the spend origins, i.e. the maximum native value of all the spends they can
+/// approve within any [`Config::BudgetPeriod`] blocks.
+///
+/// Several origins may spend from the same budget.
+pub trait SpendBudget<Origin, Balance> {
+	/// The identifier of a budget.
+	type Id: Parameter + MaxEncodedLen;
+
+	/// The budget that `origin` spends from, or `None` if its spends are not limited.
+	fn budget_of(origin: &Origin) -> Option<Self::Id>;
+
+	/// The amount of the budget `id`.
+	fn amount(id: &Self::Id) -> Balance;
+}
+
+impl<Origin, Balance: Zero> SpendBudget<Origin, Balance> for () {
+	type Id = ();
+
+	fn budget_of(_: &Origin) -> Option<()> {
+		None
+	}
+
+	fn amount(_: &()) -> Balance {
+		Zero::zero()
+	}
+}
+
 /// An index of a proposal. Just a `u32`.
 pub type ProposalIndex = u32;
 
@@ -196,6 +239,90 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
+/// The limit after which a recurring treasury spend stops paying instalments.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum RecurringSpendLimit<AssetBalance, BlockNumber> {
+	/// Instalments are paid until their sum reaches the given amount. The last instalment is
+	/// reduced if needed.
+	Total(AssetBalance),
+	/// Instalments are paid until the given block number, inclusive.
+	EndAt(BlockNumber),
+}
+
+/// Info regarding an approved recurring treasury spend.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct RecurringSpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber> {
+	/// The kind of asset to be spent.
+	asset_kind: AssetKind,
+	/// The asset amount of each instalment.
+	amount: AssetBalance,
+	/// The beneficiary of the instalments.
+	beneficiary: Beneficiary,
+	/// The block number from which the next instalment can be released.
+	next_valid_from: BlockNumber,
+	/// The number of blocks between two instalments.
+	period: BlockNumber,
+	/// The limit after which no more instalments are paid.
+	limit: RecurringSpendLimit<AssetBalance, BlockNumber>,
+	/// The sum of the instalments that were released, or that expired, so far.
+	released: AssetBalance,
+}
+
+impl<AssetKind, AssetBalance, Beneficiary, BlockNumber>
+	RecurringSpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber>
+where
+	AssetBalance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// The amount of the next instalment, or `None` if the limit of the spend is reached.
+	fn next_instalment(&self) -> Option<AssetBalance> {
+		match self.limit {
+			RecurringSpendLimit::Total(total) => {
+				let left = total.saturating_sub(self.released);
+				(!left.is_zero()).then(|| self.amount.min(left))
+			},
+			RecurringSpendLimit::EndAt(end) => (self.next_valid_from <= end).then_some(self.amount),
+		}
+	}
+
+	/// The sum of the instalments that are not released yet.
+	fn unreleased(&self) -> AssetBalance {
+		match self.limit {
+			RecurringSpendLimit::Total(total) => total.saturating_sub(self.released),
+			RecurringSpendLimit::EndAt(end) if self.next_valid_from <= end => {
+				let instalments: u32 = ((end - self.next_valid_from) / self.period)
+					.saturating_add(One::one())
+					.unique_saturated_into();
+				self.amount.saturating_mul(instalments.into())
+			},
+			RecurringSpendLimit::EndAt(_) => Zero::zero(),
+		}
+	}
+
+	/// Move on to the instalment that follows the next `count` instalments.
+	fn skip(&mut self, count: BlockNumber) {
+		let count: u32 = count.unique_saturated_into();
+		self.released = self.released.saturating_add(self.amount.saturating_mul(count.into()));
+		self.next_valid_from =
+			self.next_valid_from.saturating_add(self.period.saturating_mul(count.into()));
+	}
+}
+
+/// Index of an approved recurring treasury spend.
+pub type RecurringSpendIndex = u32;
+
+/// A spend charged to a budget of the spend origins.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct BudgetCharge<Balance, BlockNumber> {
+	/// The block number at which the spend was approved.
+	approved_at: BlockNumber,
+	/// The native value of the spend.
+	amount: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -287,6 +414,19 @@ pub mod pallet {
 
 		/// Provider for the block number. Normally this is the `frame_system` pallet.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// The budgets of the spend origins, i.e. the maximum native value of all the spends they
+		/// can approve within any [`Self::BudgetPeriod`] blocks.
+		type SpendBudget: SpendBudget<Self::RuntimeOrigin, BalanceOf<Self, I>>;
+
+		/// The number of blocks of the rolling window over which the budgets of the spend origins
+		/// apply, e.g. the [`Self::SpendPeriod`].
+		#[pallet::constant]
+		type BudgetPeriod: Get<BlockNumberFor<Self, I>>;
+
+		/// The maximum number of spends charged to a budget within a [`Self::BudgetPeriod`].
+		#[pallet::constant]
+		type MaxBudgetCharges: Get<u32>;
 	}
 
 	/// DEPRECATED: associated with `spend_local` call and will be removed in May 2025.
@@ -343,6 +483,60 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The count of recurring spends that have been made.
+	#[pallet::storage]
+	pub(crate) type RecurringSpendCount<T, I = ()> =
+		StorageValue<_, RecurringSpendIndex, ValueQuery>;
+
+	/// Recurring spends that have been approved and still have instalments to release.
+	// Hasher: Twox safe since `RecurringSpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type RecurringSpends<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		RecurringSpendIndex,
+		RecurringSpendStatus<
+			T::AssetKind,
+			AssetBalanceOf<T, I>,
+			T::Beneficiary,
+			BlockNumberFor<T, I>,
+		>,
+		OptionQuery,
+	>;
+
+	/// The spends charged to each budget of the spend origins, including the ones approved
+	/// before the last [`Config::BudgetPeriod`] blocks until the next charge.
+	#[pallet::storage]
+	pub type BudgetCharges<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BudgetIdOf<T, I>,
+		BoundedVec<BudgetCharge<BalanceOf<T, I>, BlockNumberFor<T, I>>, T::MaxBudgetCharges>,
+		ValueQuery,
+	>;
+
+	/// The budget charged for each spend that has not been processed yet, refunded if the spend
+	/// is voided.
+	#[pallet::storage]
+	pub type SpendCharges<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		SpendIndex,
+		(BudgetIdOf<T, I>, BudgetCharge<BalanceOf<T, I>, BlockNumberFor<T, I>>),
+		OptionQuery,
+	>;
+
+	/// The budget charged for each recurring spend that still has instalments to release, refunded
+	/// in proportion of the remaining instalments if the recurring spend is voided.
+	#[pallet::storage]
+	pub type RecurringSpendCharges<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		RecurringSpendIndex,
+		(BudgetIdOf<T, I>, BudgetCharge<BalanceOf<T, I>, BlockNumberFor<T, I>>),
+		OptionQuery,
+	>;
+
 	/// The blocknumber for the last triggered spend period.
 	#[pallet::storage]
 	pub(crate) type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
@@ -405,6 +599,27 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new recurring asset spend has been approved.
+		RecurringSpendApproved {
+			index: RecurringSpendIndex,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::Beneficiary,
+			valid_from: BlockNumberFor<T, I>,
+			period: BlockNumberFor<T, I>,
+			limit: RecurringSpendLimit<AssetBalanceOf<T, I>, BlockNumberFor<T, I>>,
+		},
+		/// An instalment of a recurring spend was released as a spend, to be claimed with
+		/// `payout`.
+		InstalmentReleased {
+			index: RecurringSpendIndex,
+			spend_index: SpendIndex,
+			amount: AssetBalanceOf<T, I>,
+		},
+		/// The remaining instalments of a recurring spend were voided.
+		RecurringSpendVoided { index: RecurringSpendIndex },
+		/// A recurring spend reached its limit and was removed from the storage.
+		RecurringSpendCompleted { index: RecurringSpendIndex },
 	}
 
 	/// Error for the treasury pallet.
@@ -433,6 +648,14 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// The schedule of the recurring spend is invalid, e.g. its period or amount is zero, or
+		/// it ends before it starts.
+		InvalidSchedule,
+		/// The spend origin has not enough budget left in the current budget period.
+		BudgetExceeded,
+		/// Too many spends were charged to the budget of the spend origin in the current budget
+		/// period.
+		TooManyBudgetCharges,
 	}
 
 	#[pallet::hooks]
@@ -493,7 +716,9 @@ pub mod pallet {
 		///
 		/// ## Dispatch Origin
 		///
-		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least `amount`.
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least `amount`. If
+		/// the origin has a [`Config::SpendBudget`], `amount` must not exceed its remaining budget.
+		/// The budget is not refunded if the approval is removed with `remove_approval`.
 		///
 		/// ### Details
 		/// NOTE: For record-keeping purposes, the proposer is deemed to be equivalent to the
@@ -517,6 +742,7 @@ pub mod pallet {
 			#[pallet::compact] amount: BalanceOf<T, I>,
 			beneficiary: AccountIdLookupOf<T>,
 		) -> DispatchResult {
+			let budget = T::SpendBudget::budget_of(&origin);
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
 			ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
 
@@ -539,6 +765,7 @@ pub mod pallet {
 				}
 			})
 			.unwrap_or(Ok(()))?;
+			Self::charge_budget(budget, amount)?;
 
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 			#[allow(deprecated)]
@@ -613,7 +840,8 @@ pub mod pallet {
 		///
 		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least
 		/// `amount` of `asset_kind` in the native asset. The amount of `asset_kind` is converted
-		/// for assertion using the [`Config::BalanceConverter`].
+		/// for assertion using the [`Config::BalanceConverter`]. If the origin has a
+		/// [`Config::SpendBudget`], the converted amount must not exceed its remaining budget.
 		///
 		/// ## Details
 		///
@@ -642,6 +870,7 @@ pub mod pallet {
 			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
 			valid_from: Option<BlockNumberFor<T, I>>,
 		) -> DispatchResult {
+			let budget = T::SpendBudget::budget_of(&origin);
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
 			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
 
@@ -673,8 +902,12 @@ pub mod pallet {
 				}
 			})
 			.unwrap_or(Ok(()))?;
+			let charge = Self::charge_budget(budget, native_amount)?;
 
 			let index = SpendCount::<T, I>::get();
+			if let Some(charge) = charge {
+				SpendCharges::<T, I>::insert(index, charge);
+			}
 			Spends::<T, I>::insert(
 				index,
 				SpendStatus {
@@ -774,6 +1007,7 @@ pub mod pallet {
 			if now > spend.expire_at && !matches!(spend.status, State::Attempted { .. }) {
 				// spend has expired and no further status update is expected.
 				Spends::<T, I>::remove(index);
+				SpendCharges::<T, I>::remove(index);
 				Self::deposit_event(Event::<T, I>::SpendProcessed { index });
 				return Ok(Pays::No.into())
 			}
@@ -791,6 +1025,7 @@ pub mod pallet {
 				},
 				Status::Success | Status::Unknown => {
 					Spends::<T, I>::remove(index);
+					SpendCharges::<T, I>::remove(index);
 					Self::deposit_event(Event::<T, I>::SpendProcessed { index });
 					return Ok(Pays::No.into())
 				},
@@ -807,7 +1042,8 @@ pub mod pallet {
 		///
 		/// ## Details
 		///
-		/// A spend void is only possible if the payout has not been attempted yet.
+		/// A spend void is only possible if the payout has not been attempted yet. The spend is
+		/// refunded to the budget it was charged to, if any.
 		///
 		/// ### Parameters
 		/// - `index`: The spend index.
@@ -826,9 +1062,264 @@ pub mod pallet {
 			);
 
 			Spends::<T, I>::remove(index);
+			if let Some((id, charge)) = SpendCharges::<T, I>::take(index) {
+				Self::refund_budget(&id, &charge, charge.amount);
+			}
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a recurring spend of treasury funds.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the total
+		/// amount of all instalments of `asset_kind` in the native asset. The amount of
+		/// `asset_kind` is converted for assertion using the [`Config::BalanceConverter`]. If the
+		/// origin has a [`Config::SpendBudget`], the converted total amount is charged to its
+		/// remaining budget at approval, and the share of the remaining instalments is refunded if
+		/// the recurring spend is voided.
+		///
+		/// ## Details
+		///
+		/// Create an approved recurring spend, paying `amount` of `asset_kind` to a designated
+		/// beneficiary every `period` blocks, until `limit` is reached. Each instalment must be
+		/// released with the `release_instalment` dispatchable once valid, which creates a spend
+		/// that is then claimed, checked and voided as any other spend.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `amount`: The amount of each instalment.
+		/// - `beneficiary`: The beneficiary of the instalments.
+		/// - `valid_from`: The block number from which the first instalment can be released. It can
+		///   refer to the past if the first instalment has not yet expired according to the
+		///   [`Config::PayoutPeriod`]. If `None`, it can be released immediately after approval.
+		/// - `period`: The number of blocks between two instalments.
+		/// - `limit`: The total amount of the instalments, or the block number of the last one.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendApproved`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend_recurring())]
+		pub fn spend_recurring(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] amount: AssetBalanceOf<T, I>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			valid_from: Option<BlockNumberFor<T, I>>,
+			period: BlockNumberFor<T, I>,
+			limit: RecurringSpendLimit<AssetBalanceOf<T, I>, BlockNumberFor<T, I>>,
+		) -> DispatchResult {
+			let budget = T::SpendBudget::budget_of(&origin);
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			ensure!(!amount.is_zero() && !period.is_zero(), Error::<T, I>::InvalidSchedule);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let valid_from = valid_from.unwrap_or(now);
+			ensure!(
+				valid_from.saturating_add(T::PayoutPeriod::get()) > now,
+				Error::<T, I>::SpendExpired
+			);
+
+			let total = match limit {
+				RecurringSpendLimit::Total(total) => total,
+				RecurringSpendLimit::EndAt(end) => {
+					ensure!(end >= valid_from, Error::<T, I>::InvalidSchedule);
+					let instalments: u32 = ((end - valid_from) / period)
+						.saturating_add(One::one())
+						.unique_saturated_into();
+					amount.saturating_mul(instalments.into())
+				},
+			};
+			ensure!(!total.is_zero(), Error::<T, I>::InvalidSchedule);
+
+			let native_amount = T::BalanceConverter::from_asset_balance(total, *asset_kind.clone())
+				.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+				let context = v.or_default();
+				// We group based on `max_amount`, to distinguish between different kind of
+				// origins. (assumes that all origins have different `max_amount`)
+				//
+				// Worst case is that we reject some "valid" request.
+				let spend = context.spend_in_context.entry(max_amount).or_default();
+
+				// Ensure that we don't overflow nor use more than `max_amount`
+				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
+					Err(Error::<T, I>::InsufficientPermission)
+				} else {
+					*spend = spend.saturating_add(native_amount);
+					Ok(())
+				}
+			})
+			.unwrap_or(Ok(()))?;
+			let charge = Self::charge_budget(budget, native_amount)?;
+
+			let index = RecurringSpendCount::<T, I>::get();
+			if let Some(charge) = charge {
+				RecurringSpendCharges::<T, I>::insert(index, charge);
+			}
+			RecurringSpends::<T, I>::insert(
+				index,
+				RecurringSpendStatus {
+					asset_kind: *asset_kind.clone(),
+					amount,
+					beneficiary: beneficiary.clone(),
+					next_valid_from: valid_from,
+					period,
+					limit: limit.clone(),
+					released: Zero::zero(),
+				},
+			);
+			RecurringSpendCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::RecurringSpendApproved {
+				index,
+				asset_kind: *asset_kind,
+				amount,
+				beneficiary,
+				valid_from,
+				period,
+				limit,
+			});
+			Ok(())
+		}
+
+		/// Release the next instalment of a recurring spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed.
+		///
+		/// ## Details
+		///
+		/// The instalment is released as a spend, valid from the block number at which the
+		/// instalment is due, which must then be claimed using the `payout` dispatchable within
+		/// the [`Config::PayoutPeriod`]. Instalments that were not released before the end of
+		/// their payout period are expired, and skipped.
+		///
+		/// The instalment is charged its share of the budget charged for the recurring spend, which
+		/// is refunded if it is voided with `void_spend`.
+		///
+		/// Once the limit of the recurring spend is reached, it is removed from the storage.
+		///
+		/// ### Parameters
+		/// - `index`: The recurring spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::InstalmentReleased`] if an instalment was released.
+		/// Emits [`Event::RecurringSpendCompleted`] if the limit of the spend is reached.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::release_instalment())]
+		pub fn release_instalment(
+			origin: OriginFor<T>,
+			index: RecurringSpendIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut recurring =
+				RecurringSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(now >= recurring.next_valid_from, Error::<T, I>::EarlyPayout);
+
+			// skip the instalments that can no longer be claimed.
+			let payout_period = T::PayoutPeriod::get();
+			if recurring.next_valid_from.saturating_add(payout_period) <= now {
+				let expired = (now - payout_period - recurring.next_valid_from) / recurring.period;
+				recurring.skip(expired.saturating_add(One::one()));
+			}
+
+			let Some(amount) = recurring.next_instalment() else {
+				RecurringSpends::<T, I>::remove(index);
+				RecurringSpendCharges::<T, I>::remove(index);
+				Self::deposit_event(Event::<T, I>::RecurringSpendCompleted { index });
+				return Ok(())
+			};
+			ensure!(now >= recurring.next_valid_from, Error::<T, I>::EarlyPayout);
+
+			let spend_index = SpendCount::<T, I>::get();
+			if let Some((id, charge)) = RecurringSpendCharges::<T, I>::get(index) {
+				// the instalment may be voided on its own, refunding its share of the charge.
+				let share = Perbill::from_rational(
+					amount,
+					recurring.released.saturating_add(recurring.unreleased()),
+				);
+				let amount = share.mul_floor(charge.amount);
+				SpendCharges::<T, I>::insert(
+					spend_index,
+					(id, BudgetCharge { approved_at: charge.approved_at, amount }),
+				);
+			}
+			let valid_from = recurring.next_valid_from;
+			Spends::<T, I>::insert(
+				spend_index,
+				SpendStatus {
+					asset_kind: recurring.asset_kind.clone(),
+					amount,
+					beneficiary: recurring.beneficiary.clone(),
+					valid_from,
+					expire_at: valid_from.saturating_add(payout_period),
+					status: PaymentState::Pending,
+				},
+			);
+			SpendCount::<T, I>::put(spend_index + 1);
+			Self::deposit_event(Event::<T, I>::InstalmentReleased { index, spend_index, amount });
+
+			recurring.released = recurring.released.saturating_add(amount);
+			recurring.next_valid_from = valid_from.saturating_add(recurring.period);
+			if recurring.next_instalment().is_some() {
+				RecurringSpends::<T, I>::insert(index, recurring);
+			} else {
+				RecurringSpends::<T, I>::remove(index);
+				RecurringSpendCharges::<T, I>::remove(index);
+				Self::deposit_event(Event::<T, I>::RecurringSpendCompleted { index });
+			}
+			Ok(())
+		}
+
+		/// Void the remaining instalments of a recurring spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`].
+		///
+		/// ## Details
+		///
+		/// The instalments that were already released are not affected, and can be voided
+		/// individually with the `void_spend` dispatchable. The share of the remaining instalments
+		/// is refunded to the budget the recurring spend was charged to, if any.
+		///
+		/// ### Parameters
+		/// - `index`: The recurring spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendVoided`] if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::void_recurring_spend())]
+		pub fn void_recurring_spend(
+			origin: OriginFor<T>,
+			index: RecurringSpendIndex,
+		) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let recurring =
+				RecurringSpends::<T, I>::take(index).ok_or(Error::<T, I>::InvalidIndex)?;
+
+			if let Some((id, charge)) = RecurringSpendCharges::<T, I>::take(index) {
+				let unreleased = recurring.unreleased();
+				let share = Perbill::from_rational(
+					unreleased,
+					recurring.released.saturating_add(unreleased),
+				);
+				Self::refund_budget(&id, &charge, share.mul_floor(charge.amount));
+			}
+			Self::deposit_event(Event::<T, I>::RecurringSpendVoided { index });
+			Ok(())
+		}
 	}
 }
 
@@ -889,6 +1380,82 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Approvals::<T, I>::get()
 	}
 
+	/// The budget left to the spend origins spending from the budget `id`, i.e. its amount minus
+	/// the spends charged to it within the last [`Config::BudgetPeriod`] blocks.
+	pub fn remaining_budget(id: &BudgetIdOf<T, I>) -> BalanceOf<T, I> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let spent = BudgetCharges::<T, I>::get(id)
+			.iter()
+			.filter(|charge| Self::is_charged(charge, now))
+			.fold(Zero::zero(), |spent: BalanceOf<T, I>, charge| {
+				spent.saturating_add(charge.amount)
+			});
+		T::SpendBudget::amount(id).saturating_sub(spent)
+	}
+
+	/// Whether `charge` still counts against its budget at block `now`.
+	fn is_charged(
+		charge: &BudgetCharge<BalanceOf<T, I>, BlockNumberFor<T, I>>,
+		now: BlockNumberFor<T, I>,
+	) -> bool {
+		charge.approved_at.saturating_add(T::BudgetPeriod::get()) > now
+	}
+
+	/// Charge `amount` to `budget`, if the spend origin has one.
+	///
+	/// Returns the budget and the charge, to refund it if the spend is voided.
+	fn charge_budget(
+		budget: Option<BudgetIdOf<T, I>>,
+		amount: BalanceOf<T, I>,
+	) -> Result<
+		Option<(BudgetIdOf<T, I>, BudgetCharge<BalanceOf<T, I>, BlockNumberFor<T, I>>)>,
+		DispatchError,
+	> {
+		let Some(id) = budget else { return Ok(None) };
+		let now = T::BlockNumberProvider::current_block_number();
+		BudgetCharges::<T, I>::try_mutate(&id, |charges| {
+			charges.retain(|charge| Self::is_charged(charge, now));
+			let spent = charges.iter().fold(Zero::zero(), |spent: BalanceOf<T, I>, charge| {
+				spent.saturating_add(charge.amount)
+			});
+			ensure!(
+				spent
+					.checked_add(&amount)
+					.map_or(false, |spent| spent <= T::SpendBudget::amount(&id)),
+				Error::<T, I>::BudgetExceeded
+			);
+			let charge = BudgetCharge { approved_at: now, amount };
+			charges
+				.try_push(charge.clone())
+				.map_err(|_| Error::<T, I>::TooManyBudgetCharges)?;
+			Ok(Some((id.clone(), charge)))
+		})
+	}
+
+	/// Refund `amount` of `charge` to the budget `id`.
+	///
+	/// The charges approved at the same block stop counting against the budget together, so the
+	/// amount is taken from any of them. Nothing is refunded if they no longer count against it.
+	fn refund_budget(
+		id: &BudgetIdOf<T, I>,
+		charge: &BudgetCharge<BalanceOf<T, I>, BlockNumberFor<T, I>>,
+		amount: BalanceOf<T, I>,
+	) {
+		BudgetCharges::<T, I>::mutate_exists(id, |maybe_charges| {
+			let Some(charges) = maybe_charges else { return };
+			let mut left = amount;
+			for c in charges.iter_mut().filter(|c| c.approved_at == charge.approved_at) {
+				let refunded = c.amount.min(left);
+				c.amount = c.amount.saturating_sub(refunded);
+				left = left.saturating_sub(refunded);
+			}
+			charges.retain(|c| !c.amount.is_zero());
+			if charges.is_empty() {
+				*maybe_charges = None;
+			}
+		});
+	}
+
 	/// Spend some money! returns number of approvals before spend.
 	pub fn spend_funds(
 		spend_periods_passed: BlockNumberFor<T, I>,
@@ -993,6 +1560,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
 		Self::try_state_proposals()?;
 		Self::try_state_spends()?;
+		Self::try_state_recurring_spends()?;
 
 		Ok(())
 	}
@@ -1040,6 +1608,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// [`SpendCount`].
 	/// 3. For each spend entry contained in [`Spends`] we should have spend.expire_at
 	/// > spend.valid_from.
+	/// 4. Each entry in [`SpendCharges`] should be saved under the key of an entry in [`Spends`].
 	#[cfg(any(feature = "try-runtime", test))]
 	fn try_state_spends() -> Result<(), sp_runtime::TryRuntimeError> {
 		let current_spend_count = SpendCount::<T, I>::get();
@@ -1064,6 +1633,48 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		SpendCharges::<T, I>::iter_keys().try_for_each(|index| -> DispatchResult {
+			ensure!(Spends::<T, I>::contains_key(index), "Charge of a spend that does not exist.");
+			Ok(())
+		})?;
+
+		Ok(())
+	}
+
+	/// ## Invariants of recurring spend storage items
+	///
+	/// 1. [`RecurringSpendCount`] >= Number of elements in [`RecurringSpends`].
+	/// 2. Each entry in [`RecurringSpends`] should be saved under a key strictly less than current
+	/// [`RecurringSpendCount`].
+	/// 3. For each recurring spend entry contained in [`RecurringSpends`] we should have a non-zero
+	/// period.
+	/// 4. Each entry in [`RecurringSpendCharges`] should be saved under the key of an entry in
+	/// [`RecurringSpends`].
+	#[cfg(any(feature = "try-runtime", test))]
+	fn try_state_recurring_spends() -> Result<(), sp_runtime::TryRuntimeError> {
+		let current_count = RecurringSpendCount::<T, I>::get();
+		ensure!(
+			current_count as usize >= RecurringSpends::<T, I>::iter().count(),
+			"Actual number of recurring spends exceeds `RecurringSpendCount`."
+		);
+
+		RecurringSpends::<T, I>::iter().try_for_each(|(index, recurring)| -> DispatchResult {
+			ensure!(
+				current_count > index,
+				"`RecurringSpendCount` should by strictly greater than any RecurringSpendIndex used as a key for `RecurringSpends`."
+			);
+			ensure!(!recurring.period.is_zero(), "Recurring spend cannot have a zero period.");
+			Ok(())
+		})?;
+
+		RecurringSpendCharges::<T, I>::iter_keys().try_for_each(|index| -> DispatchResult {
+			ensure!(
+				RecurringSpends::<T, I>::contains_key(index),
+				"Charge of a recurring spend that does not exist."
+			);
+			Ok(())
+		})?;
+
 		Ok(())
 	}
 }
@@ -31,7 +31,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		tokens::{ConversionFromAssetBalance, PaymentStatus},
-		ConstU32, ConstU64, OnInitialize,
+		ConstU32, ConstU64, OnInitialize, OriginTrait,
 	},
 	PalletId,
 };
@@ -152,6 +152,8 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestSpendOrigin {
 			frame_system::RawOrigin::Signed(12) => Ok(20),
 			frame_system::RawOrigin::Signed(13) => Ok(50),
 			frame_system::RawOrigin::Signed(14) => Ok(500),
+			frame_system::RawOrigin::Signed(15) => Ok(30),
+			frame_system::RawOrigin::Signed(16) => Ok(30),
 			r => Err(RuntimeOrigin::from(r)),
 		})
 	}
@@ -165,6 +167,23 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestSpendOrigin {
 	}
 }
 
+/// Gives a budget of 50 to the spend origin of account 15, and of 20 to the one of account 16,
+/// which has the same maximum spend amount.
+pub struct TestSpendBudget;
+impl SpendBudget<RuntimeOrigin, u64> for TestSpendBudget {
+	type Id = u128;
+	fn budget_of(origin: &RuntimeOrigin) -> Option<u128> {
+		origin.as_signer().copied().filter(|who| *who == 15 || *who == 16)
+	}
+	fn amount(id: &u128) -> u64 {
+		if *id == 15 {
+			50
+		} else {
+			20
+		}
+	}
+}
+
 pub struct MulBy<N>(PhantomData<N>);
 impl<N: Get<u64>> ConversionFromAssetBalance<u64, u32, u64> for MulBy<N> {
 	type Error = ();
@@ -196,6 +215,9 @@ impl Config for Test {
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	type SpendBudget = TestSpendBudget;
+	type BudgetPeriod = ConstU64<10>;
+	type MaxBudgetCharges = ConstU32<5>;
 }
 
 pub struct ExtBuilder {}
@@ -565,6 +587,164 @@ fn spending_in_batch_respects_max_total() {
 	})
 }
 
+#[test]
+fn spend_budget_works() {
+	#[allow(deprecated)]
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(TestSpendBudget::budget_of(&RuntimeOrigin::signed(14)), None);
+		assert_eq!(Treasury::remaining_budget(&15), 50);
+
+		// the budget is shared by all kinds of spends, in the native asset.
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(15), 30, 6));
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(15), Box::new(1), 5, Box::new(6), None));
+		assert_eq!(Treasury::remaining_budget(&15), 10);
+		assert_noop!(
+			Treasury::spend(RuntimeOrigin::signed(15), Box::new(1), 6, Box::new(6), None),
+			Error::<Test>::BudgetExceeded
+		);
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::signed(15),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendLimit::Total(6),
+			),
+			Error::<Test>::BudgetExceeded
+		);
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(15), 10, 6));
+		assert_eq!(Treasury::remaining_budget(&15), 0);
+		assert_noop!(
+			Treasury::spend_local(RuntimeOrigin::signed(15), 1, 6),
+			Error::<Test>::BudgetExceeded
+		);
+		// the budgets are kept per origin, even with the same maximum spend amount.
+		assert_eq!(Treasury::remaining_budget(&16), 20);
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(16), 20, 6));
+		assert_eq!(Treasury::remaining_budget(&16), 0);
+		// origins without a budget are not limited.
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(14), 500, 6));
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(14), 500, 6));
+
+		// the spends are charged for `BudgetPeriod` blocks after their approval.
+		go_to_block(10);
+		assert_eq!(Treasury::remaining_budget(&15), 0);
+		go_to_block(11);
+		assert_eq!(Treasury::remaining_budget(&15), 50);
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(15), 30, 6));
+		go_to_block(15);
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(15), 20, 6));
+		assert_eq!(Treasury::remaining_budget(&15), 0);
+		// the window is rolling: only the spend approved at block 11 is no longer charged.
+		go_to_block(21);
+		assert_eq!(Treasury::remaining_budget(&15), 30);
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(15), 5, 6));
+		assert_eq!(
+			BudgetCharges::<Test>::get(15).into_inner(),
+			vec![
+				BudgetCharge { approved_at: 15, amount: 20 },
+				BudgetCharge { approved_at: 21, amount: 5 }
+			]
+		);
+	});
+}
+
+#[test]
+fn spend_budget_limits_the_number_of_charges() {
+	#[allow(deprecated)]
+	ExtBuilder::default().build().execute_with(|| {
+		for _ in 0..5 {
+			assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(16), 1, 6));
+		}
+		assert_noop!(
+			Treasury::spend_local(RuntimeOrigin::signed(16), 1, 6),
+			Error::<Test>::TooManyBudgetCharges
+		);
+
+		go_to_block(11);
+		assert_ok!(Treasury::spend_local(RuntimeOrigin::signed(16), 1, 6));
+		assert_eq!(BudgetCharges::<Test>::get(16).len(), 1);
+	});
+}
+
+#[test]
+fn voided_spends_are_refunded_to_their_budget() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(15), Box::new(1), 10, Box::new(6), None));
+		assert_eq!(Treasury::remaining_budget(&15), 30);
+		assert!(SpendCharges::<Test>::contains_key(0));
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 0));
+		assert_eq!(Treasury::remaining_budget(&15), 50);
+		assert!(!SpendCharges::<Test>::contains_key(0));
+		assert!(!BudgetCharges::<Test>::contains_key(15));
+
+		// a recurring spend is refunded the share of its remaining instalments.
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(15),
+			Box::new(1),
+			2,
+			Box::new(6),
+			None,
+			1,
+			RecurringSpendLimit::Total(10),
+		));
+		assert_eq!(Treasury::remaining_budget(&15), 30);
+		assert_ok!(Treasury::release_instalment(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Treasury::void_recurring_spend(RuntimeOrigin::root(), 0));
+		assert_eq!(Treasury::remaining_budget(&15), 46);
+		assert!(!RecurringSpendCharges::<Test>::contains_key(0));
+
+		// the released instalments are refunded their share of the recurring spend charge.
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 1));
+		assert_eq!(Treasury::remaining_budget(&15), 50);
+		assert!(!BudgetCharges::<Test>::contains_key(15));
+	});
+}
+
+#[test]
+fn voided_instalments_are_refunded_to_their_budget() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(15),
+			Box::new(1),
+			2,
+			Box::new(6),
+			None,
+			1,
+			RecurringSpendLimit::Total(4),
+		));
+		assert_eq!(Treasury::remaining_budget(&15), 42);
+		assert_ok!(Treasury::release_instalment(RuntimeOrigin::signed(1), 0));
+		assert_eq!(
+			SpendCharges::<Test>::get(0),
+			Some((15, BudgetCharge { approved_at: 1, amount: 4 }))
+		);
+
+		// voiding the instalment refunds its share, and leaves the one of the recurring spend.
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 0));
+		assert_eq!(Treasury::remaining_budget(&15), 46);
+		assert!(!SpendCharges::<Test>::contains_key(0));
+		assert_ok!(Treasury::void_recurring_spend(RuntimeOrigin::root(), 0));
+		assert_eq!(Treasury::remaining_budget(&15), 50);
+		assert!(!BudgetCharges::<Test>::contains_key(15));
+	});
+}
+
+#[test]
+fn processed_spends_are_not_refunded() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(15), Box::new(1), 10, Box::new(6), None));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		set_status(get_payment_id(0).expect("no payment attempt"), PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 0));
+		assert!(!SpendCharges::<Test>::contains_key(0));
+		assert_eq!(Treasury::remaining_budget(&15), 30);
+	});
+}
+
 #[test]
 fn spend_origin_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -813,6 +993,271 @@ fn check_status_works() {
 	});
 }
 
+#[test]
+fn spend_recurring_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			1,
+			Box::new(6),
+			None,
+			3,
+			RecurringSpendLimit::EndAt(7),
+		));
+
+		assert_eq!(RecurringSpendCount::<Test, _>::get(), 1);
+		assert_eq!(
+			RecurringSpends::<Test, _>::get(0).unwrap(),
+			RecurringSpendStatus {
+				asset_kind: 1,
+				amount: 1,
+				beneficiary: 6,
+				next_valid_from: 1,
+				period: 3,
+				limit: RecurringSpendLimit::EndAt(7),
+				released: 0,
+			}
+		);
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 1,
+				beneficiary: 6,
+				valid_from: 1,
+				period: 3,
+				limit: RecurringSpendLimit::EndAt(7),
+			}
+			.into(),
+		);
+		// no spend is created until an instalment is released.
+		assert_eq!(SpendCount::<Test, _>::get(), 0);
+	});
+}
+
+#[test]
+fn spend_recurring_origin_permissioning_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// the total of the instalments is checked against the origin's limit: 3 instalments of 2
+		// assets are 12 native, more than the 10 `11` can spend.
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::signed(11),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendLimit::EndAt(3),
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::signed(11),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendLimit::Total(6),
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::signed(1),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendLimit::Total(2),
+			),
+			BadOrigin
+		);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			2,
+			Box::new(6),
+			None,
+			1,
+			RecurringSpendLimit::EndAt(2),
+		));
+	});
+}
+
+#[test]
+fn spend_recurring_rejects_invalid_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+		let spend_recurring = |amount, valid_from, period, limit| {
+			Treasury::spend_recurring(
+				RuntimeOrigin::signed(14),
+				Box::new(1),
+				amount,
+				Box::new(6),
+				valid_from,
+				period,
+				limit,
+			)
+		};
+
+		assert_noop!(
+			spend_recurring(0, None, 1, RecurringSpendLimit::Total(2)),
+			Error::<Test, _>::InvalidSchedule
+		);
+		assert_noop!(
+			spend_recurring(1, None, 0, RecurringSpendLimit::Total(2)),
+			Error::<Test, _>::InvalidSchedule
+		);
+		assert_noop!(
+			spend_recurring(1, None, 1, RecurringSpendLimit::Total(0)),
+			Error::<Test, _>::InvalidSchedule
+		);
+		assert_noop!(
+			spend_recurring(1, Some(12), 1, RecurringSpendLimit::EndAt(11)),
+			Error::<Test, _>::InvalidSchedule
+		);
+		// the first instalment would already be expired.
+		assert_noop!(
+			spend_recurring(1, Some(5), 1, RecurringSpendLimit::EndAt(20)),
+			Error::<Test, _>::SpendExpired
+		);
+	});
+}
+
+#[test]
+fn release_instalment_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			2,
+			Box::new(6),
+			None,
+			3,
+			RecurringSpendLimit::Total(5),
+		));
+
+		// the first instalment is released as a spend.
+		assert_ok!(Treasury::release_instalment(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(
+			Event::<Test, _>::InstalmentReleased { index: 0, spend_index: 0, amount: 2 }.into(),
+		);
+		assert_eq!(
+			Spends::<Test, _>::get(0).unwrap(),
+			SpendStatus {
+				asset_kind: 1,
+				amount: 2,
+				beneficiary: 6,
+				valid_from: 1,
+				expire_at: 6,
+				status: PaymentState::Pending,
+			}
+		);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 2);
+
+		// the next one is only valid from block 4.
+		assert_noop!(
+			Treasury::release_instalment(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::EarlyPayout
+		);
+		System::set_block_number(4);
+		assert_ok!(Treasury::release_instalment(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Spends::<Test, _>::get(1).unwrap().valid_from, 4);
+
+		// the last instalment is reduced to the amount left, and completes the spend.
+		System::set_block_number(8);
+		assert_ok!(Treasury::release_instalment(RuntimeOrigin::signed(1), 0));
+		System::assert_has_event(
+			Event::<Test, _>::InstalmentReleased { index: 0, spend_index: 2, amount: 1 }.into(),
+		);
+		System::assert_last_event(Event::<Test, _>::RecurringSpendCompleted { index: 0 }.into());
+		assert_eq!(Spends::<Test, _>::get(2).unwrap().valid_from, 7);
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+		assert_noop!(
+			Treasury::release_instalment(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 2));
+		assert_eq!(paid(6, 1), 5);
+	});
+}
+
+#[test]
+fn release_instalment_skips_expired_instalments() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(<Test as Config>::PayoutPeriod::get(), 5);
+		System::set_block_number(1);
+		// instalments at blocks 1, 3, 5, 7, 9 and 11.
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			1,
+			Box::new(6),
+			None,
+			2,
+			RecurringSpendLimit::EndAt(11),
+		));
+
+		// the instalments of blocks 1 and 3 expired.
+		System::set_block_number(8);
+		assert_ok!(Treasury::release_instalment(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Spends::<Test, _>::get(0).unwrap().valid_from, 5);
+		assert_eq!(RecurringSpends::<Test, _>::get(0).unwrap().next_valid_from, 7);
+
+		// all the remaining instalments expired.
+		System::set_block_number(30);
+		assert_ok!(Treasury::release_instalment(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::<Test, _>::RecurringSpendCompleted { index: 0 }.into());
+		assert_eq!(SpendCount::<Test, _>::get(), 1);
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+	});
+}
+
+#[test]
+fn void_recurring_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			1,
+			Box::new(6),
+			None,
+			1,
+			RecurringSpendLimit::Total(5),
+		));
+		assert_ok!(Treasury::release_instalment(RuntimeOrigin::signed(1), 0));
+
+		assert_noop!(Treasury::void_recurring_spend(RuntimeOrigin::signed(11), 0), BadOrigin);
+		assert_noop!(
+			Treasury::void_recurring_spend(RuntimeOrigin::root(), 1),
+			Error::<Test, _>::InvalidIndex
+		);
+		assert_ok!(Treasury::void_recurring_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::<Test, _>::RecurringSpendVoided { index: 0 }.into());
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+
+		// the instalment that was already released is not affected.
+		assert!(Spends::<Test, _>::get(0).is_some());
+		System::set_block_number(2);
+		assert_noop!(
+			Treasury::release_instalment(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
+
 #[test]
 fn try_state_proposals_invariant_1_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -976,6 +1421,22 @@ fn try_state_spends_invariant_3_works() {
 	});
 }
 
+#[test]
+fn try_state_spends_invariant_4_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::pallet_prelude::DispatchError::Other;
+		// Approve a spend charged to a budget
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(15), Box::new(1), 1, Box::new(6), None));
+		assert!(SpendCharges::<Test>::contains_key(0));
+		// Check invariant 4 holds
+		assert_ok!(Treasury::do_try_state());
+		// Break invariant 4 by removing the spend without its charge
+		Spends::<Test>::remove(0);
+		// Invariant 4 should be violated
+		assert_eq!(Treasury::do_try_state(), Err(Other("Charge of a spend that does not exist.")));
+	});
+}
+
 #[test]
 fn multiple_spend_periods_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1010,3 +1471,28 @@ fn multiple_spend_periods_work() {
 		assert_eq!(LastSpendPeriod::<Test>::get(), Some(8));
 	});
 }
+
+#[test]
+fn try_state_recurring_spends_invariant_1_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::pallet_prelude::DispatchError::Other;
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(10),
+			Box::new(1),
+			1,
+			Box::new(6),
+			None,
+			1,
+			RecurringSpendLimit::Total(2),
+		));
+		assert_eq!(RecurringSpends::<Test>::iter().count(), 1);
+		assert_eq!(RecurringSpendCount::<Test>::get(), 1);
+		// Break invariant 1 by decreasing `RecurringSpendCount`
+		RecurringSpendCount::<Test>::put(0);
+		// Invariant 1 should be violated
+		assert_eq!(
+			Treasury::do_try_state(),
+			Err(Other("Actual number of recurring spends exceeds `RecurringSpendCount`."))
+		);
+	});
+}
@@ -58,6 +58,9 @@ pub trait WeightInfo {
 	fn payout() -> Weight;
 	fn check_status() -> Weight;
 	fn void_spend() -> Weight;
+	fn spend_recurring() -> Weight;
+	fn release_instalment() -> Weight;
+	fn void_recurring_spend() -> Weight;
 }
 
 /// Weights for `pallet_treasury` using the Substrate node and recommended hardware.
@@ -69,14 +72,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Treasury::Approvals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::Proposals` (r:0 w:1)
 	/// Proof: `Treasury::Proposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::BudgetCharges` (r:1 w:1)
+	/// Proof: `Treasury::BudgetCharges` (`max_values`: None, `max_size`: Some(2018), added: 4493, mode: `MaxEncodedLen`)
 	fn spend_local() -> Weight {
+		// Not benchmarked yet: measured before the spend was charged to the budget of its origin.
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
 		//  Estimated: `1887`
 		// Minimum execution time: 11_807_000 picoseconds.
 		Weight::from_parts(12_313_000, 1887)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			// estimated cost of the accesses to the spend budget storage items.
+			.saturating_add(Weight::from_parts(5_000_000, 4493))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Treasury::Approvals` (r:1 w:1)
 	/// Proof: `Treasury::Approvals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
@@ -111,14 +119,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::Spends` (r:0 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::BudgetCharges` (r:1 w:1)
+	/// Proof: `Treasury::BudgetCharges` (`max_values`: None, `max_size`: Some(2018), added: 4493, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCharges` (r:0 w:1)
+	/// Proof: `Treasury::SpendCharges` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn spend() -> Weight {
+		// Not benchmarked yet: measured before the spend was charged to the budget of its origin,
+		// and its charge recorded.
 		// Proof Size summary in bytes:
 		//  Measured:  `141`
 		//  Estimated: `3502`
 		// Minimum execution time: 16_082_000 picoseconds.
 		Weight::from_parts(16_542_000, 3502)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			// estimated cost of the accesses to the spend budget storage items.
+			.saturating_add(Weight::from_parts(10_000_000, 4493))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
@@ -139,25 +155,52 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCharges` (r:0 w:1)
+	/// Proof: `Treasury::SpendCharges` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn check_status() -> Weight {
+		// Not benchmarked yet: measured before the charge of a paid or failed spend was dropped.
 		// Proof Size summary in bytes:
 		//  Measured:  `199`
 		//  Estimated: `3539`
 		// Minimum execution time: 13_379_000 picoseconds.
 		Weight::from_parts(13_751_000, 3539)
+			// estimated cost of the accesses to the spend budget storage items.
+			.saturating_add(Weight::from_parts(5_000_000, 0))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::BudgetCharges` (r:1 w:1)
+	/// Proof: `Treasury::BudgetCharges` (`max_values`: None, `max_size`: Some(2018), added: 4493, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCharges` (r:1 w:1)
+	/// Proof: `Treasury::SpendCharges` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn void_spend() -> Weight {
+		// Not benchmarked yet: measured before the charge of the voided spend was refunded to the
+		// budget of its origin.
 		// Proof Size summary in bytes:
 		//  Measured:  `199`
 		//  Estimated: `3539`
 		// Minimum execution time: 12_014_000 picoseconds.
 		Weight::from_parts(12_423_000, 3539)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			// estimated cost of the accesses to the spend budget storage items.
+			.saturating_add(Weight::from_parts(10_000_000, 7000))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn spend_recurring() -> Weight {
+		// Not benchmarked yet: stores the recurring spend once, with the same reads and writes as a
+		// single `spend`.
+		Self::spend()
+	}
+	fn release_instalment() -> Weight {
+		// Not benchmarked yet: the instalment and its charge are created as by `spend`, and the
+		// recurring spend and its charge are read, then updated or removed as by `void_spend`.
+		Self::spend().saturating_add(Self::void_spend())
+	}
+	fn void_recurring_spend() -> Weight {
+		// Not benchmarked yet: one read and one removal of the recurring spend, like `void_spend`.
+		Self::void_spend()
 	}
 }
 
@@ -169,14 +212,19 @@ impl WeightInfo for () {
 	/// Proof: `Treasury::Approvals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::Proposals` (r:0 w:1)
 	/// Proof: `Treasury::Proposals` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::BudgetCharges` (r:1 w:1)
+	/// Proof: `Treasury::BudgetCharges` (`max_values`: None, `max_size`: Some(2018), added: 4493, mode: `MaxEncodedLen`)
 	fn spend_local() -> Weight {
+		// Not benchmarked yet: measured before the spend was charged to the budget of its origin.
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
 		//  Estimated: `1887`
 		// Minimum execution time: 11_807_000 picoseconds.
 		Weight::from_parts(12_313_000, 1887)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			// estimated cost of the accesses to the spend budget storage items.
+			.saturating_add(Weight::from_parts(5_000_000, 4493))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Treasury::Approvals` (r:1 w:1)
 	/// Proof: `Treasury::Approvals` (`max_values`: Some(1), `max_size`: Some(402), added: 897, mode: `MaxEncodedLen`)
@@ -211,14 +259,22 @@ impl WeightInfo for () {
 	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Treasury::Spends` (r:0 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::BudgetCharges` (r:1 w:1)
+	/// Proof: `Treasury::BudgetCharges` (`max_values`: None, `max_size`: Some(2018), added: 4493, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCharges` (r:0 w:1)
+	/// Proof: `Treasury::SpendCharges` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn spend() -> Weight {
+		// Not benchmarked yet: measured before the spend was charged to the budget of its origin,
+		// and its charge recorded.
 		// Proof Size summary in bytes:
 		//  Measured:  `141`
 		//  Estimated: `3502`
 		// Minimum execution time: 16_082_000 picoseconds.
 		Weight::from_parts(16_542_000, 3502)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			// estimated cost of the accesses to the spend budget storage items.
+			.saturating_add(Weight::from_parts(10_000_000, 4493))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
@@ -239,24 +295,51 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCharges` (r:0 w:1)
+	/// Proof: `Treasury::SpendCharges` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn check_status() -> Weight {
+		// Not benchmarked yet: measured before the charge of a paid or failed spend was dropped.
 		// Proof Size summary in bytes:
 		//  Measured:  `199`
 		//  Estimated: `3539`
 		// Minimum execution time: 13_379_000 picoseconds.
 		Weight::from_parts(13_751_000, 3539)
+			// estimated cost of the accesses to the spend budget storage items.
+			.saturating_add(Weight::from_parts(5_000_000, 0))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::BudgetCharges` (r:1 w:1)
+	/// Proof: `Treasury::BudgetCharges` (`max_values`: None, `max_size`: Some(2018), added: 4493, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCharges` (r:1 w:1)
+	/// Proof: `Treasury::SpendCharges` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
 	fn void_spend() -> Weight {
+		// Not benchmarked yet: measured before the charge of the voided spend was refunded to the
+		// budget of its origin.
 		// Proof Size summary in bytes:
 		//  Measured:  `199`
 		//  Estimated: `3539`
 		// Minimum execution time: 12_014_000 picoseconds.
 		Weight::from_parts(12_423_000, 3539)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			// estimated cost of the accesses to the spend budget storage items.
+			.saturating_add(Weight::from_parts(10_000_000, 7000))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn spend_recurring() -> Weight {
+		// Not benchmarked yet: stores the recurring spend once, with the same reads and writes as a
+		// single `spend`.
+		Self::spend()
+	}
+	fn release_instalment() -> Weight {
+		// Not benchmarked yet: the instalment and its charge are created as by `spend`, and the
+		// recurring spend and its charge are read, then updated or removed as by `void_spend`.
+		Self::spend().saturating_add(Self::void_spend())
+	}
+	fn void_recurring_spend() -> Weight {
+		// Not benchmarked yet: one read and one removal of the recurring spend, like `void_spend`.
+		Self::void_spend()
 	}
 }
@@ -106,6 +106,8 @@ impl pallet_proxy::Config for Test {
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type CustomProxyTypeOrigin = frame_system::EnsureRoot<u64>;
+	type MaxCustomProxyCalls = ConstU32<32>;
 }
 
 parameter_types! {
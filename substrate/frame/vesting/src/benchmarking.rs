@@ -60,7 +60,7 @@ fn add_vesting_schedules<T: Config>(
 		total_locked += locked;
 
 		let schedule = VestingInfo::new(locked, per_block, starting_block.into());
-		assert_ok!(Pallet::<T>::do_vested_transfer(&source, target, schedule));
+		assert_ok!(Pallet::<T>::do_vested_transfer(&source, target, schedule.into()));
 
 		// Top up to guarantee we can always transfer another schedule.
 		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
@@ -69,6 +69,20 @@ fn add_vesting_schedules<T: Config>(
 	Ok(total_locked)
 }
 
+// A curve of `p` points, unlocking `locked` in equal parts every 10 blocks from block 1.
+fn vesting_curve<T: Config>(locked: BalanceOf<T>, p: u32) -> VestingCurveOf<T> {
+	let step = locked / p.into();
+	let points = (1..=p)
+		.map(|i| VestingPoint {
+			at: (1 + 10 * i).into(),
+			locked: if i == p { Zero::zero() } else { locked - step * i.into() },
+			unlock: Unlock::Linear,
+		})
+		.collect::<Vec<_>>();
+
+	VestingCurve::new(locked, 1_u32.into(), points.try_into().expect("at most `MAX_CURVE_POINTS`"))
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -280,6 +294,87 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn vested_transfer_curve(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<0, { T::MAX_VESTING_SCHEDULES - 1 }>,
+		p: Linear<1, { T::MAX_CURVE_POINTS }>,
+	) -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		// Give target existing locks.
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		// Add one less than max vesting schedules.
+		let orig_balance = T::Currency::free_balance(&target);
+		let mut expected_balance = add_vesting_schedules::<T>(&target, s)?;
+
+		let transfer_amount = T::MinVestedTransfer::get();
+		expected_balance += transfer_amount;
+
+		let vesting_curve = vesting_curve::<T>(transfer_amount, p);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), target_lookup, vesting_curve);
+
+		assert_eq!(
+			orig_balance + expected_balance,
+			T::Currency::free_balance(&target),
+			"Transfer didn't happen",
+		);
+		assert_eq!(
+			Pallet::<T>::vesting_balance(&target),
+			Some(expected_balance),
+			"Lock not correctly updated",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_vested_transfer_curve(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<0, { T::MAX_VESTING_SCHEDULES - 1 }>,
+		p: Linear<1, { T::MAX_CURVE_POINTS }>,
+	) -> Result<(), BenchmarkError> {
+		let source = account::<T::AccountId>("source", 0, SEED);
+		let source_lookup = T::Lookup::unlookup(source.clone());
+		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		// Give target existing locks.
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		// Add one less than max vesting schedules.
+		let orig_balance = T::Currency::free_balance(&target);
+		let mut expected_balance = add_vesting_schedules::<T>(&target, s)?;
+
+		let transfer_amount = T::MinVestedTransfer::get();
+		expected_balance += transfer_amount;
+
+		let vesting_curve = vesting_curve::<T>(transfer_amount, p);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, source_lookup, target_lookup, vesting_curve);
+
+		assert_eq!(
+			orig_balance + expected_balance,
+			T::Currency::free_balance(&target),
+			"Transfer didn't happen",
+		);
+		assert_eq!(
+			Pallet::<T>::vesting_balance(&target),
+			Some(expected_balance),
+			"Lock not correctly updated",
+		);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn not_unlocking_merge_schedules(
 		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
@@ -314,7 +409,10 @@ mod benchmarks {
 			1_u32.into(),
 		);
 		let expected_index = (s - 2) as usize;
-		assert_eq!(Vesting::<T>::get(&caller).unwrap()[expected_index], expected_schedule);
+		assert_eq!(
+			Vesting::<T>::get(&caller).unwrap()[expected_index],
+			Schedule::Linear(expected_schedule)
+		);
 		assert_eq!(
 			Pallet::<T>::vesting_balance(&caller),
 			Some(expected_balance),
@@ -380,7 +478,7 @@ mod benchmarks {
 		let expected_index = (s - 2) as usize;
 		assert_eq!(
 			Vesting::<T>::get(&caller).unwrap()[expected_index],
-			expected_schedule,
+			Schedule::Linear(expected_schedule),
 			"New schedule is properly created and placed"
 		);
 		assert_eq!(
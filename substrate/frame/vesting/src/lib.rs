@@ -22,10 +22,10 @@
 //!
 //! ## Overview
 //!
-//! A simple pallet providing a means of placing a linear curve on an account's locked balance. This
-//! pallet ensures that there is a lock in place preventing the balance to drop below the *unvested*
-//! amount for any reason other than the ones specified in `UnvestedFundsAllowedWithdrawReasons`
-//! configuration value.
+//! A simple pallet providing a means of placing a linear or piecewise curve on an account's locked
+//! balance. This pallet ensures that there is a lock in place preventing the balance to drop below
+//! the *unvested* amount for any reason other than the ones specified in
+//! `UnvestedFundsAllowedWithdrawReasons` configuration value.
 //!
 //! As the amount vested increases over time, the amount unvested reduces. However, locks remain in
 //! place and explicit action is needed on behalf of the user to ensure that the amount locked is
@@ -42,6 +42,7 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `vested_transfer_curve` - Transfer funds vesting along a curve, e.g. with a cliff or in steps.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -90,6 +91,8 @@ type BalanceOf<T> =
 type MaxLocksOf<T> =
 	<<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type ScheduleOf<T> = Schedule<BalanceOf<T>, BlockNumberFor<T>, MaxCurvePointsGet<T>>;
+type VestingCurveOf<T> = VestingCurve<BalanceOf<T>, BlockNumberFor<T>, MaxCurvePointsGet<T>>;
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
@@ -99,6 +102,7 @@ const VESTING_ID: LockIdentifier = *b"vesting ";
 enum Releases {
 	V0,
 	V1,
+	V2,
 }
 
 impl Default for Releases {
@@ -131,8 +135,8 @@ impl VestingAction {
 	/// Pick the schedules that this action dictates should continue vesting undisturbed.
 	fn pick_schedules<T: Config>(
 		&self,
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
-	) -> impl Iterator<Item = VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> + '_ {
+		schedules: Vec<ScheduleOf<T>>,
+	) -> impl Iterator<Item = ScheduleOf<T>> + '_ {
 		schedules.into_iter().enumerate().filter_map(move |(index, schedule)| {
 			if self.should_remove(index) {
 				None
@@ -151,6 +155,14 @@ impl<T: Config> Get<u32> for MaxVestingSchedulesGet<T> {
 	}
 }
 
+// Wrapper for `T::MAX_CURVE_POINTS` to satisfy `trait Get`.
+pub struct MaxCurvePointsGet<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxCurvePointsGet<T> {
+	fn get() -> u32 {
+		T::MAX_CURVE_POINTS
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -184,6 +196,9 @@ pub mod pallet {
 
 		/// Maximum number of vesting schedules an account may have at a given moment.
 		const MAX_VESTING_SCHEDULES: u32;
+
+		/// Maximum number of points of a vesting curve.
+		const MAX_CURVE_POINTS: u32;
 	}
 
 	#[pallet::extra_constants]
@@ -192,12 +207,18 @@ pub mod pallet {
 		fn max_vesting_schedules() -> u32 {
 			T::MAX_VESTING_SCHEDULES
 		}
+
+		#[pallet::constant_name(MaxCurvePoints)]
+		fn max_curve_points() -> u32 {
+			T::MAX_CURVE_POINTS
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn integrity_test() {
 			assert!(T::MAX_VESTING_SCHEDULES > 0, "`MaxVestingSchedules` must ge greater than 0");
+			assert!(T::MAX_CURVE_POINTS > 0, "`MaxCurvePoints` must be greater than 0");
 		}
 	}
 
@@ -207,7 +228,7 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
+		BoundedVec<ScheduleOf<T>, MaxVestingSchedulesGet<T>>,
 	>;
 
 	/// Storage version of the pallet.
@@ -231,7 +252,7 @@ pub mod pallet {
 			use sp_runtime::traits::Saturating;
 
 			// Genesis uses the latest storage version.
-			StorageVersion::<T>::put(Releases::V1);
+			StorageVersion::<T>::put(Releases::V2);
 
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
@@ -250,7 +271,7 @@ pub mod pallet {
 					panic!("Invalid VestingInfo params at genesis")
 				};
 
-				Vesting::<T>::try_append(who, vesting_info)
+				Vesting::<T>::try_append(who, Schedule::Linear(vesting_info))
 					.expect("Too many vesting schedules at genesis.");
 
 				let reasons =
@@ -285,6 +306,8 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// Merging the schedules would create a curve with more than `MaxCurvePoints` points.
+		TooManyCurvePoints,
 	}
 
 	#[pallet::call]
@@ -352,7 +375,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let transactor = ensure_signed(origin)?;
 			let target = T::Lookup::lookup(target)?;
-			Self::do_vested_transfer(&transactor, &target, schedule)
+			Self::do_vested_transfer(&transactor, &target, Schedule::Linear(schedule))
 		}
 
 		/// Force a vested transfer.
@@ -382,7 +405,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 			let target = T::Lookup::lookup(target)?;
 			let source = T::Lookup::lookup(source)?;
-			Self::do_vested_transfer(&source, &target, schedule)
+			Self::do_vested_transfer(&source, &target, Schedule::Linear(schedule))
 		}
 
 		/// Merge two vesting schedules together, creating a new vesting schedule that unlocks over
@@ -396,12 +419,16 @@ pub mod pallet {
 		/// NOTE: If both schedules have ended by the current block, no new schedule will be created
 		/// and both will be removed.
 		///
-		/// Merged schedule attributes:
+		/// Merged schedule attributes, if both schedules are linear:
 		/// - `starting_block`: `MAX(schedule1.starting_block, scheduled2.starting_block,
 		///   current_block)`.
 		/// - `ending_block`: `MAX(schedule1.ending_block, schedule2.ending_block)`.
 		/// - `locked`: `schedule1.locked_at(current_block) + schedule2.locked_at(current_block)`.
 		///
+		/// If any of the schedules is a curve, the merged schedule is a curve starting at the
+		/// current block, which locks at any block the sum of what both schedules lock. Its points
+		/// are the blocks at which either schedule changes pace.
+		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `schedule1_index`: index of the first schedule to merge.
@@ -464,6 +491,67 @@ pub mod pallet {
 			))
 			.into())
 		}
+
+		/// Create a vested transfer, with funds vesting along a curve.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account receiving the vested funds.
+		/// - `schedule`: The vesting curve attached to the transfer.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		///
+		/// ## Complexity
+		/// - `O(P)` where `P` is the number of points of the curve.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::vested_transfer_curve(
+			MaxLocksOf::<T>::get(),
+			T::MAX_VESTING_SCHEDULES,
+			schedule.points().len() as u32,
+		))]
+		pub fn vested_transfer_curve(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			schedule: VestingCurveOf<T>,
+		) -> DispatchResult {
+			let transactor = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_vested_transfer(&transactor, &target, Schedule::Curve(schedule))
+		}
+
+		/// Force a vested transfer, with funds vesting along a curve.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `source`: The account whose funds should be transferred.
+		/// - `target`: The account that should be transferred the vested funds.
+		/// - `schedule`: The vesting curve attached to the transfer.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		///
+		/// ## Complexity
+		/// - `O(P)` where `P` is the number of points of the curve.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::force_vested_transfer_curve(
+			MaxLocksOf::<T>::get(),
+			T::MAX_VESTING_SCHEDULES,
+			schedule.points().len() as u32,
+		))]
+		pub fn force_vested_transfer_curve(
+			origin: OriginFor<T>,
+			source: AccountIdLookupOf<T>,
+			target: AccountIdLookupOf<T>,
+			schedule: VestingCurveOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let source = T::Lookup::lookup(source)?;
+			Self::do_vested_transfer(&source, &target, Schedule::Curve(schedule))
+		}
 	}
 }
 
@@ -471,11 +559,100 @@ impl<T: Config> Pallet<T> {
 	// Public function for accessing vesting storage
 	pub fn vesting(
 		account: T::AccountId,
-	) -> Option<BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>>
-	{
+	) -> Option<BoundedVec<ScheduleOf<T>, MaxVestingSchedulesGet<T>>> {
 		Vesting::<T>::get(account)
 	}
 
+	// Create a new schedule, based off of two other schedules.
+	// NOTE: We assume both schedules have had funds unlocked up through the current block.
+	fn merge_schedules_info(
+		now: BlockNumberFor<T>,
+		schedule1: ScheduleOf<T>,
+		schedule2: ScheduleOf<T>,
+	) -> Result<Option<ScheduleOf<T>>, DispatchError> {
+		let now_as_balance = T::BlockNumberToBalance::convert(now);
+
+		// Check if one or both schedules have ended.
+		match (
+			schedule1.ending_block_as_balance::<T::BlockNumberToBalance>() <= now_as_balance,
+			schedule2.ending_block_as_balance::<T::BlockNumberToBalance>() <= now_as_balance,
+		) {
+			// If both schedules have ended, we don't merge and exit early.
+			(true, true) => return Ok(None),
+			// If one schedule has ended, we treat the one that has not ended as the new
+			// merged schedule.
+			(true, false) => return Ok(Some(schedule2)),
+			(false, true) => return Ok(Some(schedule1)),
+			// If neither schedule has ended don't exit early.
+			_ => {},
+		}
+
+		match (schedule1, schedule2) {
+			(Schedule::Linear(schedule1), Schedule::Linear(schedule2)) =>
+				Ok(Self::merge_vesting_info(now, schedule1, schedule2).map(Schedule::Linear)),
+			(schedule1, schedule2) => Self::merge_into_curve(now, &schedule1, &schedule2).map(Some),
+		}
+	}
+
+	// Create a new `VestingCurve` locking the sum of what two schedules lock, from `now` on.
+	// NOTE: We assume at least one of the schedules has not ended by `now`.
+	fn merge_into_curve(
+		now: BlockNumberFor<T>,
+		schedule1: &ScheduleOf<T>,
+		schedule2: &ScheduleOf<T>,
+	) -> Result<ScheduleOf<T>, DispatchError> {
+		let locked_at = |n| {
+			schedule1
+				.locked_at::<T::BlockNumberToBalance>(n)
+				.saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(n))
+		};
+
+		// Between two consecutive breakpoints, each schedule is either constant or linear, so the
+		// merged curve is exact, except before a point unlocking at once: there, the other
+		// schedule may still be unlocking linearly, which is captured by an extra point the block
+		// before.
+		let mut breakpoints = schedule1
+			.breakpoints::<T::BlockNumberToBalance>()
+			.into_iter()
+			.chain(schedule2.breakpoints::<T::BlockNumberToBalance>())
+			.filter(|at| *at > now)
+			.collect::<Vec<_>>();
+		breakpoints.sort();
+		breakpoints.dedup();
+
+		let locked = locked_at(now);
+		let mut points = Vec::with_capacity(breakpoints.len());
+		let mut previous = (now, locked);
+		for at in breakpoints {
+			let at_once = schedule1.unlocks_at_once_at(at) || schedule2.unlocks_at_once_at(at);
+			if at_once {
+				let before = at.saturating_sub(One::one());
+				let locked_before = locked_at(before);
+				if before > previous.0 && locked_before < previous.1 {
+					points.push(VestingPoint {
+						at: before,
+						locked: locked_before,
+						unlock: Unlock::Linear,
+					});
+				}
+			}
+
+			let point = VestingPoint {
+				at,
+				locked: locked_at(at),
+				unlock: if at_once { Unlock::AtOnce } else { Unlock::Linear },
+			};
+			previous = (point.at, point.locked);
+			points.push(point);
+		}
+
+		let points = points.try_into().map_err(|_| Error::<T>::TooManyCurvePoints)?;
+		let schedule = Schedule::Curve(VestingCurve::new(locked, now, points));
+		debug_assert!(schedule.is_valid(), "merge_into_curve schedule validation check failed");
+
+		Ok(schedule)
+	}
+
 	// Create a new `VestingInfo`, based off of two other `VestingInfo`s.
 	// NOTE: We assume both schedules have had funds unlocked up through the current block.
 	fn merge_vesting_info(
@@ -529,7 +706,7 @@ impl<T: Config> Pallet<T> {
 	fn do_vested_transfer(
 		source: &T::AccountId,
 		target: &T::AccountId,
-		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		schedule: ScheduleOf<T>,
 	) -> DispatchResult {
 		// Validate user inputs.
 		ensure!(schedule.locked() >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
@@ -538,29 +715,62 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// Check we can add to this account prior to any storage writes.
-		Self::can_add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		)?;
+		Self::can_add_schedule(target, &schedule)?;
 
 		T::Currency::transfer(source, target, schedule.locked(), ExistenceRequirement::AllowDeath)?;
 
 		// We can't let this fail because the currency transfer has already happened.
 		// Must be successful as it has been checked before.
 		// Better to return error on failure anyway.
-		let res = Self::add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		);
+		let res = Self::add_schedule(target, schedule);
 		debug_assert!(res.is_ok(), "Failed to add a schedule when we had to succeed.");
 
 		Ok(())
 	}
 
+	/// Adds a vesting schedule to a given account, unlocking any vested funds through the current
+	/// block.
+	///
+	/// Is a no-op if the amount to be vested is zero.
+	fn add_schedule(who: &T::AccountId, schedule: ScheduleOf<T>) -> DispatchResult {
+		if schedule.locked().is_zero() {
+			return Ok(())
+		}
+
+		if !schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into())
+		};
+
+		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
+
+		// NOTE: we must push the new schedule so that `exec_action`
+		// will give the correct new locked amount.
+		ensure!(schedules.try_push(schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_lock(who, locked_now);
+
+		Ok(())
+	}
+
+	/// Ensure we can call `add_schedule` without error. This should always be called prior to
+	/// `add_schedule`.
+	fn can_add_schedule(who: &T::AccountId, schedule: &ScheduleOf<T>) -> DispatchResult {
+		if !schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into())
+		}
+
+		ensure!(
+			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
+
+		Ok(())
+	}
+
 	/// Iterate through the schedules to track the current locked amount and
 	/// filter out completed and specified schedules.
 	///
@@ -572,9 +782,9 @@ impl<T: Config> Pallet<T> {
 	///
 	/// NOTE: the amount locked does not include any schedules that are filtered out via `action`.
 	fn report_schedule_updates(
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<ScheduleOf<T>>,
 		action: VestingAction,
-	) -> (Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, BalanceOf<T>) {
+	) -> (Vec<ScheduleOf<T>>, BalanceOf<T>) {
 		let now = T::BlockNumberProvider::current_block_number();
 
 		let mut total_locked_now: BalanceOf<T> = Zero::zero();
@@ -611,12 +821,10 @@ impl<T: Config> Pallet<T> {
 	/// Write an accounts updated vesting schedules to storage.
 	fn write_vesting(
 		who: &T::AccountId,
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<ScheduleOf<T>>,
 	) -> Result<(), DispatchError> {
-		let schedules: BoundedVec<
-			VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-			MaxVestingSchedulesGet<T>,
-		> = schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
+		let schedules: BoundedVec<ScheduleOf<T>, MaxVestingSchedulesGet<T>> =
+			schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
 
 		if schedules.len() == 0 {
 			Vesting::<T>::remove(&who);
@@ -643,15 +851,17 @@ impl<T: Config> Pallet<T> {
 	/// Execute a `VestingAction` against the given `schedules`. Returns the updated schedules
 	/// and locked amount.
 	fn exec_action(
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<ScheduleOf<T>>,
 		action: VestingAction,
-	) -> Result<(Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, BalanceOf<T>), DispatchError> {
+	) -> Result<(Vec<ScheduleOf<T>>, BalanceOf<T>), DispatchError> {
 		let (schedules, locked_now) = match action {
 			VestingAction::Merge { index1: idx1, index2: idx2 } => {
 				// The schedule index is based off of the schedule ordering prior to filtering out
 				// any schedules that may be ending at this block.
-				let schedule1 = *schedules.get(idx1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
-				let schedule2 = *schedules.get(idx2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				let schedule1 =
+					schedules.get(idx1).cloned().ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				let schedule2 =
+					schedules.get(idx2).cloned().ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
 
 				// The length of `schedules` decreases by 2 here since we filter out 2 schedules.
 				// Thus we know below that we can push the new merged schedule without error
@@ -660,13 +870,13 @@ impl<T: Config> Pallet<T> {
 					Self::report_schedule_updates(schedules.to_vec(), action);
 
 				let now = T::BlockNumberProvider::current_block_number();
-				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2) {
+				if let Some(new_schedule) = Self::merge_schedules_info(now, schedule1, schedule2)? {
 					// Merging created a new schedule so we:
-					// 1) need to add it to the accounts vesting schedule collection,
-					schedules.push(new_schedule);
 					// (we use `locked_at` in case this is a schedule that started in the past)
 					let new_schedule_locked =
 						new_schedule.locked_at::<T::BlockNumberToBalance>(now);
+					// 1) need to add it to the accounts vesting schedule collection,
+					schedules.push(new_schedule);
 					// and 2) update the locked amount to reflect the schedule we just added.
 					locked_now = locked_now.saturating_add(new_schedule_locked);
 				} // In the None case there was no new schedule to account for.
@@ -723,29 +933,10 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		if locked.is_zero() {
-			return Ok(())
-		}
-
-		let vesting_schedule = VestingInfo::new(locked, per_block, starting_block);
-		// Check for `per_block` or `locked` of 0.
-		if !vesting_schedule.is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into())
-		};
-
-		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
-
-		// NOTE: we must push the new schedule so that `exec_action`
-		// will give the correct new locked amount.
-		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
-
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
-
-		Self::write_vesting(who, schedules)?;
-		Self::write_lock(who, locked_now);
-
-		Ok(())
+		Self::add_schedule(
+			who,
+			Schedule::Linear(VestingInfo::new(locked, per_block, starting_block)),
+		)
 	}
 
 	/// Ensure we can call `add_vesting_schedule` without error. This should always
@@ -757,16 +948,10 @@ where
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
 		// Check for `per_block` or `locked` of 0.
-		if !VestingInfo::new(locked, per_block, starting_block).is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into())
-		}
-
-		ensure!(
-			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
-			Error::<T>::AtMaxVestingSchedules
-		);
-
-		Ok(())
+		Self::can_add_schedule(
+			who,
+			&Schedule::Linear(VestingInfo::new(locked, per_block, starting_block)),
+		)
 	}
 
 	/// Remove a vesting schedule for a given account.
@@ -799,7 +984,7 @@ where
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
 		use frame_support::storage::{with_transaction, TransactionOutcome};
-		let schedule = VestingInfo::new(locked, per_block, starting_block);
+		let schedule = Schedule::Linear(VestingInfo::new(locked, per_block, starting_block));
 		with_transaction(|| -> TransactionOutcome<DispatchResult> {
 			let result = Self::do_vested_transfer(source, target, schedule);
 
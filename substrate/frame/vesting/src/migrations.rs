@@ -38,18 +38,17 @@ pub mod v1 {
 
 	/// Migrate from single schedule to multi schedule storage.
 	/// WARNING: This migration will delete schedules if `MaxVestingSchedules < 1`.
+	///
+	/// NOTE: The schedules are written as [`Schedule::Linear`], the layout introduced by
+	/// [`super::v2`], which must not be run afterwards.
 	pub fn migrate<T: Config>() -> Weight {
 		let mut reads_writes = 0;
 
 		Vesting::<T>::translate::<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, _>(
 			|_key, vesting_info| {
 				reads_writes += 1;
-				let v: Option<
-					BoundedVec<
-						VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-						MaxVestingSchedulesGet<T>,
-					>,
-				> = vec![vesting_info].try_into().ok();
+				let v: Option<BoundedVec<ScheduleOf<T>, MaxVestingSchedulesGet<T>>> =
+					vec![Schedule::Linear(vesting_info)].try_into().ok();
 
 				if v.is_none() {
 					log::warn!(
@@ -94,3 +93,117 @@ pub mod v1 {
 		Ok(())
 	}
 }
+
+// Migration from linear schedules to schedules which may follow a curve.
+pub mod v2 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(StorageVersion::<T>::get() == Releases::V1, "Storage version too high.");
+
+		log::debug!(
+			target: "runtime::vesting",
+			"migration: Vesting storage version v2 PRE migration checks successful!"
+		);
+
+		Ok(())
+	}
+
+	/// Migrate from linear schedules to [`Schedule`]s, wrapping each existing schedule in
+	/// [`Schedule::Linear`].
+	///
+	/// A [`Schedule`] may encode to more bytes than a linear schedule, so runtimes may lower
+	/// `MAX_VESTING_SCHEDULES` along with this migration. The schedules of an account which don't
+	/// fit anymore are merged into its last one, like `merge_schedules` does.
+	pub fn migrate<T: Config>() -> Weight {
+		let mut reads_writes = 0;
+		let now = T::BlockNumberProvider::current_block_number();
+
+		// The old bound is not known anymore, but a `BoundedVec` is encoded as a `Vec`.
+		Vesting::<T>::translate::<Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, _>(
+			|_key, schedules| {
+				reads_writes += 1;
+				let mut schedules =
+					schedules.into_iter().map(Schedule::Linear).collect::<Vec<ScheduleOf<T>>>();
+				while schedules.len() > T::MAX_VESTING_SCHEDULES.max(1) as usize {
+					let (Some(schedule2), Some(schedule1)) = (schedules.pop(), schedules.pop())
+					else {
+						break
+					};
+					// Merging linear schedules can't fail.
+					if let Ok(Some(merged)) =
+						Pallet::<T>::merge_schedules_info(now, schedule1, schedule2)
+					{
+						schedules.push(merged);
+					}
+				}
+
+				let v: Option<BoundedVec<ScheduleOf<T>, MaxVestingSchedulesGet<T>>> =
+					schedules.try_into().ok();
+
+				if v.is_none() {
+					log::warn!(
+						target: "runtime::vesting",
+						"migration: Failed to move vesting schedules into a BoundedVec"
+					);
+				}
+
+				v
+			},
+		);
+
+		T::DbWeight::get().reads_writes(reads_writes, reads_writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert_eq!(StorageVersion::<T>::get(), Releases::V2);
+
+		for (_key, schedules) in Vesting::<T>::iter() {
+			assert!(
+				schedules.iter().all(|s| matches!(s, Schedule::Linear(_))),
+				"Only linear schedules are expected after the migration."
+			);
+		}
+
+		log::debug!(
+			target: "runtime::vesting",
+			"migration: Vesting storage version v2 POST migration checks successful!"
+		);
+		Ok(())
+	}
+
+	/// Migrates the vesting schedules to [`Schedule`]s if the storage version is `V1`.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<T>::get() != Releases::V1 {
+				log::info!(
+					target: "runtime::vesting",
+					"migration: Vesting storage version is not v1, skipping v2 migration."
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let weight = migrate::<T>();
+			StorageVersion::<T>::put(Releases::V2);
+			weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			if StorageVersion::<T>::get() == Releases::V1 {
+				pre_migrate::<T>()?;
+			}
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			post_migrate::<T>().map_err(Into::into)
+		}
+	}
+}
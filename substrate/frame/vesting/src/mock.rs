@@ -54,6 +54,7 @@ impl Config for Test {
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	const MAX_VESTING_SCHEDULES: u32 = 3;
+	const MAX_CURVE_POINTS: u32 = 4;
 	type MinVestedTransfer = MinVestedTransfer;
 	type WeightInfo = ();
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
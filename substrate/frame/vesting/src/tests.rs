@@ -41,6 +41,19 @@ where
 	assert!(!<VestingStorage<T>>::contains_key(account));
 }
 
+/// Builds a vesting curve for the test runtime.
+fn curve(
+	locked: u64,
+	starting_block: u64,
+	points: Vec<(u64, u64, Unlock)>,
+) -> VestingCurveOf<Test> {
+	let points = points
+		.into_iter()
+		.map(|(at, locked, unlock)| VestingPoint { at, locked, unlock })
+		.collect::<Vec<_>>();
+	VestingCurve::new(locked, starting_block, points.try_into().unwrap())
+}
+
 #[test]
 fn check_vesting_status() {
 	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
@@ -65,9 +78,18 @@ fn check_vesting_status() {
 			64, // Vesting over 20 blocks
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&1).unwrap(), vec![user1_vesting_schedule]); // Account 1 has a vesting schedule
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![user2_vesting_schedule]); // Account 2 has a vesting schedule
-		assert_eq!(VestingStorage::<Test>::get(&12).unwrap(), vec![user12_vesting_schedule]); // Account 12 has a vesting schedule
+		assert_eq!(
+			VestingStorage::<Test>::get(&1).unwrap(),
+			vec![Schedule::Linear(user1_vesting_schedule)]
+		); // Account 1 has a vesting schedule
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(user2_vesting_schedule)]
+		); // Account 2 has a vesting schedule
+		assert_eq!(
+			VestingStorage::<Test>::get(&12).unwrap(),
+			vec![Schedule::Linear(user12_vesting_schedule)]
+		); // Account 12 has a vesting schedule
 
 		// Account 1 has only 128 units vested from their illiquid ED * 5 units at block 1
 		assert_eq!(Vesting::vesting_balance(&1), Some(128 * 9));
@@ -110,7 +132,7 @@ fn check_vesting_status_for_multi_schedule_account() {
 			10,
 		);
 		// Account 2 already has a vesting schedule.
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
 
 		// Account 2's free balance is from sched0.
 		let free_balance = Balances::free_balance(&2);
@@ -128,7 +150,10 @@ fn check_vesting_status_for_multi_schedule_account() {
 		let free_balance = Balances::free_balance(&2);
 		assert_eq!(free_balance, ED * (10 + 20));
 		// The most recently added schedule exists.
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched1]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched1)]
+		);
 		// sched1 has free funds at block #1, but nothing else.
 		assert_eq!(Vesting::vesting_balance(&2), Some(free_balance - sched1.per_block()));
 
@@ -171,7 +196,10 @@ fn check_vesting_status_for_multi_schedule_account() {
 		assert_eq!(Vesting::vesting_balance(&2), Some(0));
 		// Since we have not called any extrinsics that would unlock funds the schedules
 		// are still in storage,
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched1, sched2]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched1), Schedule::Linear(sched2)]
+		);
 		// but once we unlock the funds, they are removed from storage.
 		vest_and_assert_no_vesting::<Test>(2);
 	});
@@ -207,7 +235,10 @@ fn vested_balance_should_transfer_with_multi_sched() {
 		let sched0 = VestingInfo::new(5 * ED, 128, 0);
 		assert_ok!(Vesting::vested_transfer(Some(13).into(), 1, sched0));
 		// Total 10*ED locked for all the schedules.
-		assert_eq!(VestingStorage::<Test>::get(&1).unwrap(), vec![sched0, sched0]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&1).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched0)]
+		);
 
 		let user1_free_balance = Balances::free_balance(&1);
 		assert_eq!(user1_free_balance, 3840); // Account 1 has free balance
@@ -245,7 +276,10 @@ fn vested_balance_should_transfer_using_vest_other_with_multi_sched() {
 		let sched0 = VestingInfo::new(5 * ED, 128, 0);
 		assert_ok!(Vesting::vested_transfer(Some(13).into(), 1, sched0));
 		// Total of 10*ED of locked for all the schedules.
-		assert_eq!(VestingStorage::<Test>::get(&1).unwrap(), vec![sched0, sched0]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&1).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched0)]
+		);
 
 		let user1_free_balance = Balances::free_balance(&1);
 		assert_eq!(user1_free_balance, 3840); // Account 1 has free balance
@@ -308,7 +342,10 @@ fn liquid_funds_should_transfer_with_delayed_vesting() {
 			64,
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&12).unwrap(), vec![user12_vesting_schedule]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&12).unwrap(),
+			vec![Schedule::Linear(user12_vesting_schedule)]
+		);
 
 		// Account 12 can still send liquid funds
 		assert_ok!(Balances::transfer_allow_death(Some(12).into(), 3, 256 * 5));
@@ -332,7 +369,10 @@ fn vested_transfer_works() {
 		);
 		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, new_vesting_schedule));
 		// Now account 4 should have vesting.
-		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![new_vesting_schedule]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&4).unwrap(),
+			vec![Schedule::Linear(new_vesting_schedule)]
+		);
 		// Ensure the transfer happened correctly.
 		let user3_free_balance_updated = Balances::free_balance(&3);
 		assert_eq!(user3_free_balance_updated, 256 * 25);
@@ -371,7 +411,10 @@ fn vested_transfer_correctly_fails() {
 			ED, // Vesting over 20 blocks
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![user2_vesting_schedule]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(user2_vesting_schedule)]
+		);
 
 		// Fails due to too low transfer amount.
 		let new_vesting_schedule_too_low =
@@ -472,7 +515,10 @@ fn force_vested_transfer_works() {
 			new_vesting_schedule
 		));
 		// Now account 4 should have vesting.
-		assert_eq!(VestingStorage::<Test>::get(&4).unwrap()[0], new_vesting_schedule);
+		assert_eq!(
+			VestingStorage::<Test>::get(&4).unwrap()[0],
+			Schedule::Linear(new_vesting_schedule)
+		);
 		assert_eq!(VestingStorage::<Test>::get(&4).unwrap().len(), 1);
 		// Ensure the transfer happened correctly.
 		let user3_free_balance_updated = Balances::free_balance(&3);
@@ -511,7 +557,10 @@ fn force_vested_transfer_correctly_fails() {
 			ED, // Vesting over 20 blocks
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![user2_vesting_schedule]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(user2_vesting_schedule)]
+		);
 
 		// Too low transfer amount.
 		let new_vesting_schedule_too_low =
@@ -597,12 +646,15 @@ fn merge_schedules_that_have_not_started() {
 			ED, // Vest over 20 blocks.
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
 		assert_eq!(Balances::usable_balance(&2), 0);
 
 		// Add a schedule that is identical to the one that already exists.
 		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, sched0));
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched0]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched0)]
+		);
 		assert_eq!(Balances::usable_balance(&2), 0);
 		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
 
@@ -613,7 +665,7 @@ fn merge_schedules_that_have_not_started() {
 			sched0.per_block() * 2,
 			10, // Starts at the block the schedules are merged/
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched1]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched1)]);
 
 		assert_eq!(Balances::usable_balance(&2), 0);
 	});
@@ -629,7 +681,7 @@ fn merge_ongoing_schedules() {
 			ED, // Vest over 20 blocks.
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
 
 		let sched1 = VestingInfo::new(
 			ED * 10,
@@ -639,7 +691,10 @@ fn merge_ongoing_schedules() {
 			sched0.starting_block() + 5,
 		);
 		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, sched1));
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched1]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched1)]
+		);
 
 		// Got to half way through the second schedule where both schedules are actively vesting.
 		let cur_block = 20;
@@ -671,7 +726,7 @@ fn merge_ongoing_schedules() {
 		let sched2_per_block = sched2_locked / sched2_duration;
 
 		let sched2 = VestingInfo::new(sched2_locked, sched2_per_block, cur_block);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched2]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched2)]);
 
 		// And just to double check, we assert the new merged schedule we be cleaned up as expected.
 		System::set_block_number(30);
@@ -715,7 +770,10 @@ fn merging_shifts_other_schedules_index() {
 		assert_ok!(Vesting::vested_transfer(Some(4).into(), 3, sched2));
 
 		// With no schedules vested or merged they are in the order they are created
-		assert_eq!(VestingStorage::<Test>::get(&3).unwrap(), vec![sched0, sched1, sched2]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&3).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched1), Schedule::Linear(sched2)]
+		);
 		// and the usable balance has not changed.
 		assert_eq!(usable_balance, Balances::usable_balance(&3));
 
@@ -736,7 +794,10 @@ fn merging_shifts_other_schedules_index() {
 		let sched3 = VestingInfo::new(sched3_locked, sched3_per_block, sched3_start);
 
 		// The not touched schedule moves left and the new merged schedule is appended.
-		assert_eq!(VestingStorage::<Test>::get(&3).unwrap(), vec![sched1, sched3]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&3).unwrap(),
+			vec![Schedule::Linear(sched1), Schedule::Linear(sched3)]
+		);
 		// The usable balance hasn't changed since none of the schedules have started.
 		assert_eq!(Balances::usable_balance(&3), usable_balance);
 	});
@@ -753,7 +814,7 @@ fn merge_ongoing_and_yet_to_be_started_schedules() {
 			ED, // Vesting over 20 blocks
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
 
 		// Fast forward to half way through the life of sched1.
 		let mut cur_block =
@@ -805,7 +866,7 @@ fn merge_ongoing_and_yet_to_be_started_schedules() {
 		let sched2_per_block = sched2_locked / sched2_duration;
 
 		let sched2 = VestingInfo::new(sched2_locked, sched2_per_block, sched2_start);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched2]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched2)]);
 	});
 }
 
@@ -820,7 +881,7 @@ fn merge_finished_and_ongoing_schedules() {
 			ED, // Vesting over 20 blocks.
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
 
 		let sched1 = VestingInfo::new(
 			ED * 40,
@@ -839,7 +900,10 @@ fn merge_finished_and_ongoing_schedules() {
 		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, sched2));
 
 		// The schedules are in expected order prior to merging.
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched1, sched2]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched1), Schedule::Linear(sched2)]
+		);
 
 		// Fast forward to sched0's end block.
 		let cur_block = sched0.ending_block_as_balance::<Identity>();
@@ -854,7 +918,10 @@ fn merge_finished_and_ongoing_schedules() {
 		// sched2 is now the first, since sched0 & sched1 get filtered out while "merging".
 		// sched1 gets treated like the new merged schedule by getting pushed onto back
 		// of the vesting schedules vec. Note: sched0 finished at the current block.
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched2, sched1]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched2), Schedule::Linear(sched1)]
+		);
 
 		// sched0 has finished, so its funds are fully unlocked.
 		let sched0_unlocked_now = sched0.locked();
@@ -882,7 +949,7 @@ fn merge_finishing_schedules_does_not_create_a_new_one() {
 			ED, // 20 block duration.
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
 
 		// Create sched1 and transfer it to account 2.
 		let sched1 = VestingInfo::new(
@@ -891,7 +958,10 @@ fn merge_finishing_schedules_does_not_create_a_new_one() {
 			10,
 		);
 		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, sched1));
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched1]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched1)]
+		);
 
 		let all_scheds_end = sched0
 			.ending_block_as_balance::<Identity>()
@@ -924,7 +994,7 @@ fn merge_finished_and_yet_to_be_started_schedules() {
 			ED, // 20 block duration.
 			10, // Ends at block 30
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
 
 		let sched1 = VestingInfo::new(
 			ED * 30,
@@ -932,7 +1002,10 @@ fn merge_finished_and_yet_to_be_started_schedules() {
 			35,
 		);
 		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, sched1));
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched1]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched1)]
+		);
 
 		let sched2 = VestingInfo::new(
 			ED * 40,
@@ -941,7 +1014,10 @@ fn merge_finished_and_yet_to_be_started_schedules() {
 		);
 		// Add a 3rd schedule to demonstrate how sched1 shifts.
 		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, sched2));
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched1, sched2]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched1), Schedule::Linear(sched2)]
+		);
 
 		System::set_block_number(30);
 
@@ -956,7 +1032,10 @@ fn merge_finished_and_yet_to_be_started_schedules() {
 
 		// sched0 is removed since it finished, and sched1 is removed and then pushed on the back
 		// because it is treated as the merged schedule
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched2, sched1]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched2), Schedule::Linear(sched1)]
+		);
 
 		// The usable balance is updated because merging fully unlocked sched0.
 		assert_eq!(Balances::usable_balance(&2), sched0.locked());
@@ -972,7 +1051,7 @@ fn merge_schedules_throws_proper_errors() {
 			ED, // 20 block duration.
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0]);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
 
 		// Account 2 only has 1 vesting schedule.
 		assert_noop!(
@@ -986,7 +1065,10 @@ fn merge_schedules_throws_proper_errors() {
 
 		// There are enough schedules to merge but an index is non-existent.
 		Vesting::vested_transfer(Some(3).into(), 2, sched0).unwrap();
-		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![sched0, sched0]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&2).unwrap(),
+			vec![Schedule::Linear(sched0), Schedule::Linear(sched0)]
+		);
 		assert_noop!(
 			Vesting::merge_schedules(Some(2).into(), 0, 2),
 			Error::<Test>::ScheduleIndexOutOfBounds
@@ -1019,18 +1101,28 @@ fn generates_multiple_schedules_from_genesis_config() {
 		.build()
 		.execute_with(|| {
 			let user1_sched1 = VestingInfo::new(5 * ED, 128, 0u64);
-			assert_eq!(VestingStorage::<Test>::get(&1).unwrap(), vec![user1_sched1]);
+			assert_eq!(
+				VestingStorage::<Test>::get(&1).unwrap(),
+				vec![Schedule::Linear(user1_sched1)]
+			);
 
 			let user2_sched1 = VestingInfo::new(1 * ED, 12, 10u64);
 			let user2_sched2 = VestingInfo::new(2 * ED, 25, 10u64);
-			assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![user2_sched1, user2_sched2]);
+			assert_eq!(
+				VestingStorage::<Test>::get(&2).unwrap(),
+				vec![Schedule::Linear(user2_sched1), Schedule::Linear(user2_sched2)]
+			);
 
 			let user12_sched1 = VestingInfo::new(1 * ED, 12, 10u64);
 			let user12_sched2 = VestingInfo::new(2 * ED, 25, 10u64);
 			let user12_sched3 = VestingInfo::new(3 * ED, 38, 10u64);
 			assert_eq!(
 				VestingStorage::<Test>::get(&12).unwrap(),
-				vec![user12_sched1, user12_sched2, user12_sched3]
+				vec![
+					Schedule::Linear(user12_sched1),
+					Schedule::Linear(user12_sched2),
+					Schedule::Linear(user12_sched3)
+				]
 			);
 		});
 }
@@ -1049,9 +1141,9 @@ fn multiple_schedules_from_genesis_config_errors() {
 }
 
 #[test]
-fn build_genesis_has_storage_version_v1() {
+fn build_genesis_has_storage_version_v2() {
 	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
-		assert_eq!(StorageVersion::<Test>::get(), Releases::V1);
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
 	});
 }
 
@@ -1176,7 +1268,10 @@ fn remove_vesting_schedule() {
 		);
 		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, new_vesting_schedule));
 		// Now account 4 should have vesting.
-		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![new_vesting_schedule]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&4).unwrap(),
+			vec![Schedule::Linear(new_vesting_schedule)]
+		);
 		// Account 4 has 5 * 256 locked.
 		assert_eq!(Vesting::vesting_balance(&4), Some(256 * 5));
 		// Verify only root can call.
@@ -1219,7 +1314,10 @@ fn vested_transfer_impl_works() {
 			(ED * 5) / 20, // Vesting over 20 blocks
 			10,
 		);
-		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![new_vesting_schedule]);
+		assert_eq!(
+			VestingStorage::<Test>::get(&4).unwrap(),
+			vec![Schedule::Linear(new_vesting_schedule)]
+		);
 		// Account 4 has 5 * 256 locked.
 		assert_eq!(Vesting::vesting_balance(&4), Some(256 * 5));
 
@@ -1236,3 +1334,321 @@ fn vested_transfer_impl_works() {
 		);
 	});
 }
+
+#[test]
+fn vesting_curve_validate_works() {
+	use Unlock::*;
+
+	// A cliff followed by a linear unlock.
+	assert!(curve(100, 10, vec![(20, 50, AtOnce), (30, 0, Linear)]).is_valid());
+	// Does not check for min transfer.
+	assert!(curve(1, 10, vec![(11, 0, Linear)]).is_valid());
+
+	// `locked` cannot be 0.
+	assert!(!curve(0, 10, vec![(20, 0, AtOnce)]).is_valid());
+	// There must be at least one point.
+	assert!(!curve(100, 10, vec![]).is_valid());
+	// Everything must be unlocked at the last point.
+	assert!(!curve(100, 10, vec![(20, 50, AtOnce)]).is_valid());
+	// Points must be after the starting block.
+	assert!(!curve(100, 10, vec![(10, 0, AtOnce)]).is_valid());
+	// Points must be sorted.
+	assert!(!curve(100, 10, vec![(30, 50, AtOnce), (20, 0, Linear)]).is_valid());
+	assert!(!curve(100, 10, vec![(20, 50, AtOnce), (20, 0, Linear)]).is_valid());
+	// The locked amount cannot increase.
+	assert!(!curve(100, 10, vec![(20, 150, AtOnce), (30, 0, Linear)]).is_valid());
+	assert!(!curve(100, 10, vec![(20, 50, AtOnce), (25, 60, AtOnce), (30, 0, Linear)]).is_valid());
+}
+
+#[test]
+fn vesting_curve_locked_at_works() {
+	use Unlock::*;
+
+	let schedule = curve(1000, 10, vec![(20, 500, AtOnce), (23, 200, Linear), (30, 0, AtOnce)]);
+	assert_eq!(schedule.ending_block(), 30);
+	// Nothing is unlocked before the starting block.
+	assert_eq!(schedule.locked_at(0), 1000);
+	assert_eq!(schedule.locked_at(10), 1000);
+	// The cliff.
+	assert_eq!(schedule.locked_at(19), 1000);
+	assert_eq!(schedule.locked_at(20), 500);
+	// Linear unlock, rounded up.
+	assert_eq!(schedule.locked_at(21), 400);
+	assert_eq!(schedule.locked_at(22), 300);
+	assert_eq!(schedule.locked_at(23), 200);
+	// The last step.
+	assert_eq!(schedule.locked_at(29), 200);
+	assert_eq!(schedule.locked_at(30), 0);
+	assert_eq!(schedule.locked_at(u64::MAX), 0);
+
+	let schedule = curve(100, 10, vec![(13, 0, Linear)]);
+	assert_eq!(schedule.locked_at(11), 67);
+	assert_eq!(schedule.locked_at(12), 34);
+}
+
+#[test]
+fn vested_transfer_curve_with_cliff_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_eq!(VestingStorage::<Test>::get(&4), None);
+
+		// Half is unlocked at block 20, the rest linearly until block 30.
+		let schedule =
+			curve(ED * 10, 10, vec![(20, ED * 5, Unlock::AtOnce), (30, 0, Unlock::Linear)]);
+		assert_ok!(Vesting::vested_transfer_curve(Some(3).into(), 4, schedule.clone()));
+		assert_eq!(
+			VestingStorage::<Test>::get(&4).unwrap(),
+			vec![Schedule::Curve(schedule.clone())]
+		);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+
+		System::set_block_number(19);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+
+		System::set_block_number(20);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 5));
+		assert_ok!(Vesting::vest(Some(4).into()));
+		System::assert_last_event(Event::VestingUpdated { account: 4, unvested: ED * 5 }.into());
+
+		System::set_block_number(25);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 5 / 2));
+
+		System::set_block_number(30);
+		assert_eq!(Vesting::vesting_balance(&4), Some(0));
+		assert_ok!(Vesting::vest(Some(4).into()));
+		System::assert_last_event(Event::VestingCompleted { account: 4 }.into());
+		assert!(!<VestingStorage<Test>>::contains_key(4));
+
+		// Root can create curve schedules as well.
+		let schedule =
+			curve(ED * 10, 40, vec![(50, ED * 5, Unlock::AtOnce), (60, 0, Unlock::Linear)]);
+		assert_ok!(Vesting::force_vested_transfer_curve(
+			RawOrigin::Root.into(),
+			3,
+			4,
+			schedule.clone()
+		));
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![Schedule::Curve(schedule)]);
+	});
+}
+
+#[test]
+fn vested_transfer_curve_with_steps_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// A quarter is unlocked every 10 blocks.
+		let schedule = curve(
+			ED * 4,
+			10,
+			vec![
+				(20, ED * 3, Unlock::AtOnce),
+				(30, ED * 2, Unlock::AtOnce),
+				(40, ED, Unlock::AtOnce),
+				(50, 0, Unlock::AtOnce),
+			],
+		);
+		assert_ok!(Vesting::vested_transfer_curve(Some(3).into(), 4, schedule));
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 4));
+
+		System::set_block_number(29);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 3));
+
+		System::set_block_number(35);
+		assert_ok!(Vesting::vest(Some(4).into()));
+		System::assert_last_event(Event::VestingUpdated { account: 4, unvested: ED * 2 }.into());
+		assert_eq!(Balances::usable_balance(&4), ED * 40 + ED * 2);
+
+		System::set_block_number(50);
+		assert_ok!(Vesting::vest(Some(4).into()));
+		System::assert_last_event(Event::VestingCompleted { account: 4 }.into());
+	});
+}
+
+#[test]
+fn vested_transfer_curve_correctly_fails() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let min_transfer = <Test as Config>::MinVestedTransfer::get();
+
+		// Fails due to too low transfer amount.
+		let schedule = curve(min_transfer - 1, 10, vec![(20, 0, Unlock::AtOnce)]);
+		assert_noop!(
+			Vesting::vested_transfer_curve(Some(3).into(), 4, schedule),
+			Error::<Test>::AmountLow,
+		);
+
+		// Fails due to an invalid curve.
+		let schedule = curve(min_transfer, 10, vec![(20, 1, Unlock::AtOnce)]);
+		assert_noop!(
+			Vesting::vested_transfer_curve(Some(3).into(), 4, schedule.clone()),
+			Error::<Test>::InvalidScheduleParams,
+		);
+		assert_noop!(
+			Vesting::force_vested_transfer_curve(RawOrigin::Root.into(), 3, 4, schedule),
+			Error::<Test>::InvalidScheduleParams,
+		);
+
+		// Only root can force a transfer.
+		let schedule = curve(min_transfer, 10, vec![(20, 0, Unlock::AtOnce)]);
+		assert_noop!(
+			Vesting::force_vested_transfer_curve(Some(4).into(), 3, 4, schedule),
+			BadOrigin,
+		);
+	});
+}
+
+#[test]
+fn merge_linear_and_curve_schedules() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Account 2 vests `ED * 20` from block 10 to block 30 since genesis.
+		let sched0 = VestingInfo::new(ED * 20, ED, 10);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Linear(sched0)]);
+
+		// A cliff unlocking everything at block 20.
+		let sched1 = curve(ED * 4, 10, vec![(20, 0, Unlock::AtOnce)]);
+		assert_ok!(Vesting::vested_transfer_curve(Some(3).into(), 2, sched1.clone()));
+
+		System::set_block_number(15);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 15 + ED * 4));
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+
+		// The merged curve keeps unlocking linearly until the cliff.
+		let merged = curve(
+			ED * 19,
+			15,
+			vec![
+				(19, ED * 15, Unlock::Linear),
+				(20, ED * 10, Unlock::AtOnce),
+				(30, 0, Unlock::Linear),
+			],
+		);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Curve(merged.clone())]);
+		System::assert_last_event(Event::VestingUpdated { account: 2, unvested: ED * 19 }.into());
+
+		// The merged curve locks what both schedules would have locked.
+		for n in 15..=30 {
+			assert_eq!(
+				merged.locked_at(n),
+				sched0.locked_at::<Identity>(n) + sched1.locked_at(n),
+				"Unexpected locked amount at block {}",
+				n
+			);
+		}
+
+		// Merging with a finished schedule keeps the curve as is.
+		let sched2 = VestingInfo::new(ED * 2, ED, 16);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, sched2));
+		System::set_block_number(18);
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![Schedule::Curve(merged)]);
+	});
+}
+
+#[test]
+fn merge_curves_with_too_many_points_fails() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let steps = |start: u64| {
+			curve(
+				ED * 3,
+				start,
+				vec![
+					(start + 10, ED * 2, Unlock::AtOnce),
+					(start + 20, ED, Unlock::AtOnce),
+					(start + 30, 0, Unlock::AtOnce),
+				],
+			)
+		};
+		assert_ok!(Vesting::vested_transfer_curve(Some(3).into(), 4, steps(10)));
+		assert_ok!(Vesting::vested_transfer_curve(Some(3).into(), 4, steps(15)));
+
+		assert_noop!(
+			Vesting::merge_schedules(Some(4).into(), 0, 1),
+			Error::<Test>::TooManyCurvePoints
+		);
+	});
+}
+
+#[test]
+fn remove_curve_schedule() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let schedule = curve(ED * 5, 10, vec![(20, 0, Unlock::AtOnce)]);
+		assert_ok!(Vesting::vested_transfer_curve(Some(3).into(), 4, schedule));
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 5));
+
+		assert_ok!(Vesting::force_remove_vesting_schedule(RawOrigin::Root.into(), 4, 0));
+		System::assert_last_event(Event::VestingCompleted { account: 4 }.into());
+		assert!(!<VestingStorage<Test>>::contains_key(4));
+		assert_eq!(Vesting::vesting_balance(&4), None);
+	});
+}
+
+#[test]
+fn migrate_to_v2_works() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default()
+		.existential_deposit(ED)
+		.vesting_genesis_config(vec![])
+		.build()
+		.execute_with(|| {
+			let sched0 = VestingInfo::new(ED * 5, 128, 0u64);
+			let sched1 = VestingInfo::new(ED, 12, 10u64);
+			// Write the schedules of account 4 with the v1 layout.
+			frame_support::storage::unhashed::put(
+				&VestingStorage::<Test>::hashed_key_for(&4),
+				&vec![sched0, sched1],
+			);
+			StorageVersion::<Test>::put(Releases::V1);
+
+			migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+			assert_eq!(
+				VestingStorage::<Test>::get(&4).unwrap(),
+				vec![Schedule::Linear(sched0), Schedule::Linear(sched1)]
+			);
+
+			// Running the migration again is a no-op.
+			assert_storage_noop!(migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade());
+		});
+}
+
+#[test]
+fn migrate_to_v2_merges_schedules_beyond_the_bound() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default()
+		.existential_deposit(ED)
+		.vesting_genesis_config(vec![])
+		.build()
+		.execute_with(|| {
+			let max_schedules = <Test as Config>::MAX_VESTING_SCHEDULES;
+			let schedules = (0..max_schedules + 2)
+				.map(|i| VestingInfo::new(ED * 10, ED, 10 * i as u64))
+				.collect::<Vec<_>>();
+			// Write more schedules than fit in the new layout for account 4.
+			frame_support::storage::unhashed::put(
+				&VestingStorage::<Test>::hashed_key_for(&4),
+				&schedules,
+			);
+			StorageVersion::<Test>::put(Releases::V1);
+
+			migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+			let now = System::block_number();
+			let mut expected = schedules
+				.iter()
+				.copied()
+				.map(Schedule::Linear)
+				.collect::<Vec<ScheduleOf<Test>>>();
+			while expected.len() > max_schedules as usize {
+				let (schedule2, schedule1) = (expected.pop().unwrap(), expected.pop().unwrap());
+				expected.push(
+					Vesting::merge_schedules_info(now, schedule1, schedule2).unwrap().unwrap(),
+				);
+			}
+			assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), expected);
+			// Nothing is unlocked by the migration.
+			assert_eq!(
+				Vesting::vesting_balance(&4),
+				Some(schedules.iter().map(|s| s.locked_at::<Identity>(now)).sum())
+			);
+		});
+}
@@ -15,9 +15,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Module to enforce private fields on `VestingInfo`.
+//! Module to enforce private fields on `VestingInfo` and `VestingCurve`.
 
 use super::*;
+use frame_support::{CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, Rounding, SaturatedConversion,
+};
 
 /// Struct to encode the vesting schedule of an individual account.
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -112,3 +116,272 @@ where
 		starting_block.saturating_add(duration)
 	}
 }
+
+/// How the amount released by a [`VestingPoint`] is unlocked since the previous point.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Unlock {
+	/// The amount is unlocked block by block, linearly, until the point is reached.
+	Linear,
+	/// The amount is unlocked at once when the point is reached.
+	AtOnce,
+}
+
+/// A point of a [`VestingCurve`].
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingPoint<Balance, BlockNumber> {
+	/// The block at which the point is reached.
+	pub at: BlockNumber,
+	/// The amount still locked once the point is reached.
+	pub locked: Balance,
+	/// How the amount between the previous point and this one is unlocked.
+	pub unlock: Unlock,
+}
+
+/// Struct to encode a vesting schedule following a piecewise curve.
+///
+/// Nothing is unlocked before `starting_block`. From there, the curve goes through each of its
+/// points in turn. For example:
+/// - a cliff followed by a linear unlock is an [`Unlock::AtOnce`] point at the cliff, followed by
+///   an [`Unlock::Linear`] point at the end of the schedule.
+/// - quarterly unlocks are a sequence of [`Unlock::AtOnce`] points.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(Balance: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxPoints))]
+pub struct VestingCurve<Balance, BlockNumber, MaxPoints>
+where
+	Balance: Clone + PartialEq + Eq + Debug,
+	BlockNumber: Clone + PartialEq + Eq + Debug,
+	MaxPoints: Get<u32>,
+{
+	/// Locked amount at schedule creation.
+	locked: Balance,
+	/// Starting block for unlocking(vesting).
+	starting_block: BlockNumber,
+	/// The points of the curve, sorted by block number.
+	points: BoundedVec<VestingPoint<Balance, BlockNumber>, MaxPoints>,
+}
+
+impl<Balance, BlockNumber, MaxPoints> VestingCurve<Balance, BlockNumber, MaxPoints>
+where
+	Balance: AtLeast32BitUnsigned + Copy + Debug,
+	BlockNumber: AtLeast32BitUnsigned + Copy + Bounded + Debug,
+	MaxPoints: Get<u32>,
+{
+	/// Instantiate a new `VestingCurve`.
+	pub fn new(
+		locked: Balance,
+		starting_block: BlockNumber,
+		points: BoundedVec<VestingPoint<Balance, BlockNumber>, MaxPoints>,
+	) -> Self {
+		VestingCurve { locked, starting_block, points }
+	}
+
+	/// Validate parameters for `VestingCurve`. Note that this does not check against
+	/// `MinVestedTransfer`.
+	///
+	/// The points must be strictly after `starting_block` and sorted by block number, the locked
+	/// amount must never increase, and nothing must be locked at the last point.
+	pub fn is_valid(&self) -> bool {
+		let Some(last) = self.points.last() else { return false };
+		let mut previous = (self.starting_block, self.locked);
+		for point in self.points.iter() {
+			if point.at <= previous.0 || point.locked > previous.1 {
+				return false
+			}
+			previous = (point.at, point.locked);
+		}
+
+		!self.locked.is_zero() && last.locked.is_zero()
+	}
+
+	/// Locked amount at schedule creation.
+	pub fn locked(&self) -> Balance {
+		self.locked
+	}
+
+	/// Starting block for unlocking(vesting).
+	pub fn starting_block(&self) -> BlockNumber {
+		self.starting_block
+	}
+
+	/// The points of the curve, sorted by block number.
+	pub fn points(&self) -> &[VestingPoint<Balance, BlockNumber>] {
+		&self.points
+	}
+
+	/// Block number at which the schedule ends.
+	pub fn ending_block(&self) -> BlockNumber {
+		self.points.last().map_or(self.starting_block, |point| point.at)
+	}
+
+	/// Amount locked at block `n`.
+	pub fn locked_at(&self, n: BlockNumber) -> Balance {
+		let mut previous = (self.starting_block, self.locked);
+		for point in self.points.iter() {
+			if n < point.at {
+				return match point.unlock {
+					Unlock::AtOnce => previous.1,
+					Unlock::Linear => Self::interpolate(previous, (point.at, point.locked), n),
+				}
+			}
+			previous = (point.at, point.locked);
+		}
+
+		previous.1
+	}
+
+	/// Whether the amount of the point at block `n`, if any, is unlocked at once.
+	pub(crate) fn unlocks_at_once_at(&self, n: BlockNumber) -> bool {
+		self.points.iter().any(|point| point.at == n && point.unlock == Unlock::AtOnce)
+	}
+
+	/// Amount locked at block `n`, between the points `from` and `to` of a linear segment.
+	///
+	/// Rounds up, such that the curve never unlocks more than the exact linear interpolation.
+	fn interpolate(
+		from: (BlockNumber, Balance),
+		to: (BlockNumber, Balance),
+		n: BlockNumber,
+	) -> Balance {
+		if n <= from.0 {
+			return from.1
+		}
+
+		let remaining_blocks: u128 = to.0.saturating_sub(n).saturated_into();
+		let duration: u128 = to.0.saturating_sub(from.0).saturated_into();
+		let to_unlock: u128 = from.1.saturating_sub(to.1).saturated_into();
+		let still_locked =
+			multiply_by_rational_with_rounding(to_unlock, remaining_blocks, duration, Rounding::Up)
+				.map(|amount| amount.saturated_into())
+				// Only fails if `to_unlock * remaining_blocks / duration` overflows, which is not
+				// possible since `remaining_blocks <= duration`.
+				.unwrap_or(from.1.saturating_sub(to.1));
+
+		to.1.saturating_add(still_locked)
+	}
+}
+
+/// A vesting schedule, as stored for an account.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(Balance: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxPoints))]
+pub enum Schedule<Balance, BlockNumber, MaxPoints>
+where
+	Balance: Copy + PartialEq + Eq + Debug,
+	BlockNumber: Copy + PartialEq + Eq + Debug,
+	MaxPoints: Get<u32>,
+{
+	/// A schedule unlocking the same amount every block.
+	Linear(VestingInfo<Balance, BlockNumber>),
+	/// A schedule following a piecewise curve.
+	Curve(VestingCurve<Balance, BlockNumber, MaxPoints>),
+}
+
+impl<Balance, BlockNumber, MaxPoints> From<VestingInfo<Balance, BlockNumber>>
+	for Schedule<Balance, BlockNumber, MaxPoints>
+where
+	Balance: Copy + PartialEq + Eq + Debug,
+	BlockNumber: Copy + PartialEq + Eq + Debug,
+	MaxPoints: Get<u32>,
+{
+	fn from(info: VestingInfo<Balance, BlockNumber>) -> Self {
+		Schedule::Linear(info)
+	}
+}
+
+impl<Balance, BlockNumber, MaxPoints> Schedule<Balance, BlockNumber, MaxPoints>
+where
+	Balance: AtLeast32BitUnsigned + Copy + Debug,
+	BlockNumber: AtLeast32BitUnsigned + Copy + Bounded + Debug,
+	MaxPoints: Get<u32>,
+{
+	/// Validate parameters of the schedule. Note that this does not check against
+	/// `MinVestedTransfer`.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Schedule::Linear(info) => info.is_valid(),
+			Schedule::Curve(curve) => curve.is_valid(),
+		}
+	}
+
+	/// Locked amount at schedule creation.
+	pub fn locked(&self) -> Balance {
+		match self {
+			Schedule::Linear(info) => info.locked(),
+			Schedule::Curve(curve) => curve.locked(),
+		}
+	}
+
+	/// Starting block for unlocking(vesting).
+	pub fn starting_block(&self) -> BlockNumber {
+		match self {
+			Schedule::Linear(info) => info.starting_block(),
+			Schedule::Curve(curve) => curve.starting_block(),
+		}
+	}
+
+	/// Amount locked at block `n`.
+	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+		n: BlockNumber,
+	) -> Balance {
+		match self {
+			Schedule::Linear(info) => info.locked_at::<BlockNumberToBalance>(n),
+			Schedule::Curve(curve) => curve.locked_at(n),
+		}
+	}
+
+	/// Block number at which the schedule ends (as type `Balance`).
+	pub fn ending_block_as_balance<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+	) -> Balance {
+		match self {
+			Schedule::Linear(info) => info.ending_block_as_balance::<BlockNumberToBalance>(),
+			Schedule::Curve(curve) => BlockNumberToBalance::convert(curve.ending_block()),
+		}
+	}
+
+	/// The blocks at which the unlocking pace of the schedule may change.
+	pub(crate) fn breakpoints<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+	) -> Vec<BlockNumber> {
+		match self {
+			Schedule::Linear(info) => {
+				let ending_block = info
+					.ending_block_as_balance::<BlockNumberToBalance>()
+					.saturated_into::<u128>()
+					.saturated_into();
+				alloc::vec![info.starting_block(), ending_block]
+			},
+			Schedule::Curve(curve) => core::iter::once(curve.starting_block())
+				.chain(curve.points().iter().map(|point| point.at))
+				.collect(),
+		}
+	}
+
+	/// Whether the schedule unlocks an amount at once at block `n`.
+	pub(crate) fn unlocks_at_once_at(&self, n: BlockNumber) -> bool {
+		match self {
+			Schedule::Linear(_) => false,
+			Schedule::Curve(curve) => curve.unlocks_at_once_at(n),
+		}
+	}
+}
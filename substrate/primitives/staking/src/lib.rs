@@ -315,6 +315,9 @@ pub trait StakingInterface {
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn set_current_era(era: EraIndex);
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_slashing_spans(who: &Self::AccountId, spans: u32);
 }
 
 /// Set of low level apis to manipulate staking ledger.
@@ -463,6 +466,27 @@ pub struct PagedExposureMetadata<Balance: HasCompact + codec::MaxEncodedLen> {
 	pub page_count: Page,
 }
 
+/// Projected reward of a nominator backing a single validator.
+///
+/// The projection is based on the exposure and reward points of the validator in a past era, as
+/// if the nominator had been part of its exposure.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RewardProjection<AccountId, Balance> {
+	/// The validator backed by the nominator.
+	pub validator: AccountId,
+	/// The stake of the nominator exposed to the validator.
+	pub stake: Balance,
+	/// The projected reward of the nominator for a single era.
+	pub reward: Balance,
+	/// The page of the validator exposure the nominator would be part of.
+	pub page: Page,
+	/// Number of pages of nominators of the validator, including the nominator.
+	///
+	/// Each page needs to be paid out separately, so a nominator overflowing into a new page
+	/// adds one payout to the validator.
+	pub page_count: Page,
+}
+
 /// A type that belongs only in the context of an `Agent`.
 ///
 /// `Agent` is someone that manages delegated funds from [`Delegator`] accounts. It can
@@ -561,6 +585,16 @@ pub trait DelegationInterface {
 		num_slashing_spans: u32,
 	) -> DispatchResult;
 
+	/// Transfer `amount` of the delegation of `from` to `to`.
+	///
+	/// The funds stay delegated to the same `Agent`, and are moved to the account of `to`. `to`
+	/// must either not be a delegator yet or delegate to the same `Agent`.
+	fn transfer_delegation(
+		from: Delegator<Self::AccountId>,
+		to: Delegator<Self::AccountId>,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
 	/// Returns pending slashes posted to the `Agent` account. None if not an `Agent`.
 	///
 	/// Slashes to `Agent` account are not immediate and are applied lazily. Since `Agent`
@@ -335,6 +335,13 @@ impl CliConfiguration<Self> for RelayChainCli {
 		self.base.base.transaction_pool(is_dev)
 	}
 
+	fn transaction_pool_journal(
+		&self,
+		config_dir: &std::path::PathBuf,
+	) -> Result<Option<sc_service::config::TransactionPoolJournalOptions>> {
+		self.base.base.transaction_pool_journal(config_dir)
+	}
+
 	fn trie_cache_maximum_size(&self) -> Result<Option<usize>> {
 		self.base.base.trie_cache_maximum_size()
 	}
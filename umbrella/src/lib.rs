@@ -419,6 +419,10 @@ pub use pallet_contracts_uapi;
 #[cfg(feature = "pallet-conviction-voting")]
 pub use pallet_conviction_voting;
 
+/// Runtime API for conviction-voting FRAME pallet.
+#[cfg(feature = "pallet-conviction-voting-runtime-api")]
+pub use pallet_conviction_voting_runtime_api;
+
 /// Logic as per the description of The Fellowship for core Polkadot technology.
 #[cfg(feature = "pallet-core-fellowship")]
 pub use pallet_core_fellowship;
@@ -435,6 +439,10 @@ pub use pallet_democracy;
 #[cfg(feature = "pallet-dev-mode")]
 pub use pallet_dev_mode;
 
+/// PALLET paged, multi-block election provider.
+#[cfg(feature = "pallet-election-provider-multi-block")]
+pub use pallet_election_provider_multi_block;
+
 /// PALLET two phase election providers.
 #[cfg(feature = "pallet-election-provider-multi-phase")]
 pub use pallet_election_provider_multi_phase;